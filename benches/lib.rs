@@ -10,12 +10,19 @@ use criterion::{
     criterion_group,
     criterion_main,
 };
+// Only the throughput groups report per-byte rates
+#[cfg(any(feature = "hash", feature = "hpke"))]
+use criterion::{
+    BenchmarkId,
+    Throughput,
+};
 // These imports are used within conditional compilation blocks
 #[allow(unused_imports)]
 use libq::{
     Algorithm,
     KemContext,
-    LibQCryptoProvider,
+    LibQKemProvider,
+    LibQSignatureProvider,
     SignatureContext,
 };
 
@@ -25,7 +32,7 @@ fn bench_key_generation(c: &mut Criterion) {
 
     #[cfg(feature = "ml-dsa")]
     {
-        let provider = Box::new(LibQCryptoProvider::new().unwrap());
+        let provider = Box::new(LibQSignatureProvider::new().unwrap());
         let mut context = SignatureContext::with_provider(provider);
 
         group.bench_function("ml-dsa-65", |b| {
@@ -37,7 +44,7 @@ fn bench_key_generation(c: &mut Criterion) {
 
     #[cfg(feature = "ml-kem")]
     {
-        let provider = Box::new(LibQCryptoProvider::new().unwrap());
+        let provider = Box::new(LibQKemProvider::new().unwrap());
         let mut context = KemContext::with_provider(provider);
 
         group.bench_function("ml-kem-768", |b| {
@@ -56,7 +63,7 @@ fn bench_signing(c: &mut Criterion) {
 
     #[cfg(feature = "ml-dsa")]
     {
-        let provider = Box::new(LibQCryptoProvider::new().unwrap());
+        let provider = Box::new(LibQSignatureProvider::new().unwrap());
         let mut context = SignatureContext::with_provider(provider);
         let keypair = context.generate_keypair(Algorithm::MlDsa65, None).unwrap();
         let message = black_box(b"Hello, world! This is a test message for benchmarking.");
@@ -79,7 +86,7 @@ fn bench_verification(c: &mut Criterion) {
 
     #[cfg(feature = "ml-dsa")]
     {
-        let provider = Box::new(LibQCryptoProvider::new().unwrap());
+        let provider = Box::new(LibQSignatureProvider::new().unwrap());
         let mut context = SignatureContext::with_provider(provider);
         let keypair = context.generate_keypair(Algorithm::MlDsa65, None).unwrap();
         let message = black_box(b"Hello, world! This is a test message for benchmarking.");
@@ -105,7 +112,7 @@ fn bench_encapsulation(c: &mut Criterion) {
 
     #[cfg(feature = "ml-kem")]
     {
-        let provider = Box::new(LibQCryptoProvider::new().unwrap());
+        let provider = Box::new(LibQKemProvider::new().unwrap());
         let mut context = KemContext::with_provider(provider);
         let public_key = context
             .generate_keypair(Algorithm::MlKem768, None)
@@ -130,7 +137,7 @@ fn bench_decapsulation(c: &mut Criterion) {
 
     #[cfg(feature = "ml-kem")]
     {
-        let provider = Box::new(LibQCryptoProvider::new().unwrap());
+        let provider = Box::new(LibQKemProvider::new().unwrap());
         let mut context = KemContext::with_provider(provider);
         let keypair = context.generate_keypair(Algorithm::MlKem768, None).unwrap();
         let (ciphertext, _shared_secret) = context
@@ -149,12 +156,100 @@ fn bench_decapsulation(c: &mut Criterion) {
     group.finish();
 }
 
+/// Input sizes for the throughput groups: one small message and one bulk payload
+#[cfg(any(feature = "hash", feature = "hpke"))]
+const THROUGHPUT_SIZES: [(&str, usize); 2] = [("1KB", 1024), ("1MB", 1024 * 1024)];
+
+fn bench_hash_throughput(c: &mut Criterion) {
+    #[allow(unused_mut)]
+    let mut group = c.benchmark_group("hash_throughput");
+
+    #[cfg(feature = "hash")]
+    {
+        let mut context = libq::create_hash_context();
+
+        for algorithm in [
+            Algorithm::Sha3_256,
+            Algorithm::Sha3_512,
+            Algorithm::Shake128,
+            Algorithm::Shake256,
+            Algorithm::Kt128,
+        ] {
+            for (label, size) in THROUGHPUT_SIZES {
+                let data = vec![0xA5u8; size];
+                group.throughput(Throughput::Bytes(size as u64));
+                group.bench_with_input(
+                    BenchmarkId::new(algorithm.to_string(), label),
+                    &data,
+                    |b, data| {
+                        b.iter(|| {
+                            let _digest = context.hash(algorithm, black_box(data)).unwrap();
+                        });
+                    },
+                );
+            }
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_hpke_seal(c: &mut Criterion) {
+    #[allow(unused_mut)]
+    let mut group = c.benchmark_group("hpke_seal");
+
+    #[cfg(feature = "hpke")]
+    {
+        use lib_q_hpke::{
+            HpkeAead,
+            HpkeCipherSuite,
+            HpkeContext,
+            HpkeKdf,
+            HpkeKem,
+        };
+
+        let provider = Box::new(LibQKemProvider::new().unwrap());
+        let mut kem_context = KemContext::with_provider(provider);
+        let public_key = kem_context
+            .generate_keypair(Algorithm::MlKem768, None)
+            .unwrap()
+            .public_key;
+
+        let mut hpke = HpkeContext::new();
+        hpke.set_cipher_suite(HpkeCipherSuite::new(
+            HpkeKem::MlKem768,
+            HpkeKdf::HkdfShake256,
+            HpkeAead::Saturnin256,
+        ));
+
+        for (label, size) in THROUGHPUT_SIZES {
+            let plaintext = vec![0x5Au8; size];
+            group.throughput(Throughput::Bytes(size as u64));
+            group.bench_with_input(
+                BenchmarkId::new("ml-kem-768/saturnin", label),
+                &plaintext,
+                |b, plaintext| {
+                    b.iter(|| {
+                        let _sealed = hpke
+                            .seal(&public_key, b"bench", b"", black_box(plaintext))
+                            .unwrap();
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_key_generation,
     bench_signing,
     bench_verification,
     bench_encapsulation,
-    bench_decapsulation
+    bench_decapsulation,
+    bench_hash_throughput,
+    bench_hpke_seal
 );
 criterion_main!(benches);
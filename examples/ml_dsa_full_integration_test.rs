@@ -6,7 +6,10 @@
 //!
 //! Run: `cargo run -p lib-q-examples --example ml_dsa_full_integration_test`
 
-use lib_q_core::Signature;
+use lib_q_core::{
+    SecretExportConsent,
+    Signature,
+};
 use lib_q_ml_dsa::constants::{
     KEY_GENERATION_RANDOMNESS_SIZE,
     SIGNING_RANDOMNESS_SIZE,
@@ -46,7 +49,10 @@ fn test_high_level_api() -> Result<(), Box<dyn std::error::Error>> {
     );
     println!(
         "   📊 Secret key size: {} bytes",
-        keypair.secret_key().as_bytes().len()
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len()
     );
 
     // Sign message with automatic randomness
@@ -6,7 +6,6 @@
 use std::time::Instant;
 
 use lib_q_aead::security::constant_time::constant_time_eq;
-use lib_q_aead::security::memory::secure_zero_slice;
 use lib_q_aead::security::timing::{
     TimingProtection,
     protect_timing,
@@ -25,6 +24,8 @@ use lib_q_aead::{
     Nonce,
     create_aead,
 };
+use lib_q_core::SecretExportConsent;
+use zeroize::Zeroize;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("lib-q-aead Security Features Example");
@@ -133,7 +134,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("-------------------");
 
     // Test valid inputs
-    validate_key(key.as_bytes())?;
+    validate_key(key.expose_secret(SecretExportConsent))?;
     validate_nonce(nonce.as_bytes())?;
     println!("✓ Valid key and nonce passed validation");
 
@@ -167,7 +168,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("✓ Created sensitive data: {} bytes", sensitive_data.len());
 
     // Securely zero the data
-    secure_zero_slice(&mut sensitive_data);
+    sensitive_data.as_mut_slice().zeroize();
 
     // Verify it's zeroed
     let is_zeroed = sensitive_data.iter().all(|&b| b == 0);
@@ -0,0 +1,95 @@
+//! Canonical encoding of multi-field associated data
+//!
+//! Concatenating several metadata fields into one AAD string is ambiguous: `"ab" || "c"` and
+//! `"a" || "bc"` authenticate the same bytes. [`AssociatedData`] frames every field with
+//! [`lib_q_core::codec::write_frame`], so distinct field sequences always encode differently.
+
+use alloc::vec::Vec;
+
+use lib_q_core::{
+    Result,
+    codec,
+};
+
+/// Builder for length-prefixed associated data
+///
+/// Each field is encoded as `length (u32 BE) || bytes`, in the order added (the
+/// [`lib_q_core::codec`] framing). Pass [`finish`](Self::finish) (or
+/// [`as_bytes`](Self::as_bytes)) as the `associated_data` argument of
+/// [`Aead::encrypt`](crate::Aead::encrypt) / [`Aead::decrypt`](crate::Aead::decrypt); both
+/// sides must build the AAD from the same fields in the same order. Raw AAD keeps working for
+/// single-field or fixed-layout metadata.
+///
+/// ```rust
+/// use lib_q_aead::AssociatedData;
+///
+/// let aad = AssociatedData::new()
+///     .add_field(b"invoice")?
+///     .add_field(b"2026-10-16")?
+///     .finish();
+/// assert_eq!(aad.len(), 4 + 7 + 4 + 10);
+/// # Ok::<(), lib_q_core::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssociatedData {
+    encoded: Vec<u8>,
+}
+
+impl AssociatedData {
+    /// Start an empty field list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append one field; empty fields are encoded too
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::InvalidMessageSize`] if `field` is longer than
+    /// `u32::MAX` bytes.
+    pub fn add_field(mut self, field: &[u8]) -> Result<Self> {
+        codec::write_frame(&mut self.encoded, &[field])?;
+        Ok(self)
+    }
+
+    /// The canonical encoding of the fields added so far
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.encoded
+    }
+
+    /// Consume the builder and return the canonical encoding
+    pub fn finish(self) -> Vec<u8> {
+        self.encoded
+    }
+}
+
+impl AsRef<[u8]> for AssociatedData {
+    fn as_ref(&self) -> &[u8] {
+        &self.encoded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_boundaries_are_encoded() {
+        let build = |fields: &[&[u8]]| {
+            fields
+                .iter()
+                .try_fold(AssociatedData::new(), |aad, f| aad.add_field(f))
+                .unwrap()
+        };
+        let ab_c = build(&[b"ab", b"c"]);
+        let a_bc = build(&[b"a", b"bc"]);
+        let abc = build(&[b"abc"]);
+        let abc_empty = build(&[b"abc", b""]);
+        assert_ne!(ab_c, a_bc);
+        assert_ne!(ab_c.as_bytes(), abc.as_bytes());
+        assert_ne!(abc, abc_empty);
+        assert!(AssociatedData::new().finish().is_empty());
+
+        assert_eq!(abc.finish(), [&[0, 0, 0, 3][..], b"abc"].concat());
+    }
+}
@@ -18,6 +18,8 @@ use alloc::{
 };
 
 // Re-export types: algorithm IDs from `lib-q-types`; crypto API from `lib-q-core`
+#[cfg(feature = "alloc")]
+pub use lib_q_core::StreamingAead;
 pub use lib_q_core::{
     Aead,
     AeadKey,
@@ -30,12 +32,16 @@ pub use lib_q_types::{
 };
 
 // Internal modules
+#[cfg(feature = "alloc")]
+mod associated_data;
 mod metadata;
 mod plugin;
 mod registry;
 pub mod security;
 
 // Re-export public API
+#[cfg(feature = "alloc")]
+pub use associated_data::AssociatedData;
 pub use metadata::{
     AeadMetadata,
     AeadWithMetadata,
@@ -48,6 +54,7 @@ pub use plugin::{
 pub use registry::AeadRegistry;
 // Re-export security sub-modules for testing
 pub use security::constant_time;
+pub use security::nonce::NonceTracker;
 // Re-export security API
 pub use security::{
     SecurityConfig,
@@ -70,7 +77,7 @@ mod provider;
 pub use provider::LibQAeadProvider;
 
 #[cfg(feature = "wasm")]
-mod wasm;
+pub mod wasm;
 
 // Macro is exported at crate root via #[macro_export] in plugin.rs
 
@@ -87,6 +94,8 @@ mod romulus_n;
 mod saturnin;
 #[cfg(feature = "shake256")]
 mod shake256;
+#[cfg(feature = "chacha20poly1305")]
+pub mod streaming;
 #[cfg(feature = "tweak-aead")]
 mod tweak_aead;
 
@@ -103,6 +112,8 @@ pub use romulus_n::RomulusNAead;
 pub use saturnin::SaturninAead;
 #[cfg(feature = "shake256")]
 pub use shake256::Shake256Aead;
+#[cfg(feature = "chacha20poly1305")]
+pub use streaming::ChaCha20Poly1305Stream;
 #[cfg(feature = "tweak-aead")]
 pub use tweak_aead::TweakAead;
 
@@ -348,6 +359,7 @@ mod tests {
                 assert_eq!(meta.algorithm, algorithm);
                 assert!(meta.key_size > 0);
                 assert!(meta.nonce_size > 0);
+                assert_eq!(algorithm.nonce_len(), Some(meta.nonce_size));
                 assert!(meta.tag_size > 0);
             }
         }
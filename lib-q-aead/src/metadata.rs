@@ -9,6 +9,7 @@ use lib_q_core::{
     Algorithm,
     Nonce,
     Result,
+    SecretExportConsent,
 };
 
 /// Performance tier for AEAD algorithms
@@ -165,10 +166,13 @@ pub trait AeadWithMetadata: Aead {
 
     /// Validate key size
     fn validate_key(&self, key: &AeadKey) -> Result<()> {
-        if !self.metadata().validate_key_size(key.as_bytes().len()) {
+        if !self
+            .metadata()
+            .validate_key_size(key.expose_secret(SecretExportConsent).len())
+        {
             return Err(lib_q_core::Error::InvalidKeySize {
                 expected: self.key_size(),
-                actual: key.as_bytes().len(),
+                actual: key.expose_secret(SecretExportConsent).len(),
             });
         }
         Ok(())
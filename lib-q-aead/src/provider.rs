@@ -8,6 +8,7 @@ extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+use lib_q_core::SecretExportConsent;
 #[cfg(feature = "alloc")]
 use lib_q_core::api::{
     AeadOperations,
@@ -78,7 +79,7 @@ impl AeadOperations for LibQAeadProvider {
         self.security_validator
             .validate_algorithm_category(algorithm, AlgorithmCategory::Aead)?;
         self.security_validator
-            .validate_key_material(key.as_bytes())?;
+            .validate_key_material(key.expose_secret(SecretExportConsent))?;
         self.security_validator.validate_nonce(nonce.as_bytes())?;
         self.security_validator.validate_aead_message(plaintext)?;
         if let Some(ad) = associated_data {
@@ -100,7 +101,7 @@ impl AeadOperations for LibQAeadProvider {
         self.security_validator
             .validate_algorithm_category(algorithm, AlgorithmCategory::Aead)?;
         self.security_validator
-            .validate_key_material(key.as_bytes())?;
+            .validate_key_material(key.expose_secret(SecretExportConsent))?;
         self.security_validator.validate_nonce(nonce.as_bytes())?;
         // AEAD ciphertexts are variable-length; do not use `validate_ciphertext` (KEM-only sizes).
         if ciphertext.is_empty() {
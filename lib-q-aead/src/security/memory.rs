@@ -8,6 +8,8 @@
 
 use core::ptr;
 
+use zeroize::Zeroize;
+
 /// Secure memory zeroization
 ///
 /// Securely zeros a memory region to prevent sensitive data from remaining
@@ -40,15 +42,11 @@ pub fn secure_zero<T>(data: &mut T) {
 /// * `data` - Slice to zero
 ///
 /// # Security
-/// This function uses compiler barriers to ensure the zeroing operation
-/// is not optimized away by the compiler.
+/// Now delegates to [`Zeroize::zeroize`]; earlier versions used a plain loop that the
+/// optimizer was free to drop for buffers that are not read again.
+#[deprecated(note = "use `lib_q_utils::secure_zeroize` or `zeroize::Zeroize`")]
 pub fn secure_zero_slice(data: &mut [u8]) {
-    for byte in data.iter_mut() {
-        *byte = 0;
-    }
-
-    // Compiler barrier to prevent optimization
-    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    data.zeroize();
 }
 
 /// Secure memory copy
@@ -132,7 +130,7 @@ pub fn secure_move<T>(dst: &mut T, src: &mut T) {
 /// sensitive data from remaining in memory.
 pub fn secure_move_slice(dst: &mut [u8], src: &mut [u8]) {
     secure_copy_slice(dst, src);
-    secure_zero_slice(src);
+    src.zeroize();
 }
 
 /// Secure memory comparison
@@ -467,6 +465,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_secure_zero_slice() {
         let mut data = [1, 2, 3, 4, 5];
         secure_zero_slice(&mut data);
@@ -3,6 +3,10 @@
 //! This module provides secure nonce generation and uniqueness checking for AEAD operations.
 //! It implements proper nonce management to prevent nonce reuse attacks.
 
+use alloc::collections::{
+    BTreeSet,
+    VecDeque,
+};
 use alloc::vec::Vec;
 use core::sync::atomic::Ordering;
 #[cfg(all(feature = "alloc", feature = "std"))]
@@ -10,6 +14,8 @@ use core::sync::atomic::Ordering;
 use std::collections::HashSet;
 
 use lib_q_core::{
+    Aead,
+    AeadKey,
     Error,
     Nonce,
     Result,
@@ -418,6 +424,123 @@ pub fn mark_nonce_used(nonce: &Nonce) -> Result<()> {
     get_nonce_manager().mark_nonce_used(nonce)
 }
 
+/// Default number of `(key id, nonce)` pairs remembered by a [`NonceTracker`]
+pub const DEFAULT_NONCE_TRACKER_CAPACITY: usize = 4096;
+
+/// Session-scoped record of the `(key id, nonce)` pairs used for encryption
+///
+/// Encrypting two messages under the same key and nonce breaks confidentiality and
+/// authenticity for every AEAD in this crate. A tracker attached to a session refuses such a
+/// repeat with [`Error::NonceReuse`] before the cipher runs: route encryptions through
+/// [`encrypt`](Self::encrypt), or call [`check_and_record`](Self::check_and_record) first.
+///
+/// This is a safety net, not a guarantee. Only the most recent `capacity` pairs are kept
+/// (the oldest is evicted first), and nothing seen by another tracker or process is known.
+/// The key id is any caller-chosen label for the key; never pass the key itself.
+#[derive(Debug, Clone)]
+pub struct NonceTracker {
+    capacity: usize,
+    seen: BTreeSet<(Vec<u8>, Vec<u8>)>,
+    order: VecDeque<(Vec<u8>, Vec<u8>)>,
+}
+
+impl NonceTracker {
+    /// Create a tracker remembering at most `capacity` pairs (0 disables tracking)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            seen: BTreeSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Maximum number of remembered pairs
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of pairs currently remembered
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Whether no pair is remembered
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Forget every remembered pair, e.g. after rotating keys
+    pub fn clear(&mut self) {
+        self.seen.clear();
+        self.order.clear();
+    }
+
+    /// Whether `nonce` has already been recorded under `key_id`
+    pub fn contains(&self, key_id: &[u8], nonce: &Nonce) -> bool {
+        self.seen
+            .contains(&(key_id.to_vec(), nonce.as_bytes().to_vec()))
+    }
+
+    /// Record `nonce` under `key_id`, failing if it was already recorded
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NonceReuse`] if the pair is still remembered.
+    pub fn check_and_record(&mut self, key_id: &[u8], nonce: &Nonce) -> Result<()> {
+        if self.contains(key_id, nonce) {
+            return Err(Error::NonceReuse);
+        }
+        self.record(key_id, nonce);
+        Ok(())
+    }
+
+    /// Encrypt with `aead` unless `nonce` was already used under `key_id`
+    ///
+    /// The pair is only recorded once encryption succeeds, so a call rejected for an invalid
+    /// key or input can be retried with the same nonce.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NonceReuse`] for a repeated pair, otherwise the error of
+    /// [`Aead::encrypt`].
+    pub fn encrypt(
+        &mut self,
+        aead: &dyn Aead,
+        key_id: &[u8],
+        key: &AeadKey,
+        nonce: &Nonce,
+        plaintext: &[u8],
+        associated_data: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        if self.contains(key_id, nonce) {
+            return Err(Error::NonceReuse);
+        }
+        let ciphertext = aead.encrypt(key, nonce, plaintext, associated_data)?;
+        self.record(key_id, nonce);
+        Ok(ciphertext)
+    }
+
+    fn record(&mut self, key_id: &[u8], nonce: &Nonce) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.order.len() == self.capacity &&
+            let Some(oldest) = self.order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+        let pair = (key_id.to_vec(), nonce.as_bytes().to_vec());
+        self.seen.insert(pair.clone());
+        self.order.push_back(pair);
+    }
+}
+
+impl Default for NonceTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_NONCE_TRACKER_CAPACITY)
+    }
+}
+
 /// Nonce generation utilities
 pub mod utils {
     use super::*;
@@ -569,6 +692,29 @@ mod tests {
         assert!(manager.mark_nonce_used(&nonce).is_ok());
     }
 
+    #[test]
+    fn test_nonce_tracker_evicts_oldest_pair() {
+        let mut tracker = NonceTracker::new(2);
+        let nonces: Vec<Nonce> = (1..=3u8).map(|i| Nonce::new(vec![i; 16])).collect();
+
+        for nonce in &nonces {
+            tracker.check_and_record(b"k1", nonce).unwrap();
+        }
+        assert_eq!(tracker.len(), 2);
+        // The first nonce was evicted, the later two are still tracked
+        assert!(!tracker.contains(b"k1", &nonces[0]));
+        assert_eq!(
+            tracker.check_and_record(b"k1", &nonces[2]),
+            Err(Error::NonceReuse)
+        );
+        // Same nonce under another key is fine
+        assert!(tracker.check_and_record(b"k2", &nonces[2]).is_ok());
+
+        let mut disabled = NonceTracker::new(0);
+        disabled.check_and_record(b"k1", &nonces[0]).unwrap();
+        assert!(disabled.check_and_record(b"k1", &nonces[0]).is_ok());
+    }
+
     #[test]
     fn test_counter_operations() {
         let manager = NonceManager::new();
@@ -9,7 +9,7 @@ use core::ops::{
     DerefMut,
 };
 
-use crate::security::memory::secure_zero_slice;
+use zeroize::Zeroize;
 
 /// Maximum size for stack-allocated buffers
 pub const MAX_STACK_BUFFER_SIZE: usize = 32768; // 32KB max stack usage
@@ -76,7 +76,7 @@ impl<const N: usize> UninitStackBuffer<N> {
     /// Clear the buffer
     pub fn clear(&mut self) {
         if self.used > 0 {
-            secure_zero_slice(self.as_mut_slice());
+            self.as_mut_slice().zeroize();
             self.used = 0;
         }
     }
@@ -158,7 +158,7 @@ impl<const N: usize> StackBuffer<N> {
 
     /// Clear the buffer and reset usage
     pub fn clear(&mut self) {
-        secure_zero_slice(&mut self.data[..self.used]);
+        self.data[..self.used].zeroize();
         self.used = 0;
     }
 
@@ -170,7 +170,7 @@ impl<const N: usize> StackBuffer<N> {
 
         if new_len < self.used {
             // Zero the unused portion
-            secure_zero_slice(&mut self.data[new_len..self.used]);
+            self.data[new_len..self.used].zeroize();
         }
 
         self.used = new_len;
@@ -267,7 +267,7 @@ impl<const N: usize> DerefMut for StackBuffer<N> {
 impl<const N: usize> Drop for StackBuffer<N> {
     fn drop(&mut self) {
         // Securely zero the entire buffer on drop
-        secure_zero_slice(&mut self.data);
+        self.data.zeroize();
     }
 }
 
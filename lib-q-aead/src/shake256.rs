@@ -30,19 +30,22 @@ use lib_q_core::{
     Error,
     Nonce,
     Result,
+    SecretExportConsent,
 };
 use lib_q_sha3::digest::{
     Update,
     XofReader,
 };
-use zeroize::Zeroizing;
+use zeroize::{
+    Zeroize,
+    Zeroizing,
+};
 
 // Plugin trait implementation
 use crate::metadata::{
     AeadMetadata,
     AeadWithMetadata,
 };
-use crate::security::memory::secure_zero_slice;
 use crate::security::stack_buffer::UninitStackBuffer;
 
 /// SHAKE256 AEAD implementation with proper domain separation
@@ -196,7 +199,7 @@ impl Shake256Aead {
             for (i, byte) in plaintext.iter_mut().enumerate() {
                 *byte ^= keystream[i];
             }
-            secure_zero_slice(&mut keystream);
+            keystream.zeroize();
         }
 
         Ok(())
@@ -238,7 +241,7 @@ impl Shake256Aead {
         self.validate_key(key)?;
         self.validate_nonce(nonce)?;
         crate::security::validation::validate_plaintext(plaintext)?;
-        crate::security::validation::validate_key(key.as_bytes())?;
+        crate::security::validation::validate_key(key.expose_secret(SecretExportConsent))?;
         crate::security::validation::validate_nonce(nonce.as_bytes())?;
 
         let associated_data = associated_data.unwrap_or(&[]);
@@ -248,7 +251,7 @@ impl Shake256Aead {
         #[cfg(feature = "shake256")]
         {
             let mut key_staged = Zeroizing::new([0u8; 32]);
-            key_staged.copy_from_slice(key.as_bytes());
+            key_staged.copy_from_slice(key.expose_secret(SecretExportConsent));
             let mut nonce_staged = Zeroizing::new([0u8; 16]);
             nonce_staged.copy_from_slice(nonce.as_bytes());
             let kb = key_staged.as_slice();
@@ -293,7 +296,7 @@ impl Shake256Aead {
         self.validate_nonce(nonce)?;
         self.validate_ciphertext_size(ciphertext.len())?;
         crate::security::validation::validate_ciphertext(ciphertext)?;
-        crate::security::validation::validate_key(key.as_bytes())?;
+        crate::security::validation::validate_key(key.expose_secret(SecretExportConsent))?;
         crate::security::validation::validate_nonce(nonce.as_bytes())?;
 
         let associated_data = associated_data.unwrap_or(&[]);
@@ -304,7 +307,7 @@ impl Shake256Aead {
             let (ciphertext_data, tag) = ciphertext.split_at(ciphertext.len() - self.tag_size());
 
             let mut key_staged = Zeroizing::new([0u8; 32]);
-            key_staged.copy_from_slice(key.as_bytes());
+            key_staged.copy_from_slice(key.expose_secret(SecretExportConsent));
             let mut nonce_staged = Zeroizing::new([0u8; 16]);
             nonce_staged.copy_from_slice(nonce.as_bytes());
             let kb = key_staged.as_slice();
@@ -0,0 +1,328 @@
+//! Streaming (segmented) ChaCha20-Poly1305
+//!
+//! Implements [`StreamingAead`] with the STREAM construction: each segment is sealed with
+//! ChaCha20-Poly1305 under the 12-byte nonce
+//!
+//! ```text
+//! nonce_prefix (7 bytes) || segment counter (4 bytes, big-endian) || final flag (1 byte)
+//! ```
+//!
+//! The counter fixes each segment's position and the flag marks the end of the stream, so a
+//! receiver detects reordered, duplicated, dropped and truncated segments. The nonce prefix
+//! must be unique per stream under a given key.
+
+use alloc::vec::Vec;
+
+use chacha20poly1305::aead::{
+    Aead as _,
+    KeyInit,
+    Payload,
+};
+use chacha20poly1305::{
+    ChaCha20Poly1305,
+    Nonce as ChaChaNonce,
+};
+use lib_q_core::{
+    AeadKey,
+    Error,
+    Result,
+    SecretExportConsent,
+    StreamingAead,
+};
+
+/// ChaCha20-Poly1305 key size in bytes
+pub const CHACHA20_POLY1305_KEY_SIZE: usize = 32;
+
+/// Per-stream nonce prefix size in bytes
+pub const STREAM_NONCE_PREFIX_SIZE: usize = 7;
+
+/// Authentication tag appended to every segment
+pub const STREAM_TAG_SIZE: usize = 16;
+
+const NONCE_SIZE: usize = 12;
+const FINAL_SEGMENT_FLAG: u8 = 0x01;
+
+/// Segmented ChaCha20-Poly1305 stream
+///
+/// Use one instance per direction: the sender only calls
+/// [`seal_chunk`](StreamingAead::seal_chunk), the receiver only calls
+/// [`open_chunk`](StreamingAead::open_chunk), and both call
+/// [`finalize`](StreamingAead::finalize) once the last segment has been processed.
+pub struct ChaCha20Poly1305Stream {
+    cipher: ChaCha20Poly1305,
+    nonce_prefix: [u8; STREAM_NONCE_PREFIX_SIZE],
+    segment_size: usize,
+    counter: u32,
+    finished: bool,
+}
+
+impl ChaCha20Poly1305Stream {
+    /// Create a stream with the given key, per-stream nonce prefix and plaintext segment size
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidKeySize`] for a key that is not 32 bytes,
+    /// [`Error::InvalidNonceSize`] for a prefix that is not 7 bytes, and
+    /// [`Error::InvalidState`] for a zero segment size.
+    pub fn new(key: &AeadKey, nonce_prefix: &[u8], segment_size: usize) -> Result<Self> {
+        let key = key.expose_secret(SecretExportConsent);
+        if key.len() != CHACHA20_POLY1305_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: CHACHA20_POLY1305_KEY_SIZE,
+                actual: key.len(),
+            });
+        }
+        let nonce_prefix: [u8; STREAM_NONCE_PREFIX_SIZE] =
+            nonce_prefix
+                .try_into()
+                .map_err(|_| Error::InvalidNonceSize {
+                    expected: STREAM_NONCE_PREFIX_SIZE,
+                    actual: nonce_prefix.len(),
+                })?;
+        if segment_size == 0 {
+            return Err(Error::InvalidState {
+                operation: "ChaCha20-Poly1305 stream".into(),
+                reason: "segment size must be non-zero".into(),
+            });
+        }
+        let cipher = ChaCha20Poly1305::new_from_slice(key).map_err(|_| Error::InvalidKeySize {
+            expected: CHACHA20_POLY1305_KEY_SIZE,
+            actual: key.len(),
+        })?;
+
+        Ok(Self {
+            cipher,
+            nonce_prefix,
+            segment_size,
+            counter: 0,
+            finished: false,
+        })
+    }
+
+    /// Plaintext bytes carried by every non-final segment
+    pub fn segment_size(&self) -> usize {
+        self.segment_size
+    }
+
+    /// Nonce for the next segment, or an error once the stream is closed or exhausted
+    fn next_nonce(&self, operation: &str, last: bool) -> Result<ChaChaNonce> {
+        if self.finished {
+            return Err(Error::InvalidState {
+                operation: operation.into(),
+                reason: "final segment already processed".into(),
+            });
+        }
+        if self.counter == u32::MAX && !last {
+            return Err(Error::InvalidState {
+                operation: operation.into(),
+                reason: "segment counter exhausted".into(),
+            });
+        }
+
+        let mut nonce = [0u8; NONCE_SIZE];
+        nonce[..STREAM_NONCE_PREFIX_SIZE].copy_from_slice(&self.nonce_prefix);
+        nonce[STREAM_NONCE_PREFIX_SIZE..NONCE_SIZE - 1]
+            .copy_from_slice(&self.counter.to_be_bytes());
+        nonce[NONCE_SIZE - 1] = if last { FINAL_SEGMENT_FLAG } else { 0 };
+        Ok(ChaChaNonce::from(nonce))
+    }
+
+    fn advance(&mut self, last: bool) {
+        if last {
+            self.finished = true;
+        } else {
+            self.counter += 1;
+        }
+    }
+}
+
+impl StreamingAead for ChaCha20Poly1305Stream {
+    fn seal_chunk(
+        &mut self,
+        plaintext: &[u8],
+        associated_data: Option<&[u8]>,
+        last: bool,
+    ) -> Result<Vec<u8>> {
+        if last && plaintext.len() > self.segment_size {
+            return Err(Error::InvalidMessageSize {
+                max: self.segment_size,
+                actual: plaintext.len(),
+            });
+        }
+        if !last && plaintext.len() != self.segment_size {
+            return Err(Error::InvalidPlaintextSize {
+                expected: self.segment_size,
+                actual: plaintext.len(),
+            });
+        }
+
+        let nonce = self.next_nonce("seal_chunk", last)?;
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad: associated_data.unwrap_or(&[]),
+                },
+            )
+            .map_err(|_| Error::EncryptionFailed {
+                operation: "ChaCha20-Poly1305 stream segment".into(),
+            })?;
+
+        self.advance(last);
+        Ok(ciphertext)
+    }
+
+    fn open_chunk(
+        &mut self,
+        ciphertext: &[u8],
+        associated_data: Option<&[u8]>,
+        last: bool,
+    ) -> Result<Vec<u8>> {
+        let full_segment = self.segment_size + STREAM_TAG_SIZE;
+        let size_ok = if last {
+            (STREAM_TAG_SIZE..=full_segment).contains(&ciphertext.len())
+        } else {
+            ciphertext.len() == full_segment
+        };
+        if !size_ok {
+            return Err(Error::InvalidCiphertextSize {
+                expected: full_segment,
+                actual: ciphertext.len(),
+            });
+        }
+
+        let nonce = self.next_nonce("open_chunk", last)?;
+        let plaintext = self
+            .cipher
+            .decrypt(
+                &nonce,
+                Payload {
+                    msg: ciphertext,
+                    aad: associated_data.unwrap_or(&[]),
+                },
+            )
+            .map_err(|_| Error::AuthenticationFailed {
+                operation: "ChaCha20-Poly1305 stream segment".into(),
+            })?;
+
+        self.advance(last);
+        Ok(plaintext)
+    }
+
+    fn finalize(self) -> Result<()> {
+        if self.finished {
+            Ok(())
+        } else {
+            Err(Error::AuthenticationFailed {
+                operation: "ChaCha20-Poly1305 stream ended before its final segment".into(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    const SEGMENT: usize = 64;
+
+    fn stream() -> ChaCha20Poly1305Stream {
+        let key = AeadKey::new(vec![0x42; CHACHA20_POLY1305_KEY_SIZE]);
+        ChaCha20Poly1305Stream::new(&key, &[7u8; STREAM_NONCE_PREFIX_SIZE], SEGMENT).unwrap()
+    }
+
+    fn seal_all(message: &[u8]) -> Vec<Vec<u8>> {
+        let mut sender = stream();
+        let chunks: Vec<&[u8]> = message.chunks(SEGMENT).collect();
+        let segments = chunks
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                sender
+                    .seal_chunk(chunk, Some(b"header"), i + 1 == chunks.len())
+                    .unwrap()
+            })
+            .collect();
+        sender.finalize().unwrap();
+        segments
+    }
+
+    #[test]
+    fn multi_segment_round_trip() {
+        let message: Vec<u8> = (0..SEGMENT * 3 + 17).map(|i| i as u8).collect();
+        let segments = seal_all(&message);
+        assert_eq!(segments.len(), 4);
+
+        let mut receiver = stream();
+        let mut recovered = Vec::new();
+        for (i, segment) in segments.iter().enumerate() {
+            let last = i + 1 == segments.len();
+            recovered.extend(receiver.open_chunk(segment, Some(b"header"), last).unwrap());
+        }
+        receiver.finalize().unwrap();
+        assert_eq!(recovered, message);
+    }
+
+    #[test]
+    fn dropping_last_segment_fails_finalize() {
+        let message = vec![0xA5u8; SEGMENT * 3];
+        let segments = seal_all(&message);
+
+        let mut receiver = stream();
+        for segment in &segments[..segments.len() - 1] {
+            receiver
+                .open_chunk(segment, Some(b"header"), false)
+                .unwrap();
+        }
+        assert!(matches!(
+            receiver.finalize(),
+            Err(Error::AuthenticationFailed { .. })
+        ));
+
+        // Treating the new tail as final does not authenticate either.
+        let mut receiver = stream();
+        receiver
+            .open_chunk(&segments[0], Some(b"header"), false)
+            .unwrap();
+        assert!(matches!(
+            receiver.open_chunk(&segments[1], Some(b"header"), true),
+            Err(Error::AuthenticationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn reordered_segments_fail() {
+        let message = vec![1u8; SEGMENT * 2 + 1];
+        let segments = seal_all(&message);
+
+        let mut receiver = stream();
+        assert!(matches!(
+            receiver.open_chunk(&segments[1], Some(b"header"), false),
+            Err(Error::AuthenticationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_parameters_and_use_after_final() {
+        let key = AeadKey::new(vec![0u8; 16]);
+        assert!(matches!(
+            ChaCha20Poly1305Stream::new(&key, &[0u8; STREAM_NONCE_PREFIX_SIZE], SEGMENT),
+            Err(Error::InvalidKeySize { .. })
+        ));
+
+        let mut sender = stream();
+        assert!(matches!(
+            sender.seal_chunk(&[0u8; SEGMENT - 1], None, false),
+            Err(Error::InvalidPlaintextSize { .. })
+        ));
+        sender.seal_chunk(&[], None, true).unwrap();
+        assert!(matches!(
+            sender.seal_chunk(&[], None, true),
+            Err(Error::InvalidState { .. })
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-//! JavaScript / wasm-bindgen surface for AEAD operations (Saturnin, Duplex-Sponge, Romulus-N).
+//! JavaScript / wasm-bindgen surface for AEAD operations (every AEAD compiled into the build).
 //!
 //! Keys are taken by value and moved into [`lib_q_core::AeadKey`], which clears its backing buffer
 //! on drop. JavaScript callers should not retain or log copies of key material passed into these
@@ -13,11 +13,14 @@ use alloc::vec::Vec;
 use lib_q_core::api::AeadOperations;
 use lib_q_core::{
     AeadKey,
+    Error,
     Nonce,
+    SecretExportConsent,
 };
 use wasm_bindgen::prelude::*;
 
 use crate::{
+    AeadMetadata,
     Algorithm,
     AlgorithmCategory,
     LibQAeadProvider,
@@ -27,14 +30,30 @@ fn js_err(msg: impl core::fmt::Display) -> JsValue {
     lib_q_core::wasm_common::wasm_js_error("LIB_Q_AEAD", msg)
 }
 
+/// Authentication failures get their own code so callers can tell a forged or corrupted
+/// message apart from bad parameters.
+fn decrypt_err(error: Error) -> JsValue {
+    match error {
+        Error::AuthenticationFailed { .. } | Error::VerificationFailed { .. } => {
+            lib_q_core::wasm_common::wasm_js_error("LIB_Q_AEAD_AUTH", "AEAD authentication failed")
+        }
+        other => js_err(other),
+    }
+}
+
 fn parse_algorithm(name: &str) -> Result<Algorithm, JsValue> {
     let a = match name.trim() {
         "Saturnin" | "saturnin" => Algorithm::Saturnin,
+        "Shake256Aead" | "SHAKE256-AEAD" | "shake256-aead" => Algorithm::Shake256Aead,
         "DuplexSpongeAead" | "Duplex-Sponge-AEAD" | "duplex" => Algorithm::DuplexSpongeAead,
+        "TweakAead" | "Tweak-AEAD" | "tweak" => Algorithm::TweakAead,
         "RomulusN" | "Romulus-N" | "romulus-n" | "romulus" => Algorithm::RomulusN,
+        "RomulusM" | "Romulus-M" | "romulus-m" => Algorithm::RomulusM,
+        "RoccaS" | "Rocca-S" | "rocca-s" => Algorithm::RoccaS,
         other => {
             return Err(js_err(format!(
-                "unknown AEAD algorithm '{other}'; use Saturnin, DuplexSpongeAead, or RomulusN"
+                "unknown AEAD algorithm '{other}'; use Saturnin, Shake256Aead, DuplexSpongeAead, \
+                 TweakAead, RomulusN, RomulusM, or RoccaS"
             )));
         }
     };
@@ -44,7 +63,45 @@ fn parse_algorithm(name: &str) -> Result<Algorithm, JsValue> {
     Ok(a)
 }
 
-/// AEAD encrypt: `algorithm` is `Saturnin`, `DuplexSpongeAead`, or `RomulusN`.
+/// Reject keys and nonces of the wrong length before any key material is copied
+fn validate_params(
+    algorithm: Algorithm,
+    key: &[u8],
+    nonce: &[u8],
+) -> Result<&'static AeadMetadata, JsValue> {
+    let metadata = crate::get_algorithm_metadata(algorithm).ok_or_else(|| {
+        js_err(format!(
+            "AEAD algorithm {algorithm:?} is not available in this build"
+        ))
+    })?;
+    if !metadata.validate_key_size(key.len()) {
+        return Err(js_err(Error::InvalidKeySize {
+            expected: metadata.key_size,
+            actual: key.len(),
+        }));
+    }
+    if !metadata.validate_nonce_size(nonce.len()) {
+        return Err(js_err(Error::InvalidNonceSize {
+            expected: metadata.nonce_size,
+            actual: nonce.len(),
+        }));
+    }
+    Ok(metadata)
+}
+
+fn optional_aad(associated_data: &[u8]) -> Option<&[u8]> {
+    if associated_data.is_empty() {
+        None
+    } else {
+        Some(associated_data)
+    }
+}
+
+/// AEAD encrypt: `algorithm` is `Saturnin`, `Shake256Aead`, `DuplexSpongeAead`, `TweakAead`,
+/// `RomulusN`, `RomulusM`, or `RoccaS` (each only if compiled in).
+///
+/// The key and nonce must have the algorithm's exact sizes. An empty `associated_data` is
+/// treated as absent.
 #[wasm_bindgen]
 pub fn aead_encrypt(
     algorithm: &str,
@@ -54,20 +111,19 @@ pub fn aead_encrypt(
     associated_data: Vec<u8>,
 ) -> Result<Vec<u8>, JsValue> {
     let alg = parse_algorithm(algorithm)?;
-    let provider = LibQAeadProvider::new().map_err(js_err)?;
     let k = AeadKey::new(key);
+    validate_params(alg, k.expose_secret(SecretExportConsent), &nonce)?;
+    let provider = LibQAeadProvider::new().map_err(js_err)?;
     let n = Nonce::new(nonce);
-    let ad = if associated_data.is_empty() {
-        None
-    } else {
-        Some(associated_data.as_slice())
-    };
     provider
-        .encrypt(alg, &k, &n, &plaintext, ad)
+        .encrypt(alg, &k, &n, &plaintext, optional_aad(&associated_data))
         .map_err(js_err)
 }
 
 /// AEAD decrypt (same `algorithm` names as [`aead_encrypt`]).
+///
+/// A ciphertext that fails authentication is reported with code `LIB_Q_AEAD_AUTH`; wrong
+/// parameter sizes, including a ciphertext shorter than the tag, with `LIB_Q_AEAD`.
 #[wasm_bindgen]
 pub fn aead_decrypt(
     algorithm: &str,
@@ -77,17 +133,19 @@ pub fn aead_decrypt(
     associated_data: Vec<u8>,
 ) -> Result<Vec<u8>, JsValue> {
     let alg = parse_algorithm(algorithm)?;
-    let provider = LibQAeadProvider::new().map_err(js_err)?;
     let k = AeadKey::new(key);
+    let metadata = validate_params(alg, k.expose_secret(SecretExportConsent), &nonce)?;
+    if ciphertext.len() < metadata.tag_size {
+        return Err(js_err(Error::aead_ciphertext_shorter_than_tag(
+            metadata.tag_size,
+            ciphertext.len(),
+        )));
+    }
+    let provider = LibQAeadProvider::new().map_err(js_err)?;
     let n = Nonce::new(nonce);
-    let ad = if associated_data.is_empty() {
-        None
-    } else {
-        Some(associated_data.as_slice())
-    };
     provider
-        .decrypt(alg, &k, &n, &ciphertext, ad)
-        .map_err(js_err)
+        .decrypt(alg, &k, &n, &ciphertext, optional_aad(&associated_data))
+        .map_err(decrypt_err)
 }
 
 /// Array of AEAD algorithm names available in this build (native JS array of strings).
@@ -9,6 +9,7 @@ use std::time::{
 };
 
 use lib_q_aead::security::constant_time::constant_time_eq;
+#[allow(deprecated)]
 use lib_q_aead::security::memory::secure_zero_slice;
 use lib_q_aead::security::timing::protect_timing;
 use lib_q_aead::security::validation::validate_plaintext;
@@ -18,6 +19,7 @@ use lib_q_aead::{
     Nonce,
     create_aead,
 };
+use lib_q_core::SecretExportConsent;
 
 /// Generate a proper test key with good entropy
 fn create_test_key() -> AeadKey {
@@ -267,7 +269,7 @@ fn test_key_material_protection() {
 
     // Verify that the key data is still intact (not zeroed during encryption)
     assert_eq!(
-        key.as_bytes(),
+        key.expose_secret(SecretExportConsent),
         key_data,
         "Key data should not be modified during encryption"
     );
@@ -374,6 +376,7 @@ fn test_domain_separation_security() {
 
 #[cfg(feature = "shake256")]
 #[test]
+#[allow(deprecated)]
 fn test_memory_safety() {
     let aead = create_aead(Algorithm::Shake256Aead).expect("Failed to create SHAKE256 AEAD");
 
@@ -491,3 +491,42 @@ fn test_aead_total_overhead() {
         assert_eq!(metadata.total_overhead(), expected_overhead);
     }
 }
+
+#[test]
+fn test_associated_data_groupings_are_not_interchangeable() {
+    let aead = create_aead(Algorithm::Shake256Aead).unwrap();
+    let key = create_test_key();
+    let nonce = create_test_nonce();
+
+    let ab_c = AssociatedData::new()
+        .add_field(b"ab")
+        .unwrap()
+        .add_field(b"c")
+        .unwrap()
+        .finish();
+    let a_bc = AssociatedData::new()
+        .add_field(b"a")
+        .unwrap()
+        .add_field(b"bc")
+        .unwrap()
+        .finish();
+    assert_ne!(ab_c, a_bc);
+
+    let ciphertext = aead
+        .encrypt(&key, &nonce, b"structured aad", Some(&ab_c))
+        .unwrap();
+    assert_eq!(
+        aead.decrypt(&key, &nonce, &ciphertext, Some(&ab_c))
+            .unwrap(),
+        b"structured aad"
+    );
+    assert!(
+        aead.decrypt(&key, &nonce, &ciphertext, Some(&a_bc))
+            .is_err()
+    );
+    // Naive concatenation of either grouping is also rejected.
+    assert!(
+        aead.decrypt(&key, &nonce, &ciphertext, Some(b"abc"))
+            .is_err()
+    );
+}
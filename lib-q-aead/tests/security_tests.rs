@@ -100,6 +100,7 @@ fn test_constant_time_operations() {
 
 /// Test memory safety operations
 #[test]
+#[allow(deprecated)]
 fn test_memory_safety_operations() {
     use lib_q_aead::security::memory::*;
 
@@ -511,6 +512,34 @@ fn test_aead_operations_with_security() {
     }
 }
 
+/// A nonce tracker catches an encryption that repeats a nonce under the same key
+#[test]
+fn test_nonce_tracker_rejects_reuse() {
+    let algorithm = Algorithm::Shake256Aead;
+    let aead = create_aead(algorithm).unwrap();
+    let key = test_key_for_security(algorithm);
+    let mut tracker = NonceTracker::default();
+
+    let first = Nonce::new((1..=16).collect());
+    let second = Nonce::new((1..=16).rev().collect());
+    let ciphertext = tracker
+        .encrypt(aead.as_ref(), b"session-key", &key, &first, b"one", None)
+        .unwrap();
+    tracker
+        .encrypt(aead.as_ref(), b"session-key", &key, &second, b"two", None)
+        .unwrap();
+
+    assert_eq!(
+        tracker.encrypt(aead.as_ref(), b"session-key", &key, &first, b"three", None),
+        Err(lib_q_core::Error::NonceReuse)
+    );
+    // The rejected call never reached the cipher; the first message still decrypts
+    assert_eq!(
+        aead.decrypt(&key, &first, &ciphertext, None).unwrap(),
+        b"one"
+    );
+}
+
 /// Test security configuration
 #[test]
 fn test_security_configuration() {
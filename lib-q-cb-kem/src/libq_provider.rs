@@ -12,6 +12,7 @@ use alloc::{
     vec::Vec,
 };
 
+use lib_q_core::SecretExportConsent;
 #[cfg(feature = "alloc")]
 use lib_q_core::api::{
     Algorithm,
@@ -193,8 +194,11 @@ impl KemOperations for LibQCbKemProvider {
             .validate_algorithm_category(algorithm, lib_q_core::api::AlgorithmCategory::Kem)?;
 
         // Validate secret key size only (skip entropy validation for CB-KEM keys)
-        self.security_validator
-            .validate_key_size(algorithm, secret_key.as_bytes(), true)?;
+        self.security_validator.validate_key_size(
+            algorithm,
+            secret_key.expose_secret(SecretExportConsent),
+            true,
+        )?;
 
         // Validate ciphertext
         self.security_validator
@@ -327,10 +331,10 @@ impl LibQCbKemProvider {
     /// Decapsulate using Classical McEliece
     fn decapsulate_cb_kem(&self, secret_key: &KemSecretKey, ciphertext: &[u8]) -> Result<Vec<u8>> {
         // Validate secret key size
-        if secret_key.as_bytes().len() != CRYPTO_SECRETKEYBYTES {
+        if secret_key.expose_secret(SecretExportConsent).len() != CRYPTO_SECRETKEYBYTES {
             return Err(Error::InvalidKeySize {
                 expected: CRYPTO_SECRETKEYBYTES,
-                actual: secret_key.as_bytes().len(),
+                actual: secret_key.expose_secret(SecretExportConsent).len(),
             });
         }
 
@@ -344,7 +348,7 @@ impl LibQCbKemProvider {
 
         // Create secret key from bytes
         let mut secret_key_buf = [0u8; CRYPTO_SECRETKEYBYTES];
-        secret_key_buf.copy_from_slice(secret_key.as_bytes());
+        secret_key_buf.copy_from_slice(secret_key.expose_secret(SecretExportConsent));
         let secret_key = SecretKey::from(&mut secret_key_buf);
 
         // Create ciphertext from bytes
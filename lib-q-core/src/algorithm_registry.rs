@@ -1001,7 +1001,7 @@ pub fn algorithms_by_security_level(level: u32) -> &'static [Algorithm] {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
 
@@ -13,6 +13,7 @@ use crate::traits::*;
 extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::{
+    boxed::Box,
     format,
     string::String,
     vec::Vec,
@@ -26,6 +27,8 @@ pub use lib_q_types::{
     AlgorithmCategory,
     SecurityLevel,
 };
+#[cfg(feature = "alloc")]
+use rand_core::CryptoRng;
 // Hash function imports
 // #[cfg(feature = "hash")]
 // use lib_q_sha3::{
@@ -42,10 +45,19 @@ pub use lib_q_types::{
 #[allow(unused_imports)]
 use rand_core::Rng;
 use subtle::ConstantTimeEq;
+#[cfg(feature = "alloc")]
+use subtle::{
+    Choice,
+    ConditionallySelectable,
+};
 
 // Define cryptographic operation traits for dependency injection
 // This allows implementations to be provided by higher-level crates
 
+/// Size in bytes of a public-key fingerprint, see
+/// [`SignatureOperations::public_key_fingerprint`]
+pub const PUBLIC_KEY_FINGERPRINT_SIZE: usize = 16;
+
 /// Key Encapsulation Mechanism operations
 #[cfg(feature = "alloc")]
 pub trait KemOperations {
@@ -73,6 +85,28 @@ pub trait KemOperations {
     ) -> Result<KemPublicKey>;
 }
 
+/// Maximum length of a FIPS 204 / FIPS 205 signature context string, in bytes
+pub const MAX_SIGNATURE_CONTEXT_SIZE: usize = 255;
+
+/// Reject context strings longer than [`MAX_SIGNATURE_CONTEXT_SIZE`] bytes
+///
+/// Shared by [`SignatureContext`](crate::contexts::SignatureContext) and the signature
+/// providers, so every entry point reports the same limit and error.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidContextSize`](crate::error::Error::InvalidContextSize) if
+/// `context` is too long.
+pub fn validate_signature_context(context: &[u8]) -> Result<()> {
+    if context.len() > MAX_SIGNATURE_CONTEXT_SIZE {
+        return Err(crate::error::Error::InvalidContextSize {
+            max: MAX_SIGNATURE_CONTEXT_SIZE,
+            actual: context.len(),
+        });
+    }
+    Ok(())
+}
+
 /// Digital Signature operations
 #[cfg(feature = "alloc")]
 pub trait SignatureOperations {
@@ -95,12 +129,205 @@ pub trait SignatureOperations {
         message: &[u8],
         signature: &[u8],
     ) -> Result<bool>;
+
+    /// Sign under a context string (FIPS 204 / FIPS 205 domain separation)
+    ///
+    /// The default accepts only an empty context, which is identical to
+    /// [`sign`](Self::sign); providers that support context strings override it.
+    fn sign_with_context(
+        &self,
+        algorithm: Algorithm,
+        secret_key: &SigSecretKey,
+        message: &[u8],
+        context: &[u8],
+        randomness: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        if context.is_empty() {
+            return self.sign(algorithm, secret_key, message, randomness);
+        }
+        Err(crate::error::Error::NotImplemented {
+            feature: format!("Signature context strings for {algorithm:?}"),
+        })
+    }
+
+    /// Verify a signature made under a context string
+    ///
+    /// The default accepts only an empty context, which is identical to
+    /// [`verify`](Self::verify).
+    fn verify_with_context(
+        &self,
+        algorithm: Algorithm,
+        public_key: &SigPublicKey,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> Result<bool> {
+        if context.is_empty() {
+            return self.verify(algorithm, public_key, message, signature);
+        }
+        Err(crate::error::Error::NotImplemented {
+            feature: format!("Signature context strings for {algorithm:?}"),
+        })
+    }
+
+    /// Short identifier of `public_key` for logs and key lookup
+    ///
+    /// The fingerprint is the first [`PUBLIC_KEY_FINGERPRINT_SIZE`] bytes of SHAKE256 over
+    /// the encoded public key. lib-q-core has no SHAKE256 of its own, so the default returns
+    /// [`Error::NotImplemented`](crate::error::Error::NotImplemented); providers backed by a
+    /// hash implementation override it.
+    fn public_key_fingerprint(
+        &self,
+        algorithm: Algorithm,
+        _public_key: &SigPublicKey,
+    ) -> Result<[u8; PUBLIC_KEY_FINGERPRINT_SIZE]> {
+        Err(crate::error::Error::NotImplemented {
+            feature: format!("Public key fingerprints for {algorithm:?}"),
+        })
+    }
+
+    /// Verify many `(public_key, message, signature)` items
+    ///
+    /// The result is aligned index-for-index with `items`. An item whose key or
+    /// signature has the wrong size is reported as `false` rather than failing the
+    /// whole batch; any other error is returned. The default verifies items one at a
+    /// time; providers may override it to share work between items with the same key.
+    fn verify_batch(
+        &self,
+        algorithm: Algorithm,
+        items: &[(SigPublicKey, Vec<u8>, Vec<u8>)],
+    ) -> Result<Vec<bool>> {
+        verify_each(self, algorithm, items)
+    }
+}
+
+/// Default [`SignatureOperations::verify_batch`]: every item through
+/// [`verify`](SignatureOperations::verify), with key and signature size errors reported as
+/// `false`
+///
+/// Providers that override `verify_batch` for some algorithms fall back to this for the rest.
+#[cfg(feature = "alloc")]
+pub fn verify_each<S: SignatureOperations + ?Sized>(
+    operations: &S,
+    algorithm: Algorithm,
+    items: &[(SigPublicKey, Vec<u8>, Vec<u8>)],
+) -> Result<Vec<bool>> {
+    items
+        .iter()
+        .map(|(public_key, message, signature)| {
+            match operations.verify(algorithm, public_key, message, signature) {
+                Err(
+                    crate::error::Error::InvalidKeySize { .. } |
+                    crate::error::Error::InvalidSignatureSize { .. },
+                ) => Ok(false),
+                other => other,
+            }
+        })
+        .collect()
 }
 
 /// Hash operations
 #[cfg(feature = "alloc")]
 pub trait HashOperations {
     fn hash(&self, algorithm: Algorithm, data: &[u8]) -> Result<Vec<u8>>;
+
+    /// Start an incremental hash for `algorithm`
+    ///
+    /// Providers without streaming support keep this default, which returns
+    /// [`Error::NotImplemented`](crate::error::Error::NotImplemented).
+    fn hash_state(&self, algorithm: Algorithm) -> Result<Box<dyn HashState>> {
+        Err(crate::error::Error::NotImplemented {
+            feature: format!("Incremental hashing for {algorithm:?}"),
+        })
+    }
+
+    /// Hash `data` under a customization string (KT128 / KT256, cSHAKE)
+    ///
+    /// An empty `customization` is identical to [`hash`](Self::hash). The default
+    /// implementation supports only that case and returns
+    /// [`Error::NotImplemented`](crate::error::Error::NotImplemented) otherwise.
+    fn hash_with_customization(
+        &self,
+        algorithm: Algorithm,
+        data: &[u8],
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        if customization.is_empty() {
+            return self.hash(algorithm, data);
+        }
+        Err(crate::error::Error::NotImplemented {
+            feature: format!("Customized hashing for {algorithm:?}"),
+        })
+    }
+
+    /// Hash `data` with a customization string and, for KMAC, a key
+    ///
+    /// cSHAKE and KangarooTwelve take `customization` only; KMAC requires `key` and uses
+    /// `customization` as its customization string S. The default implementation handles
+    /// keyless calls through [`hash_with_customization`](Self::hash_with_customization)
+    /// and returns [`Error::NotImplemented`](crate::error::Error::NotImplemented) for keyed ones.
+    fn hash_customized(
+        &self,
+        algorithm: Algorithm,
+        data: &[u8],
+        customization: &[u8],
+        key: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        match key {
+            None => self.hash_with_customization(algorithm, data, customization),
+            Some(_) => Err(crate::error::Error::NotImplemented {
+                feature: format!("Keyed hashing for {algorithm:?}"),
+            }),
+        }
+    }
+
+    /// Hash a sequence of fields with TupleHash (SP 800-185)
+    ///
+    /// Each entry of `fields` is encoded as its own tuple element, so `["ab", "c"]` and
+    /// `["a", "bc"]` give different digests. The default implementation returns
+    /// [`Error::NotImplemented`](crate::error::Error::NotImplemented).
+    fn hash_tuple(
+        &self,
+        algorithm: Algorithm,
+        fields: &[&[u8]],
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        let _ = (fields, customization);
+        Err(crate::error::Error::NotImplemented {
+            feature: format!("Tuple hashing for {algorithm:?}"),
+        })
+    }
+
+    /// Hash `data` with ParallelHash (SP 800-185) using `block_size`-byte blocks
+    ///
+    /// The block size is encoded into the digest, so the same input hashed with different
+    /// block sizes gives different outputs. The default implementation returns
+    /// [`Error::NotImplemented`](crate::error::Error::NotImplemented).
+    fn hash_parallel(
+        &self,
+        algorithm: Algorithm,
+        data: &[u8],
+        block_size: usize,
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        let _ = (data, block_size, customization);
+        Err(crate::error::Error::NotImplemented {
+            feature: format!("Parallel hashing for {algorithm:?}"),
+        })
+    }
+}
+
+/// Incremental hash state returned by [`HashOperations::hash_state`]
+///
+/// Input is absorbed chunk by chunk with [`update`](HashState::update); the digest is
+/// identical to a one-shot [`HashOperations::hash`] over the concatenated chunks.
+#[cfg(feature = "alloc")]
+pub trait HashState: Send {
+    /// Absorb more input
+    fn update(&mut self, data: &[u8]);
+
+    /// Consume the state and return the digest
+    fn finalize(self: Box<Self>) -> Vec<u8>;
 }
 
 /// AEAD operations (Layer A — `Result` only)
@@ -169,6 +396,45 @@ pub use crate::contexts::KemContext;
 /// Utility functions that work consistently across platforms
 pub struct Utils;
 
+/// Default cap for [`Utils::random_bytes`] (1 MiB)
+pub const MAX_RANDOM_BYTES: usize = 1024 * 1024;
+
+#[cfg(any(feature = "rand", feature = "alloc"))]
+fn check_random_length(length: usize, max: usize) -> Result<()> {
+    const MIN_RANDOM_SIZE: usize = 1;
+    if !(MIN_RANDOM_SIZE..=max).contains(&length) {
+        return Err(crate::error::Error::RandomBytesLengthInvalid {
+            min: MIN_RANDOM_SIZE,
+            max,
+            requested: length,
+        });
+    }
+    Ok(())
+}
+
+/// RNG installed with [`Utils::set_global_rng`]
+///
+/// Only exists in builds without an OS entropy source (`rand` / `getrandom`), so no
+/// dependency can replace OS randomness by installing an RNG.
+#[cfg(all(
+    feature = "custom_rng",
+    not(any(feature = "rand", feature = "getrandom"))
+))]
+static GLOBAL_RNG: spin::Mutex<Option<Box<dyn CryptoRng + Send>>> = spin::Mutex::new(None);
+
+/// Draw `length` bytes from the installed global RNG, if any
+#[cfg(all(
+    feature = "custom_rng",
+    not(any(feature = "rand", feature = "getrandom"))
+))]
+fn global_random_bytes(length: usize) -> Option<Vec<u8>> {
+    let mut rng = GLOBAL_RNG.lock();
+    let rng = rng.as_mut()?;
+    let mut bytes = alloc::vec![0u8; length];
+    rng.fill_bytes(&mut bytes);
+    Some(bytes)
+}
+
 impl Utils {
     /// Generate cryptographically secure random bytes
     ///
@@ -176,18 +442,27 @@ impl Utils {
     /// - In std environments with the "rand" feature: Uses rand::rng()
     /// - In no_std environments with the "getrandom" feature: Uses getrandom directly
     /// - In no_std environments without getrandom: Returns an error
-    #[cfg(feature = "rand")]
+    ///
+    /// With the `custom_rng` feature and neither `rand` nor `getrandom`, an RNG installed
+    /// through [`Utils::set_global_rng`] is used instead; it never replaces OS randomness.
+    ///
+    /// Requests are capped at [`MAX_RANDOM_BYTES`] so that an untrusted length (for example
+    /// one read from a network message) cannot trigger an arbitrarily large allocation. Use
+    /// [`Utils::random_bytes_with_limit`] when a larger buffer is intended.
+    #[cfg(any(feature = "rand", all(feature = "getrandom", feature = "alloc")))]
     pub fn random_bytes(length: usize) -> Result<Vec<u8>> {
-        const MIN_RANDOM_SIZE: usize = 1;
-        const MAX_RANDOM_SIZE: usize = 1024 * 1024; // 1MB limit
-        if !(MIN_RANDOM_SIZE..=MAX_RANDOM_SIZE).contains(&length) {
-            return Err(crate::error::Error::RandomBytesLengthInvalid {
-                min: MIN_RANDOM_SIZE,
-                max: MAX_RANDOM_SIZE,
-                requested: length,
-            });
-        }
+        Self::random_bytes_with_limit(length, MAX_RANDOM_BYTES)
+    }
 
+    /// Generate cryptographically secure random bytes with a caller-chosen size cap
+    ///
+    /// Behaves like [`Utils::random_bytes`] but accepts any `length` in `1..=max`. Pass a
+    /// limit that reflects what the caller is prepared to allocate (for example the size of a
+    /// one-time pad); `usize::MAX` removes the cap entirely and should only be used with
+    /// trusted lengths.
+    #[cfg(feature = "rand")]
+    pub fn random_bytes_with_limit(length: usize, max: usize) -> Result<Vec<u8>> {
+        check_random_length(length, max)?;
         let mut bytes = alloc::vec![0u8; length];
 
         // Use rand for cryptographically secure random generation
@@ -198,19 +473,13 @@ impl Utils {
         Ok(bytes)
     }
 
+    /// Generate cryptographically secure random bytes with a caller-chosen size cap
+    ///
+    /// See the `rand` variant of this function for the semantics of `max`.
     #[cfg(all(feature = "getrandom", not(feature = "rand")))]
     #[cfg(feature = "alloc")]
-    pub fn random_bytes(length: usize) -> Result<Vec<u8>> {
-        const MIN_RANDOM_SIZE: usize = 1;
-        const MAX_RANDOM_SIZE: usize = 1024 * 1024; // 1MB limit
-        if !(MIN_RANDOM_SIZE..=MAX_RANDOM_SIZE).contains(&length) {
-            return Err(crate::error::Error::RandomBytesLengthInvalid {
-                min: MIN_RANDOM_SIZE,
-                max: MAX_RANDOM_SIZE,
-                requested: length,
-            });
-        }
-
+    pub fn random_bytes_with_limit(length: usize, max: usize) -> Result<Vec<u8>> {
+        check_random_length(length, max)?;
         let mut bytes = alloc::vec![0u8; length];
 
         // Generate cryptographically secure random bytes using getrandom
@@ -261,14 +530,77 @@ impl Utils {
         }
     }
 
+    /// Without `rand` or `getrandom` there is no entropy source unless the `custom_rng`
+    /// feature is enabled and [`Utils::set_global_rng`] has been called.
     #[cfg(not(any(feature = "rand", feature = "getrandom")))]
     #[cfg(feature = "alloc")]
-    pub fn random_bytes(_length: usize) -> Result<Vec<u8>> {
+    pub fn random_bytes(length: usize) -> Result<Vec<u8>> {
+        Self::random_bytes_with_limit(length, MAX_RANDOM_BYTES)
+    }
+
+    #[cfg(not(any(feature = "rand", feature = "getrandom")))]
+    #[cfg(feature = "alloc")]
+    pub fn random_bytes_with_limit(length: usize, max: usize) -> Result<Vec<u8>> {
+        check_random_length(length, max)?;
+        #[cfg(feature = "custom_rng")]
+        if let Some(bytes) = global_random_bytes(length) {
+            return Ok(bytes);
+        }
         Err(crate::error::Error::RandomGenerationFailed {
             operation: String::from("random_bytes"),
         })
     }
 
+    /// Generate `length` random bytes from a caller-supplied RNG
+    ///
+    /// For platforms without an OS entropy source: pass a hardware TRNG or a DRBG seeded from
+    /// one. The output is only as strong as `rng`. Requests are capped at
+    /// [`MAX_RANDOM_BYTES`] like [`Utils::random_bytes`].
+    #[cfg(feature = "alloc")]
+    pub fn random_bytes_from_rng<R: CryptoRng + ?Sized>(
+        rng: &mut R,
+        length: usize,
+    ) -> Result<Vec<u8>> {
+        check_random_length(length, MAX_RANDOM_BYTES)?;
+        let mut bytes = alloc::vec![0u8; length];
+        rng.fill_bytes(&mut bytes);
+        Ok(bytes)
+    }
+
+    /// Install `rng` as the process-wide source for [`Utils::random_bytes`]
+    ///
+    /// Only for targets without OS randomness: when the crate is built with `rand` or
+    /// `getrandom`, `rng` is dropped unused and this returns `false`, so an installed RNG can
+    /// never replace an OS entropy source. Otherwise it replaces any previously installed RNG
+    /// and returns `true`. Nothing checks the quality of the RNG handed in; call this once at
+    /// startup.
+    #[cfg(feature = "custom_rng")]
+    pub fn set_global_rng<R: CryptoRng + Send + 'static>(rng: R) -> bool {
+        #[cfg(not(any(feature = "rand", feature = "getrandom")))]
+        {
+            *GLOBAL_RNG.lock() = Some(Box::new(rng));
+            true
+        }
+        #[cfg(any(feature = "rand", feature = "getrandom"))]
+        {
+            drop(rng);
+            false
+        }
+    }
+
+    /// Remove the RNG installed with [`Utils::set_global_rng`], returning whether one was set
+    #[cfg(feature = "custom_rng")]
+    pub fn clear_global_rng() -> bool {
+        #[cfg(not(any(feature = "rand", feature = "getrandom")))]
+        {
+            GLOBAL_RNG.lock().take().is_some()
+        }
+        #[cfg(any(feature = "rand", feature = "getrandom"))]
+        {
+            false
+        }
+    }
+
     #[cfg(not(any(feature = "rand", feature = "getrandom")))]
     #[cfg(not(feature = "alloc"))]
     pub fn random_bytes(_length: usize) -> Result<&'static [u8]> {
@@ -294,15 +626,27 @@ impl Utils {
 
     /// Convert hex string to bytes
     ///
+    /// Leading and trailing whitespace is ignored; everything else must be an ASCII hex digit.
+    /// Decoding works on raw bytes, so non-ASCII input is rejected rather than split mid-character.
+    ///
     /// # Errors
     ///
     /// Returns [`crate::error::Error::HexDecode`] with a [`crate::error::HexDecodeError`] reason when the
-    /// trimmed input is not valid hexadecimal (odd length or non-hex digit).
+    /// trimmed input is not valid hexadecimal: odd length, or the exact index of the first non-hex digit.
     #[cfg(feature = "alloc")]
     pub fn hex_to_bytes(hex: &str) -> Result<Vec<u8>> {
         use crate::error::HexDecodeError;
 
-        let hex = hex.trim();
+        const fn nibble(digit: u8) -> Option<u8> {
+            match digit {
+                b'0'..=b'9' => Some(digit - b'0'),
+                b'a'..=b'f' => Some(digit - b'a' + 10),
+                b'A'..=b'F' => Some(digit - b'A' + 10),
+                _ => None,
+            }
+        }
+
+        let hex = hex.trim().as_bytes();
 
         if !hex.len().is_multiple_of(2) {
             return Err(crate::error::Error::HexDecode(HexDecodeError::OddLength {
@@ -311,14 +655,17 @@ impl Utils {
         }
 
         let mut bytes = Vec::with_capacity(hex.len() / 2);
-        for i in (0..hex.len()).step_by(2) {
-            let byte = u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| {
-                crate::error::Error::HexDecode(HexDecodeError::InvalidDigit {
-                    pair_start: i,
-                    char_count: hex.len(),
-                })
-            })?;
-            bytes.push(byte);
+        for (pair_start, pair) in (0..).step_by(2).zip(hex.as_chunks::<2>().0) {
+            let digit = |offset: usize| {
+                nibble(pair[offset]).ok_or(crate::error::Error::HexDecode(
+                    HexDecodeError::InvalidDigit {
+                        position: pair_start + offset,
+                        pair_start,
+                        char_count: hex.len(),
+                    },
+                ))
+            };
+            bytes.push((digit(0)? << 4) | digit(1)?);
         }
 
         Ok(bytes)
@@ -332,11 +679,42 @@ impl Utils {
     }
 
     /// Constant-time comparison of two byte slices
+    ///
+    /// Same result as [`constant_time_eq`](Self::constant_time_eq).
     pub fn constant_time_compare(a: &[u8], b: &[u8]) -> bool {
-        if a.len() != b.len() {
-            return false;
+        Self::constant_time_eq(a, b)
+    }
+
+    /// Constant-time equality of two byte slices, including their lengths
+    ///
+    /// Both slices are scanned up to the longer length, with missing bytes read as zero,
+    /// and a length mismatch is folded into the same accumulator instead of returning
+    /// early. The running time depends only on `max(a.len(), b.len())`, so it is
+    /// data-independent for equal-length inputs. Returns the same result as `a == b`.
+    pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+        let mut equal = (a.len() as u64).ct_eq(&(b.len() as u64));
+        for i in 0..a.len().max(b.len()) {
+            let x = a.get(i).copied().unwrap_or(0);
+            let y = b.get(i).copied().unwrap_or(0);
+            equal &= x.ct_eq(&y);
         }
-        a.ct_eq(b).into()
+        equal.into()
+    }
+
+    /// Constant-time byte-wise selection: `a` when `choice` is `false`, `b` when `true`
+    ///
+    /// Every output byte is computed as `a[i] ^ (mask & (a[i] ^ b[i]))` with `mask` all-zero
+    /// or all-one bits derived from `choice`, so neither the control flow nor the memory
+    /// accesses depend on it. The slices must have equal length (checked in debug builds);
+    /// in release builds the output has the length of the shorter one.
+    #[cfg(feature = "alloc")]
+    pub fn ct_select(a: &[u8], b: &[u8], choice: bool) -> Vec<u8> {
+        debug_assert_eq!(a.len(), b.len(), "ct_select inputs must have equal length");
+        let choice = Choice::from(u8::from(choice));
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| u8::conditional_select(x, y, choice))
+            .collect()
     }
 }
 
@@ -348,6 +726,8 @@ mod tests {
         HashContext,
         SignatureContext,
     };
+    #[cfg(feature = "alloc")]
+    use crate::testing::SeededRng;
 
     #[test]
     fn test_provider_architecture() {
@@ -504,6 +884,41 @@ mod tests {
         assert!(!Utils::constant_time_compare(b"hello", b"hell"));
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_ct_select() {
+        let a = [0x00, 0xFF, 0x5A, 0xA5];
+        let b = [0xFF, 0x00, 0x5A, 0x3C];
+        assert_eq!(Utils::ct_select(&a, &b, false), a);
+        assert_eq!(Utils::ct_select(&a, &b, true), b);
+        assert!(Utils::ct_select(&[], &[], true).is_empty());
+
+        // The mask is all-zero or all-one bits, so every bit position is taken from a
+        // single input: selecting between complements reproduces the chosen one exactly.
+        let ones = [0xFFu8; 32];
+        let zeros = [0u8; 32];
+        assert_eq!(Utils::ct_select(&zeros, &ones, true), ones);
+        assert_eq!(Utils::ct_select(&zeros, &ones, false), zeros);
+    }
+
+    #[test]
+    fn test_constant_time_eq_matches_slice_equality() {
+        let cases: [(&[u8], &[u8]); 8] = [
+            (b"", b""),
+            (b"hello", b"hello"),
+            (b"hello", b"hellp"),
+            (b"hello", b"jello"),
+            (b"hello", b"hell"),
+            (b"hell", b"hello"),
+            (b"", b"\0"),
+            (b"abc\0", b"abc"),
+        ];
+        for (a, b) in cases {
+            assert_eq!(Utils::constant_time_eq(a, b), a == b, "{a:?} vs {b:?}");
+            assert_eq!(Utils::constant_time_compare(a, b), a == b);
+        }
+    }
+
     #[cfg(feature = "getrandom")]
     #[test]
     fn test_random_bytes_entropy_quality() {
@@ -590,7 +1005,7 @@ mod tests {
     #[cfg(any(feature = "rand", all(feature = "getrandom", feature = "alloc")))]
     #[test]
     fn test_random_bytes_size_limits() {
-        const MAX_SIZE: usize = 1024 * 1024; // 1MB
+        const MAX_SIZE: usize = MAX_RANDOM_BYTES;
         assert_eq!(
             Utils::random_bytes(0),
             Err(crate::error::Error::RandomBytesLengthInvalid {
@@ -622,6 +1037,30 @@ mod tests {
         }
     }
 
+    #[cfg(any(feature = "rand", all(feature = "getrandom", feature = "alloc")))]
+    #[test]
+    fn test_random_bytes_with_limit() {
+        const LIMIT: usize = 4096;
+        assert_eq!(
+            Utils::random_bytes_with_limit(LIMIT, LIMIT).unwrap().len(),
+            LIMIT
+        );
+        assert_eq!(
+            Utils::random_bytes_with_limit(LIMIT + 1, LIMIT),
+            Err(crate::error::Error::RandomBytesLengthInvalid {
+                min: 1,
+                max: LIMIT,
+                requested: LIMIT + 1,
+            })
+        );
+        assert!(Utils::random_bytes_with_limit(0, usize::MAX).is_err());
+
+        // Above the default cap once the caller opts in.
+        let large = Utils::random_bytes_with_limit(4 * MAX_RANDOM_BYTES, usize::MAX).unwrap();
+        assert_eq!(large.len(), 4 * MAX_RANDOM_BYTES);
+        assert!(large.iter().any(|&byte| byte != 0));
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn test_hex_to_bytes_decode_errors() {
@@ -637,9 +1076,35 @@ mod tests {
         assert_eq!(
             Utils::hex_to_bytes("12g3").unwrap_err(),
             Error::HexDecode(HexDecodeError::InvalidDigit {
+                position: 2,
                 pair_start: 2,
                 char_count: 4,
             })
         );
+        assert_eq!(
+            Utils::hex_to_bytes("123g").unwrap_err(),
+            Error::HexDecode(HexDecodeError::InvalidDigit {
+                position: 3,
+                pair_start: 2,
+                char_count: 4,
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_random_bytes_from_rng_is_deterministic_for_a_seed() {
+        let first = Utils::random_bytes_from_rng(&mut SeededRng::new(b"seed 7"), 37).unwrap();
+        let second = Utils::random_bytes_from_rng(&mut SeededRng::new(b"seed 7"), 37).unwrap();
+        let other_seed = Utils::random_bytes_from_rng(&mut SeededRng::new(b"seed 8"), 37).unwrap();
+        assert_eq!(first.len(), 37);
+        assert_eq!(first, second);
+        assert_ne!(first, other_seed);
+
+        assert!(matches!(
+            Utils::random_bytes_from_rng(&mut SeededRng::new(b"seed 7"), 0),
+            Err(crate::error::Error::RandomBytesLengthInvalid { .. })
+        ));
+        assert!(Utils::random_bytes_from_rng(&mut SeededRng::new(b"seed 7"), MAX_RANDOM_BYTES + 1).is_err());
     }
 }
@@ -0,0 +1,127 @@
+//! Length-prefixed framing for concatenated byte strings
+//!
+//! [`write_frame`] appends each segment as `length (u32 BE) || bytes`, and [`read_frames`]
+//! splits such a buffer back into its segments. Because every segment carries its own
+//! length, concatenating variable-length outputs (encapsulated keys, ciphertexts,
+//! signatures) is unambiguous, and empty segments round-trip.
+
+use alloc::vec::Vec;
+
+use crate::error::{
+    Error,
+    Result,
+};
+
+/// Size of the length prefix in front of every segment
+pub const LENGTH_PREFIX_SIZE: usize = 4;
+
+/// Append `segments` to `out`, each prefixed with its length as a big-endian `u32`
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidMessageSize`] if a segment is longer than `u32::MAX` bytes; `out`
+/// is left unchanged in that case.
+pub fn write_frame(out: &mut Vec<u8>, segments: &[&[u8]]) -> Result<()> {
+    let mut total = 0usize;
+    for segment in segments {
+        if u32::try_from(segment.len()).is_err() {
+            return Err(Error::InvalidMessageSize {
+                max: u32::MAX as usize,
+                actual: segment.len(),
+            });
+        }
+        total = total.saturating_add(LENGTH_PREFIX_SIZE + segment.len());
+    }
+
+    out.reserve(total);
+    for segment in segments {
+        out.extend_from_slice(&(segment.len() as u32).to_be_bytes());
+        out.extend_from_slice(segment);
+    }
+    Ok(())
+}
+
+/// Split a buffer written by [`write_frame`] into its segments
+///
+/// The returned slices borrow from `bytes`. An empty buffer holds no segments.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidMessageSize`] if a length prefix is cut off or declares more
+/// bytes than remain in the buffer.
+pub fn read_frames(bytes: &[u8]) -> Result<Vec<&[u8]>> {
+    let mut segments = Vec::new();
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        let (prefix, body) =
+            rest.split_first_chunk::<LENGTH_PREFIX_SIZE>()
+                .ok_or(Error::InvalidMessageSize {
+                    max: rest.len(),
+                    actual: LENGTH_PREFIX_SIZE,
+                })?;
+        let len = u32::from_be_bytes(*prefix) as usize;
+        if len > body.len() {
+            return Err(Error::InvalidMessageSize {
+                max: body.len(),
+                actual: len,
+            });
+        }
+        let (segment, remaining) = body.split_at(len);
+        segments.push(segment);
+        rest = remaining;
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn empty_segments_round_trip() {
+        let mut out = Vec::new();
+        write_frame(&mut out, &[b"", b"x", b""]).unwrap();
+        assert_eq!(out, [0, 0, 0, 0, 0, 0, 0, 1, b'x', 0, 0, 0, 0]);
+        assert_eq!(read_frames(&out).unwrap(), [&b""[..], b"x", b""]);
+
+        let mut none = Vec::new();
+        write_frame(&mut none, &[]).unwrap();
+        assert!(none.is_empty());
+        assert!(read_frames(&none).unwrap().is_empty());
+    }
+
+    #[test]
+    fn many_segments_round_trip_and_append() {
+        let owned: Vec<Vec<u8>> = (0..300u16)
+            .map(|i| vec![(i % 251) as u8; usize::from(i)])
+            .collect();
+        let segments: Vec<&[u8]> = owned.iter().map(Vec::as_slice).collect();
+
+        let mut out = b"prefix".to_vec();
+        let (first, second) = segments.split_at(100);
+        write_frame(&mut out, first).unwrap();
+        write_frame(&mut out, second).unwrap();
+        assert_eq!(read_frames(&out[6..]).unwrap(), segments);
+    }
+
+    #[test]
+    fn oversized_or_truncated_length_prefix_is_rejected() {
+        let mut out = Vec::new();
+        write_frame(&mut out, &[b"abc", b"defg"]).unwrap();
+
+        // A length claiming far more than the buffer holds must not be trusted.
+        let mut oversized = out.clone();
+        oversized[..4].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(matches!(
+            read_frames(&oversized),
+            Err(Error::InvalidMessageSize { actual, .. }) if actual == u32::MAX as usize
+        ));
+
+        // One byte short of the last segment, and a prefix cut in half.
+        assert!(read_frames(&out[..out.len() - 1]).is_err());
+        assert!(read_frames(&out[..9]).is_err());
+        assert!(read_frames(&[0, 0]).is_err());
+    }
+}
@@ -148,12 +148,7 @@ impl AeadContext {
         ciphertext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
-        if !self.inner.is_initialized() {
-            return Err(crate::error::Error::InvalidState {
-                operation: String::from("decrypt"),
-                reason: String::from("Context not initialized"),
-            });
-        }
+        self.inner.ensure_initialized()?;
 
         // Validate algorithm category
         if algorithm.category() != AlgorithmCategory::Aead {
@@ -171,6 +166,26 @@ impl AeadContext {
         }
     }
 
+    /// Fail early unless a provider with AEAD support is set
+    ///
+    /// Operations report a missing provider only once their arguments pass validation; call
+    /// this right after building the context to surface a misconfiguration up front. Like
+    /// every operation, it initializes the context on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ProviderNotConfigured`](crate::error::Error::ProviderNotConfigured)
+    /// if no provider is set or the provider has no AEAD operations.
+    pub fn ensure_provider(&self) -> Result<()> {
+        self.inner.ensure_initialized()?;
+        match self.inner.provider().and_then(|p| p.aead()) {
+            Some(_) => Ok(()),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("AEAD"),
+            }),
+        }
+    }
+
     /// Check if the context is initialized
     pub fn is_initialized(&self) -> bool {
         self.inner.is_initialized()
@@ -6,6 +6,10 @@
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 use core::marker::PhantomData;
+use core::sync::atomic::{
+    AtomicBool,
+    Ordering,
+};
 
 use crate::api::CryptoProvider;
 use crate::error::Result;
@@ -26,10 +30,13 @@ pub trait ContextOps {
 }
 
 /// Generic context wrapper that provides common functionality
+///
+/// Initialization is tracked with an atomic flag so that `&self` operations can initialize
+/// the context lazily, the same way `&mut self` operations do.
 #[cfg(feature = "alloc")]
 pub struct BaseContext<T> {
     _phantom: PhantomData<T>,
-    initialized: bool,
+    initialized: AtomicBool,
     provider: Option<Box<dyn CryptoProvider>>,
 }
 
@@ -39,7 +46,7 @@ impl<T> BaseContext<T> {
     pub fn new() -> Self {
         Self {
             _phantom: PhantomData,
-            initialized: false,
+            initialized: AtomicBool::new(false),
             provider: None,
         }
     }
@@ -48,23 +55,20 @@ impl<T> BaseContext<T> {
     pub fn with_provider(provider: Box<dyn CryptoProvider>) -> Self {
         Self {
             _phantom: PhantomData,
-            initialized: false,
+            initialized: AtomicBool::new(false),
             provider: Some(provider),
         }
     }
 
     /// Initialize the context
-    pub fn init(&mut self) -> Result<()> {
-        if self.initialized {
-            return Ok(());
-        }
-        self.initialized = true;
+    pub fn init(&self) -> Result<()> {
+        self.initialized.store(true, Ordering::Release);
         Ok(())
     }
 
     /// Check if the context is initialized
     pub fn is_initialized(&self) -> bool {
-        self.initialized
+        self.initialized.load(Ordering::Acquire)
     }
 
     /// Get the provider (if any)
@@ -78,8 +82,8 @@ impl<T> BaseContext<T> {
     }
 
     /// Ensure the context is initialized
-    pub fn ensure_initialized(&mut self) -> Result<()> {
-        if !self.initialized {
+    pub fn ensure_initialized(&self) -> Result<()> {
+        if !self.is_initialized() {
             self.init()?;
         }
         Ok(())
@@ -172,7 +176,7 @@ mod tests {
 
     #[test]
     fn test_base_context_initialization() {
-        let mut context = BaseContext::<()>::new();
+        let context = BaseContext::<()>::new();
         assert!(!context.is_initialized());
 
         let result = context.init();
@@ -213,7 +217,7 @@ mod tests {
 
     #[test]
     fn test_ensure_initialized() {
-        let mut context = BaseContext::<()>::new();
+        let context = BaseContext::<()>::new();
         assert!(!context.is_initialized());
 
         let result = context.ensure_initialized();
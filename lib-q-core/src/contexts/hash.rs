@@ -6,6 +6,7 @@
 #[cfg(feature = "alloc")]
 use alloc::{
     boxed::Box,
+    format,
     string::String,
     vec::Vec,
 };
@@ -17,6 +18,7 @@ use crate::api::{
     Algorithm,
     AlgorithmCategory,
     CryptoProvider,
+    HashState,
 };
 use crate::error::Result;
 
@@ -24,6 +26,8 @@ use crate::error::Result;
 #[cfg(feature = "alloc")]
 pub struct HashContext {
     inner: BaseContext<Self>,
+    /// In-progress streaming hash and the algorithm it was started with
+    stream: Option<(Algorithm, Box<dyn HashState>)>,
 }
 
 #[cfg(feature = "alloc")]
@@ -32,6 +36,7 @@ impl HashContext {
     pub fn new() -> Self {
         Self {
             inner: BaseContext::new(),
+            stream: None,
         }
     }
 
@@ -39,6 +44,7 @@ impl HashContext {
     pub fn with_provider(provider: Box<dyn CryptoProvider>) -> Self {
         Self {
             inner: BaseContext::with_provider(provider),
+            stream: None,
         }
     }
 
@@ -50,6 +56,7 @@ impl HashContext {
                 Ok(provider) => BaseContext::with_provider(Box::new(provider)),
                 Err(_) => BaseContext::new(),
             },
+            stream: None,
         }
     }
 
@@ -83,6 +90,183 @@ impl HashContext {
         }
     }
 
+    /// Hash data under a customization string
+    ///
+    /// Shorthand for [`hash_customized`](Self::hash_customized) without a key, so it covers
+    /// KangarooTwelve ([`Algorithm::Kt128`], [`Algorithm::Kt256`]) and cSHAKE; an empty
+    /// `customization` gives the same digest as [`hash`](Self::hash).
+    pub fn hash_with_customization(
+        &mut self,
+        algorithm: Algorithm,
+        data: &[u8],
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.hash_customized(algorithm, data, customization, None)
+    }
+
+    /// Hash data with a customization string and optional key
+    ///
+    /// Routes `customization` to cSHAKE's and KangarooTwelve's customization input and, for
+    /// KMAC, `key` to the MAC key with `customization` as its customization string. KMAC
+    /// without a key, a key for any other algorithm, or a non-empty customization for an
+    /// algorithm without one is an error.
+    pub fn hash_customized(
+        &mut self,
+        algorithm: Algorithm,
+        data: &[u8],
+        customization: &[u8],
+        key: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        self.inner.ensure_initialized()?;
+
+        if algorithm.category() != AlgorithmCategory::Hash {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a hash algorithm",
+            });
+        }
+
+        match self.inner.provider().and_then(|p| p.hash()) {
+            Some(hash_ops) => hash_ops.hash_customized(algorithm, data, customization, key),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("hash"),
+            }),
+        }
+    }
+
+    /// Hash several fields as one unambiguous tuple
+    ///
+    /// Only [`Algorithm::TupleHash128`] and [`Algorithm::TupleHash256`] are accepted. Field
+    /// boundaries are part of the encoding, unlike hashing the concatenated fields with
+    /// [`hash`](Self::hash).
+    pub fn hash_tuple(
+        &mut self,
+        algorithm: Algorithm,
+        fields: &[&[u8]],
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.inner.ensure_initialized()?;
+
+        if algorithm.category() != AlgorithmCategory::Hash {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a hash algorithm",
+            });
+        }
+
+        match self.inner.provider().and_then(|p| p.hash()) {
+            Some(hash_ops) => hash_ops.hash_tuple(algorithm, fields, customization),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("hash"),
+            }),
+        }
+    }
+
+    /// Hash data with ParallelHash using an explicit block size
+    ///
+    /// Only [`Algorithm::ParallelHash128`] and [`Algorithm::ParallelHash256`] are accepted and
+    /// `block_size` must be non-zero ([`Error::InvalidParameter`](crate::error::Error::InvalidParameter)
+    /// otherwise). The block size is part of the domain: digests computed
+    /// with different block sizes are unrelated, so verifiers must use the same value.
+    pub fn hash_parallel(
+        &mut self,
+        algorithm: Algorithm,
+        data: &[u8],
+        block_size: usize,
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.inner.ensure_initialized()?;
+
+        if algorithm.category() != AlgorithmCategory::Hash {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a hash algorithm",
+            });
+        }
+
+        match self.inner.provider().and_then(|p| p.hash()) {
+            Some(hash_ops) => hash_ops.hash_parallel(algorithm, data, block_size, customization),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("hash"),
+            }),
+        }
+    }
+
+    /// Absorb a chunk of input into a streaming hash
+    ///
+    /// The first call starts a stream for `algorithm`; later calls must pass the same
+    /// algorithm until [`finalize`](Self::finalize) is called. The resulting digest matches
+    /// [`hash`](Self::hash) over the concatenation of all chunks.
+    pub fn update(&mut self, algorithm: Algorithm, data: &[u8]) -> Result<()> {
+        self.inner.ensure_initialized()?;
+
+        if algorithm.category() != AlgorithmCategory::Hash {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a hash algorithm",
+            });
+        }
+
+        if let Some((active, state)) = self.stream.as_mut() {
+            if *active != algorithm {
+                return Err(crate::error::Error::InvalidState {
+                    operation: String::from("hash update"),
+                    reason: format!(
+                        "streaming hash started with {active:?}, cannot continue with {algorithm:?}"
+                    ),
+                });
+            }
+            state.update(data);
+            return Ok(());
+        }
+
+        let mut state = match self.inner.provider().and_then(|p| p.hash()) {
+            Some(hash_ops) => hash_ops.hash_state(algorithm)?,
+            None => {
+                return Err(crate::error::Error::ProviderNotConfigured {
+                    operation: String::from("hash update"),
+                });
+            }
+        };
+        state.update(data);
+        self.stream = Some((algorithm, state));
+        Ok(())
+    }
+
+    /// Finish the streaming hash started by [`update`](Self::update) and return its digest
+    ///
+    /// The context is ready for a new stream afterwards.
+    pub fn finalize(&mut self) -> Result<Vec<u8>> {
+        match self.stream.take() {
+            Some((_, state)) => Ok(state.finalize()),
+            None => Err(crate::error::Error::InvalidState {
+                operation: String::from("hash finalize"),
+                reason: String::from("no streaming hash in progress"),
+            }),
+        }
+    }
+
+    /// Algorithm of the in-progress streaming hash, if any
+    pub fn streaming_algorithm(&self) -> Option<Algorithm> {
+        self.stream.as_ref().map(|(algorithm, _)| *algorithm)
+    }
+
+    /// Fail early unless a provider with hash support is set
+    ///
+    /// Operations report a missing provider only once their arguments pass validation; call
+    /// this right after building the context to surface a misconfiguration up front. Like
+    /// every operation, it initializes the context on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ProviderNotConfigured`](crate::error::Error::ProviderNotConfigured)
+    /// if no provider is set or the provider has no hash operations.
+    pub fn ensure_provider(&self) -> Result<()> {
+        self.inner.ensure_initialized()?;
+        match self.inner.provider().and_then(|p| p.hash()) {
+            Some(_) => Ok(()),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("hash"),
+            }),
+        }
+    }
+
     /// Check if the context is initialized
     pub fn is_initialized(&self) -> bool {
         self.inner.is_initialized()
@@ -163,6 +347,27 @@ mod tests {
         assert!(context.is_initialized());
     }
 
+    #[test]
+    fn test_hash_context_streaming_requires_provider_support() {
+        let mut context = HashContext::new();
+        assert!(matches!(
+            context.update(Algorithm::Sha3_256, b"chunk"),
+            Err(crate::error::Error::ProviderNotConfigured { .. })
+        ));
+
+        // MockHashProvider keeps the default `hash_state`, which is not implemented.
+        context.set_provider(Box::new(MockHashProvider));
+        assert!(matches!(
+            context.update(Algorithm::Sha3_256, b"chunk"),
+            Err(crate::error::Error::NotImplemented { .. })
+        ));
+        assert!(context.streaming_algorithm().is_none());
+        assert!(matches!(
+            context.finalize(),
+            Err(crate::error::Error::InvalidState { .. })
+        ));
+    }
+
     #[test]
     fn test_hash_context_algorithm_validation() {
         let mut context = HashContext::new();
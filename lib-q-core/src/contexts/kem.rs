@@ -10,7 +10,14 @@ use alloc::{
     vec::Vec,
 };
 
-use super::BaseContext;
+use rand_core::CryptoRng;
+use zeroize::Zeroizing;
+
+use super::{
+    BaseContext,
+    MAX_KEYGEN_SEED_SIZE,
+    keygen_seed_size,
+};
 #[cfg(test)]
 use crate::api::KemOperations;
 use crate::api::{
@@ -92,6 +99,55 @@ impl KemContext {
         }
     }
 
+    /// Generate a keypair with key-generation randomness drawn from `rng`
+    ///
+    /// The algorithm's key-generation seed is read from `rng` and handed to the provider, so a
+    /// seeded RNG yields the same key pair on every run. Use this for reproducible tests or
+    /// when the platform has no OS entropy source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`](crate::error::Error::InvalidAlgorithm) if
+    /// `algorithm` is not a KEM,
+    /// [`Error::UnsupportedOperation`](crate::error::Error::UnsupportedOperation) for KEMs
+    /// other than ML-KEM, and any error of [`generate_keypair`](Self::generate_keypair).
+    pub fn generate_keypair_with_rng<R: CryptoRng + ?Sized>(
+        &mut self,
+        algorithm: Algorithm,
+        rng: &mut R,
+    ) -> Result<KemKeypair> {
+        if algorithm.category() != AlgorithmCategory::Kem {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a KEM algorithm",
+            });
+        }
+        let mut seed = Zeroizing::new([0u8; MAX_KEYGEN_SEED_SIZE]);
+        let seed = &mut seed[..keygen_seed_size(algorithm)?];
+        rng.fill_bytes(seed);
+        self.generate_keypair(algorithm, Some(seed))
+    }
+
+    /// Generate a keypair and hand back the secret key already wrapped in [`Zeroizing`]
+    ///
+    /// Prefer this over copying [`KemSecretKey::expose_secret`] into a plain `Vec`, which
+    /// would leave an unzeroized copy behind. Rebuild a [`KemSecretKey`] from the bytes when
+    /// decapsulating.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`generate_keypair`](Self::generate_keypair) with no caller randomness.
+    pub fn generate_keypair_zeroizing(
+        &mut self,
+        algorithm: Algorithm,
+    ) -> Result<(KemPublicKey, Zeroizing<Vec<u8>>)> {
+        let KemKeypair {
+            public_key,
+            mut secret_key,
+        } = self.generate_keypair(algorithm, None)?;
+        let secret = Zeroizing::new(core::mem::take(&mut secret_key.data));
+        Ok((public_key, secret))
+    }
+
     /// Encapsulate a key using the given public key
     pub fn encapsulate(
         &self,
@@ -99,12 +155,7 @@ impl KemContext {
         public_key: &KemPublicKey,
         randomness: Option<&[u8]>,
     ) -> Result<(Vec<u8>, Vec<u8>)> {
-        if !self.inner.is_initialized() {
-            return Err(crate::error::Error::InvalidState {
-                operation: String::from("encapsulate"),
-                reason: String::from("Context not initialized"),
-            });
-        }
+        self.inner.ensure_initialized()?;
 
         // Validate algorithm category
         if algorithm.category() != AlgorithmCategory::Kem {
@@ -129,12 +180,7 @@ impl KemContext {
         secret_key: &KemSecretKey,
         ciphertext: &[u8],
     ) -> Result<Vec<u8>> {
-        if !self.inner.is_initialized() {
-            return Err(crate::error::Error::InvalidState {
-                operation: String::from("decapsulate"),
-                reason: String::from("Context not initialized"),
-            });
-        }
+        self.inner.ensure_initialized()?;
 
         // Validate algorithm category
         if algorithm.category() != AlgorithmCategory::Kem {
@@ -152,6 +198,26 @@ impl KemContext {
         }
     }
 
+    /// Fail early unless a provider with KEM support is set
+    ///
+    /// Operations report a missing provider only once their arguments pass validation; call
+    /// this right after building the context to surface a misconfiguration up front. Like
+    /// every operation, it initializes the context on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ProviderNotConfigured`](crate::error::Error::ProviderNotConfigured)
+    /// if no provider is set or the provider has no KEM operations.
+    pub fn ensure_provider(&self) -> Result<()> {
+        self.inner.ensure_initialized()?;
+        match self.inner.provider().and_then(|p| p.kem()) {
+            Some(_) => Ok(()),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("KEM"),
+            }),
+        }
+    }
+
     /// Check if the context is initialized
     pub fn is_initialized(&self) -> bool {
         self.inner.is_initialized()
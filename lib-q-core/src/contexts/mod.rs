@@ -25,3 +25,24 @@ pub use hash::*;
 pub use kem::*;
 #[cfg(feature = "alloc")]
 pub use signature::*;
+
+/// Size of the key-generation seed drawn from an injected RNG
+///
+/// ML-KEM takes the FIPS 203 seed `d ‖ z` (64 bytes) and ML-DSA the FIPS 204 seed `ξ`
+/// (32 bytes); other algorithms do not accept RNG injection.
+#[cfg(feature = "alloc")]
+pub(crate) fn keygen_seed_size(algorithm: crate::api::Algorithm) -> crate::error::Result<usize> {
+    use crate::api::Algorithm;
+
+    match algorithm {
+        Algorithm::MlKem512 | Algorithm::MlKem768 | Algorithm::MlKem1024 => Ok(64),
+        Algorithm::MlDsa44 | Algorithm::MlDsa65 | Algorithm::MlDsa87 => Ok(32),
+        _ => Err(crate::error::Error::UnsupportedOperation {
+            operation: "RNG-injected key generation is only available for ML-KEM and ML-DSA".into(),
+        }),
+    }
+}
+
+/// Largest value returned by [`keygen_seed_size`]
+#[cfg(feature = "alloc")]
+pub(crate) const MAX_KEYGEN_SEED_SIZE: usize = 64;
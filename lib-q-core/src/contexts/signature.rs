@@ -10,13 +10,22 @@ use alloc::{
     vec::Vec,
 };
 
-use super::BaseContext;
+use rand_core::CryptoRng;
+use zeroize::Zeroizing;
+
+use super::{
+    BaseContext,
+    MAX_KEYGEN_SEED_SIZE,
+    keygen_seed_size,
+};
 #[cfg(test)]
 use crate::api::SignatureOperations;
 use crate::api::{
     Algorithm,
     AlgorithmCategory,
     CryptoProvider,
+    PUBLIC_KEY_FINGERPRINT_SIZE,
+    validate_signature_context,
 };
 use crate::error::Result;
 use crate::traits::{
@@ -92,6 +101,35 @@ impl SignatureContext {
         }
     }
 
+    /// Generate a keypair with key-generation randomness drawn from `rng`
+    ///
+    /// The algorithm's key-generation seed is read from `rng` and handed to the provider, so a
+    /// seeded RNG yields the same key pair on every run. Use this for reproducible tests or
+    /// when the platform has no OS entropy source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`](crate::error::Error::InvalidAlgorithm) if
+    /// `algorithm` is not a signature algorithm,
+    /// [`Error::UnsupportedOperation`](crate::error::Error::UnsupportedOperation) for
+    /// signature algorithms other than ML-DSA, and any error of
+    /// [`generate_keypair`](Self::generate_keypair).
+    pub fn generate_keypair_with_rng<R: CryptoRng + ?Sized>(
+        &mut self,
+        algorithm: Algorithm,
+        rng: &mut R,
+    ) -> Result<SigKeypair> {
+        if algorithm.category() != AlgorithmCategory::Signature {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a signature algorithm",
+            });
+        }
+        let mut seed = Zeroizing::new([0u8; MAX_KEYGEN_SEED_SIZE]);
+        let seed = &mut seed[..keygen_seed_size(algorithm)?];
+        rng.fill_bytes(seed);
+        self.generate_keypair(algorithm, Some(seed))
+    }
+
     /// Sign a message using the given secret key
     pub fn sign(
         &self,
@@ -100,12 +138,7 @@ impl SignatureContext {
         message: &[u8],
         randomness: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
-        if !self.inner.is_initialized() {
-            return Err(crate::error::Error::InvalidState {
-                operation: String::from("sign"),
-                reason: String::from("Context not initialized"),
-            });
-        }
+        self.inner.ensure_initialized()?;
 
         // Validate algorithm category
         if algorithm.category() != AlgorithmCategory::Signature {
@@ -131,12 +164,7 @@ impl SignatureContext {
         message: &[u8],
         signature: &[u8],
     ) -> Result<bool> {
-        if !self.inner.is_initialized() {
-            return Err(crate::error::Error::InvalidState {
-                operation: String::from("verify"),
-                reason: String::from("Context not initialized"),
-            });
-        }
+        self.inner.ensure_initialized()?;
 
         // Validate algorithm category
         if algorithm.category() != AlgorithmCategory::Signature {
@@ -154,6 +182,164 @@ impl SignatureContext {
         }
     }
 
+    /// Sign a message under a context string (FIPS 204 / FIPS 205 domain separation)
+    ///
+    /// `context` may be at most
+    /// [`MAX_SIGNATURE_CONTEXT_SIZE`](crate::api::MAX_SIGNATURE_CONTEXT_SIZE) bytes; a
+    /// signature made under one context does not verify under another. An empty context is
+    /// identical to [`sign`](Self::sign).
+    pub fn sign_with_context(
+        &self,
+        algorithm: Algorithm,
+        secret_key: &SigSecretKey,
+        message: &[u8],
+        context: &[u8],
+        randomness: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        self.inner.ensure_initialized()?;
+
+        if algorithm.category() != AlgorithmCategory::Signature {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a signature algorithm",
+            });
+        }
+        validate_signature_context(context)?;
+
+        match self.inner.provider().and_then(|p| p.signature()) {
+            Some(sig_ops) => {
+                sig_ops.sign_with_context(algorithm, secret_key, message, context, randomness)
+            }
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("signature"),
+            }),
+        }
+    }
+
+    /// Verify a signature made under a context string
+    pub fn verify_with_context(
+        &self,
+        algorithm: Algorithm,
+        public_key: &SigPublicKey,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> Result<bool> {
+        self.inner.ensure_initialized()?;
+
+        if algorithm.category() != AlgorithmCategory::Signature {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a signature algorithm",
+            });
+        }
+        validate_signature_context(context)?;
+
+        match self.inner.provider().and_then(|p| p.signature()) {
+            Some(sig_ops) => {
+                sig_ops.verify_with_context(algorithm, public_key, message, context, signature)
+            }
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("signature"),
+            }),
+        }
+    }
+
+    /// Fingerprint of an `algorithm` public key, see
+    /// [`SignatureOperations::public_key_fingerprint`](crate::api::SignatureOperations::public_key_fingerprint)
+    ///
+    /// The key must have the encoded length of `algorithm`, so a key cannot be fingerprinted
+    /// as belonging to the wrong parameter set.
+    pub fn public_key_fingerprint(
+        &self,
+        algorithm: Algorithm,
+        public_key: &SigPublicKey,
+    ) -> Result<[u8; PUBLIC_KEY_FINGERPRINT_SIZE]> {
+        self.inner.ensure_initialized()?;
+
+        if algorithm.category() != AlgorithmCategory::Signature {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a signature algorithm",
+            });
+        }
+        if let Some(expected) = algorithm.public_key_len() &&
+            public_key.as_bytes().len() != expected
+        {
+            return Err(crate::error::Error::InvalidKeySize {
+                expected,
+                actual: public_key.as_bytes().len(),
+            });
+        }
+
+        match self.inner.provider().and_then(|p| p.signature()) {
+            Some(sig_ops) => sig_ops.public_key_fingerprint(algorithm, public_key),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("signature"),
+            }),
+        }
+    }
+
+    /// Sign `message` and return the signature with the signer's key fingerprint
+    ///
+    /// `public_key` must be the public half of `secret_key`; it is only fingerprinted, not
+    /// checked against the secret key. Signing uses the provider's default randomness, as
+    /// [`sign`](Self::sign) with `None`.
+    pub fn sign_with_fingerprint(
+        &self,
+        algorithm: Algorithm,
+        secret_key: &SigSecretKey,
+        public_key: &SigPublicKey,
+        message: &[u8],
+    ) -> Result<(Vec<u8>, [u8; PUBLIC_KEY_FINGERPRINT_SIZE])> {
+        let fingerprint = self.public_key_fingerprint(algorithm, public_key)?;
+        let signature = self.sign(algorithm, secret_key, message, None)?;
+        Ok((signature, fingerprint))
+    }
+
+    /// Verify a batch of `(public_key, message, signature)` items
+    ///
+    /// Returns one boolean per item, in input order. Malformed keys or signatures
+    /// yield `false` for that item, as in
+    /// [`SignatureOperations::verify_batch`](crate::api::SignatureOperations::verify_batch).
+    pub fn verify_batch(
+        &self,
+        algorithm: Algorithm,
+        items: &[(SigPublicKey, Vec<u8>, Vec<u8>)],
+    ) -> Result<Vec<bool>> {
+        self.inner.ensure_initialized()?;
+
+        if algorithm.category() != AlgorithmCategory::Signature {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a signature algorithm",
+            });
+        }
+
+        match self.inner.provider().and_then(|p| p.signature()) {
+            Some(sig_ops) => sig_ops.verify_batch(algorithm, items),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("signature"),
+            }),
+        }
+    }
+
+    /// Fail early unless a provider with signature support is set
+    ///
+    /// Operations report a missing provider only once their arguments pass validation; call
+    /// this right after building the context to surface a misconfiguration up front. Like
+    /// every operation, it initializes the context on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ProviderNotConfigured`](crate::error::Error::ProviderNotConfigured)
+    /// if no provider is set or the provider has no signature operations.
+    pub fn ensure_provider(&self) -> Result<()> {
+        self.inner.ensure_initialized()?;
+        match self.inner.provider().and_then(|p| p.signature()) {
+            Some(_) => Ok(()),
+            None => Err(crate::error::Error::ProviderNotConfigured {
+                operation: String::from("signature"),
+            }),
+        }
+    }
+
     /// Check if the context is initialized
     pub fn is_initialized(&self) -> bool {
         self.inner.is_initialized()
@@ -0,0 +1,432 @@
+//! COSE_Key encoding of post-quantum public keys
+//!
+//! Public keys are written as the CBOR map
+//!
+//! ```text
+//! { 1 (kty): 7 (AKP), 3 (alg): <COSE alg>, -1 (pub): bstr }
+//! ```
+//!
+//! using the "Algorithm Key Pair" key type and the ML-DSA algorithm identifiers registered
+//! in the IANA COSE Algorithms registry (ML-DSA-44 `-48`, ML-DSA-65 `-49`, ML-DSA-87 `-50`).
+//! Encoding is deterministic (RFC 8949 §4.2.1): shortest-form integers and labels in
+//! ascending order. Parsing accepts the labels in any order and skips other integer or string
+//! parameters such as `kid`, but rejects duplicate labels and trailing bytes.
+//!
+//! ML-KEM has no registered COSE algorithm identifier yet, so KEM keys cannot be encoded;
+//! no provisional values are invented here.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::api::{
+    Algorithm,
+    AlgorithmCategory,
+};
+use crate::encoding::check_public_key_len;
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::traits::{
+    KemPublicKey,
+    SigPublicKey,
+};
+
+/// `kty` label
+const LABEL_KTY: i64 = 1;
+/// `alg` label
+const LABEL_ALG: i64 = 3;
+/// `pub` label of the AKP key type
+const LABEL_PUB: i64 = -1;
+/// `kty` value of the "Algorithm Key Pair" key type
+pub const COSE_KTY_AKP: i64 = 7;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_MAP: u8 = 5;
+
+/// Registered COSE algorithm identifier for `algorithm`, if there is one
+pub fn cose_algorithm_id(algorithm: Algorithm) -> Option<i64> {
+    match algorithm {
+        Algorithm::MlDsa44 => Some(-48),
+        Algorithm::MlDsa65 => Some(-49),
+        Algorithm::MlDsa87 => Some(-50),
+        _ => None,
+    }
+}
+
+/// Inverse of [`cose_algorithm_id`]
+pub fn algorithm_from_cose_id(id: i64) -> Option<Algorithm> {
+    match id {
+        -48 => Some(Algorithm::MlDsa44),
+        -49 => Some(Algorithm::MlDsa65),
+        -50 => Some(Algorithm::MlDsa87),
+        _ => None,
+    }
+}
+
+impl SigPublicKey {
+    /// Encode as a COSE_Key (`kty` AKP) for `algorithm`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedAlgorithm`] if `algorithm` has no registered COSE
+    /// identifier, and [`Error::InvalidKeySize`] if the key length does not match it.
+    pub fn to_cose_key(&self, algorithm: Algorithm) -> Result<Vec<u8>> {
+        encode_public_key(algorithm, self.as_bytes())
+    }
+
+    /// Decode a COSE_Key written by [`to_cose_key`](Self::to_cose_key)
+    ///
+    /// Returns the algorithm named by `alg` together with the key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedAlgorithm`] for an unknown `alg`,
+    /// [`Error::InvalidAlgorithm`] if `alg` names a non-signature algorithm,
+    /// [`Error::InvalidKeySize`] if `pub` has the wrong length and [`Error::InvalidFormat`]
+    /// for malformed CBOR or a missing or wrong `kty`.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<(Algorithm, Self)> {
+        let (algorithm, key) = decode_public_key(bytes, AlgorithmCategory::Signature)?;
+        Ok((algorithm, Self::new(key)))
+    }
+}
+
+impl KemPublicKey {
+    /// Encode as a COSE_Key (`kty` AKP) for `algorithm`
+    ///
+    /// # Errors
+    ///
+    /// No KEM algorithm has a registered COSE identifier yet, so this currently always
+    /// returns [`Error::UnsupportedAlgorithm`] (or [`Error::InvalidAlgorithm`] for a
+    /// non-KEM algorithm).
+    pub fn to_cose_key(&self, algorithm: Algorithm) -> Result<Vec<u8>> {
+        if algorithm.category() != AlgorithmCategory::Kem {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a KEM algorithm",
+            });
+        }
+        encode_public_key(algorithm, self.as_bytes())
+    }
+
+    /// Decode a COSE_Key written by [`to_cose_key`](Self::to_cose_key)
+    ///
+    /// The key is validated with [`from_bytes_checked`](Self::from_bytes_checked).
+    ///
+    /// # Errors
+    ///
+    /// As for [`SigPublicKey::from_cose_key`], with [`Error::InvalidAlgorithm`] if `alg`
+    /// names a non-KEM algorithm.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<(Algorithm, Self)> {
+        let (algorithm, key) = decode_public_key(bytes, AlgorithmCategory::Kem)?;
+        Ok((algorithm, Self::from_bytes_checked(&key, algorithm)?))
+    }
+}
+
+fn encode_public_key(algorithm: Algorithm, key: &[u8]) -> Result<Vec<u8>> {
+    let alg = cose_algorithm_id(algorithm).ok_or_else(|| Error::UnsupportedAlgorithm {
+        algorithm: format!("{algorithm:?} has no registered COSE algorithm identifier"),
+    })?;
+    check_public_key_len(algorithm, key.len())?;
+
+    let mut out = Vec::with_capacity(key.len() + 16);
+    write_head(&mut out, MAJOR_MAP, 3);
+    write_int(&mut out, LABEL_KTY);
+    write_int(&mut out, COSE_KTY_AKP);
+    write_int(&mut out, LABEL_ALG);
+    write_int(&mut out, alg);
+    write_int(&mut out, LABEL_PUB);
+    write_head(&mut out, MAJOR_BYTES, key.len() as u64);
+    out.extend_from_slice(key);
+    Ok(out)
+}
+
+fn decode_public_key(bytes: &[u8], category: AlgorithmCategory) -> Result<(Algorithm, Vec<u8>)> {
+    let mut reader = Reader { bytes, pos: 0 };
+    let (major, entries) = reader.head()?;
+    if major != MAJOR_MAP {
+        return Err(format_error("COSE_Key: not a CBOR map"));
+    }
+
+    let mut seen: Vec<i64> = Vec::new();
+    let mut kty = None;
+    let mut alg = None;
+    let mut key = None;
+    for _ in 0..entries {
+        let (major, arg) = reader.head()?;
+        let label = match major {
+            MAJOR_UNSIGNED | MAJOR_NEGATIVE => int_value(major, arg)?,
+            MAJOR_TEXT => {
+                // String labels are private-use parameters; skip them with their value
+                reader.take(arg)?;
+                reader.skip_value()?;
+                continue;
+            }
+            _ => return Err(format_error("COSE_Key: invalid map label")),
+        };
+        if seen.contains(&label) {
+            return Err(format_error("COSE_Key: duplicate map label"));
+        }
+        seen.push(label);
+
+        match label {
+            LABEL_KTY => kty = Some(reader.int()?),
+            LABEL_ALG => alg = Some(reader.int()?),
+            LABEL_PUB => key = Some(reader.bytes()?),
+            _ => reader.skip_value()?,
+        }
+    }
+    if reader.pos != bytes.len() {
+        return Err(format_error("COSE_Key: trailing bytes"));
+    }
+
+    if kty != Some(COSE_KTY_AKP) {
+        return Err(format_error("COSE_Key: kty must be AKP (7)"));
+    }
+    let alg = alg.ok_or_else(|| format_error("COSE_Key: missing alg"))?;
+    let algorithm = algorithm_from_cose_id(alg).ok_or_else(|| Error::UnsupportedAlgorithm {
+        algorithm: format!("COSE algorithm {alg} is not supported"),
+    })?;
+    if algorithm.category() != category {
+        return Err(Error::InvalidAlgorithm {
+            algorithm: "COSE alg does not match the key type",
+        });
+    }
+    let key = key.ok_or_else(|| format_error("COSE_Key: missing pub"))?;
+    check_public_key_len(algorithm, key.len())?;
+    Ok((algorithm, key.to_vec()))
+}
+
+fn write_head(out: &mut Vec<u8>, major: u8, arg: u64) {
+    let major = major << 5;
+    if arg < 24 {
+        out.push(major | arg as u8);
+    } else if arg <= u64::from(u8::MAX) {
+        out.extend_from_slice(&[major | 24, arg as u8]);
+    } else if arg <= u64::from(u16::MAX) {
+        out.push(major | 25);
+        out.extend_from_slice(&(arg as u16).to_be_bytes());
+    } else if arg <= u64::from(u32::MAX) {
+        out.push(major | 26);
+        out.extend_from_slice(&(arg as u32).to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&arg.to_be_bytes());
+    }
+}
+
+fn write_int(out: &mut Vec<u8>, value: i64) {
+    if value >= 0 {
+        write_head(out, MAJOR_UNSIGNED, value as u64);
+    } else {
+        write_head(out, MAJOR_NEGATIVE, !value as u64);
+    }
+}
+
+fn int_value(major: u8, arg: u64) -> Result<i64> {
+    let arg = i64::try_from(arg).map_err(|_| format_error("COSE_Key: integer out of range"))?;
+    Ok(if major == MAJOR_UNSIGNED { arg } else { !arg })
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: u64) -> Result<&'a [u8]> {
+        let len = usize::try_from(len).map_err(|_| format_error("COSE_Key: truncated"))?;
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| format_error("COSE_Key: truncated"))?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    /// Major type and argument of the next data item; indefinite lengths are rejected
+    fn head(&mut self) -> Result<(u8, u64)> {
+        let initial = self.take(1)?[0];
+        let major = initial >> 5;
+        let arg = match initial & 0x1F {
+            info @ 0..=23 => u64::from(info),
+            24 => u64::from(self.take(1)?[0]),
+            25 => u64::from(u16::from_be_bytes([self.take(1)?[0], self.take(1)?[0]])),
+            26 => {
+                let b = self.take(4)?;
+                u64::from(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+            }
+            27 => {
+                let b = self.take(8)?;
+                u64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]])
+            }
+            _ => return Err(format_error("COSE_Key: unsupported CBOR encoding")),
+        };
+        Ok((major, arg))
+    }
+
+    fn int(&mut self) -> Result<i64> {
+        match self.head()? {
+            (major @ (MAJOR_UNSIGNED | MAJOR_NEGATIVE), arg) => int_value(major, arg),
+            _ => Err(format_error("COSE_Key: expected an integer")),
+        }
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        match self.head()? {
+            (MAJOR_BYTES, len) => self.take(len),
+            _ => Err(format_error("COSE_Key: expected a byte string")),
+        }
+    }
+
+    /// Skip an integer or string value of a parameter this module does not interpret
+    fn skip_value(&mut self) -> Result<()> {
+        match self.head()? {
+            (MAJOR_UNSIGNED | MAJOR_NEGATIVE, _) => Ok(()),
+            (MAJOR_BYTES | MAJOR_TEXT, len) => self.take(len).map(|_| ()),
+            _ => Err(format_error("COSE_Key: unsupported parameter value")),
+        }
+    }
+}
+
+fn format_error(context: &'static str) -> Error {
+    Error::InvalidFormat { context }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::sample_public_key;
+
+    #[test]
+    fn test_ml_dsa_round_trip() {
+        for (algorithm, alg) in [
+            (Algorithm::MlDsa44, [0x38, 0x2F]),
+            (Algorithm::MlDsa65, [0x38, 0x30]),
+            (Algorithm::MlDsa87, [0x38, 0x31]),
+        ] {
+            let key = SigPublicKey::new(sample_public_key(algorithm));
+            let encoded = key.to_cose_key(algorithm).unwrap();
+            // {1: 7, 3: alg, -1: bstr(len)} with a two-byte length
+            assert_eq!(
+                &encoded[..7],
+                &[0xA3, 0x01, 0x07, 0x03, alg[0], alg[1], 0x20]
+            );
+            assert_eq!(encoded[7], 0x59);
+
+            let (decoded_algorithm, decoded) = SigPublicKey::from_cose_key(&encoded).unwrap();
+            assert_eq!(decoded_algorithm, algorithm);
+            assert_eq!(decoded.as_bytes(), key.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_parse_skips_kid_and_accepts_any_label_order() {
+        let key = sample_public_key(Algorithm::MlDsa44);
+        let mut encoded = Vec::new();
+        write_head(&mut encoded, MAJOR_MAP, 4);
+        write_int(&mut encoded, LABEL_PUB);
+        write_head(&mut encoded, MAJOR_BYTES, key.len() as u64);
+        encoded.extend_from_slice(&key);
+        write_int(&mut encoded, 2);
+        write_head(&mut encoded, MAJOR_BYTES, 3);
+        encoded.extend_from_slice(b"kid");
+        write_int(&mut encoded, LABEL_ALG);
+        write_int(&mut encoded, -48);
+        write_int(&mut encoded, LABEL_KTY);
+        write_int(&mut encoded, COSE_KTY_AKP);
+
+        let (algorithm, decoded) = SigPublicKey::from_cose_key(&encoded).unwrap();
+        assert_eq!(algorithm, Algorithm::MlDsa44);
+        assert_eq!(decoded.as_bytes(), key.as_slice());
+    }
+
+    #[test]
+    fn test_unknown_alg_is_rejected() {
+        let mut encoded = SigPublicKey::new(sample_public_key(Algorithm::MlDsa65))
+            .to_cose_key(Algorithm::MlDsa65)
+            .unwrap();
+        // -49 -> -7 (ES256)
+        encoded.splice(4..6, [0x26]);
+        assert!(matches!(
+            SigPublicKey::from_cose_key(&encoded),
+            Err(Error::UnsupportedAlgorithm { .. })
+        ));
+    }
+
+    #[test]
+    fn test_malformed_cose_keys_are_rejected() {
+        let encoded = SigPublicKey::new(sample_public_key(Algorithm::MlDsa44))
+            .to_cose_key(Algorithm::MlDsa44)
+            .unwrap();
+
+        let mut trailing = encoded.clone();
+        trailing.push(0);
+        assert!(matches!(
+            SigPublicKey::from_cose_key(&trailing),
+            Err(Error::InvalidFormat { .. })
+        ));
+
+        let mut wrong_kty = encoded.clone();
+        wrong_kty[2] = 0x01;
+        assert!(matches!(
+            SigPublicKey::from_cose_key(&wrong_kty),
+            Err(Error::InvalidFormat { .. })
+        ));
+
+        assert!(matches!(
+            SigPublicKey::from_cose_key(&encoded[..encoded.len() - 1]),
+            Err(Error::InvalidFormat { .. })
+        ));
+
+        let mut short = Vec::new();
+        write_head(&mut short, MAJOR_MAP, 3);
+        write_int(&mut short, LABEL_KTY);
+        write_int(&mut short, COSE_KTY_AKP);
+        write_int(&mut short, LABEL_ALG);
+        write_int(&mut short, -48);
+        write_int(&mut short, LABEL_PUB);
+        write_head(&mut short, MAJOR_BYTES, 4);
+        short.extend_from_slice(&[0; 4]);
+        assert!(matches!(
+            SigPublicKey::from_cose_key(&short),
+            Err(Error::InvalidKeySize { .. })
+        ));
+
+        let mut duplicate = Vec::new();
+        write_head(&mut duplicate, MAJOR_MAP, 2);
+        write_int(&mut duplicate, LABEL_KTY);
+        write_int(&mut duplicate, COSE_KTY_AKP);
+        write_int(&mut duplicate, LABEL_KTY);
+        write_int(&mut duplicate, COSE_KTY_AKP);
+        assert!(matches!(
+            SigPublicKey::from_cose_key(&duplicate),
+            Err(Error::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ml_kem_has_no_cose_encoding() {
+        let key = KemPublicKey::new(vec![0; 1184]);
+        assert!(matches!(
+            key.to_cose_key(Algorithm::MlKem768),
+            Err(Error::UnsupportedAlgorithm { .. })
+        ));
+        assert!(matches!(
+            key.to_cose_key(Algorithm::MlDsa65),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+
+        let sig = SigPublicKey::new(sample_public_key(Algorithm::MlDsa65))
+            .to_cose_key(Algorithm::MlDsa65)
+            .unwrap();
+        assert!(matches!(
+            KemPublicKey::from_cose_key(&sig),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+    }
+}
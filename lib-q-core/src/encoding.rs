@@ -0,0 +1,306 @@
+//! PEM encoding for lib-Q key material
+//!
+//! Implements the textual encoding of [RFC 7468](https://www.rfc-editor.org/rfc/rfc7468):
+//! a `-----BEGIN <label>-----` line, the payload as standard base64 wrapped at 64
+//! characters, and a matching `-----END <label>-----` line. The payload is carried as-is,
+//! so it may be DER or the raw key bytes produced by the lib-Q implementations.
+//!
+//! Decoding accepts LF and CRLF line endings and explanatory text before the
+//! encapsulation boundary, and rejects malformed base64, including non-canonical padding bits.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use base64ct::{
+    Base64,
+    Encoding,
+};
+
+use crate::error::{
+    Error,
+    Result,
+};
+
+const PEM_LINE_WIDTH: usize = 64;
+
+/// Wrap `der_or_raw` in a PEM block with the given `label`
+///
+/// Lines are terminated with LF and the output ends with a newline.
+pub fn to_pem(label: &str, der_or_raw: &[u8]) -> String {
+    let body = base64_encode(der_or_raw);
+    let mut pem =
+        String::with_capacity(body.len() + body.len() / PEM_LINE_WIDTH + 2 * label.len() + 32);
+    pem.push_str("-----BEGIN ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+    for line in body.as_bytes().chunks(PEM_LINE_WIDTH) {
+        // The base64 alphabet is ASCII, so every chunk is valid UTF-8.
+        pem.push_str(core::str::from_utf8(line).unwrap_or_default());
+        pem.push('\n');
+    }
+    pem.push_str("-----END ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+    pem
+}
+
+/// Parse the first PEM block in `s`, returning its label and decoded payload
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidFormat`] when no `BEGIN` line is found, the block is truncated
+/// (no matching `END` line), the labels differ, or the body is not valid base64.
+pub fn from_pem(s: &str) -> Result<(String, Vec<u8>)> {
+    let mut lines = s.lines().map(|line| line.trim_end_matches('\r'));
+
+    let label = lines
+        .by_ref()
+        .find_map(|line| boundary_label(line.trim(), "-----BEGIN "))
+        .ok_or_else(|| pem_error("PEM: missing BEGIN line"))?;
+
+    let mut body = String::new();
+    let mut terminated = false;
+    for line in lines {
+        let line = line.trim();
+        if let Some(end_label) = boundary_label(line, "-----END ") {
+            if end_label != label {
+                return Err(pem_error("PEM: END label does not match BEGIN label"));
+            }
+            terminated = true;
+            break;
+        }
+        body.push_str(line);
+    }
+    if !terminated {
+        return Err(pem_error("PEM: missing END line"));
+    }
+
+    let data = base64_decode(&body).ok_or_else(|| pem_error("PEM: malformed base64 body"))?;
+    Ok((String::from(label), data))
+}
+
+/// Parse `<prefix><label>-----` and return the label
+fn boundary_label<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    line.strip_prefix(prefix)?.strip_suffix("-----")
+}
+
+fn pem_error(context: &'static str) -> Error {
+    Error::InvalidFormat { context }
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    Base64::encode_string(data)
+}
+
+/// Strict standard-alphabet base64 decoding with mandatory padding
+fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    Base64::decode_vec(encoded).ok()
+}
+
+/// Unpadded base64url encoding (RFC 4648 §5), as used by JOSE
+#[cfg(feature = "jwk")]
+pub(crate) fn base64url_encode(data: &[u8]) -> String {
+    base64ct::Base64UrlUnpadded::encode_string(data)
+}
+
+/// Strict unpadded base64url decoding; padding and the standard-alphabet `+`/`/` are rejected
+#[cfg(feature = "jwk")]
+pub(crate) fn base64url_decode(encoded: &str) -> Option<Vec<u8>> {
+    base64ct::Base64UrlUnpadded::decode_vec(encoded).ok()
+}
+
+/// Check `actual` against the public key length of `algorithm`, for the COSE and JWK encodings
+///
+/// Algorithms without a fixed public key length are accepted as-is.
+#[cfg(any(feature = "cose", feature = "jwk"))]
+pub(crate) fn check_public_key_len(algorithm: crate::api::Algorithm, actual: usize) -> Result<()> {
+    match algorithm.public_key_len() {
+        Some(expected) if expected != actual => Err(Error::InvalidKeySize { expected, actual }),
+        _ => Ok(()),
+    }
+}
+
+/// Deterministic public key of the right length for `algorithm`, for encoding tests
+#[cfg(all(test, any(feature = "cose", feature = "jwk")))]
+pub(crate) fn sample_public_key(algorithm: crate::api::Algorithm) -> Vec<u8> {
+    let len = algorithm.public_key_len().unwrap();
+    (0..len).map(|i| i as u8).collect()
+}
+
+/// Serde adapter for byte payloads of public values (keys, nonces, proofs)
+///
+/// Human-readable formats (JSON, TOML, ...) carry the bytes as a standard base64 string;
+/// binary formats (CBOR, postcard, ...) carry them as a native byte string. Use it with
+/// `#[serde(with = "lib_q_core::encoding::serde_payload")]` on a `Vec<u8>` field.
+#[cfg(feature = "serde")]
+pub mod serde_payload {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt;
+
+    use serde::de::{
+        self,
+        Visitor,
+    };
+    use serde::{
+        Deserializer,
+        Serializer,
+    };
+
+    /// Serialize `bytes` as base64 text or a raw byte string, depending on the format
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&super::base64_encode(bytes))
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    /// Deserialize bytes written by [`serialize`]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PayloadVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(PayloadVisitor)
+        }
+    }
+
+    struct PayloadVisitor;
+
+    impl<'de> Visitor<'de> for PayloadVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a base64 string or a byte string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+            super::base64_decode(v).ok_or_else(|| E::custom("malformed base64 payload"))
+        }
+
+        fn visit_string<E: de::Error>(self, v: String) -> Result<Vec<u8>, E> {
+            self.visit_str(&v)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(Vec::from(v))
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(byte) = seq.next_element()? {
+                out.push(byte);
+            }
+            Ok(out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn base64_known_vectors() {
+        let vectors: [(&[u8], &str); 7] = [
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+        ];
+        for (raw, encoded) in vectors {
+            assert_eq!(base64_encode(raw), encoded);
+            assert_eq!(base64_decode(encoded).as_deref(), Some(raw));
+        }
+        for bad in ["Zg=", "Zh==", "Z===", "Zg==Zm9v", "Zm9v!A==", "Zm 9v"] {
+            assert!(base64_decode(bad).is_none(), "{bad}");
+        }
+    }
+
+    #[cfg(feature = "jwk")]
+    #[test]
+    fn base64url_known_vectors() {
+        let vectors: [(&[u8], &str); 4] = [
+            (b"", ""),
+            (b"f", "Zg"),
+            (b"fo", "Zm8"),
+            (&[0xFB, 0xFF, 0xBF], "-_-_"),
+        ];
+        for (raw, encoded) in vectors {
+            assert_eq!(base64url_encode(raw), encoded);
+            assert_eq!(base64url_decode(encoded).as_deref(), Some(raw));
+        }
+        for bad in ["Zg==", "+/+/", "Z", "Zh"] {
+            assert!(base64url_decode(bad).is_none(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn pem_round_trip_with_crlf() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1184).collect();
+        let pem = to_pem("ML-KEM-768 PUBLIC KEY", &data);
+        assert!(pem.starts_with("-----BEGIN ML-KEM-768 PUBLIC KEY-----\n"));
+        assert!(
+            pem.lines()
+                .all(|line| line.len() <= PEM_LINE_WIDTH || line.starts_with("-----"))
+        );
+
+        let (label, decoded) = from_pem(&pem).unwrap();
+        assert_eq!(label, "ML-KEM-768 PUBLIC KEY");
+        assert_eq!(decoded, data);
+
+        let crlf = format!("explanatory text\r\n{}", pem.replace('\n', "\r\n"));
+        assert_eq!(from_pem(&crlf).unwrap(), (label, data));
+    }
+
+    #[test]
+    fn pem_rejects_truncated_and_malformed_blocks() {
+        let pem = to_pem("TEST", &[7u8; 100]);
+        let truncated: String = pem.lines().take(2).collect::<Vec<_>>().join("\n");
+        assert!(matches!(
+            from_pem(&truncated),
+            Err(Error::InvalidFormat {
+                context: "PEM: missing END line"
+            })
+        ));
+
+        assert!(from_pem("no pem here").is_err());
+        assert!(from_pem(&pem.replace("END TEST", "END OTHER")).is_err());
+        assert!(from_pem(&pem.replacen("BwcH", "Bw*H", 1)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_public_types_round_trip_as_base64() {
+        use crate::{
+            KemPublicKey,
+            Nonce,
+            SigPublicKey,
+        };
+
+        let kem = KemPublicKey::new(alloc::vec![1, 2, 3, 4, 5]);
+        let json = serde_json::to_string(&kem).unwrap();
+        assert_eq!(json, r#"{"data":"AQIDBAU="}"#);
+        assert_eq!(serde_json::from_str::<KemPublicKey>(&json).unwrap(), kem);
+
+        let sig = SigPublicKey::new((0..=255u8).collect());
+        let json = serde_json::to_string(&sig).unwrap();
+        assert_eq!(serde_json::from_str::<SigPublicKey>(&json).unwrap(), sig);
+
+        let nonce = Nonce::new(alloc::vec![0xFF; 12]);
+        let json = serde_json::to_string(&nonce).unwrap();
+        assert_eq!(json, r#"{"data":"////////////////"}"#);
+        assert_eq!(serde_json::from_str::<Nonce>(&json).unwrap(), nonce);
+
+        assert!(serde_json::from_str::<Nonce>(r#"{"data":"not base64!"}"#).is_err());
+    }
+}
@@ -27,6 +27,8 @@ pub enum HexDecodeError {
     },
     /// A two-character slice is not a valid hexadecimal byte.
     InvalidDigit {
+        /// UTF-8 byte index in the trimmed string of the first byte that is not a hex digit.
+        position: usize,
         /// UTF-8 byte index in the trimmed string where the invalid pair starts.
         pair_start: usize,
         /// Total UTF-8 byte length of the trimmed hex string.
@@ -42,11 +44,12 @@ impl fmt::Display for HexDecodeError {
                 "odd hex length ({char_count} characters); length must be even"
             ),
             HexDecodeError::InvalidDigit {
+                position,
                 pair_start,
                 char_count,
             } => write!(
                 f,
-                "invalid hex digit at index {pair_start} (trimmed length {char_count})"
+                "invalid hex digit at index {position} (pair starting at {pair_start}, trimmed length {char_count})"
             ),
         }
     }
@@ -115,6 +118,13 @@ pub enum Error {
     /// **Resolution:** Reduce the associated data size or use a different approach.
     InvalidAssociatedDataSize { max: usize, actual: usize },
 
+    /// Invalid signature context size
+    ///
+    /// **When it occurs:** A FIPS 204 / FIPS 205 context string exceeds the maximum length.
+    /// **Cause:** The context (domain-separation) string is longer than 255 bytes.
+    /// **Resolution:** Use a shorter context string, for example a fixed protocol label.
+    InvalidContextSize { max: usize, actual: usize },
+
     /// Invalid tag size
     ///
     /// **When it occurs:** An authentication tag has an incorrect size.
@@ -175,6 +185,25 @@ pub enum Error {
     #[cfg(not(feature = "alloc"))]
     DecryptionFailed { operation: &'static str },
 
+    /// Decapsulation failed
+    ///
+    /// **When it occurs:** A KEM cannot recover a shared secret from an encapsulated key.
+    /// **Cause:** The ciphertext is malformed for the parameter set, or the KEM reports an
+    /// internal decoding failure. `retriable` is set when the error-correcting decoder of a KEM
+    /// with a nonzero decryption failure rate (HQC) gave up; for a legitimately formed
+    /// ciphertext this is astronomically rare but possible, see
+    /// [`Kem::has_decryption_failures`](crate::Kem::has_decryption_failures).
+    /// **Resolution:** Check the ciphertext was produced for this algorithm and recipient key.
+    /// When `retriable` is set, let the sender re-encapsulate, and treat repeated failures as
+    /// tampering.
+    #[cfg(feature = "alloc")]
+    DecapsulationFailed { operation: String, retriable: bool },
+    #[cfg(not(feature = "alloc"))]
+    DecapsulationFailed {
+        operation: &'static str,
+        retriable: bool,
+    },
+
     /// Key generation failed
     ///
     /// **When it occurs:** Key pair generation fails.
@@ -292,6 +321,16 @@ pub enum Error {
     /// Invalid key format
     InvalidKeyFormat,
 
+    /// Invalid serialized format
+    ///
+    /// **When it occurs:** A binary frame does not start with the expected magic bytes, or
+    /// declares a format version this build does not support.
+    /// **Cause:** The data is not of the expected kind, was corrupted, or was written by a
+    /// newer release with a changed format.
+    /// **Resolution:** Check the data was produced by the matching encoder, and upgrade if it
+    /// comes from a newer version. `context` names the check that failed.
+    InvalidFormat { context: &'static str },
+
     /// Invalid key with specific reason
     #[cfg(feature = "alloc")]
     InvalidKey { key_type: String, reason: String },
@@ -335,6 +374,34 @@ pub enum Error {
     /// length exceeds that capacity. This is distinct from [`Error::InvalidMessageSize`], which
     /// reflects configured cryptographic policy limits on caller-supplied payloads.
     BufferTooSmall { capacity: usize, requested: usize },
+
+    /// A numeric or configuration parameter is out of range
+    ///
+    /// **When it occurs:** An operation is given a parameter value it cannot work with, such
+    /// as a ParallelHash block size of zero.
+    /// **Resolution:** `parameter` names the argument and `reason` the accepted range.
+    InvalidParameter {
+        parameter: &'static str,
+        reason: &'static str,
+    },
+
+    /// Reading input from an I/O source failed
+    ///
+    /// **When it occurs:** A streaming helper that pulls its input from a
+    /// [`std::io::Read`] (e.g. hashing a file) gets an error from the reader.
+    /// **Resolution:** Inspect `message` (the reader's error) and retry or report; no partial
+    /// result is returned.
+    #[cfg(feature = "std")]
+    Io { message: String },
+
+    /// A nonce was about to be reused under the same key
+    ///
+    /// **When it occurs:** An AEAD nonce tracker has already seen this (key id, nonce) pair
+    /// in the current session and refuses the encryption before it runs.
+    /// **Cause:** A nonce counter was reset, a random nonce source repeated, or a message was
+    /// re-encrypted with a fixed nonce.
+    /// **Resolution:** Derive a fresh nonce (or a fresh key) for every encryption.
+    NonceReuse,
 }
 
 impl Error {
@@ -352,6 +419,65 @@ impl Error {
     }
 }
 
+impl Error {
+    /// Stable, machine-readable code for this error's variant
+    ///
+    /// Codes are the variant name in `SCREAMING_SNAKE_CASE` (e.g. `"NOT_IMPLEMENTED"`,
+    /// `"INVALID_KEY_SIZE"`) and do not change between releases, so bindings can branch on
+    /// them without parsing [`Display`](fmt::Display) output. The WASM bindings report them
+    /// as the `code` field of their error objects.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Error::InvalidKeySize { .. } => "INVALID_KEY_SIZE",
+            Error::InvalidSignatureSize { .. } => "INVALID_SIGNATURE_SIZE",
+            Error::InvalidNonceSize { .. } => "INVALID_NONCE_SIZE",
+            Error::InvalidMessageSize { .. } => "INVALID_MESSAGE_SIZE",
+            Error::InvalidCiphertextSize { .. } => "INVALID_CIPHERTEXT_SIZE",
+            Error::InvalidPlaintextSize { .. } => "INVALID_PLAINTEXT_SIZE",
+            Error::InvalidAssociatedDataSize { .. } => "INVALID_ASSOCIATED_DATA_SIZE",
+            Error::InvalidContextSize { .. } => "INVALID_CONTEXT_SIZE",
+            Error::InvalidTagSize { .. } => "INVALID_TAG_SIZE",
+            Error::InvalidHashSize { .. } => "INVALID_HASH_SIZE",
+            Error::InvalidRandomnessSize { .. } => "INVALID_RANDOMNESS_SIZE",
+            Error::RandomBytesLengthInvalid { .. } => "RANDOM_BYTES_LENGTH_INVALID",
+            Error::BufferTooSmall { .. } => "BUFFER_TOO_SMALL",
+            Error::InvalidParameter { .. } => "INVALID_PARAMETER",
+            Error::InvalidAlgorithm { .. } => "INVALID_ALGORITHM",
+            Error::UnsupportedAlgorithm { .. } => "UNSUPPORTED_ALGORITHM",
+            Error::InvalidSecurityLevel { .. } => "INVALID_SECURITY_LEVEL",
+            Error::InvalidKeyFormat => "INVALID_KEY_FORMAT",
+            Error::InvalidFormat { .. } => "INVALID_FORMAT",
+            Error::InvalidKey { .. } => "INVALID_KEY",
+            Error::HexDecode(..) => "HEX_DECODE",
+            Error::VerificationFailed { .. } => "VERIFICATION_FAILED",
+            Error::AuthenticationFailed { .. } => "AUTHENTICATION_FAILED",
+            Error::EncryptionFailed { .. } => "ENCRYPTION_FAILED",
+            Error::DecryptionFailed { .. } => "DECRYPTION_FAILED",
+            Error::DecapsulationFailed { .. } => "DECAPSULATION_FAILED",
+            Error::KeyGenerationFailed { .. } => "KEY_GENERATION_FAILED",
+            Error::RandomGenerationFailed { .. } => "RANDOM_GENERATION_FAILED",
+            Error::SigningFailed { .. } => "SIGNING_FAILED",
+            Error::MemoryAllocationFailed { .. } => "MEMORY_ALLOCATION_FAILED",
+            Error::InternalError { .. } => "INTERNAL_ERROR",
+            Error::NotImplemented { .. } => "NOT_IMPLEMENTED",
+            Error::ProviderNotConfigured { .. } => "PROVIDER_NOT_CONFIGURED",
+            Error::UnsupportedOperation { .. } => "UNSUPPORTED_OPERATION",
+            Error::InvalidState { .. } => "INVALID_STATE",
+            Error::PluginDependencyError { .. } => "PLUGIN_DEPENDENCY_ERROR",
+            Error::PluginVersionIncompatible { .. } => "PLUGIN_VERSION_INCOMPATIBLE",
+            #[cfg(feature = "std")]
+            Error::Io { .. } => "IO",
+            Error::NonceReuse => "NONCE_REUSE",
+        }
+    }
+}
+
+/// Formats through `core::fmt` only, so errors can be rendered without `alloc`
+///
+/// Size variants ([`Error::InvalidKeySize`], [`Error::InvalidNonceSize`], ...) carry plain
+/// numbers and [`Error::InvalidAlgorithm`] a `&'static str`, so in `no_std` builds they can be
+/// written into any [`fmt::Write`] sink, such as a fixed stack buffer. Only the variants whose
+/// fields hold `String`/`Vec` depend on `alloc`; without it those fields are `&'static str`.
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -388,6 +514,9 @@ impl fmt::Display for Error {
                     "Invalid associated data size: maximum {max}, got {actual}"
                 )
             }
+            Error::InvalidContextSize { max, actual } => {
+                write!(f, "Invalid context size: maximum {max}, got {actual}")
+            }
             Error::InvalidTagSize { expected, actual } => {
                 write!(f, "Invalid tag size: expected {expected}, got {actual}")
             }
@@ -412,6 +541,21 @@ impl fmt::Display for Error {
             Error::DecryptionFailed { operation } => {
                 write!(f, "Decryption failed: {operation}")
             }
+            Error::DecapsulationFailed {
+                operation,
+                retriable: false,
+            } => {
+                write!(f, "Decapsulation failed: {operation}")
+            }
+            Error::DecapsulationFailed {
+                operation,
+                retriable: true,
+            } => {
+                write!(
+                    f,
+                    "Decapsulation failed: {operation}; retry with a fresh encapsulation"
+                )
+            }
             Error::KeyGenerationFailed { operation } => {
                 write!(f, "Key generation failed: {operation}")
             }
@@ -508,6 +652,9 @@ impl fmt::Display for Error {
             Error::InvalidKeyFormat => {
                 write!(f, "Invalid key format")
             }
+            Error::InvalidFormat { context } => {
+                write!(f, "Invalid format: {context}")
+            }
             Error::InvalidKey { key_type, reason } => {
                 write!(f, "Invalid {key_type}: {reason}")
             }
@@ -545,6 +692,16 @@ impl fmt::Display for Error {
                     "Insufficient fixed buffer capacity: capacity {capacity}, requested {requested}"
                 )
             }
+            Error::InvalidParameter { parameter, reason } => {
+                write!(f, "Invalid parameter {parameter}: {reason}")
+            }
+            #[cfg(feature = "std")]
+            Error::Io { message } => {
+                write!(f, "I/O error: {message}")
+            }
+            Error::NonceReuse => {
+                write!(f, "Nonce reuse detected for this key")
+            }
         }
     }
 }
@@ -587,6 +744,7 @@ impl Error {
             Error::VerificationFailed { .. } => "VerificationFailed".to_string(),
             Error::EncryptionFailed { .. } => "EncryptionFailed".to_string(),
             Error::DecryptionFailed { .. } => "DecryptionFailed".to_string(),
+            Error::DecapsulationFailed { .. } => "DecapsulationFailed".to_string(),
             Error::KeyGenerationFailed { .. } => "KeyGenerationFailed".to_string(),
             Error::RandomGenerationFailed { .. } => "RandomGenerationFailed".to_string(),
             Error::SigningFailed { .. } => "SigningFailed".to_string(),
@@ -597,10 +755,12 @@ impl Error {
             Error::UnsupportedOperation { .. } => "UnsupportedOperation".to_string(),
             Error::InvalidState { .. } => "InvalidState".to_string(),
             Error::InvalidAssociatedDataSize { .. } => "InvalidAssociatedDataSize".to_string(),
+            Error::InvalidContextSize { .. } => "InvalidContextSize".to_string(),
             Error::InvalidTagSize { .. } => "InvalidTagSize".to_string(),
             Error::PluginDependencyError { .. } => "PluginDependencyError".to_string(),
             Error::PluginVersionIncompatible { .. } => "PluginVersionIncompatible".to_string(),
             Error::InvalidKeyFormat => "InvalidKeyFormat".to_string(),
+            Error::InvalidFormat { .. } => "InvalidFormat".to_string(),
             Error::InvalidKey { .. } => "InvalidKey".to_string(),
             Error::UnsupportedAlgorithm { .. } => "UnsupportedAlgorithm".to_string(),
             Error::AuthenticationFailed { .. } => "AuthenticationFailed".to_string(),
@@ -608,6 +768,19 @@ impl Error {
             Error::RandomBytesLengthInvalid { .. } => "RandomBytesLengthInvalid".to_string(),
             Error::HexDecode(..) => "HexDecode".to_string(),
             Error::BufferTooSmall { .. } => "BufferTooSmall".to_string(),
+            Error::InvalidParameter { .. } => "InvalidParameter".to_string(),
+            #[cfg(feature = "std")]
+            Error::Io { .. } => "Io".to_string(),
+            Error::NonceReuse => "NonceReuse".to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io {
+            message: error.to_string(),
         }
     }
 }
@@ -645,8 +818,123 @@ pub fn is_supported_security_level(level: u32) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use core::fmt::Write as _;
+
     use super::*;
 
+    /// Fixed-capacity `fmt::Write` sink, standing in for an embedded log buffer
+    struct StackBuffer<const N: usize> {
+        bytes: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> StackBuffer<N> {
+        fn new() -> Self {
+            Self {
+                bytes: [0; N],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl<const N: usize> fmt::Write for StackBuffer<N> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_error_display_without_alloc() {
+        let mut buffer = StackBuffer::<64>::new();
+        write!(
+            buffer,
+            "{}",
+            Error::InvalidKeySize {
+                expected: 32,
+                actual: 16,
+            }
+        )
+        .unwrap();
+        assert_eq!(buffer.as_str(), "Invalid key size: expected 32, got 16");
+
+        let mut buffer = StackBuffer::<64>::new();
+        write!(
+            buffer,
+            "{}",
+            Error::InvalidNonceSize {
+                expected: 12,
+                actual: 8,
+            }
+        )
+        .unwrap();
+        assert_eq!(buffer.as_str(), "Invalid nonce size: expected 12, got 8");
+
+        let mut buffer = StackBuffer::<64>::new();
+        write!(
+            buffer,
+            "{}",
+            Error::InvalidAlgorithm {
+                algorithm: "ML-KEM-999",
+            }
+        )
+        .unwrap();
+        assert_eq!(buffer.as_str(), "Invalid algorithm: ML-KEM-999");
+
+        let mut buffer = StackBuffer::<64>::new();
+        write!(
+            buffer,
+            "{}",
+            Error::InvalidFormat {
+                context: "bad magic",
+            }
+        )
+        .unwrap();
+        assert_eq!(buffer.as_str(), "Invalid format: bad magic");
+
+        let mut buffer = StackBuffer::<64>::new();
+        write!(buffer, "{}", Error::NonceReuse).unwrap();
+        assert_eq!(buffer.as_str(), "Nonce reuse detected for this key");
+
+        // Messages longer than the sink report an error instead of allocating.
+        let mut small = StackBuffer::<8>::new();
+        assert!(
+            write!(
+                small,
+                "{}",
+                Error::InvalidKeySize {
+                    expected: 32,
+                    actual: 16,
+                }
+            )
+            .is_err()
+        );
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_static_str_variants_display_without_alloc() {
+        let mut buffer = StackBuffer::<64>::new();
+        write!(
+            buffer,
+            "{}",
+            Error::NotImplemented {
+                feature: "streaming",
+            }
+        )
+        .unwrap();
+        assert_eq!(buffer.as_str(), "Feature not implemented: streaming");
+    }
+
     #[test]
     fn test_error_display() {
         #[cfg(not(feature = "std"))]
@@ -659,6 +947,88 @@ mod tests {
         assert_eq!(error.to_string(), "Invalid key size: expected 32, got 16");
     }
 
+    #[test]
+    fn test_error_codes_are_stable() {
+        assert_eq!(
+            Error::NotImplemented {
+                feature: "x".into()
+            }
+            .code(),
+            "NOT_IMPLEMENTED"
+        );
+        assert_eq!(
+            Error::InvalidKeySize {
+                expected: 32,
+                actual: 16
+            }
+            .code(),
+            "INVALID_KEY_SIZE"
+        );
+        assert_eq!(Error::InvalidKeyFormat.code(), "INVALID_KEY_FORMAT");
+        assert_eq!(
+            Error::DecapsulationFailed {
+                operation: "HQC-128 decoding".into(),
+                retriable: true,
+            }
+            .code(),
+            "DECAPSULATION_FAILED"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_failure_variant_display() {
+        assert_eq!(
+            Error::VerificationFailed {
+                operation: "ML-DSA-65 signature".to_string(),
+            }
+            .to_string(),
+            "Verification failed: ML-DSA-65 signature"
+        );
+        assert_eq!(
+            Error::DecapsulationFailed {
+                operation: "ML-KEM-768".to_string(),
+                retriable: false,
+            }
+            .to_string(),
+            "Decapsulation failed: ML-KEM-768"
+        );
+        assert_eq!(
+            Error::InvalidFormat {
+                context: "PEM: missing END line",
+            }
+            .to_string(),
+            "Invalid format: PEM: missing END line"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_io_error_display() {
+        let error = Error::from(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "short read",
+        ));
+        assert_eq!(error.code(), "IO");
+        assert_eq!(error.to_string(), "I/O error: short read");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_retriable_decapsulation_failure_display() {
+        #[cfg(not(feature = "std"))]
+        use alloc::string::ToString;
+
+        assert_eq!(
+            Error::DecapsulationFailed {
+                operation: "HQC-128 decoding".to_string(),
+                retriable: true,
+            }
+            .to_string(),
+            "Decapsulation failed: HQC-128 decoding; retry with a fresh encapsulation"
+        );
+    }
+
     #[test]
     fn test_invalid_key_error_display() {
         #[cfg(feature = "alloc")]
@@ -0,0 +1,212 @@
+//! JWK encoding of post-quantum public keys
+//!
+//! Public keys are written as JSON Web Keys of the "Algorithm Key Pair" key type from the
+//! JOSE/COSE ML-DSA draft:
+//!
+//! ```text
+//! {"kty":"AKP","alg":"ML-DSA-65","pub":"<base64url public key>"}
+//! ```
+//!
+//! The key material is unpadded base64url (RFC 7515 §2). Parsing ignores other members such
+//! as `kid` or `use`, so keys can be taken straight from a JWKS `keys` array. This is the JSON
+//! counterpart of the `cose` module and covers the same algorithms; ML-KEM has no registered
+//! JOSE name yet either.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::api::{
+    Algorithm,
+    AlgorithmCategory,
+};
+use crate::encoding::{
+    base64url_decode,
+    base64url_encode,
+    check_public_key_len,
+};
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::traits::{
+    KemPublicKey,
+    SigPublicKey,
+};
+
+/// `kty` value of the "Algorithm Key Pair" key type
+pub const JWK_KTY_AKP: &str = "AKP";
+
+#[derive(Serialize, Deserialize)]
+struct Jwk<'a> {
+    kty: &'a str,
+    alg: &'a str,
+    #[serde(rename = "pub")]
+    public: &'a str,
+}
+
+/// Registered JOSE algorithm name for `algorithm`, if there is one
+pub fn jose_algorithm_name(algorithm: Algorithm) -> Option<&'static str> {
+    match algorithm {
+        Algorithm::MlDsa44 => Some("ML-DSA-44"),
+        Algorithm::MlDsa65 => Some("ML-DSA-65"),
+        Algorithm::MlDsa87 => Some("ML-DSA-87"),
+        _ => None,
+    }
+}
+
+/// Inverse of [`jose_algorithm_name`]
+pub fn algorithm_from_jose_name(name: &str) -> Option<Algorithm> {
+    match name {
+        "ML-DSA-44" => Some(Algorithm::MlDsa44),
+        "ML-DSA-65" => Some(Algorithm::MlDsa65),
+        "ML-DSA-87" => Some(Algorithm::MlDsa87),
+        _ => None,
+    }
+}
+
+impl SigPublicKey {
+    /// Encode as a JWK (`kty` AKP) for `algorithm`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedAlgorithm`] if `algorithm` has no registered JOSE name,
+    /// and [`Error::InvalidKeySize`] if the key length does not match it.
+    pub fn to_jwk(&self, algorithm: Algorithm) -> Result<String> {
+        encode_public_key(algorithm, self.as_bytes())
+    }
+}
+
+impl KemPublicKey {
+    /// Encode as a JWK (`kty` AKP) for `algorithm`
+    ///
+    /// # Errors
+    ///
+    /// No KEM algorithm has a registered JOSE name yet, so this currently always returns
+    /// [`Error::UnsupportedAlgorithm`] (or [`Error::InvalidAlgorithm`] for a non-KEM
+    /// algorithm).
+    pub fn to_jwk(&self, algorithm: Algorithm) -> Result<String> {
+        if algorithm.category() != AlgorithmCategory::Kem {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a KEM algorithm",
+            });
+        }
+        encode_public_key(algorithm, self.as_bytes())
+    }
+}
+
+/// Decode a JWK written by `to_jwk`, returning the algorithm named by `alg` and the key bytes
+///
+/// # Errors
+///
+/// Returns [`Error::UnsupportedAlgorithm`] for an unknown `alg`, [`Error::InvalidKeySize`]
+/// if `pub` has the wrong length, and [`Error::InvalidFormat`] for malformed JSON, a `kty`
+/// other than `AKP` or a `pub` that is not unpadded base64url.
+pub fn from_jwk(json: &str) -> Result<(Algorithm, Vec<u8>)> {
+    let jwk: Jwk<'_> = serde_json::from_str(json).map_err(|_| jwk_error("JWK: malformed JSON"))?;
+    if jwk.kty != JWK_KTY_AKP {
+        return Err(jwk_error("JWK: kty must be AKP"));
+    }
+    let algorithm =
+        algorithm_from_jose_name(jwk.alg).ok_or_else(|| Error::UnsupportedAlgorithm {
+            algorithm: format!("JOSE algorithm {} is not supported", jwk.alg),
+        })?;
+    let key = base64url_decode(jwk.public).ok_or_else(|| jwk_error("JWK: pub is not base64url"))?;
+    check_public_key_len(algorithm, key.len())?;
+    Ok((algorithm, key))
+}
+
+fn encode_public_key(algorithm: Algorithm, key: &[u8]) -> Result<String> {
+    let alg = jose_algorithm_name(algorithm).ok_or_else(|| Error::UnsupportedAlgorithm {
+        algorithm: format!("{algorithm:?} has no registered JOSE algorithm name"),
+    })?;
+    check_public_key_len(algorithm, key.len())?;
+
+    let public = base64url_encode(key);
+    serde_json::to_string(&Jwk {
+        kty: JWK_KTY_AKP,
+        alg,
+        public: &public,
+    })
+    .map_err(|_| jwk_error("JWK: serialization failed"))
+}
+
+fn jwk_error(context: &'static str) -> Error {
+    Error::InvalidFormat { context }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::sample_public_key;
+
+    #[test]
+    fn test_ml_dsa_round_trip() {
+        for algorithm in [Algorithm::MlDsa44, Algorithm::MlDsa65, Algorithm::MlDsa87] {
+            let key = SigPublicKey::new(sample_public_key(algorithm));
+            let json = key.to_jwk(algorithm).unwrap();
+            let prefix = format!(
+                r#"{{"kty":"AKP","alg":"{}","pub":""#,
+                jose_algorithm_name(algorithm).unwrap()
+            );
+            assert!(json.starts_with(&prefix), "{json}");
+
+            let (decoded_algorithm, decoded) = from_jwk(&json).unwrap();
+            assert_eq!(decoded_algorithm, algorithm);
+            assert_eq!(decoded, key.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_jwks_members_are_ignored() {
+        let key = sample_public_key(Algorithm::MlDsa44);
+        let json = format!(
+            r#"{{"kid":"k1","use":"sig","pub":"{}","alg":"ML-DSA-44","kty":"AKP"}}"#,
+            base64url_encode(&key)
+        );
+        assert_eq!(from_jwk(&json).unwrap(), (Algorithm::MlDsa44, key));
+    }
+
+    #[test]
+    fn test_malformed_jwks_are_rejected() {
+        let json = SigPublicKey::new(sample_public_key(Algorithm::MlDsa65))
+            .to_jwk(Algorithm::MlDsa65)
+            .unwrap();
+
+        for bad in [
+            String::from("not json"),
+            json.replace(r#""kty":"AKP""#, r#""kty":"OKP""#),
+            json.replace(r#","alg":"ML-DSA-65""#, ""),
+            json.replace(r#""pub":""#, r#""pub":"+"#),
+            json.replace(r#""}"#, r#"="}"#),
+        ] {
+            assert!(from_jwk(&bad).is_err(), "{bad}");
+        }
+        assert!(matches!(
+            from_jwk(&json.replace("ML-DSA-65", "ES256")),
+            Err(Error::UnsupportedAlgorithm { .. })
+        ));
+        assert!(matches!(
+            from_jwk(&json.replace("ML-DSA-65", "ML-DSA-44")),
+            Err(Error::InvalidKeySize { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ml_kem_has_no_jwk_encoding() {
+        let key = KemPublicKey::new(vec![0; 1184]);
+        assert!(matches!(
+            key.to_jwk(Algorithm::MlKem768),
+            Err(Error::UnsupportedAlgorithm { .. })
+        ));
+        assert!(matches!(
+            key.to_jwk(Algorithm::MlDsa65),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+    }
+}
@@ -0,0 +1,421 @@
+//! Self-describing binary encoding for key pairs
+//!
+//! A [`SerializedKeyPair`] records which algorithm produced the keys, so a stored key pair
+//! cannot silently be loaded for a different algorithm. The encoding is
+//!
+//! ```text
+//! magic "LQKP" (4) || version (1) || algorithm id (u16 BE) ||
+//! public key length (u32 BE) || secret key length (u32 BE) || public key || secret key
+//! ```
+//!
+//! Algorithm ids are explicitly assigned below and never reused; they are independent of the
+//! order of the [`Algorithm`] enum.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use subtle::ConstantTimeEq;
+use zeroize::{
+    Zeroize,
+    ZeroizeOnDrop,
+};
+
+use crate::api::{
+    Algorithm,
+    AlgorithmCategory,
+};
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::security::SecurityConstants;
+use crate::traits::{
+    KemKeypair,
+    SecretExportConsent,
+    SigKeypair,
+};
+
+/// Magic bytes opening every serialized key pair
+pub const KEY_PAIR_MAGIC: [u8; 4] = *b"LQKP";
+
+/// Current serialized key pair format version
+pub const KEY_PAIR_FORMAT_VERSION: u8 = 1;
+
+const HEADER_SIZE: usize = KEY_PAIR_MAGIC.len() + 1 + 2 + 4 + 4;
+
+/// Key pair bundled with the algorithm that produced it
+///
+/// The secret key is only reachable through [`expose_secret`](Self::expose_secret), is
+/// zeroized on drop, redacted from `Debug` output and compared in constant time.
+#[derive(Clone)]
+pub struct SerializedKeyPair {
+    /// Algorithm that produced the keys
+    pub algorithm: Algorithm,
+    /// Public key bytes
+    pub public_key: Vec<u8>,
+    secret_key: Vec<u8>,
+}
+
+impl SerializedKeyPair {
+    /// Borrow the secret key bytes
+    ///
+    /// The [`SecretExportConsent`] argument documents at the call site that secret material
+    /// leaves the key pair here.
+    pub fn expose_secret(&self, _consent: SecretExportConsent) -> &[u8] {
+        &self.secret_key
+    }
+
+    /// Bundle a KEM key pair with its algorithm
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if `algorithm` is not a KEM algorithm.
+    pub fn from_kem_keypair(algorithm: Algorithm, keypair: &KemKeypair) -> Result<Self> {
+        if algorithm.category() != AlgorithmCategory::Kem {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a KEM algorithm",
+            });
+        }
+        Ok(Self {
+            algorithm,
+            public_key: keypair.public_key().as_bytes().to_vec(),
+            secret_key: keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
+        })
+    }
+
+    /// Bundle a signature key pair with its algorithm
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if `algorithm` is not a signature algorithm.
+    pub fn from_sig_keypair(algorithm: Algorithm, keypair: &SigKeypair) -> Result<Self> {
+        if algorithm.category() != AlgorithmCategory::Signature {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a signature algorithm",
+            });
+        }
+        Ok(Self {
+            algorithm,
+            public_key: keypair.public_key().as_bytes().to_vec(),
+            secret_key: keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
+        })
+    }
+
+    /// Rebuild the KEM key pair
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if the bundled algorithm is not a KEM algorithm.
+    pub fn to_kem_keypair(&self) -> Result<KemKeypair> {
+        if self.algorithm.category() != AlgorithmCategory::Kem {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a KEM algorithm",
+            });
+        }
+        Ok(KemKeypair::new(
+            self.public_key.clone(),
+            self.secret_key.clone(),
+        ))
+    }
+
+    /// Rebuild the signature key pair
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if the bundled algorithm is not a signature
+    /// algorithm.
+    pub fn to_sig_keypair(&self) -> Result<SigKeypair> {
+        if self.algorithm.category() != AlgorithmCategory::Signature {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a signature algorithm",
+            });
+        }
+        Ok(SigKeypair::new(
+            self.public_key.clone(),
+            self.secret_key.clone(),
+        ))
+    }
+
+    /// Encode as `magic || version || algorithm id || lengths || public key || secret key`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if the algorithm has no key pair id or no known key
+    /// sizes, and [`Error::InvalidKey`] if the keys do not have the algorithm's sizes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let id = algorithm_id(self.algorithm).ok_or(Error::InvalidAlgorithm {
+            algorithm: "Algorithm has no serialized key pair id",
+        })?;
+        validate_key_sizes(self.algorithm, self.public_key.len(), self.secret_key.len())?;
+
+        let mut out =
+            Vec::with_capacity(HEADER_SIZE + self.public_key.len() + self.secret_key.len());
+        out.extend_from_slice(&KEY_PAIR_MAGIC);
+        out.push(KEY_PAIR_FORMAT_VERSION);
+        out.extend_from_slice(&id.to_be_bytes());
+        out.extend_from_slice(&encode_len(self.public_key.len())?);
+        out.extend_from_slice(&encode_len(self.secret_key.len())?);
+        out.extend_from_slice(&self.public_key);
+        out.extend_from_slice(&self.secret_key);
+        Ok(out)
+    }
+
+    /// Decode a key pair written by [`to_bytes`](Self::to_bytes)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] for a truncated header, a wrong magic or version, an
+    /// unknown algorithm id, or declared lengths that disagree with the buffer;
+    /// [`Error::InvalidKey`] for key sizes that do not match the tagged algorithm; and
+    /// [`Error::InvalidAlgorithm`] if the tagged algorithm has no known key sizes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(format_error("SerializedKeyPair: truncated header"));
+        }
+        let (header, body) = bytes.split_at(HEADER_SIZE);
+        if header[..4] != KEY_PAIR_MAGIC {
+            return Err(format_error("SerializedKeyPair: bad magic"));
+        }
+        if header[4] != KEY_PAIR_FORMAT_VERSION {
+            return Err(format_error("SerializedKeyPair: unsupported version"));
+        }
+        let algorithm = algorithm_from_id(u16::from_be_bytes([header[5], header[6]]))
+            .ok_or_else(|| format_error("SerializedKeyPair: unknown algorithm id"))?;
+        let public_len = decode_len(&header[7..11]);
+        let secret_len = decode_len(&header[11..15]);
+
+        if public_len.checked_add(secret_len) != Some(body.len()) {
+            return Err(format_error(
+                "SerializedKeyPair: declared lengths do not match the encoded keys",
+            ));
+        }
+        validate_key_sizes(algorithm, public_len, secret_len)?;
+
+        let (public_key, secret_key) = body.split_at(public_len);
+        Ok(Self {
+            algorithm,
+            public_key: public_key.to_vec(),
+            secret_key: secret_key.to_vec(),
+        })
+    }
+
+    /// Decode a key pair and require it to be tagged with `expected`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if the blob is valid but tagged with another
+    /// algorithm, and any error of [`from_bytes`](Self::from_bytes).
+    pub fn from_bytes_for(bytes: &[u8], expected: Algorithm) -> Result<Self> {
+        let keypair = Self::from_bytes(bytes)?;
+        if keypair.algorithm != expected {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Serialized key pair was produced by a different algorithm",
+            });
+        }
+        Ok(keypair)
+    }
+}
+
+impl PartialEq for SerializedKeyPair {
+    fn eq(&self, other: &Self) -> bool {
+        self.algorithm == other.algorithm &&
+            self.public_key == other.public_key &&
+            bool::from(self.secret_key.ct_eq(&other.secret_key))
+    }
+}
+
+impl Eq for SerializedKeyPair {}
+
+impl fmt::Debug for SerializedKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SerializedKeyPair")
+            .field("algorithm", &self.algorithm)
+            .field("public_key", &self.public_key)
+            .field("secret_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl Zeroize for SerializedKeyPair {
+    fn zeroize(&mut self) {
+        self.secret_key.zeroize();
+    }
+}
+
+impl Drop for SerializedKeyPair {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SerializedKeyPair {}
+
+/// Key pair algorithm id: the [`Algorithm::wire_id`] of a KEM or signature scheme with keys
+fn algorithm_id(algorithm: Algorithm) -> Option<u16> {
+    has_key_pair(algorithm).then(|| algorithm.wire_id())
+}
+
+fn algorithm_from_id(id: u16) -> Option<Algorithm> {
+    Algorithm::from_wire_id(id).filter(|&algorithm| has_key_pair(algorithm))
+}
+
+fn has_key_pair(algorithm: Algorithm) -> bool {
+    matches!(
+        algorithm.category(),
+        AlgorithmCategory::Kem | AlgorithmCategory::Signature
+    ) && algorithm.public_key_len().is_some()
+}
+
+/// Check key lengths against the sizes [`SecurityConstants`] knows for `algorithm`
+///
+/// An algorithm without known key sizes is rejected rather than accepted unchecked.
+fn validate_key_sizes(algorithm: Algorithm, public_len: usize, secret_len: usize) -> Result<()> {
+    let constants = SecurityConstants::new();
+    for (is_secret, actual) in [(false, public_len), (true, secret_len)] {
+        if constants.get_expected_key_size(algorithm, is_secret)? != actual {
+            return Err(key_error(if is_secret {
+                "secret key size does not match the algorithm"
+            } else {
+                "public key size does not match the algorithm"
+            }));
+        }
+    }
+    Ok(())
+}
+
+fn encode_len(len: usize) -> Result<[u8; 4]> {
+    u32::try_from(len)
+        .map(u32::to_be_bytes)
+        .map_err(|_| key_error("key too large"))
+}
+
+fn decode_len(bytes: &[u8]) -> usize {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize
+}
+
+/// Framing error: the blob is not a well-formed serialized key pair
+fn format_error(context: &'static str) -> Error {
+    Error::InvalidFormat { context }
+}
+
+/// Key error: the framing is fine but a key has the wrong size
+fn key_error(reason: &str) -> Error {
+    Error::InvalidKey {
+        key_type: String::from("SerializedKeyPair"),
+        reason: String::from(reason),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn ml_kem_512() -> SerializedKeyPair {
+        SerializedKeyPair {
+            algorithm: Algorithm::MlKem512,
+            public_key: vec![0x11; 800],
+            secret_key: vec![0x22; 1632],
+        }
+    }
+
+    #[test]
+    fn round_trip_preserves_algorithm_and_keys() {
+        let keypair = ml_kem_512();
+        let bytes = keypair.to_bytes().unwrap();
+        assert_eq!(&bytes[..4], b"LQKP");
+        assert_eq!(bytes.len(), HEADER_SIZE + 800 + 1632);
+
+        let decoded = SerializedKeyPair::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, keypair);
+        assert_eq!(
+            decoded.to_kem_keypair().unwrap().public_key().as_bytes(),
+            &[0x11; 800]
+        );
+        assert!(decoded.to_sig_keypair().is_err());
+        assert_eq!(decoded.expose_secret(SecretExportConsent), &[0x22; 1632]);
+
+        let mut other_secret = ml_kem_512();
+        other_secret.secret_key[0] ^= 1;
+        assert_ne!(other_secret, keypair);
+    }
+
+    #[test]
+    fn rejects_algorithm_without_known_key_sizes() {
+        assert!(matches!(
+            validate_key_sizes(Algorithm::Sha3_256, 0, 0),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_blob_tagged_with_wrong_algorithm() {
+        let bytes = ml_kem_512().to_bytes().unwrap();
+
+        // Valid blob, but the caller expects a different algorithm.
+        assert!(matches!(
+            SerializedKeyPair::from_bytes_for(&bytes, Algorithm::MlKem768),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+
+        // Re-tagging the ML-KEM-512 keys as ML-KEM-768 fails the size check.
+        let mut retagged = bytes.clone();
+        retagged[5..7].copy_from_slice(&0x0002u16.to_be_bytes());
+        assert!(matches!(
+            SerializedKeyPair::from_bytes(&retagged),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_headers_and_lengths() {
+        let bytes = ml_kem_512().to_bytes().unwrap();
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        let mut bad_version = bytes.clone();
+        bad_version[4] = KEY_PAIR_FORMAT_VERSION + 1;
+        let mut unknown_id = bytes.clone();
+        unknown_id[5..7].copy_from_slice(&0xFFFFu16.to_be_bytes());
+        let mut bad_length = bytes.clone();
+        bad_length[7..11].copy_from_slice(&801u32.to_be_bytes());
+
+        for blob in [
+            &bad_magic[..],
+            &bad_version,
+            &unknown_id,
+            &bad_length,
+            &bytes[..bytes.len() - 1],
+            &bytes[..HEADER_SIZE - 1],
+        ] {
+            assert!(matches!(
+                SerializedKeyPair::from_bytes(blob),
+                Err(Error::InvalidFormat { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn algorithm_ids_round_trip() {
+        for algorithm in [
+            Algorithm::MlKem1024,
+            Algorithm::CbKem8192128,
+            Algorithm::Hqc192,
+            Algorithm::MlDsa65,
+            Algorithm::FnDsa1024,
+            Algorithm::SlhDsaShake256256fRobust,
+        ] {
+            let id = algorithm_id(algorithm).unwrap();
+            assert_eq!(algorithm_from_id(id), Some(algorithm));
+        }
+        assert_eq!(algorithm_id(Algorithm::Sha3_256), None);
+    }
+}
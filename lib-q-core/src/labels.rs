@@ -0,0 +1,110 @@
+//! Domain-separation labels for lib-Q's internal key derivation
+//!
+//! Every domain-separation string lib-Q feeds into a derivation or a signed transcript is one
+//! of the `LABEL_*` constants below, so the same inputs fed to two different derivations can
+//! never produce related outputs. Most derivations hash through [`hash_with_label`]; HPKE
+//! rekeying uses them as `LabeledExpand` labels, `lib_q_utils::derive_key` as cSHAKE256
+//! customization strings, and signcryption as a transcript prefix.
+//!
+//! The labels are fixed ASCII strings; changing one changes every key derived under it. Newer
+//! labels carry a version suffix. The hybrid KEM and `derive_key` labels predate that rule and
+//! keep their original unversioned bytes, since renaming them would change existing outputs.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use digest::{
+    ExtendableOutput,
+    Update,
+};
+
+/// AEAD key for `libq::envelope`, derived from the KEM shared secret
+pub const LABEL_ENVELOPE_KDF: &[u8] = b"lib-q envelope v1";
+
+/// Shared secret of the X25519 + ML-KEM-768 hybrid KEM in `lib-q-kem`, derived from both
+/// component shared secrets and ciphertexts
+pub const LABEL_HYBRID_X25519_MLKEM768: &[u8] = b"lib-Q X25519-ML-KEM-768";
+
+/// Next AEAD key derived by `lib-q-hpke` context rekeying, from the current exporter secret
+pub const LABEL_HPKE_REKEY_KEY: &[u8] = b"lib-q hpke rekey key v1";
+
+/// Next AEAD base nonce derived by `lib-q-hpke` context rekeying
+pub const LABEL_HPKE_REKEY_NONCE: &[u8] = b"lib-q hpke rekey nonce v1";
+
+/// Next exporter secret derived by `lib-q-hpke` context rekeying
+pub const LABEL_HPKE_REKEY_EXPORTER: &[u8] = b"lib-q hpke rekey exporter v1";
+
+/// cSHAKE256 customization string of the extract step of `lib_q_utils::derive_key`
+pub const LABEL_KDF_EXTRACT: &[u8] = b"lib-Q KDF extract";
+
+/// cSHAKE256 customization string of the expand step of `lib_q_utils::derive_key`
+pub const LABEL_KDF_EXPAND: &[u8] = b"lib-Q KDF expand";
+
+/// Prefix of the transcript signed by `libq::signcrypt`, ahead of the encapsulated key and
+/// plaintext
+pub const LABEL_SIGNCRYPT: &[u8] = b"lib-q signcrypt v1";
+
+/// Every label in use; no label is a prefix of another
+///
+/// Only some of these go through [`hash_with_label`] (see the module docs), but all of them
+/// are listed here so the set stays complete.
+pub const ALL_LABELS: &[&[u8]] = &[
+    LABEL_ENVELOPE_KDF,
+    LABEL_HYBRID_X25519_MLKEM768,
+    LABEL_HPKE_REKEY_KEY,
+    LABEL_HPKE_REKEY_NONCE,
+    LABEL_HPKE_REKEY_EXPORTER,
+    LABEL_KDF_EXTRACT,
+    LABEL_KDF_EXPAND,
+    LABEL_SIGNCRYPT,
+];
+
+/// `X(label || inputs[0] || inputs[1] || ...)`, squeezed to `out_len` bytes
+///
+/// `X` is the extendable-output function to use, normally SHAKE256. Inputs are concatenated
+/// without length prefixes, which keeps derivations byte-compatible with their pre-label
+/// form; callers must only pass fixed-length inputs, or frame variable-length ones with
+/// [`codec::write_frame`](crate::codec::write_frame) first.
+pub fn hash_with_label<X>(label: &[u8], inputs: &[&[u8]], out_len: usize) -> Vec<u8>
+where
+    X: Default + Update + ExtendableOutput,
+{
+    let mut xof = X::default();
+    xof.update(label);
+    for input in inputs {
+        xof.update(input);
+    }
+    let mut out = vec![0u8; out_len];
+    xof.finalize_xof_into(&mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use lib_q_sha3::Shake256;
+
+    use super::*;
+
+    #[test]
+    fn test_labels_separate_identical_inputs() {
+        let inputs: [&[u8]; 2] = [b"shared secret", b"ciphertext"];
+        let envelope = hash_with_label::<Shake256>(LABEL_ENVELOPE_KDF, &inputs, 32);
+        let hybrid = hash_with_label::<Shake256>(LABEL_HYBRID_X25519_MLKEM768, &inputs, 32);
+        assert_eq!(envelope.len(), 32);
+        assert_ne!(envelope, hybrid);
+
+        // Squeezing more output extends rather than changes the derivation.
+        let longer = hash_with_label::<Shake256>(LABEL_ENVELOPE_KDF, &inputs, 64);
+        assert_eq!(longer[..32], envelope[..]);
+    }
+
+    #[test]
+    fn test_labels_are_prefix_free() {
+        for (i, a) in ALL_LABELS.iter().enumerate() {
+            assert!(a.is_ascii());
+            for b in &ALL_LABELS[i + 1..] {
+                assert!(!a.starts_with(b) && !b.starts_with(a));
+            }
+        }
+    }
+}
@@ -15,7 +15,8 @@
 #![deny(unsafe_code)]
 #![deny(unused_qualifications)]
 
-#[cfg(feature = "alloc")]
+// Tests always link `alloc`, so they can format errors in every feature configuration.
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
 pub mod algorithm_registry;
@@ -28,11 +29,26 @@ pub mod wasm_common;
 #[cfg(feature = "alloc")]
 pub mod aead_semantic;
 #[cfg(feature = "alloc")]
+pub mod codec;
+#[cfg(feature = "alloc")]
 pub mod contexts;
+#[cfg(feature = "cose")]
+pub mod cose;
+#[cfg(feature = "alloc")]
+pub mod encoding;
+#[cfg(feature = "jwk")]
+pub mod jwk;
+#[cfg(feature = "alloc")]
+pub mod key_format;
+#[cfg(feature = "alloc")]
+pub mod labels;
 #[cfg(feature = "alloc")]
 pub mod providers;
 #[cfg(feature = "alloc")]
 pub mod security;
+// The crate's own unit tests use `SeededRng` without the feature (lib-q-sha3 is a dev-dependency)
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 // WASM bindings
 #[cfg(feature = "wasm")]
@@ -60,6 +76,8 @@ pub use error::{
 };
 // Re-export new modular components
 #[cfg(feature = "alloc")]
+pub use key_format::SerializedKeyPair;
+#[cfg(feature = "alloc")]
 pub use providers::LibQCryptoProvider;
 #[cfg(feature = "alloc")]
 pub use security::SecurityValidator;
@@ -112,8 +130,10 @@ pub fn create_signature_context() -> SignatureContext {
 //   - `std` is enabled — `std` supplies the handler;
 //   - `wasm` is enabled — the `wasm-bindgen` cdylib links `std`, which supplies the handler;
 //     defining our own would be a duplicate `panic_impl` lang item;
-//   - `no_panic_handler` is set — firmware/integrators that supply their own handler opt out.
+//   - `no_panic_handler` is set — firmware/integrators that supply their own handler opt out;
+//   - building the unit tests — the test harness links `std`.
 #[cfg(all(
+    not(test),
     not(feature = "std"),
     not(feature = "wasm"),
     not(feature = "no_panic_handler")
@@ -164,7 +184,22 @@ mod tests {
             assert_eq!(public_key.as_bytes(), &[1, 2, 3, 4]);
 
             let secret_key = KemSecretKey::new(vec![5, 6, 7, 8]);
-            assert_eq!(secret_key.as_bytes(), &[5, 6, 7, 8]);
+            assert_eq!(secret_key.expose_secret(SecretExportConsent), &[5, 6, 7, 8]);
         }
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_expose_secret_returns_key_bytes() {
+        let kem = KemSecretKey::new(vec![1; 32]);
+        assert_eq!(kem.expose_secret(SecretExportConsent), &[1; 32]);
+
+        let sig = SigSecretKey::new(vec![2; 64]);
+        assert_eq!(sig.expose_secret(SecretExportConsent), &[2; 64]);
+
+        let aead = AeadKey::new(vec![3; 16]);
+        assert_eq!(aead.expose_secret(SecretExportConsent), &[3; 16]);
+
+        assert_eq!(size_of::<SecretExportConsent>(), 0);
+    }
 }
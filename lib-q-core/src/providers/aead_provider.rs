@@ -18,6 +18,7 @@ use crate::security::SecurityValidator;
 use crate::traits::{
     AeadKey,
     Nonce,
+    SecretExportConsent,
 };
 
 /// Stub AEAD provider bundled with `lib-q-core` (no algorithm implementations).
@@ -55,7 +56,7 @@ impl AeadOperations for LibQAeadStubProvider {
 
         // Validate key
         self.security_validator
-            .validate_key_material(key.as_bytes())?;
+            .validate_key_material(key.expose_secret(SecretExportConsent))?;
 
         // Validate nonce
         self.security_validator.validate_nonce(nonce.as_bytes())?;
@@ -88,7 +89,7 @@ impl AeadOperations for LibQAeadStubProvider {
 
         // Validate key
         self.security_validator
-            .validate_key_material(key.as_bytes())?;
+            .validate_key_material(key.expose_secret(SecretExportConsent))?;
 
         // Validate nonce
         self.security_validator.validate_nonce(nonce.as_bytes())?;
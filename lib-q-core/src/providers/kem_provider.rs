@@ -19,6 +19,7 @@ use crate::traits::{
     KemKeypair,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 
 /// lib-Q KEM provider implementation
@@ -136,7 +137,7 @@ impl KemOperations for LibQKemProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Validate ciphertext
         self.security_validator
@@ -173,7 +174,7 @@ impl KemOperations for LibQKemProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Route to specific algorithm implementation
         // Note: Actual implementations are provided by the main lib-q crate
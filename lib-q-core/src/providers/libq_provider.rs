@@ -169,6 +169,7 @@ use crate::traits::{
     KemPublicKey,
     KemSecretKey,
     Nonce,
+    SecretExportConsent,
     SigKeypair,
     SigPublicKey,
     SigSecretKey,
@@ -254,7 +255,7 @@ impl KemOperations for WasmKemProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Validate ciphertext
         self.security_validator
@@ -280,7 +281,7 @@ impl KemOperations for WasmKemProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Return proper error indicating WASM implementation needed
         Err(crate::error::Error::NotImplemented {
@@ -345,7 +346,7 @@ impl SignatureOperations for WasmSignatureProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Validate message
         self.security_validator
@@ -464,7 +465,7 @@ impl AeadOperations for WasmAeadProvider {
 
         // Validate key
         self.security_validator
-            .validate_key_material(key.as_bytes())?;
+            .validate_key_material(key.expose_secret(SecretExportConsent))?;
 
         // Validate nonce
         self.security_validator.validate_nonce(nonce.as_bytes())?;
@@ -500,7 +501,7 @@ impl AeadOperations for WasmAeadProvider {
 
         // Validate key
         self.security_validator
-            .validate_key_material(key.as_bytes())?;
+            .validate_key_material(key.expose_secret(SecretExportConsent))?;
 
         // Validate nonce
         self.security_validator.validate_nonce(nonce.as_bytes())?;
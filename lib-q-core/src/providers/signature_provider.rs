@@ -16,6 +16,7 @@ use crate::api::{
 use crate::error::Result;
 use crate::security::SecurityValidator;
 use crate::traits::{
+    SecretExportConsent,
     SigKeypair,
     SigPublicKey,
     SigSecretKey,
@@ -107,7 +108,7 @@ impl SignatureOperations for LibQSignatureProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Validate message
         self.security_validator
@@ -3,8 +3,6 @@
 //! This module provides security-related constants used throughout the library
 //! for validation and configuration.
 
-use lib_q_types::hqc;
-
 use crate::api::Algorithm;
 use crate::error::Result;
 
@@ -96,187 +94,17 @@ impl SecurityConstants {
     ///
     /// Returns the expected key size in bytes, or an error if the algorithm
     /// doesn't use keys or is not supported.
+    ///
+    /// Sizes come from [`Algorithm::public_key_len`] and [`Algorithm::secret_key_len`].
     pub fn get_expected_key_size(&self, algorithm: Algorithm, is_secret: bool) -> Result<usize> {
-        let expected_size = match algorithm {
-            // KEM algorithms
-            Algorithm::MlKem512 => {
-                if is_secret {
-                    1632
-                } else {
-                    800
-                }
-            }
-            Algorithm::MlKem768 => {
-                if is_secret {
-                    2400
-                } else {
-                    1184
-                }
-            }
-            Algorithm::MlKem1024 => {
-                if is_secret {
-                    3168
-                } else {
-                    1568
-                }
-            }
-            // CB-KEM algorithms
-            Algorithm::CbKem348864 => {
-                if is_secret {
-                    6492 // CB-KEM-348864 secret key size
-                } else {
-                    261120 // CB-KEM-348864 public key size
-                }
-            }
-            Algorithm::CbKem460896 => {
-                if is_secret {
-                    13608 // CB-KEM-460896 secret key size
-                } else {
-                    524160 // CB-KEM-460896 public key size
-                }
-            }
-            Algorithm::CbKem6688128 => {
-                if is_secret {
-                    13932 // CB-KEM-6688128 secret key size
-                } else {
-                    1044992 // CB-KEM-6688128 public key size
-                }
-            }
-            Algorithm::CbKem6960119 => {
-                if is_secret {
-                    13948 // CB-KEM-6960119 secret key size
-                } else {
-                    1047319 // CB-KEM-6960119 public key size
-                }
-            }
-            Algorithm::CbKem8192128 => {
-                if is_secret {
-                    14120 // CB-KEM-8192128 secret key size
-                } else {
-                    1357824 // CB-KEM-8192128 public key size
-                }
-            }
-
-            // HQC KEM — sizes from `lib_q_types::hqc` (single source of truth).
-            Algorithm::Hqc128 => {
-                if is_secret {
-                    hqc::HQC128_SECRET_KEY_BYTES
-                } else {
-                    hqc::HQC128_PUBLIC_KEY_BYTES
-                }
-            }
-            Algorithm::Hqc192 => {
-                if is_secret {
-                    hqc::HQC192_SECRET_KEY_BYTES
-                } else {
-                    hqc::HQC192_PUBLIC_KEY_BYTES
-                }
-            }
-            Algorithm::Hqc256 => {
-                if is_secret {
-                    hqc::HQC256_SECRET_KEY_BYTES
-                } else {
-                    hqc::HQC256_PUBLIC_KEY_BYTES
-                }
-            }
-
-            // Signature algorithms
-            Algorithm::MlDsa44 => {
-                if is_secret {
-                    2560 // ML-DSA-44 secret key size
-                } else {
-                    1312 // ML-DSA-44 public key size
-                }
-            }
-            Algorithm::MlDsa65 => {
-                if is_secret {
-                    4032 // ML-DSA-65 secret key size
-                } else {
-                    1952 // ML-DSA-65 public key size
-                }
-            }
-            Algorithm::MlDsa87 => {
-                if is_secret {
-                    4896 // ML-DSA-87 secret key size
-                } else {
-                    2592 // ML-DSA-87 public key size
-                }
-            }
-            Algorithm::FnDsa => {
-                if is_secret {
-                    1281 // FN-DSA-512 secret key size (logn=9)
-                } else {
-                    897 // FN-DSA-512 public key size (logn=9)
-                }
-            }
-            Algorithm::FnDsa512 => {
-                if is_secret {
-                    1281 // FN-DSA-512 secret key size (logn=9)
-                } else {
-                    897 // FN-DSA-512 public key size (logn=9)
-                }
-            }
-            Algorithm::FnDsa1024 => {
-                if is_secret {
-                    2561 // FN-DSA-1024 secret key size (logn=10)
-                } else {
-                    1793 // FN-DSA-1024 public key size (logn=10)
-                }
-            }
-
-            // SLH-DSA algorithms
-            Algorithm::SlhDsaSha256128fRobust => {
-                if is_secret {
-                    64 // SLH-DSA SHA256-128f secret key size (4 * N where N=16)
-                } else {
-                    32 // SLH-DSA SHA256-128f public key size (2 * N where N=16)
-                }
-            }
-            Algorithm::SlhDsaSha256192fRobust => {
-                if is_secret {
-                    96 // SLH-DSA SHA256-192f secret key size (4 * N where N=24)
-                } else {
-                    48 // SLH-DSA SHA256-192f public key size (2 * N where N=24)
-                }
-            }
-            Algorithm::SlhDsaSha256256fRobust => {
-                if is_secret {
-                    128 // SLH-DSA SHA256-256f secret key size (4 * N where N=32)
-                } else {
-                    64 // SLH-DSA SHA256-256f public key size (2 * N where N=32)
-                }
-            }
-            Algorithm::SlhDsaShake256128fRobust => {
-                if is_secret {
-                    64 // SLH-DSA SHAKE256-128f secret key size (4 * N where N=16)
-                } else {
-                    32 // SLH-DSA SHAKE256-128f public key size (2 * N where N=16)
-                }
-            }
-            Algorithm::SlhDsaShake256192fRobust => {
-                if is_secret {
-                    96 // SLH-DSA SHAKE256-192f secret key size (4 * N where N=24)
-                } else {
-                    48 // SLH-DSA SHAKE256-192f public key size (2 * N where N=24)
-                }
-            }
-            Algorithm::SlhDsaShake256256fRobust => {
-                if is_secret {
-                    128 // SLH-DSA SHAKE256-256f secret key size (4 * N where N=32)
-                } else {
-                    64 // SLH-DSA SHAKE256-256f public key size (2 * N where N=32)
-                }
-            }
-
-            // Hash algorithms don't have keys
-            _ => {
-                return Err(crate::error::Error::InvalidAlgorithm {
-                    algorithm: "Algorithm does not use keys",
-                });
-            }
+        let expected_size = if is_secret {
+            algorithm.secret_key_len()
+        } else {
+            algorithm.public_key_len()
         };
-
-        Ok(expected_size)
+        expected_size.ok_or(crate::error::Error::InvalidAlgorithm {
+            algorithm: "Algorithm does not use keys",
+        })
     }
 
     /// Get the expected ciphertext size for a given algorithm
@@ -290,30 +118,11 @@ impl SecurityConstants {
     /// Returns the expected ciphertext size in bytes, or an error if the algorithm
     /// doesn't produce ciphertext or is not supported.
     pub fn get_expected_ciphertext_size(&self, algorithm: Algorithm) -> Result<usize> {
-        let expected_size = match algorithm {
-            Algorithm::MlKem512 => 768,   // ML-KEM-512 ciphertext size
-            Algorithm::MlKem768 => 1088,  // ML-KEM-768 ciphertext size
-            Algorithm::MlKem1024 => 1568, // ML-KEM-1024 ciphertext size
-
-            // CB-KEM algorithms
-            Algorithm::CbKem348864 => 96, // CB-KEM-348864 ciphertext size
-            Algorithm::CbKem460896 => 156, // CB-KEM-460896 ciphertext size
-            Algorithm::CbKem6688128 => 208, // CB-KEM-6688128 ciphertext size
-            Algorithm::CbKem6960119 => 194, // CB-KEM-6960119 ciphertext size
-            Algorithm::CbKem8192128 => 208, // CB-KEM-8192128 ciphertext size
-
-            Algorithm::Hqc128 => hqc::HQC128_CIPHERTEXT_BYTES,
-            Algorithm::Hqc192 => hqc::HQC192_CIPHERTEXT_BYTES,
-            Algorithm::Hqc256 => hqc::HQC256_CIPHERTEXT_BYTES,
-
-            _ => {
-                return Err(crate::error::Error::InvalidAlgorithm {
-                    algorithm: "Algorithm does not produce ciphertext",
-                });
-            }
-        };
-
-        Ok(expected_size)
+        algorithm
+            .ciphertext_len()
+            .ok_or(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm does not produce ciphertext",
+            })
     }
 
     /// Get the expected signature size for a given algorithm
@@ -327,30 +136,11 @@ impl SecurityConstants {
     /// Returns the expected signature size in bytes, or an error if the algorithm
     /// doesn't produce signatures or is not supported.
     pub fn get_expected_signature_size(&self, algorithm: Algorithm) -> Result<usize> {
-        let expected_size = match algorithm {
-            Algorithm::MlDsa44 => 2420,   // ML-DSA-44 signature size
-            Algorithm::MlDsa65 => 3309,   // ML-DSA-65 signature size
-            Algorithm::MlDsa87 => 4627,   // ML-DSA-87 signature size
-            Algorithm::FnDsa => 666,      // FN-DSA-512 signature size (logn=9)
-            Algorithm::FnDsa512 => 666,   // FN-DSA-512 signature size (logn=9)
-            Algorithm::FnDsa1024 => 1280, // FN-DSA-1024 signature size (logn=10)
-
-            // SLH-DSA signature sizes (actual sizes from implementation)
-            Algorithm::SlhDsaSha256128fRobust => 17088, // SLH-DSA SHA256-128f signature size
-            Algorithm::SlhDsaSha256192fRobust => 35664, // SLH-DSA SHA256-192f signature size
-            Algorithm::SlhDsaSha256256fRobust => 49856, // SLH-DSA SHA256-256f signature size
-            Algorithm::SlhDsaShake256128fRobust => 17088, // SLH-DSA SHAKE256-128f signature size
-            Algorithm::SlhDsaShake256192fRobust => 35664, // SLH-DSA SHAKE256-192f signature size
-            Algorithm::SlhDsaShake256256fRobust => 49856, // SLH-DSA SHAKE256-256f signature size
-
-            _ => {
-                return Err(crate::error::Error::InvalidAlgorithm {
-                    algorithm: "Algorithm does not produce signatures",
-                });
-            }
-        };
-
-        Ok(expected_size)
+        algorithm
+            .signature_len()
+            .ok_or(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm does not produce signatures",
+            })
     }
 
     /// Set the maximum AEAD plaintext, ciphertext, or AAD size (bytes) for one operation.
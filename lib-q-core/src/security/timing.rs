@@ -14,8 +14,6 @@
 #[cfg(feature = "alloc")]
 use alloc::string::ToString;
 
-use subtle::ConstantTimeEq;
-
 use crate::error::Result;
 
 /// Timing attack prevention validator
@@ -61,10 +59,7 @@ impl TimingValidator {
     /// Returns `true` if the slices are equal, `false` otherwise.
     /// The comparison is performed in constant time to prevent timing attacks.
     pub fn constant_time_compare(&self, a: &[u8], b: &[u8]) -> bool {
-        if a.len() != b.len() {
-            return false;
-        }
-        a.ct_eq(b).into()
+        crate::Utils::constant_time_eq(a, b)
     }
 
     /// Constant-time selection between two values
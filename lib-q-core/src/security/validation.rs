@@ -188,6 +188,8 @@ impl SecurityValidator {
     }
 
     /// Validate hash absorb input length.
+    ///
+    /// Only the upper bound is enforced; empty input is valid for every hash.
     pub fn validate_hash_input(&self, data: &[u8]) -> Result<()> {
         if data.len() > self.constants.max_hash_message_size() {
             return Err(crate::error::Error::InvalidMessageSize {
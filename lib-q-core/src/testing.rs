@@ -0,0 +1,119 @@
+//! Deterministic randomness for tests (feature `testing`)
+//!
+//! **Test-only.** [`SeededRng`] turns a fixed seed into a reproducible byte stream, so tests
+//! can build stable fixtures (keys, nonces, ciphertexts) and assert on exact values instead
+//! of on two random draws being different. Never use it to generate production key
+//! material: anyone who knows the seed knows every output.
+//!
+//! `lib-q-utils` re-exports this module, so `lib_q_utils::SeededRng` is the same type.
+
+use core::convert::Infallible;
+
+use lib_q_sha3::{
+    ExtendableOutput,
+    Shake256,
+    Shake256Reader,
+    Update,
+    XofReader,
+};
+use rand_core::{
+    SeedableRng,
+    TryCryptoRng,
+    TryRng,
+};
+
+/// Domain separation prefix absorbed before the seed; kept from when the RNG lived in
+/// `lib-q-utils` so existing fixtures stay valid
+const DOMAIN: &[u8] = b"lib-q-utils SeededRng v1";
+
+/// Reproducible RNG: SHAKE256 over a fixed seed, squeezed as a DRBG
+///
+/// Two instances created from the same seed emit identical streams, however the output is
+/// split across `next_u32`/`next_u64`/`fill_bytes` calls. It implements the `rand_core`
+/// crypto RNG traits only so it can stand in for an OS RNG in tests.
+#[derive(Clone, Debug)]
+pub struct SeededRng {
+    reader: Shake256Reader,
+}
+
+impl SeededRng {
+    /// Create an RNG from an arbitrary-length seed
+    pub fn new(seed: &[u8]) -> Self {
+        let mut shake = Shake256::default();
+        shake.update(&(DOMAIN.len() as u64).to_be_bytes());
+        shake.update(DOMAIN);
+        shake.update(&(seed.len() as u64).to_be_bytes());
+        shake.update(seed);
+        Self {
+            reader: shake.finalize_xof(),
+        }
+    }
+}
+
+impl TryRng for SeededRng {
+    type Error = Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut bytes = [0u8; 4];
+        self.reader.read(&mut bytes);
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut bytes = [0u8; 8];
+        self.reader.read(&mut bytes);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        self.reader.read(dest);
+        Ok(())
+    }
+}
+
+// SHAKE256 output is pseudorandom, so the trait holds for a secret seed. With the public
+// seeds tests use, the stream is predictable by design.
+impl TryCryptoRng for SeededRng {}
+
+impl SeedableRng for SeededRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(&seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::Rng;
+
+    use super::*;
+
+    #[test]
+    fn same_seed_same_stream() {
+        let mut a = SeededRng::new(b"fixture");
+        let mut b = SeededRng::new(b"fixture");
+        let (mut xa, mut xb) = ([0u8; 96], [0u8; 96]);
+        a.fill_bytes(&mut xa);
+        b.fill_bytes(&mut xb);
+        assert_eq!(xa, xb);
+        assert_eq!(a.next_u64(), b.next_u64());
+
+        let mut c = SeededRng::new(b"other fixture");
+        let mut xc = [0u8; 96];
+        c.fill_bytes(&mut xc);
+        assert_ne!(xa, xc);
+    }
+
+    #[test]
+    fn output_does_not_depend_on_read_sizes() {
+        let mut whole = [0u8; 12];
+        SeededRng::new(b"split").fill_bytes(&mut whole);
+
+        let mut split = SeededRng::new(b"split");
+        let first = split.next_u32().to_le_bytes();
+        let second = split.next_u64().to_le_bytes();
+        assert_eq!(whole[..4], first);
+        assert_eq!(whole[4..], second);
+    }
+}
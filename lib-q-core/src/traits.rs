@@ -10,6 +10,10 @@ use crate::error::Result;
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "spin")))]
+use alloc::format;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "spin")))]
+use alloc::string::String;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -18,6 +22,39 @@ use js_sys::Uint8Array;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
+/// Check output buffers for [`Kem::encapsulate_into`] and [`Kem::decapsulate_into`]
+///
+/// Exposed so `Kem` implementations that override those methods report wrong-sized buffers
+/// the same way as the defaults.
+pub fn check_kem_output_len(
+    ciphertext_out: &[u8],
+    ciphertext_len: usize,
+    shared_secret_out: &[u8],
+    shared_secret_len: usize,
+) -> Result<()> {
+    if ciphertext_out.len() != ciphertext_len {
+        return Err(crate::Error::InvalidCiphertextSize {
+            expected: ciphertext_len,
+            actual: ciphertext_out.len(),
+        });
+    }
+    check_shared_secret_len(shared_secret_out, shared_secret_len)
+}
+
+/// Check the output buffer for [`Kem::decapsulate_into`]
+///
+/// The shared-secret half of [`check_kem_output_len`], for callers that have no ciphertext
+/// buffer to check.
+pub fn check_shared_secret_len(shared_secret_out: &[u8], shared_secret_len: usize) -> Result<()> {
+    if shared_secret_out.len() != shared_secret_len {
+        return Err(crate::Error::InvalidKeySize {
+            expected: shared_secret_len,
+            actual: shared_secret_out.len(),
+        });
+    }
+    Ok(())
+}
+
 /// Trait for key encapsulation mechanisms
 pub trait Kem {
     /// Generate a keypair
@@ -41,6 +78,79 @@ pub trait Kem {
     #[cfg(not(feature = "alloc"))]
     fn derive_public_key(&self, secret_key: &KemSecretKey) -> Result<KemPublicKey>;
 
+    /// Encapsulate into caller-provided buffers
+    ///
+    /// `ciphertext_out` and `shared_secret_out` must be exactly the algorithm's ciphertext
+    /// and shared secret sizes; otherwise [`Error::InvalidCiphertextSize`](crate::Error::InvalidCiphertextSize)
+    /// or [`Error::InvalidKeySize`](crate::Error::InvalidKeySize) is returned and neither
+    /// buffer is written. KEMs with fixed sizes (ML-KEM) override this to skip the
+    /// intermediate allocation; the default copies out of [`encapsulate`](Self::encapsulate).
+    fn encapsulate_into(
+        &self,
+        public_key: &KemPublicKey,
+        ciphertext_out: &mut [u8],
+        shared_secret_out: &mut [u8],
+    ) -> Result<()> {
+        let (ciphertext, shared_secret) = self.encapsulate(public_key)?;
+        #[cfg(feature = "alloc")]
+        let shared_secret = zeroize::Zeroizing::new(shared_secret);
+        check_kem_output_len(
+            ciphertext_out,
+            ciphertext.len(),
+            shared_secret_out,
+            shared_secret.len(),
+        )?;
+        ciphertext_out.copy_from_slice(&ciphertext);
+        shared_secret_out.copy_from_slice(&shared_secret);
+        Ok(())
+    }
+
+    /// Decapsulate into a caller-provided buffer
+    ///
+    /// `shared_secret_out` must be exactly the algorithm's shared secret size, otherwise
+    /// [`Error::InvalidKeySize`](crate::Error::InvalidKeySize) is returned and it is not
+    /// written. See [`encapsulate_into`](Self::encapsulate_into).
+    fn decapsulate_into(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+        shared_secret_out: &mut [u8],
+    ) -> Result<()> {
+        let shared_secret = self.decapsulate(secret_key, ciphertext)?;
+        #[cfg(feature = "alloc")]
+        let shared_secret = zeroize::Zeroizing::new(shared_secret);
+        check_shared_secret_len(shared_secret_out, shared_secret.len())?;
+        shared_secret_out.copy_from_slice(&shared_secret);
+        Ok(())
+    }
+
+    /// Whether `decapsulate` can fail on an honestly generated ciphertext
+    ///
+    /// True for KEMs with a nonzero decryption failure rate (HQC); their decoding failures
+    /// surface as [`Error::DecapsulationFailed`](crate::Error::DecapsulationFailed) with
+    /// `retriable: true`. Protocols built on such a KEM need a way to re-encapsulate.
+    fn has_decryption_failures(&self) -> bool {
+        false
+    }
+
+    /// Encapsulate with caller-chosen randomness `m` instead of the system RNG
+    ///
+    /// Exists to reproduce known-answer test vectors: the same public key and `m` always give
+    /// the same ciphertext and shared secret, so `m` must never be reused or predictable
+    /// outside of tests. KEMs without a deterministic path return
+    /// [`Error::NotImplemented`](crate::Error::NotImplemented).
+    #[cfg(feature = "deterministic")]
+    fn encapsulate_deterministic(
+        &self,
+        public_key: &KemPublicKey,
+        m: &[u8; 32],
+    ) -> Result<(Vec<u8>, Vec<u8>)> {
+        let _ = (public_key, m);
+        Err(crate::Error::NotImplemented {
+            feature: "Deterministic encapsulation".into(),
+        })
+    }
+
     /// Authenticated encapsulation (RFC 9180 AuthEncap)
     #[cfg(feature = "alloc")]
     fn auth_encapsulate(
@@ -85,6 +195,18 @@ pub trait Signature {
 
     /// Verify a signature
     fn verify(&self, public_key: &SigPublicKey, message: &[u8], signature: &[u8]) -> Result<bool>;
+
+    /// Size of an encoded public key in bytes
+    fn public_key_size(&self) -> usize;
+
+    /// Size of an encoded secret key in bytes
+    fn secret_key_size(&self) -> usize;
+
+    /// Size of a signature in bytes
+    ///
+    /// For schemes whose signatures vary in length this is the maximum, so it is always
+    /// safe to pre-allocate.
+    fn signature_size(&self) -> usize;
 }
 
 /// Trait for hash functions
@@ -99,6 +221,22 @@ pub trait Hash {
     fn output_size(&self) -> usize;
 }
 
+/// Algorithm identity shared by KEM, signature and hash implementations
+///
+/// [`Kem`], [`Signature`] and [`Hash`] have unrelated method sets, so generic tooling (test
+/// harnesses, capability reports) cannot hold a mix of them. Implementations also implement
+/// this trait, which lets them be collected as `Box<dyn Primitive>` and dispatched on by
+/// algorithm or category.
+pub trait Primitive {
+    /// The algorithm this instance implements
+    fn algorithm(&self) -> crate::api::Algorithm;
+
+    /// Category of [`algorithm`](Self::algorithm)
+    fn category(&self) -> crate::api::AlgorithmCategory {
+        self.algorithm().category()
+    }
+}
+
 /// Trait for authenticated encryption with associated data (AEAD).
 ///
 /// # Verification timing and the `Result` API
@@ -155,9 +293,74 @@ pub trait Aead {
     ) -> Result<&'static [u8]>;
 }
 
+/// Segmented AEAD for messages too large to hold in memory at once.
+///
+/// A stream is a sequence of fixed-size segments, each sealed under its own nonce
+/// derived from a per-stream prefix, a monotonically increasing segment counter and a
+/// final-segment flag. Reordering, dropping or duplicating segments therefore fails
+/// authentication, and truncating the stream is detected because the receiver never
+/// opens a segment sealed with the final flag set.
+///
+/// Every segment but the last must carry exactly the implementation's segment size of
+/// plaintext; the final segment may be shorter (including empty). After the final
+/// segment has been processed the stream rejects further segments.
+#[cfg(feature = "alloc")]
+pub trait StreamingAead {
+    /// Encrypt the next segment; set `last` on the final segment of the stream
+    fn seal_chunk(
+        &mut self,
+        plaintext: &[u8],
+        associated_data: Option<&[u8]>,
+        last: bool,
+    ) -> Result<Vec<u8>>;
+
+    /// Decrypt and verify the next segment; `last` must match the sender's flag
+    fn open_chunk(
+        &mut self,
+        ciphertext: &[u8],
+        associated_data: Option<&[u8]>,
+        last: bool,
+    ) -> Result<Vec<u8>>;
+
+    /// Finish the stream
+    ///
+    /// Returns [`Error::AuthenticationFailed`](crate::Error::AuthenticationFailed) unless a
+    /// final segment was sealed or opened, so a receiver calling this after the last
+    /// segment it received detects truncation.
+    fn finalize(self) -> Result<()>
+    where
+        Self: Sized;
+}
+
+/// Acknowledgment token required to read secret key bytes
+///
+/// Secret key types only hand out their bytes through `expose_secret`, which takes this
+/// zero-sized token by value. Constructing it at the call site marks every export of secret
+/// material as intentional and keeps those sites easy to audit with a text search.
+///
+/// # Examples
+///
+/// ```rust
+/// use lib_q_core::{
+///     KemSecretKey,
+///     SecretExportConsent,
+/// };
+///
+/// let secret_key = KemSecretKey::new(vec![1, 2, 3]);
+/// assert_eq!(secret_key.expose_secret(SecretExportConsent), &[1, 2, 3]);
+/// ```
+///
+/// Without the token the export does not compile:
+///
+/// ```compile_fail
+/// let secret_key = lib_q_core::KemSecretKey::new(vec![1, 2, 3]);
+/// let _ = secret_key.expose_secret();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SecretExportConsent;
+
 // Key types
 /// KEM keypair with automatic memory zeroization
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct KemKeypair {
     #[cfg_attr(feature = "wasm", wasm_bindgen(skip))]
@@ -167,19 +370,34 @@ pub struct KemKeypair {
 }
 
 /// KEM public key
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct KemPublicKey {
     #[cfg_attr(feature = "wasm", wasm_bindgen(skip))]
     #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::encoding::serde_payload"))]
     pub data: Vec<u8>,
     #[cfg(not(feature = "alloc"))]
     pub data: &'static [u8],
 }
 
+/// Constant-time equality, see [`Utils::constant_time_eq`](crate::Utils::constant_time_eq)
+///
+/// Public keys are not secret, but comparing a stored key against an attacker-supplied one
+/// (key pinning, allow-lists) would otherwise reveal how many leading bytes match.
+impl PartialEq for KemPublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        crate::Utils::constant_time_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for KemPublicKey {}
+
 /// KEM secret key with automatic memory zeroization
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///
+/// Intentionally not `serde`-serializable, so secret material cannot leak into configs or logs
+/// through a derived impl.
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct KemSecretKey {
     #[cfg_attr(feature = "wasm", wasm_bindgen(skip))]
@@ -200,8 +418,16 @@ impl Zeroize for KemSecretKey {
 #[cfg(feature = "alloc")]
 impl ZeroizeOnDrop for KemSecretKey {}
 
+/// Constant-time equality, see [`Utils::constant_time_eq`](crate::Utils::constant_time_eq)
+impl PartialEq for KemSecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        crate::Utils::constant_time_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for KemSecretKey {}
+
 /// Signature keypair with automatic memory zeroization
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct SigKeypair {
     #[cfg_attr(feature = "wasm", wasm_bindgen(skip))]
@@ -211,20 +437,34 @@ pub struct SigKeypair {
 }
 
 /// Signature public key
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct SigPublicKey {
     #[cfg_attr(feature = "wasm", wasm_bindgen(skip))]
     #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::encoding::serde_payload"))]
     pub data: Vec<u8>,
     #[cfg_attr(feature = "wasm", wasm_bindgen(skip))]
     #[cfg(not(feature = "alloc"))]
     pub data: &'static [u8],
 }
 
+/// Constant-time equality, see [`Utils::constant_time_eq`](crate::Utils::constant_time_eq)
+///
+/// Public keys are not secret, but comparing a stored key against an attacker-supplied one
+/// (key pinning, allow-lists) would otherwise reveal how many leading bytes match.
+impl PartialEq for SigPublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        crate::Utils::constant_time_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for SigPublicKey {}
+
 /// Signature secret key with automatic memory zeroization
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///
+/// Intentionally not `serde`-serializable, like [`KemSecretKey`].
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct SigSecretKey {
     #[cfg_attr(feature = "wasm", wasm_bindgen(skip))]
@@ -245,6 +485,15 @@ impl Zeroize for SigSecretKey {
 #[cfg(feature = "alloc")]
 impl ZeroizeOnDrop for SigSecretKey {}
 
+/// Constant-time equality, see [`Utils::constant_time_eq`](crate::Utils::constant_time_eq)
+impl PartialEq for SigSecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        crate::Utils::constant_time_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for SigSecretKey {}
+
 /// AEAD key
 pub struct AeadKey {
     #[cfg(feature = "alloc")]
@@ -263,10 +512,21 @@ impl Zeroize for AeadKey {
 #[cfg(feature = "alloc")]
 impl ZeroizeOnDrop for AeadKey {}
 
+/// Constant-time equality, see [`Utils::constant_time_eq`](crate::Utils::constant_time_eq)
+impl PartialEq for AeadKey {
+    fn eq(&self, other: &Self) -> bool {
+        crate::Utils::constant_time_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for AeadKey {}
+
 /// Nonce for AEAD operations
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nonce {
     #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::encoding::serde_payload"))]
     pub data: Vec<u8>,
     #[cfg(not(feature = "alloc"))]
     pub data: &'static [u8],
@@ -350,11 +610,70 @@ impl SigKeypair {
 }
 
 impl KemPublicKey {
+    /// Wrap public key bytes without validation
+    ///
+    /// Kept for backward compatibility; prefer
+    /// [`from_bytes_checked`](Self::from_bytes_checked) for keys received from
+    /// untrusted sources so malformed keys are rejected before `encapsulate`.
     #[cfg(feature = "alloc")]
     pub fn new(data: Vec<u8>) -> Self {
         Self { data }
     }
 
+    /// Create a public key after validating it for `algorithm`
+    ///
+    /// The length must match the algorithm's public key size. For ML-KEM this also
+    /// runs the FIPS 203 §7.2 encapsulation key check: every 12-bit coefficient of
+    /// the encoded vector `t` must be reduced modulo `q = 3329`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlgorithm`](crate::error::Error::InvalidAlgorithm) for non-KEM
+    /// algorithms, [`InvalidKeySize`](crate::error::Error::InvalidKeySize) on a length
+    /// mismatch and [`InvalidKey`](crate::error::Error::InvalidKey) when an ML-KEM
+    /// coefficient is out of range.
+    #[cfg(feature = "alloc")]
+    pub fn from_bytes_checked(bytes: &[u8], algorithm: crate::api::Algorithm) -> Result<Self> {
+        use crate::api::{
+            Algorithm,
+            AlgorithmCategory,
+        };
+
+        if algorithm.category() != AlgorithmCategory::Kem {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a KEM algorithm",
+            });
+        }
+
+        let expected =
+            crate::security::SecurityConstants::new().get_expected_key_size(algorithm, false)?;
+        if bytes.len() != expected {
+            return Err(crate::error::Error::InvalidKeySize {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        let ml_kem_rank = match algorithm {
+            Algorithm::MlKem512 => Some(2),
+            Algorithm::MlKem768 => Some(3),
+            Algorithm::MlKem1024 => Some(4),
+            _ => None,
+        };
+        if let Some(rank) = ml_kem_rank &&
+            !ml_kem_coefficients_reduced(&bytes[..rank * ML_KEM_POLYNOMIAL_BYTES])
+        {
+            return Err(crate::error::Error::InvalidKey {
+                key_type: "ML-KEM encapsulation key".into(),
+                reason: "coefficient not reduced modulo q".into(),
+            });
+        }
+
+        Ok(Self {
+            data: bytes.to_vec(),
+        })
+    }
+
     #[cfg(not(feature = "alloc"))]
     pub fn new(data: &'static [u8]) -> Self {
         Self { data }
@@ -365,6 +684,84 @@ impl KemPublicKey {
     }
 }
 
+/// PEM label for a public key, e.g. `ML-KEM-768 PUBLIC KEY`
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "spin")))]
+fn public_key_pem_label(algorithm: crate::api::Algorithm) -> String {
+    match crate::algorithm_registry::registry().get_metadata(&algorithm) {
+        Some(metadata) => format!("{} PUBLIC KEY", metadata.name),
+        None => format!("{algorithm:?} PUBLIC KEY"),
+    }
+}
+
+/// Decode a PEM public key block, requiring the label for `algorithm`
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "spin")))]
+fn public_key_from_pem(pem: &str, algorithm: crate::api::Algorithm) -> Result<Vec<u8>> {
+    let (label, data) = crate::encoding::from_pem(pem)?;
+    if label != public_key_pem_label(algorithm) {
+        return Err(crate::error::Error::InvalidFormat {
+            context: "PEM: label does not name the expected algorithm",
+        });
+    }
+    Ok(data)
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "spin")))]
+impl KemPublicKey {
+    /// Encode as PEM with the label `<algorithm name> PUBLIC KEY`
+    pub fn to_pem(&self, algorithm: crate::api::Algorithm) -> String {
+        crate::encoding::to_pem(&public_key_pem_label(algorithm), &self.data)
+    }
+
+    /// Decode a PEM block written by [`to_pem`](Self::to_pem)
+    ///
+    /// The label must name `algorithm`, and the payload is validated with
+    /// [`from_bytes_checked`](Self::from_bytes_checked).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`](crate::error::Error::InvalidFormat) for malformed PEM
+    /// armor or a label naming another algorithm, and any error of `from_bytes_checked` for
+    /// the key itself.
+    pub fn from_pem(pem: &str, algorithm: crate::api::Algorithm) -> Result<Self> {
+        Self::from_bytes_checked(&public_key_from_pem(pem, algorithm)?, algorithm)
+    }
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "spin")))]
+impl SigPublicKey {
+    /// Encode as PEM with the label `<algorithm name> PUBLIC KEY`
+    pub fn to_pem(&self, algorithm: crate::api::Algorithm) -> String {
+        crate::encoding::to_pem(&public_key_pem_label(algorithm), &self.data)
+    }
+
+    /// Decode a PEM block written by [`to_pem`](Self::to_pem); the label must name `algorithm`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`](crate::error::Error::InvalidFormat) for malformed PEM
+    /// armor or a label naming another algorithm.
+    pub fn from_pem(pem: &str, algorithm: crate::api::Algorithm) -> Result<Self> {
+        Ok(Self::new(public_key_from_pem(pem, algorithm)?))
+    }
+}
+
+/// Encoded size of one ML-KEM polynomial (256 coefficients of 12 bits)
+#[cfg(feature = "alloc")]
+const ML_KEM_POLYNOMIAL_BYTES: usize = 384;
+
+/// FIPS 203 modulus check: `ByteEncode12(ByteDecode12(t)) == t`
+///
+/// Equivalent to every packed 12-bit coefficient being below `q = 3329`.
+#[cfg(feature = "alloc")]
+fn ml_kem_coefficients_reduced(encoded_t: &[u8]) -> bool {
+    const Q: u16 = 3329;
+    encoded_t.as_chunks::<3>().0.iter().all(|b| {
+        let c0 = u16::from(b[0]) | (u16::from(b[1] & 0x0F) << 8);
+        let c1 = (u16::from(b[1]) >> 4) | (u16::from(b[2]) << 4);
+        c0 < Q && c1 < Q
+    })
+}
+
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 impl KemPublicKey {
@@ -391,9 +788,69 @@ impl KemSecretKey {
         Self { data }
     }
 
+    /// Borrow the KEM secret key bytes, e.g. for persistence
+    ///
+    /// The [`SecretExportConsent`] argument documents at the call site that secret material
+    /// leaves the key type here.
+    pub fn expose_secret(&self, _consent: SecretExportConsent) -> &[u8] {
+        &self.data
+    }
+
+    #[deprecated(note = "use `expose_secret(SecretExportConsent)` to export secret key bytes")]
     pub fn as_bytes(&self) -> &[u8] {
         &self.data
     }
+
+    /// Recover the encapsulation key embedded in an ML-KEM decapsulation key
+    ///
+    /// A FIPS 203 decapsulation key is `dk_PKE || ek || H(ek) || z`, so the public key is
+    /// sliced out rather than recomputed. The secret key length is checked first and the
+    /// result is validated with [`KemPublicKey::from_bytes_checked`]; the stored `H(ek)` is
+    /// not re-hashed here.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlgorithm`](crate::error::Error::InvalidAlgorithm) for non-KEM
+    /// algorithms, [`UnsupportedAlgorithm`](crate::error::Error::UnsupportedAlgorithm) for
+    /// KEMs other than ML-KEM (use [`Kem::derive_public_key`] there),
+    /// [`InvalidKeySize`](crate::error::Error::InvalidKeySize) on a length mismatch and
+    /// [`InvalidKey`](crate::error::Error::InvalidKey) if the embedded key is malformed.
+    #[cfg(feature = "alloc")]
+    pub fn derive_public(&self, algorithm: crate::api::Algorithm) -> Result<KemPublicKey> {
+        use crate::api::{
+            Algorithm,
+            AlgorithmCategory,
+        };
+
+        if algorithm.category() != AlgorithmCategory::Kem {
+            return Err(crate::error::Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a KEM algorithm",
+            });
+        }
+        let rank = match algorithm {
+            Algorithm::MlKem512 => 2,
+            Algorithm::MlKem768 => 3,
+            Algorithm::MlKem1024 => 4,
+            _ => {
+                return Err(crate::error::Error::UnsupportedAlgorithm {
+                    algorithm: "derive_public only supports ML-KEM".into(),
+                });
+            }
+        };
+
+        let expected =
+            crate::security::SecurityConstants::new().get_expected_key_size(algorithm, true)?;
+        if self.data.len() != expected {
+            return Err(crate::error::Error::InvalidKeySize {
+                expected,
+                actual: self.data.len(),
+            });
+        }
+
+        let ek_start = rank * ML_KEM_POLYNOMIAL_BYTES;
+        let ek_end = ek_start + rank * ML_KEM_POLYNOMIAL_BYTES + 32;
+        KemPublicKey::from_bytes_checked(&self.data[ek_start..ek_end], algorithm)
+    }
 }
 
 #[cfg(feature = "wasm")]
@@ -441,6 +898,15 @@ impl SigSecretKey {
         Self { data }
     }
 
+    /// Borrow the signature secret key bytes, e.g. for persistence
+    ///
+    /// The [`SecretExportConsent`] argument documents at the call site that secret material
+    /// leaves the key type here.
+    pub fn expose_secret(&self, _consent: SecretExportConsent) -> &[u8] {
+        &self.data
+    }
+
+    #[deprecated(note = "use `expose_secret(SecretExportConsent)` to export secret key bytes")]
     pub fn as_bytes(&self) -> &[u8] {
         &self.data
     }
@@ -457,6 +923,15 @@ impl AeadKey {
         Self { data }
     }
 
+    /// Borrow the AEAD key bytes, e.g. for persistence
+    ///
+    /// The [`SecretExportConsent`] argument documents at the call site that secret material
+    /// leaves the key type here.
+    pub fn expose_secret(&self, _consent: SecretExportConsent) -> &[u8] {
+        &self.data
+    }
+
+    #[deprecated(note = "use `expose_secret(SecretExportConsent)` to export secret key bytes")]
     pub fn as_bytes(&self) -> &[u8] {
         &self.data
     }
@@ -473,7 +948,81 @@ impl Nonce {
         Self { data }
     }
 
+    /// Generate a random nonce of the length `algorithm` expects
+    ///
+    /// Random nonces are only safe while the number of messages per key stays well below the
+    /// birthday bound of the nonce space; use [`Nonce::zeroed`] with a counter otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlgorithm`](crate::error::Error::InvalidAlgorithm) for non-AEAD
+    /// algorithms and passes through [`Utils::random_bytes`](crate::Utils::random_bytes) errors.
+    #[cfg(feature = "alloc")]
+    pub fn generate(algorithm: crate::api::Algorithm) -> Result<Self> {
+        crate::Utils::random_bytes(aead_nonce_len(algorithm)?).map(Self::new)
+    }
+
+    /// All-zero nonce of the length `algorithm` expects, e.g. as the start of a counter
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlgorithm`](crate::error::Error::InvalidAlgorithm) for non-AEAD
+    /// algorithms.
+    #[cfg(feature = "alloc")]
+    pub fn zeroed(algorithm: crate::api::Algorithm) -> Result<Self> {
+        Ok(Self::new(alloc::vec![0u8; aead_nonce_len(algorithm)?]))
+    }
+
+    /// Derive the nonce for message number `counter` from a per-session base nonce
+    ///
+    /// The big-endian counter is XORed into the last eight bytes of `base`, as in TLS 1.3 and
+    /// HPKE, so counter `0` yields `base` itself. `size` is the nonce length of the AEAD in use
+    /// (see [`Algorithm::nonce_len`](crate::api::Algorithm::nonce_len)).
+    ///
+    /// Every nonce must be used at most once per key: never restart or reuse a counter with
+    /// the same key and base, and never share a base between two senders of one key. Reusing
+    /// a nonce breaks confidentiality and, for most AEADs, authenticity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidNonceSize`](crate::error::Error::InvalidNonceSize) if `base` is not
+    /// `size` bytes long or `size` is too short to hold a 64-bit counter.
+    #[cfg(feature = "alloc")]
+    pub fn from_counter(base: &[u8], counter: u64, size: usize) -> Result<Self> {
+        const COUNTER_LEN: usize = size_of::<u64>();
+        if size < COUNTER_LEN {
+            return Err(crate::error::Error::InvalidNonceSize {
+                expected: COUNTER_LEN,
+                actual: size,
+            });
+        }
+        if base.len() != size {
+            return Err(crate::error::Error::InvalidNonceSize {
+                expected: size,
+                actual: base.len(),
+            });
+        }
+
+        let mut data = base.to_vec();
+        for (byte, counter_byte) in data[size - COUNTER_LEN..]
+            .iter_mut()
+            .zip(counter.to_be_bytes())
+        {
+            *byte ^= counter_byte;
+        }
+        Ok(Self::new(data))
+    }
+
     pub fn as_bytes(&self) -> &[u8] {
         &self.data
     }
 }
+
+#[cfg(feature = "alloc")]
+fn aead_nonce_len(algorithm: crate::api::Algorithm) -> Result<usize> {
+    algorithm
+        .nonce_len()
+        .ok_or(crate::error::Error::InvalidAlgorithm {
+            algorithm: "Algorithm is not an AEAD algorithm",
+        })
+}
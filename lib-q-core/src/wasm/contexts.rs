@@ -41,6 +41,7 @@ use crate::security::SecurityValidator;
 use crate::traits::{
     AeadKey,
     Nonce,
+    SecretExportConsent,
 };
 // Import secure error handling
 use crate::wasm::conversions::WASM_SIGNATURE_ALGORITHM_IDS;
@@ -236,7 +237,7 @@ impl WasmKemContext {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))
             .map_err(error_to_js_value)?;
 
         // Validate ciphertext
@@ -975,6 +976,14 @@ mod tests {
         assert!(info.contains("lib-Q") || info == "{}");
     }
 
+    #[cfg(target_arch = "wasm32")]
+    fn error_code(error: &JsValue) -> String {
+        js_sys::Reflect::get(error, &"code".into())
+            .ok()
+            .and_then(|code| code.as_string())
+            .unwrap_or_default()
+    }
+
     #[test]
     #[cfg(target_arch = "wasm32")]
     fn test_wasm_kem_context_operations() {
@@ -984,8 +993,10 @@ mod tests {
         let result = context.generate_keypair("ml-kem-512", None);
         assert!(result.is_err());
         if let Err(error) = result {
-            let error_str = error.as_string().unwrap_or_default();
-            assert!(error_str.contains("NotImplemented") || error_str.contains("WASM"));
+            assert!(matches!(
+                error_code(&error).as_str(),
+                "NOT_IMPLEMENTED" | "PROVIDER_NOT_CONFIGURED"
+            ));
         }
     }
 
@@ -998,8 +1009,10 @@ mod tests {
         let result = context.generate_keypair("ml-dsa-65", None);
         assert!(result.is_err());
         if let Err(error) = result {
-            let error_str = error.as_string().unwrap_or_default();
-            assert!(error_str.contains("NotImplemented") || error_str.contains("WASM"));
+            assert!(matches!(
+                error_code(&error).as_str(),
+                "NOT_IMPLEMENTED" | "PROVIDER_NOT_CONFIGURED"
+            ));
         }
     }
 
@@ -1013,8 +1026,10 @@ mod tests {
         let result = context.hash("sha3-256", &data);
         assert!(result.is_err());
         if let Err(error) = result {
-            let error_str = error.as_string().unwrap_or_default();
-            assert!(error_str.contains("NotImplemented") || error_str.contains("WASM"));
+            assert!(matches!(
+                error_code(&error).as_str(),
+                "NOT_IMPLEMENTED" | "PROVIDER_NOT_CONFIGURED"
+            ));
         }
     }
 
@@ -1031,12 +1046,10 @@ mod tests {
         let result = context.encrypt("saturnin", &key, &nonce, &plaintext, None);
         assert!(result.is_err());
         if let Err(error) = result {
-            let error_str = error.as_string().unwrap_or_default();
-            assert!(
-                error_str.contains("Provider not configured") ||
-                    error_str.contains("NotImplemented") ||
-                    error_str.contains("WASM")
-            );
+            assert!(matches!(
+                error_code(&error).as_str(),
+                "NOT_IMPLEMENTED" | "PROVIDER_NOT_CONFIGURED"
+            ));
         }
     }
 }
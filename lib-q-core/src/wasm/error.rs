@@ -15,10 +15,19 @@ use crate::error::Error;
 /// - Prevents information leakage through error messages
 /// - Ensures consistent error handling across WASM bindings
 /// - Maintains security by not exposing internal implementation details
+///
+/// The result is a structured object `{ code, codeNumeric, message }` (see
+/// [`wasm_js_error`](crate::wasm_common::wasm_js_error)) where `code` is the stable
+/// [`Error::code`], so JavaScript can branch on the failure category:
+///
+/// ```js
+/// try { ctx.generate_keypair("ml-kem-768"); }
+/// catch (e) { if (e.code === "NOT_IMPLEMENTED") { /* fall back */ } }
+/// ```
 #[cfg(feature = "wasm")]
 pub fn error_to_js_value(error: Error) -> JsValue {
     // Security: Use generic error messages to prevent information leakage
-    let message = match error {
+    let message = match &error {
         Error::InvalidAlgorithm { .. } => "Invalid algorithm specified",
         Error::InvalidKeySize { .. } => "Invalid key size",
         Error::InvalidMessageSize { .. } => "Invalid message size",
@@ -27,12 +36,14 @@ pub fn error_to_js_value(error: Error) -> JsValue {
         Error::InvalidCiphertextSize { .. } => "Invalid ciphertext",
         Error::InvalidPlaintextSize { .. } => "Invalid plaintext",
         Error::InvalidKeyFormat => "Invalid key material",
+        Error::InvalidFormat { .. } => "Invalid data format",
         Error::InvalidSecurityLevel { .. } => "Invalid security level",
         Error::NotImplemented { .. } => "Feature not implemented",
         Error::ProviderNotConfigured { .. } => "Provider not configured",
         Error::VerificationFailed { .. } => "Verification failed",
         Error::EncryptionFailed { .. } => "Encryption failed",
         Error::DecryptionFailed { .. } => "Decryption failed",
+        Error::DecapsulationFailed { .. } => "Decapsulation failed",
         Error::KeyGenerationFailed { .. } => "Key generation failed",
         Error::RandomGenerationFailed { .. } => "Random generation failed",
         Error::SigningFailed { .. } => "Signing failed",
@@ -46,15 +57,20 @@ pub fn error_to_js_value(error: Error) -> JsValue {
         Error::UnsupportedAlgorithm { .. } => "Unsupported algorithm",
         Error::AuthenticationFailed { .. } => "Authentication failed",
         Error::InvalidAssociatedDataSize { .. } => "Invalid associated data size",
+        Error::InvalidContextSize { .. } => "Invalid context size",
         Error::InvalidTagSize { .. } => "Invalid tag size",
         Error::InvalidHashSize { .. } => "Invalid hash size",
         Error::InvalidRandomnessSize { .. } => "Invalid randomness size",
         Error::RandomBytesLengthInvalid { .. } => "Invalid random length",
         Error::HexDecode(..) => "Invalid hex encoding",
         Error::BufferTooSmall { .. } => "Insufficient buffer capacity",
+        Error::InvalidParameter { .. } => "Invalid parameter",
+        #[cfg(feature = "std")]
+        Error::Io { .. } => "I/O error",
+        Error::NonceReuse => "Nonce reuse detected",
     };
 
-    JsValue::from_str(message)
+    crate::wasm_common::wasm_js_error(error.code(), message)
 }
 
 /// Helper function to convert Result<T, Error> to Result<T, JsValue>
@@ -78,20 +94,23 @@ pub fn convert_result<T>(result: Result<T, Error>) -> Result<T, JsValue> {
 pub fn parse_algorithm_wasm(algorithm: &str) -> Result<crate::api::Algorithm, JsValue> {
     // Security: Validate input length to prevent DoS attacks
     if algorithm.len() > 64 {
-        return Err(JsValue::from_str("Algorithm name too long"));
+        return Err(crate::wasm_common::wasm_js_error(
+            "INVALID_ALGORITHM",
+            "Algorithm name too long",
+        ));
     }
 
     // Reject control characters; allow Unicode for algorithm string forms that use non-ASCII.
     // [`crate::wasm::conversions::WasmConversions::string_to_algorithm`].
     if algorithm.chars().any(|c| c.is_control()) {
-        return Err(JsValue::from_str("Invalid algorithm name format"));
+        return Err(crate::wasm_common::wasm_js_error(
+            "INVALID_ALGORITHM",
+            "Invalid algorithm name format",
+        ));
     }
 
-    match crate::wasm::conversions::WasmConversions::string_to_algorithm(algorithm) {
-        Ok(a) => Ok(a),
-        Err(Error::UnsupportedAlgorithm { .. }) => Err(JsValue::from_str("Unsupported algorithm")),
-        Err(_) => Err(JsValue::from_str("Invalid algorithm specified")),
-    }
+    crate::wasm::conversions::WasmConversions::string_to_algorithm(algorithm)
+        .map_err(error_to_js_value)
 }
 
 /// Secure WASM error handling macro
@@ -157,13 +176,50 @@ pub fn secure_deserialize<T: serde::de::DeserializeOwned>(value: &JsValue) -> Re
 
 #[cfg(test)]
 mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    fn field(error: &JsValue, name: &str) -> Option<String> {
+        js_sys::Reflect::get(error, &name.into())
+            .ok()
+            .and_then(|value| value.as_string())
+    }
 
     #[test]
     #[cfg(target_arch = "wasm32")]
     fn test_error_conversion() {
         let error = Error::InvalidAlgorithm { algorithm: "test" };
         let js_error = error_to_js_value(error);
-        assert!(js_error.is_string());
+        assert!(js_error.is_object());
+        assert_eq!(
+            field(&js_error, "code").as_deref(),
+            Some("INVALID_ALGORITHM")
+        );
+        assert_eq!(
+            field(&js_error, "message").as_deref(),
+            Some("Invalid algorithm specified")
+        );
+    }
+
+    #[test]
+    #[cfg(target_arch = "wasm32")]
+    fn test_unsupported_algorithm_error_shape() {
+        let error = parse_algorithm_wasm("not-an-algorithm").unwrap_err();
+        assert_eq!(
+            field(&error, "code").as_deref(),
+            Some("UNSUPPORTED_ALGORITHM")
+        );
+        assert_eq!(
+            field(&error, "message").as_deref(),
+            Some("Unsupported algorithm")
+        );
+        assert!(
+            js_sys::Reflect::get(&error, &"codeNumeric".into())
+                .unwrap()
+                .as_f64()
+                .is_some()
+        );
     }
 
     #[test]
@@ -98,7 +98,11 @@ impl SecureWasmKemContext {
                 .generate_keypair(algorithm, randomness_bytes.as_deref()),
         )?;
 
-        // Serialize and return
+        // Secret keys are not serde-serializable; export the key bytes explicitly
+        let keypair = serde_json::json!({
+            "public_key": { "data": keypair.public_key.data },
+            "secret_key": { "data": keypair.secret_key.data },
+        });
         match secure_serialize(&keypair) {
             Ok(value) => Ok(value),
             Err(error) => Err(error),
@@ -270,7 +274,11 @@ impl SecureWasmSignatureContext {
                 .generate_keypair(algorithm, randomness_bytes.as_deref()),
         )?;
 
-        // Serialize and return
+        // Secret keys are not serde-serializable; export the key bytes explicitly
+        let keypair = serde_json::json!({
+            "public_key": { "data": keypair.public_key.data },
+            "secret_key": { "data": keypair.secret_key.data },
+        });
         match secure_serialize(&keypair) {
             Ok(value) => Ok(value),
             Err(error) => Err(error),
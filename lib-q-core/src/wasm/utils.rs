@@ -116,28 +116,15 @@ pub fn bytes_to_hex(data: &Uint8Array) -> String {
 /// Convert hexadecimal string to bytes
 ///
 /// This function provides secure hex decoding for WASM:
-/// - Validates hex string format
-/// - Handles errors gracefully
+/// - Validates hex string format via [`crate::api::Utils::hex_to_bytes`]
+/// - Reports the index of the first invalid digit in the error message
 /// - Returns Uint8Array
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn hex_to_bytes(hex: &str) -> Result<Uint8Array, String> {
-    if !hex.len().is_multiple_of(2) {
-        return Err("Invalid hex string length".to_string());
-    }
-
-    let length = hex.len() / 2;
-    let array = Uint8Array::new_with_length(length as u32);
-
-    for (i, chunk) in hex.as_bytes().chunks(2).enumerate() {
-        let hex_str = core::str::from_utf8(chunk).map_err(|_| "Invalid hex character")?;
-
-        let byte = u8::from_str_radix(hex_str, 16).map_err(|_| "Invalid hex character")?;
-
-        array.set_index(i as u32, byte);
-    }
-
-    Ok(array)
+    crate::api::Utils::hex_to_bytes(hex)
+        .map(|bytes| Uint8Array::from(bytes.as_slice()))
+        .map_err(|e| e.to_string())
 }
 
 /// Get library information for WASM
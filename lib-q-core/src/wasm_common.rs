@@ -13,6 +13,11 @@ extern crate alloc;
 #[cfg(feature = "alloc")]
 #[allow(unused_imports)]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use zeroize::Zeroize;
 
 /// Common trait for WASM key pairs
 #[cfg(feature = "wasm")]
@@ -22,11 +27,16 @@ pub trait WasmKeyPair {
 }
 
 /// Generic WASM key pair implementation
+///
+/// The secret key is held in Rust memory and handed to JavaScript as a fresh copy on
+/// each `secret_key` read. JS callers should call [`zeroize`](Self::zeroize) (or the
+/// generated `free()`, which drops the value and zeroizes it too) as soon as the key is no
+/// longer needed, and overwrite any copies they made with `array.fill(0)`.
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub struct WasmKeyPairImpl {
     public_key: Uint8Array,
-    secret_key: Uint8Array,
+    secret_key: Vec<u8>,
 }
 
 #[cfg(feature = "wasm")]
@@ -36,7 +46,7 @@ impl WasmKeyPairImpl {
     pub fn new(public_key: Uint8Array, secret_key: Uint8Array) -> WasmKeyPairImpl {
         WasmKeyPairImpl {
             public_key,
-            secret_key,
+            secret_key: conversions::uint8array_to_vec(&secret_key),
         }
     }
 
@@ -45,9 +55,24 @@ impl WasmKeyPairImpl {
         self.public_key.clone()
     }
 
+    /// Copy of the secret key; empty after [`zeroize`](Self::zeroize)
     #[wasm_bindgen(getter)]
     pub fn secret_key(&self) -> Uint8Array {
-        self.secret_key.clone()
+        conversions::vec_to_uint8array(&self.secret_key)
+    }
+
+    /// Overwrite the secret key with zeros
+    ///
+    /// Safe to call more than once; `secret_key` returns an empty array afterwards.
+    pub fn zeroize(&mut self) {
+        zeroize_secret(&mut self.secret_key);
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl Drop for WasmKeyPairImpl {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
@@ -58,10 +83,18 @@ impl WasmKeyPair for WasmKeyPairImpl {
     }
 
     fn secret_key(&self) -> Uint8Array {
-        self.secret_key.clone()
+        conversions::vec_to_uint8array(&self.secret_key)
     }
 }
 
+/// Overwrite secret bytes with zeros and empty the buffer
+///
+/// Used by the WASM key wrappers before their memory is released.
+#[cfg(feature = "alloc")]
+pub fn zeroize_secret(buffer: &mut Vec<u8>) {
+    buffer.zeroize();
+}
+
 /// WASM-compatible hash result
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
@@ -103,19 +136,24 @@ fn wasm_error_code_numeric(code: &str) -> u32 {
 }
 
 /// Structured error for JavaScript callers: `{ "code", "codeNumeric", "message" }`.
+///
+/// Serialized as a plain JS object (not an ES `Map`), so `error.code` works in JavaScript.
 #[cfg(feature = "wasm")]
 pub fn wasm_js_error(code: &str, message: impl core::fmt::Display) -> JsValue {
     use alloc::format;
 
+    use serde::Serialize;
+
     let code_numeric = wasm_error_code_numeric(code);
     let v = serde_json::json!({
         "code": code,
         "codeNumeric": code_numeric,
         "message": format!("{message}"),
     });
-    serde_wasm_bindgen::to_value(&v).unwrap_or_else(|_| {
-        JsValue::from_str("lib-q-core: failed to serialize structured WASM error")
-    })
+    v.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .unwrap_or_else(|_| {
+            JsValue::from_str("lib-q-core: failed to serialize structured WASM error")
+        })
 }
 
 /// Utility functions for WASM conversions
@@ -150,4 +188,18 @@ mod tests {
         // In a real WASM environment, these would be tested with wasm-bindgen-test
         // This is a placeholder for WASM-specific initialization
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_zeroize_secret_clears_buffer() {
+        let mut secret = alloc::vec![0xA5u8; 64];
+        let capacity = secret.capacity();
+        super::zeroize_secret(&mut secret);
+        assert!(secret.is_empty());
+        assert_eq!(secret.capacity(), capacity);
+
+        // Idempotent, so an explicit `zeroize()` followed by `Drop` is fine.
+        super::zeroize_secret(&mut secret);
+        assert!(secret.is_empty());
+    }
 }
@@ -3,6 +3,7 @@
 use lib_q_core::algorithm_registry::AlgorithmRegistry;
 use lib_q_core::contexts::{
     AeadContext,
+    HashContext,
     KemContext,
     SignatureContext,
 };
@@ -25,6 +26,7 @@ use lib_q_core::traits::{
 use lib_q_core::{
     Algorithm,
     AlgorithmCategory,
+    SecretExportConsent,
     SecurityValidator,
     Utils,
     algorithms_by_category,
@@ -102,8 +104,9 @@ fn test_factories_contexts_and_security_constants() {
     let enc_uninit = kem.encapsulate(Algorithm::MlKem512, &pk, None);
     assert!(matches!(
         enc_uninit,
-        Err(Error::InvalidState { operation, .. }) if operation == "encapsulate"
+        Err(Error::ProviderNotConfigured { operation }) if operation == "KEM"
     ));
+    assert!(kem.is_initialized());
 
     let mut kem_init = KemContext::new();
     let _ = kem_init.generate_keypair(Algorithm::MlKem512, None);
@@ -115,8 +118,9 @@ fn test_factories_contexts_and_security_constants() {
     let sign_uninit = sig.sign(Algorithm::MlDsa65, &sk, b"msg", None);
     assert!(matches!(
         sign_uninit,
-        Err(Error::InvalidState { operation, .. }) if operation == "sign"
+        Err(Error::ProviderNotConfigured { operation }) if operation == "signature"
     ));
+    assert!(sig.is_initialized());
 
     let mut sig_ctx = SignatureContext::new();
     let _ = sig_ctx.generate_keypair(Algorithm::MlDsa65, None);
@@ -181,6 +185,10 @@ fn test_error_display_all_variants() {
         max: 64,
         actual: 128,
     });
+    check(Error::InvalidContextSize {
+        max: 255,
+        actual: 256,
+    });
     check(Error::InvalidTagSize {
         expected: 16,
         actual: 8,
@@ -205,6 +213,14 @@ fn test_error_display_all_variants() {
     check(Error::DecryptionFailed {
         operation: "dec".to_string(),
     });
+    check(Error::DecapsulationFailed {
+        operation: "decap".to_string(),
+        retriable: false,
+    });
+    check(Error::DecapsulationFailed {
+        operation: "decode".to_string(),
+        retriable: true,
+    });
     check(Error::KeyGenerationFailed {
         operation: "kg".to_string(),
     });
@@ -275,6 +291,7 @@ fn test_error_display_all_variants() {
         char_count: 3,
     }));
     check(Error::HexDecode(HexDecodeError::InvalidDigit {
+        position: 3,
         pair_start: 2,
         char_count: 4,
     }));
@@ -282,6 +299,10 @@ fn test_error_display_all_variants() {
         capacity: 4096,
         requested: 8192,
     });
+    check(Error::InvalidParameter {
+        parameter: "block_size",
+        reason: "must be greater than zero",
+    });
 }
 
 /// Hit remaining SecurityConstants match arms (CB-KEM, SLH-DSA) and Default.
@@ -420,7 +441,8 @@ fn test_aead_context_coverage_paths() {
 
     let ctx_uninit = AeadContext::new();
     let d = ctx_uninit.decrypt(Algorithm::Saturnin, &key, &nonce, b"ct", None);
-    assert!(matches!(d, Err(Error::InvalidState { .. })));
+    assert!(matches!(d, Err(Error::ProviderNotConfigured { .. })));
+    assert!(ctx_uninit.is_initialized());
 }
 
 fn pseudo_key_bytes(len: usize, seed: u32) -> Vec<u8> {
@@ -449,11 +471,17 @@ fn test_utils_traits_and_security_levels_vec() {
 
     let kp = KemKeypair::new(pseudo_key_bytes(800, 0xA1), pseudo_key_bytes(1632, 0xA2));
     assert_eq!(kp.public_key().as_bytes().len(), 800);
-    assert_eq!(kp.secret_key().as_bytes().len(), 1632);
+    assert_eq!(
+        kp.secret_key().expose_secret(SecretExportConsent).len(),
+        1632
+    );
 
     let sp = SigKeypair::new(pseudo_key_bytes(1952, 0xB1), pseudo_key_bytes(4032, 0xB2));
     assert_eq!(sp.public_key().as_bytes().len(), 1952);
-    assert_eq!(sp.secret_key().as_bytes().len(), 4032);
+    assert_eq!(
+        sp.secret_key().expose_secret(SecretExportConsent).len(),
+        4032
+    );
 
     let mut ksk = KemSecretKey::new(pseudo_key_bytes(32, 0xC1));
     zeroize::Zeroize::zeroize(&mut ksk);
@@ -547,3 +575,252 @@ fn test_libq_provider_operation_paths() {
         Err(Error::NotImplemented { .. })
     ));
 }
+
+#[test]
+fn test_kem_public_key_from_bytes_checked() {
+    // All-zero `t` and `rho` encode reduced coefficients, so the key passes the modulus check.
+    let valid = vec![0u8; 1184];
+    let pk = KemPublicKey::from_bytes_checked(&valid, Algorithm::MlKem768).unwrap();
+    assert_eq!(pk.as_bytes(), valid.as_slice());
+
+    assert!(matches!(
+        KemPublicKey::from_bytes_checked(&valid[..1183], Algorithm::MlKem768),
+        Err(Error::InvalidKeySize {
+            expected: 1184,
+            actual: 1183
+        })
+    ));
+    let mut too_long = valid.clone();
+    too_long.push(0);
+    assert!(matches!(
+        KemPublicKey::from_bytes_checked(&too_long, Algorithm::MlKem768),
+        Err(Error::InvalidKeySize {
+            expected: 1184,
+            actual: 1185
+        })
+    ));
+
+    // 0xFFF in the last coefficient of `t` exceeds q = 3329; the trailing `rho` is not checked.
+    let mut unreduced = vec![0u8; 800];
+    unreduced[766] = 0xF0;
+    unreduced[767] = 0xFF;
+    assert!(matches!(
+        KemPublicKey::from_bytes_checked(&unreduced, Algorithm::MlKem512),
+        Err(Error::InvalidKey { .. })
+    ));
+    let mut seed_only = vec![0u8; 800];
+    seed_only[768..].fill(0xFF);
+    assert!(KemPublicKey::from_bytes_checked(&seed_only, Algorithm::MlKem512).is_ok());
+
+    // Non-ML-KEM algorithms are length-checked only.
+    assert!(
+        KemPublicKey::from_bytes_checked(
+            &pseudo_key_bytes(lib_q_types::hqc::HQC128_PUBLIC_KEY_BYTES, 0x40),
+            Algorithm::Hqc128
+        )
+        .is_ok()
+    );
+    assert!(matches!(
+        KemPublicKey::from_bytes_checked(&valid, Algorithm::MlDsa65),
+        Err(Error::InvalidAlgorithm { .. })
+    ));
+}
+
+#[test]
+fn test_secret_key_equality_is_length_aware() {
+    let k = pseudo_key_bytes(32, 0x50);
+    let mut flipped = k.clone();
+    flipped[31] ^= 1;
+
+    assert!(KemSecretKey::new(k.clone()) == KemSecretKey::new(k.clone()));
+    assert!(KemSecretKey::new(k.clone()) != KemSecretKey::new(flipped.clone()));
+    assert!(KemSecretKey::new(k.clone()) != KemSecretKey::new(k[..31].to_vec()));
+
+    assert!(SigSecretKey::new(k.clone()) == SigSecretKey::new(k.clone()));
+    assert!(SigSecretKey::new(k.clone()) != SigSecretKey::new(flipped.clone()));
+
+    assert!(AeadKey::new(k.clone()) == AeadKey::new(k.clone()));
+    assert!(AeadKey::new(k.clone()) != AeadKey::new(flipped));
+    // A zero-padded key must not compare equal to its unpadded prefix.
+    let mut padded = k.clone();
+    padded.push(0);
+    assert!(AeadKey::new(k) != AeadKey::new(padded));
+}
+
+#[test]
+fn test_public_key_equality_matches_byte_equality() {
+    let k = pseudo_key_bytes(64, 0x60);
+    let mut flipped_first = k.clone();
+    flipped_first[0] ^= 0x80;
+    let mut flipped_last = k.clone();
+    flipped_last[63] ^= 1;
+    let mut padded = k.clone();
+    padded.push(0);
+    let candidates = [
+        k.clone(),
+        flipped_first,
+        flipped_last,
+        k[..63].to_vec(),
+        padded,
+    ];
+
+    for a in &candidates {
+        for b in &candidates {
+            assert_eq!(
+                KemPublicKey::new(a.clone()) == KemPublicKey::new(b.clone()),
+                a == b
+            );
+            assert_eq!(
+                SigPublicKey::new(a.clone()) == SigPublicKey::new(b.clone()),
+                a == b
+            );
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_public_key_pem_round_trip() {
+    let pk = KemPublicKey::from_bytes_checked(&[0u8; 1184], Algorithm::MlKem768).unwrap();
+    let pem = pk.to_pem(Algorithm::MlKem768);
+    assert!(pem.starts_with("-----BEGIN ML-KEM-768 PUBLIC KEY-----"));
+    assert_eq!(
+        KemPublicKey::from_pem(&pem, Algorithm::MlKem768).unwrap(),
+        pk
+    );
+    assert!(matches!(
+        KemPublicKey::from_pem(&pem, Algorithm::MlKem512),
+        Err(Error::InvalidFormat { .. })
+    ));
+
+    let sig_pk = SigPublicKey::new(pseudo_key_bytes(1952, 0x60));
+    let pem = sig_pk.to_pem(Algorithm::MlDsa65);
+    assert!(pem.starts_with("-----BEGIN ML-DSA-65 PUBLIC KEY-----"));
+    assert_eq!(
+        SigPublicKey::from_pem(&pem.replace('\n', "\r\n"), Algorithm::MlDsa65).unwrap(),
+        sig_pk
+    );
+}
+
+#[test]
+fn test_nonce_generate_and_zeroed_match_algorithm() {
+    for algorithm in algorithms_by_category(AlgorithmCategory::Aead) {
+        let expected = algorithm.nonce_len().unwrap();
+        let first = Nonce::generate(algorithm).unwrap();
+        let second = Nonce::generate(algorithm).unwrap();
+        assert_eq!(first.as_bytes().len(), expected, "{algorithm:?}");
+        assert_ne!(first, second, "{algorithm:?}");
+
+        let zeroed = Nonce::zeroed(algorithm).unwrap();
+        assert_eq!(zeroed.as_bytes(), vec![0u8; expected].as_slice());
+    }
+
+    assert!(matches!(
+        Nonce::generate(Algorithm::MlKem768),
+        Err(Error::InvalidAlgorithm { .. })
+    ));
+    assert!(matches!(
+        Nonce::zeroed(Algorithm::Sha3_256),
+        Err(Error::InvalidAlgorithm { .. })
+    ));
+}
+
+#[test]
+fn test_nonce_from_counter() {
+    let size = Algorithm::Saturnin.nonce_len().unwrap();
+    let base = Nonce::generate(Algorithm::Saturnin).unwrap();
+
+    let first = Nonce::from_counter(base.as_bytes(), 0, size).unwrap();
+    assert_eq!(first, base);
+
+    let nonces: Vec<Nonce> = (0..64)
+        .chain([u64::from(u32::MAX), u64::MAX])
+        .map(|counter| Nonce::from_counter(base.as_bytes(), counter, size).unwrap())
+        .collect();
+    for (i, nonce) in nonces.iter().enumerate() {
+        assert_eq!(nonce.as_bytes().len(), size);
+        // Only the trailing counter bytes may differ from the base.
+        assert_eq!(nonce.as_bytes()[..size - 8], base.as_bytes()[..size - 8]);
+        assert!(nonces[i + 1..].iter().all(|other| other != nonce));
+    }
+
+    let zero = Nonce::zeroed(Algorithm::Saturnin).unwrap();
+    let third = Nonce::from_counter(zero.as_bytes(), 0x0102, size).unwrap();
+    assert_eq!(third.as_bytes()[size - 2..], [0x01, 0x02]);
+
+    assert!(matches!(
+        Nonce::from_counter(base.as_bytes(), 1, 12),
+        Err(Error::InvalidNonceSize {
+            expected: 12,
+            actual: 16
+        })
+    ));
+    assert!(matches!(
+        Nonce::from_counter(&[0u8; 4], 1, 4),
+        Err(Error::InvalidNonceSize {
+            expected: 8,
+            actual: 4
+        })
+    ));
+}
+
+#[test]
+fn test_contexts_initialize_lazily_and_report_missing_provider() {
+    let not_configured =
+        |result: lib_q_core::Result<()>| matches!(result, Err(Error::ProviderNotConfigured { .. }));
+
+    // `&self` operations initialize like the `&mut self` ones instead of failing with
+    // `InvalidState` on a fresh context.
+    let kem = KemContext::new();
+    let pk = KemPublicKey::new(vec![0u8; 1184]);
+    assert!(not_configured(
+        kem.encapsulate(Algorithm::MlKem768, &pk, None).map(|_| ())
+    ));
+    assert!(kem.is_initialized());
+    assert!(not_configured(kem.ensure_provider()));
+
+    let sig = SignatureContext::new();
+    let sig_pk = SigPublicKey::new(vec![0u8; 1952]);
+    assert!(not_configured(
+        sig.verify(Algorithm::MlDsa65, &sig_pk, b"m", &[0u8; 3309])
+            .map(|_| ())
+    ));
+    assert!(sig.is_initialized());
+    assert!(not_configured(sig.ensure_provider()));
+
+    let hash = HashContext::new();
+    assert!(!hash.is_initialized());
+    assert!(not_configured(hash.ensure_provider()));
+    assert!(hash.is_initialized());
+
+    let aead = AeadContext::new();
+    let key = AeadKey::new(vec![0u8; 32]);
+    let nonce = Nonce::zeroed(Algorithm::Saturnin).unwrap();
+    assert!(not_configured(
+        aead.decrypt(Algorithm::Saturnin, &key, &nonce, &[0u8; 32], None)
+            .map(|_| ())
+    ));
+    assert!(aead.is_initialized());
+    assert!(not_configured(aead.ensure_provider()));
+
+    assert!(
+        KemContext::with_default_provider()
+            .ensure_provider()
+            .is_ok()
+    );
+    assert!(
+        SignatureContext::with_default_provider()
+            .ensure_provider()
+            .is_ok()
+    );
+    assert!(
+        HashContext::with_default_provider()
+            .ensure_provider()
+            .is_ok()
+    );
+    assert!(
+        AeadContext::with_default_provider()
+            .ensure_provider()
+            .is_ok()
+    );
+}
@@ -0,0 +1,35 @@
+//! `Utils::set_global_rng` (features `custom_rng` and `testing`)
+//!
+//! The global RNG is process-wide state, so this lives in its own test binary instead of
+//! next to the unit tests that draw from `Utils::random_bytes` in parallel. Which test runs
+//! depends on whether the build has an OS entropy source (`rand` / `getrandom`).
+
+#![cfg(all(feature = "custom_rng", feature = "testing"))]
+
+use lib_q_core::Utils;
+use lib_q_core::testing::SeededRng;
+
+#[test]
+#[cfg(not(any(feature = "rand", feature = "getrandom")))]
+fn test_global_rng_backs_random_bytes_without_os_rng() {
+    let expected = Utils::random_bytes_from_rng(&mut SeededRng::new(b"seed 42"), 64).unwrap();
+
+    assert!(Utils::random_bytes(64).is_err());
+    assert!(Utils::set_global_rng(SeededRng::new(b"seed 42")));
+    let drawn = Utils::random_bytes(64);
+    assert!(Utils::clear_global_rng());
+    assert_eq!(drawn.unwrap(), expected);
+
+    assert!(!Utils::clear_global_rng());
+    assert!(Utils::random_bytes(64).is_err());
+}
+
+#[test]
+#[cfg(any(feature = "rand", feature = "getrandom"))]
+fn test_global_rng_never_replaces_os_rng() {
+    let predictable = Utils::random_bytes_from_rng(&mut SeededRng::new(b"seed 42"), 64).unwrap();
+
+    assert!(!Utils::set_global_rng(SeededRng::new(b"seed 42")));
+    assert_ne!(Utils::random_bytes(64).unwrap(), predictable);
+    assert!(!Utils::clear_global_rng());
+}
@@ -9,6 +9,7 @@ use alloc::{
     vec::Vec,
 };
 
+use lib_q_core::SecretExportConsent;
 #[cfg(feature = "alloc")]
 use lib_q_core::{
     Aead,
@@ -68,7 +69,7 @@ impl Aead for DuplexSpongeAead {
         plaintext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
-        let kb = key.as_bytes();
+        let kb = key.expose_secret(SecretExportConsent);
         if kb.len() != KEY_BYTES {
             return Err(Error::InvalidKeySize {
                 expected: KEY_BYTES,
@@ -111,7 +112,7 @@ impl Aead for DuplexSpongeAead {
         ciphertext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
-        let kb = key.as_bytes();
+        let kb = key.expose_secret(SecretExportConsent);
         if kb.len() != KEY_BYTES {
             return Err(Error::InvalidKeySize {
                 expected: KEY_BYTES,
@@ -163,7 +164,7 @@ impl AeadDecryptSemantic for DuplexSpongeAead {
         ciphertext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<DecryptSemanticOutcome> {
-        let kb = key.as_bytes();
+        let kb = key.expose_secret(SecretExportConsent);
         if kb.len() != KEY_BYTES {
             return Err(Error::InvalidKeySize {
                 expected: KEY_BYTES,
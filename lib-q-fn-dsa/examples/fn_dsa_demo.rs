@@ -5,6 +5,7 @@
 
 #![allow(clippy::print_stdout, clippy::print_stderr)]
 
+use lib_q_core::SecretExportConsent;
 use lib_q_fn_dsa::*;
 
 fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
@@ -162,11 +163,14 @@ fn demo_sig_keypair_functionality() -> std::result::Result<(), Box<dyn std::erro
 
     // Show key sizes
     println!("• Public key size: {} bytes", public_key.as_bytes().len());
-    println!("• Secret key size: {} bytes", secret_key.as_bytes().len());
+    println!(
+        "• Secret key size: {} bytes",
+        secret_key.expose_secret(SecretExportConsent).len()
+    );
 
     // Demonstrate keypair reconstruction
     let public_key_bytes = public_key.as_bytes().to_vec();
-    let secret_key_bytes = secret_key.as_bytes().to_vec();
+    let secret_key_bytes = secret_key.expose_secret(SecretExportConsent).to_vec();
     let reconstructed_keypair = lib_q_core::SigKeypair::new(public_key_bytes, secret_key_bytes);
     println!("• Reconstructed SigKeypair from individual key bytes");
 
@@ -204,7 +208,8 @@ fn demo_sig_keypair_functionality() -> std::result::Result<(), Box<dyn std::erro
     // Demonstrate keypair uniqueness
     let keypair2 = fn_dsa.generate_keypair()?;
     let different_public = keypair.public_key().as_bytes() != keypair2.public_key().as_bytes();
-    let different_secret = keypair.secret_key().as_bytes() != keypair2.secret_key().as_bytes();
+    let different_secret = keypair.secret_key().expose_secret(SecretExportConsent) !=
+        keypair2.secret_key().expose_secret(SecretExportConsent);
     println!(
         "• Keypair uniqueness: {}",
         if different_public && different_secret {
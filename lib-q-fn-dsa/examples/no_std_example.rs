@@ -10,7 +10,10 @@
 
 extern crate alloc;
 
-use lib_q_core::Result;
+use lib_q_core::{
+    Result,
+    SecretExportConsent,
+};
 use lib_q_fn_dsa::*;
 
 /// no_std-compatible FN-DSA operations
@@ -31,7 +34,9 @@ impl NoStdFnDsa {
         let keypair = self.fn_dsa.generate_keypair()?;
         Ok(NoStdKeypair {
             public_key: alloc::vec::Vec::from(keypair.public_key.as_bytes()),
-            secret_key: alloc::vec::Vec::from(keypair.secret_key.as_bytes()),
+            secret_key: alloc::vec::Vec::from(
+                keypair.secret_key.expose_secret(SecretExportConsent),
+            ),
         })
     }
 
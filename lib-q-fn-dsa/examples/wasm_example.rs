@@ -12,7 +12,10 @@
 #[cfg(target_arch = "wasm32")]
 extern crate alloc;
 
-use lib_q_core::Result;
+use lib_q_core::{
+    Result,
+    SecretExportConsent,
+};
 use lib_q_fn_dsa::*;
 
 /// WASM-compatible FN-DSA operations
@@ -33,7 +36,10 @@ impl WasmFnDsa {
         let keypair = self.fn_dsa.generate_keypair()?;
         Ok(WasmKeypair {
             public_key: keypair.public_key.as_bytes().to_vec(),
-            secret_key: keypair.secret_key.as_bytes().to_vec(),
+            secret_key: keypair
+                .secret_key
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
         })
     }
 
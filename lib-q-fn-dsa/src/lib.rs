@@ -103,6 +103,11 @@ pub use fn_dsa::{
     signature_size,
     vrfy_key_size,
 };
+use lib_q_core::{
+    Algorithm,
+    Primitive,
+    SecretExportConsent,
+};
 pub use lib_q_core::{
     Error,
     Result,
@@ -150,10 +155,10 @@ fn sign_from_seed_bytes(
     message: &[u8],
     seed: &[u8; 32],
 ) -> Result<Vec<u8>> {
-    let mut sk =
-        SigningKeyStandard::decode(secret_key.as_bytes()).ok_or_else(|| Error::InvalidKeySize {
+    let mut sk = SigningKeyStandard::decode(secret_key.expose_secret(SecretExportConsent))
+        .ok_or_else(|| Error::InvalidKeySize {
             expected: sign_key_size(logn),
-            actual: secret_key.as_bytes().len(),
+            actual: secret_key.expose_secret(SecretExportConsent).len(),
         })?;
     let mut signature = vec![0u8; signature_size(logn)];
     let mut rng = Kt128Rng::from_seed_bytes(*seed);
@@ -247,6 +252,12 @@ impl FnDsaImpl for FnDsa512 {
     }
 }
 
+impl Primitive for FnDsa512 {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::FnDsa512
+    }
+}
+
 impl Signature for FnDsa512 {
     fn generate_keypair(&self) -> Result<SigKeypair> {
         // Generate keypair using the underlying FN-DSA implementation
@@ -270,12 +281,11 @@ impl Signature for FnDsa512 {
 
     fn sign(&self, secret_key: &SigSecretKey, message: &[u8]) -> Result<Vec<u8>> {
         // Decode the signing key
-        let mut sk = SigningKeyStandard::decode(secret_key.as_bytes()).ok_or_else(|| {
-            Error::InvalidKeySize {
+        let mut sk = SigningKeyStandard::decode(secret_key.expose_secret(SecretExportConsent))
+            .ok_or_else(|| Error::InvalidKeySize {
                 expected: sign_key_size(self.logn()),
-                actual: secret_key.as_bytes().len(),
-            }
-        })?;
+                actual: secret_key.expose_secret(SecretExportConsent).len(),
+            })?;
 
         // Create signature buffer
         let mut signature = {
@@ -321,6 +331,18 @@ impl Signature for FnDsa512 {
 
         Ok(is_valid)
     }
+
+    fn public_key_size(&self) -> usize {
+        vrfy_key_size(self.logn())
+    }
+
+    fn secret_key_size(&self) -> usize {
+        sign_key_size(self.logn())
+    }
+
+    fn signature_size(&self) -> usize {
+        signature_size(self.logn())
+    }
 }
 
 impl Default for FnDsa512 {
@@ -371,6 +393,12 @@ impl FnDsaImpl for FnDsa1024 {
     }
 }
 
+impl Primitive for FnDsa1024 {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::FnDsa1024
+    }
+}
+
 impl Signature for FnDsa1024 {
     fn generate_keypair(&self) -> Result<SigKeypair> {
         // Generate keypair using the underlying FN-DSA implementation
@@ -394,12 +422,11 @@ impl Signature for FnDsa1024 {
 
     fn sign(&self, secret_key: &SigSecretKey, message: &[u8]) -> Result<Vec<u8>> {
         // Decode the signing key
-        let mut sk = SigningKeyStandard::decode(secret_key.as_bytes()).ok_or_else(|| {
-            Error::InvalidKeySize {
+        let mut sk = SigningKeyStandard::decode(secret_key.expose_secret(SecretExportConsent))
+            .ok_or_else(|| Error::InvalidKeySize {
                 expected: sign_key_size(self.logn()),
-                actual: secret_key.as_bytes().len(),
-            }
-        })?;
+                actual: secret_key.expose_secret(SecretExportConsent).len(),
+            })?;
 
         // Create signature buffer
         let mut signature = {
@@ -445,6 +472,18 @@ impl Signature for FnDsa1024 {
 
         Ok(is_valid)
     }
+
+    fn public_key_size(&self) -> usize {
+        vrfy_key_size(self.logn())
+    }
+
+    fn secret_key_size(&self) -> usize {
+        sign_key_size(self.logn())
+    }
+
+    fn signature_size(&self) -> usize {
+        signature_size(self.logn())
+    }
 }
 
 impl Default for FnDsa1024 {
@@ -485,6 +524,15 @@ impl FnDsa {
     }
 }
 
+impl Primitive for FnDsa {
+    fn algorithm(&self) -> Algorithm {
+        match self.security_level {
+            FnDsaSecurityLevel::Level1 => Algorithm::FnDsa512,
+            FnDsaSecurityLevel::Level5 => Algorithm::FnDsa1024,
+        }
+    }
+}
+
 impl Signature for FnDsa {
     fn generate_keypair(&self) -> Result<SigKeypair> {
         // Generate keypair using the underlying FN-DSA implementation
@@ -508,12 +556,11 @@ impl Signature for FnDsa {
 
     fn sign(&self, secret_key: &SigSecretKey, message: &[u8]) -> Result<Vec<u8>> {
         // Decode the signing key
-        let mut sk = SigningKeyStandard::decode(secret_key.as_bytes()).ok_or_else(|| {
-            Error::InvalidKeySize {
+        let mut sk = SigningKeyStandard::decode(secret_key.expose_secret(SecretExportConsent))
+            .ok_or_else(|| Error::InvalidKeySize {
                 expected: sign_key_size(self.logn()),
-                actual: secret_key.as_bytes().len(),
-            }
-        })?;
+                actual: secret_key.expose_secret(SecretExportConsent).len(),
+            })?;
 
         // Create signature buffer
         let mut signature = {
@@ -559,6 +606,18 @@ impl Signature for FnDsa {
 
         Ok(is_valid)
     }
+
+    fn public_key_size(&self) -> usize {
+        vrfy_key_size(self.logn())
+    }
+
+    fn secret_key_size(&self) -> usize {
+        sign_key_size(self.logn())
+    }
+
+    fn signature_size(&self) -> usize {
+        signature_size(self.logn())
+    }
 }
 
 impl Default for FnDsa {
@@ -686,7 +745,10 @@ mod tests {
             vrfy_key_size(FN_DSA_LOGN_512)
         );
         assert_eq!(
-            keypair.secret_key().as_bytes().len(),
+            keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .len(),
             sign_key_size(FN_DSA_LOGN_512)
         );
 
@@ -777,8 +839,8 @@ mod tests {
             "same seed => same verifying key"
         );
         assert_eq!(
-            kp_a.secret_key.as_bytes(),
-            kp_b.secret_key.as_bytes(),
+            kp_a.secret_key.expose_secret(SecretExportConsent),
+            kp_b.secret_key.expose_secret(SecretExportConsent),
             "same seed => same signing key"
         );
         // A different seed must yield a different key.
@@ -798,7 +860,10 @@ mod tests {
         let kp_a = fn_dsa.generate_keypair_from_seed(&seed)?;
         let kp_b = fn_dsa.generate_keypair_from_seed(&seed)?;
         assert_eq!(kp_a.public_key.as_bytes(), kp_b.public_key.as_bytes());
-        assert_eq!(kp_a.secret_key.as_bytes(), kp_b.secret_key.as_bytes());
+        assert_eq!(
+            kp_a.secret_key.expose_secret(SecretExportConsent),
+            kp_b.secret_key.expose_secret(SecretExportConsent)
+        );
         Ok(())
     }
 
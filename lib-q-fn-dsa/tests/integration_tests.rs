@@ -6,6 +6,7 @@
 #![cfg(test)]
 
 use lib_q_core::{
+    SecretExportConsent,
     SigKeypair,
     SigPublicKey,
     SigSecretKey,
@@ -23,7 +24,10 @@ fn test_basic_fn_dsa_functionality() -> TestResult {
 
     let keypair = fn_dsa.generate_keypair()?;
     let expected_sizes = FnDsaSecurityLevel::Level1.key_sizes();
-    assert_eq!(keypair.secret_key.as_bytes().len(), expected_sizes.0);
+    assert_eq!(
+        keypair.secret_key.expose_secret(SecretExportConsent).len(),
+        expected_sizes.0
+    );
     assert_eq!(keypair.public_key.as_bytes().len(), expected_sizes.1);
 
     let message = b"Hello, FN-DSA!";
@@ -170,7 +174,7 @@ fn test_key_serialization() -> TestResult {
     let public_key_bytes = keypair.public_key().as_bytes();
     assert!(!public_key_bytes.is_empty(), "Public key should have bytes");
 
-    let secret_key_bytes = keypair.secret_key().as_bytes();
+    let secret_key_bytes = keypair.secret_key().expose_secret(SecretExportConsent);
     assert!(!secret_key_bytes.is_empty(), "Secret key should have bytes");
 
     let new_public_key = SigPublicKey::new(public_key_bytes.to_vec());
@@ -267,8 +271,14 @@ fn test_algorithm_consistency() -> TestResult {
         keypair2.public_key().as_bytes().len()
     );
     assert_eq!(
-        keypair1.secret_key().as_bytes().len(),
-        keypair2.secret_key().as_bytes().len()
+        keypair1
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
+        keypair2
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len()
     );
     Ok(())
 }
@@ -339,7 +349,10 @@ fn test_memory_usage() -> TestResult {
         expected_public_key_size
     );
     assert_eq!(
-        keypair.secret_key().as_bytes().len(),
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
         expected_secret_key_size
     );
 
@@ -366,7 +379,7 @@ fn test_sig_keypair_functionality() -> TestResult {
         "Public key should not be empty"
     );
     assert!(
-        !secret_key.as_bytes().is_empty(),
+        !secret_key.expose_secret(SecretExportConsent).is_empty(),
         "Secret key should not be empty"
     );
 
@@ -379,7 +392,7 @@ fn test_sig_keypair_functionality() -> TestResult {
         "Public key should have expected size"
     );
     assert_eq!(
-        secret_key.as_bytes().len(),
+        secret_key.expose_secret(SecretExportConsent).len(),
         expected_secret_key_size,
         "Secret key should have expected size"
     );
@@ -390,7 +403,7 @@ fn test_sig_keypair_functionality() -> TestResult {
     assert!(is_valid, "Signature should be valid");
 
     let public_key_bytes = public_key.as_bytes().to_vec();
-    let secret_key_bytes = secret_key.as_bytes().to_vec();
+    let secret_key_bytes = secret_key.expose_secret(SecretExportConsent).to_vec();
     let reconstructed_keypair = SigKeypair::new(public_key_bytes, secret_key_bytes);
 
     let signature2 = fn_dsa.sign(reconstructed_keypair.secret_key(), message)?;
@@ -419,7 +432,10 @@ fn test_sig_keypair_security_levels() -> TestResult {
         "512-bit public key should have expected size"
     );
     assert_eq!(
-        keypair512.secret_key().as_bytes().len(),
+        keypair512
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
         expected_secret_key_size_512,
         "512-bit secret key should have expected size"
     );
@@ -436,7 +452,10 @@ fn test_sig_keypair_security_levels() -> TestResult {
         "1024-bit public key should have expected size"
     );
     assert_eq!(
-        keypair1024.secret_key().as_bytes().len(),
+        keypair1024
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
         expected_secret_key_size_1024,
         "1024-bit secret key should have expected size"
     );
@@ -446,7 +465,14 @@ fn test_sig_keypair_security_levels() -> TestResult {
         "1024-bit public key should be larger than 512-bit public key"
     );
     assert!(
-        keypair1024.secret_key().as_bytes().len() > keypair512.secret_key().as_bytes().len(),
+        keypair1024
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len() >
+            keypair512
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .len(),
         "1024-bit secret key should be larger than 512-bit secret key"
     );
 
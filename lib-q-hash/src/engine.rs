@@ -0,0 +1,263 @@
+//! Reusable, allocation-free hashing behind a shared reference
+//!
+//! [`create_hash`](crate::create_hash) boxes a fresh hasher and every [`Hash::hash`]
+//! returns a new `Vec`. [`HashEngine`] instead keeps one initialized state per algorithm and
+//! clones it on the stack for each call, writing the digest into a caller-provided buffer.
+//! The engine holds no interior mutability, so a single instance can be shared across
+//! threads.
+//!
+//! [`Hash::hash`]: lib_q_core::Hash::hash
+
+use digest::{
+    Digest,
+    ExtendableOutput,
+    Update,
+};
+use lib_q_core::{
+    Algorithm,
+    Error,
+    Result,
+};
+use sha2::{
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_224,
+    Sha512_256,
+};
+
+use crate::{
+    CShake128,
+    CShake256,
+    Keccak224,
+    Keccak256,
+    Keccak384,
+    Keccak512,
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Shake128,
+    Shake256,
+    TurboShake128,
+    TurboShake256,
+};
+
+/// Pre-initialized hash states for allocation-free hashing
+///
+/// Covers the SHA-3, Keccak, SHA-2, SHAKE, cSHAKE and TurboSHAKE families. KMAC, TupleHash,
+/// ParallelHash and KangarooTwelve buffer their input on the heap and are only available
+/// through [`create_hash`](crate::create_hash).
+#[derive(Clone, Debug, Default)]
+pub struct HashEngine {
+    sha3_224: Sha3_224,
+    sha3_256: Sha3_256,
+    sha3_384: Sha3_384,
+    sha3_512: Sha3_512,
+    keccak224: Keccak224,
+    keccak256: Keccak256,
+    keccak384: Keccak384,
+    keccak512: Keccak512,
+    sha224: Sha224,
+    sha256: Sha256,
+    sha384: Sha384,
+    sha512: Sha512,
+    sha512_224: Sha512_224,
+    sha512_256: Sha512_256,
+    shake128: Shake128,
+    shake256: Shake256,
+    cshake128: CShake128,
+    cshake256: CShake256,
+    turbo_shake128: TurboShake128<0x1F>,
+    turbo_shake256: TurboShake256<0x1F>,
+}
+
+impl HashEngine {
+    /// Create an engine with every state in its initial configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `algorithm` is one of the engine's XOFs, whose output length is up to the caller
+    pub fn is_xof(algorithm: Algorithm) -> bool {
+        matches!(
+            algorithm,
+            Algorithm::Shake128 |
+                Algorithm::Shake256 |
+                Algorithm::CShake128 |
+                Algorithm::CShake256 |
+                Algorithm::TurboShake128 |
+                Algorithm::TurboShake256
+        )
+    }
+
+    /// Hash `data` with `algorithm` into `out` without allocating
+    ///
+    /// Fixed-output digests require `out` to be exactly the digest size. For the XOFs (SHAKE,
+    /// cSHAKE and TurboSHAKE) `out` may have any length and is filled completely; the
+    /// lengths used by [`create_hash`](crate::create_hash) produce the same bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if `algorithm` is not covered by the engine and
+    /// [`Error::InvalidMessageSize`] if `out` does not match a fixed digest size.
+    pub fn hash_into(&self, algorithm: Algorithm, data: &[u8], out: &mut [u8]) -> Result<()> {
+        match algorithm {
+            Algorithm::Sha3_224 => fixed_into(&self.sha3_224, data, out),
+            Algorithm::Sha3_256 => fixed_into(&self.sha3_256, data, out),
+            Algorithm::Sha3_384 => fixed_into(&self.sha3_384, data, out),
+            Algorithm::Sha3_512 => fixed_into(&self.sha3_512, data, out),
+            Algorithm::Keccak224 => fixed_into(&self.keccak224, data, out),
+            Algorithm::Keccak256 => fixed_into(&self.keccak256, data, out),
+            Algorithm::Keccak384 => fixed_into(&self.keccak384, data, out),
+            Algorithm::Keccak512 => fixed_into(&self.keccak512, data, out),
+            Algorithm::Sha224 => fixed_into(&self.sha224, data, out),
+            Algorithm::Sha256 => fixed_into(&self.sha256, data, out),
+            Algorithm::Sha384 => fixed_into(&self.sha384, data, out),
+            Algorithm::Sha512 => fixed_into(&self.sha512, data, out),
+            Algorithm::Sha512_224 => fixed_into(&self.sha512_224, data, out),
+            Algorithm::Sha512_256 => fixed_into(&self.sha512_256, data, out),
+            Algorithm::Shake128 => xof_into(&self.shake128, data, out),
+            Algorithm::Shake256 => xof_into(&self.shake256, data, out),
+            Algorithm::CShake128 => xof_into(&self.cshake128, data, out),
+            Algorithm::CShake256 => xof_into(&self.cshake256, data, out),
+            Algorithm::TurboShake128 => xof_into(&self.turbo_shake128, data, out),
+            Algorithm::TurboShake256 => xof_into(&self.turbo_shake256, data, out),
+            _ => Err(Error::InvalidAlgorithm {
+                algorithm: "HashEngine supports SHA-3, Keccak, SHA-2, SHAKE, cSHAKE and TurboSHAKE",
+            }),
+        }
+    }
+}
+
+fn fixed_into<D: Digest + Clone>(state: &D, data: &[u8], out: &mut [u8]) -> Result<()> {
+    let size = <D as Digest>::output_size();
+    if out.len() != size {
+        return Err(Error::InvalidMessageSize {
+            max: size,
+            actual: out.len(),
+        });
+    }
+    let mut hasher = state.clone();
+    Digest::update(&mut hasher, data);
+    out.copy_from_slice(&hasher.finalize());
+    Ok(())
+}
+
+fn xof_into<X: ExtendableOutput + Update + Clone>(
+    state: &X,
+    data: &[u8],
+    out: &mut [u8],
+) -> Result<()> {
+    let mut hasher = state.clone();
+    Update::update(&mut hasher, data);
+    hasher.finalize_xof_into(out);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm_to_hash_algorithm,
+        create_hash,
+    };
+
+    const COVERED: [Algorithm; 20] = [
+        Algorithm::Sha3_224,
+        Algorithm::Sha3_256,
+        Algorithm::Sha3_384,
+        Algorithm::Sha3_512,
+        Algorithm::Keccak224,
+        Algorithm::Keccak256,
+        Algorithm::Keccak384,
+        Algorithm::Keccak512,
+        Algorithm::Sha224,
+        Algorithm::Sha256,
+        Algorithm::Sha384,
+        Algorithm::Sha512,
+        Algorithm::Sha512_224,
+        Algorithm::Sha512_256,
+        Algorithm::Shake128,
+        Algorithm::Shake256,
+        Algorithm::CShake128,
+        Algorithm::CShake256,
+        Algorithm::TurboShake128,
+        Algorithm::TurboShake256,
+    ];
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn hash_into_matches_allocating_path() {
+        assert_send_sync::<HashEngine>();
+        let engine = HashEngine::new();
+        let mut out = [0u8; 64];
+        for algorithm in COVERED {
+            let reference = create_hash(algorithm_to_hash_algorithm(algorithm).unwrap()).unwrap();
+            for data in [&b""[..], b"abc", &[0x5A; 1000]] {
+                let out = &mut out[..reference.output_size()];
+                engine.hash_into(algorithm, data, out).unwrap();
+                assert_eq!(
+                    out,
+                    reference.hash(data).unwrap().as_slice(),
+                    "{algorithm:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hash_into_rejects_wrong_size_and_uncovered_algorithms() {
+        let engine = HashEngine::new();
+        let mut short = [0u8; 31];
+        assert!(matches!(
+            engine.hash_into(Algorithm::Sha3_256, b"abc", &mut short),
+            Err(Error::InvalidMessageSize {
+                max: 32,
+                actual: 31
+            })
+        ));
+        for algorithm in [Algorithm::Kmac128, Algorithm::Kt128, Algorithm::MlKem768] {
+            assert!(matches!(
+                engine.hash_into(algorithm, b"abc", &mut short),
+                Err(Error::InvalidAlgorithm { .. })
+            ));
+        }
+
+        assert!(HashEngine::is_xof(Algorithm::Shake256));
+        assert!(!HashEngine::is_xof(Algorithm::Sha3_256));
+
+        // XOF output length is up to the caller; a prefix of a longer read is consistent.
+        let mut long = [0u8; 200];
+        engine
+            .hash_into(Algorithm::Shake256, b"abc", &mut long)
+            .unwrap();
+        engine
+            .hash_into(Algorithm::Shake256, b"abc", &mut short)
+            .unwrap();
+        assert_eq!(short, long[..31]);
+    }
+
+    /// Many small messages through one shared engine and one output buffer, checked
+    /// against the boxed hasher at the end.
+    #[test]
+    fn hash_into_many_small_messages_reuses_state() {
+        let engine = HashEngine::new();
+        let reference = create_hash(crate::HashAlgorithm::Sha3_256).unwrap();
+        let mut message = [0u8; 16];
+        let mut out = [0u8; 32];
+        for i in 0u32..20_000 {
+            message[..4].copy_from_slice(&i.to_le_bytes());
+            engine
+                .hash_into(Algorithm::Sha3_256, &message, &mut out)
+                .unwrap();
+            message[4..].copy_from_slice(&out[..12]);
+        }
+        engine
+            .hash_into(Algorithm::Sha3_256, &message, &mut out)
+            .unwrap();
+        assert_eq!(out.as_slice(), reference.hash(&message).unwrap().as_slice());
+    }
+}
@@ -11,8 +11,10 @@ use digest::{
     XofReader,
 };
 use lib_q_core::{
+    Algorithm,
     Error,
     Hash,
+    Primitive,
     Result,
 };
 
@@ -281,6 +283,43 @@ impl TurboShake256Hash {
     }
 }
 
+macro_rules! impl_primitive {
+    ($($name:ident => $algorithm:ident),* $(,)?) => {
+        $(
+            impl Primitive for $name {
+                fn algorithm(&self) -> Algorithm {
+                    Algorithm::$algorithm
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive! {
+    CShake128Hash => CShake128,
+    CShake256Hash => CShake256,
+    Shake128Hash => Shake128,
+    Shake256Hash => Shake256,
+    Sha3_224Hash => Sha3_224,
+    Sha3_256Hash => Sha3_256,
+    Sha3_384Hash => Sha3_384,
+    Sha3_512Hash => Sha3_512,
+    Kt128Hash => Kt128,
+    Kt256Hash => Kt256,
+    Keccak224Hash => Keccak224,
+    Keccak256Hash => Keccak256,
+    Keccak384Hash => Keccak384,
+    Keccak512Hash => Keccak512,
+    Kmac128Hash => Kmac128,
+    Kmac256Hash => Kmac256,
+    TupleHash128Hash => TupleHash128,
+    TupleHash256Hash => TupleHash256,
+    ParallelHash128Hash => ParallelHash128,
+    ParallelHash256Hash => ParallelHash256,
+    TurboShake128Hash => TurboShake128,
+    TurboShake256Hash => TurboShake256,
+}
+
 // Implement lib_q_core::Hash trait for cSHAKE types
 impl Hash for CShake128Hash {
     fn hash(&self, data: &[u8]) -> Result<Vec<u8>> {
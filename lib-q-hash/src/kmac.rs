@@ -49,6 +49,11 @@ use digest::{
     Update,
     XofReader,
 };
+use lib_q_core::{
+    Algorithm,
+    Error,
+    Utils,
+};
 use subtle::{
     Choice,
     ConstantTimeEq,
@@ -342,6 +347,67 @@ impl SerializableState for Kmac256 {
     }
 }
 
+/// Tag length produced and checked by [`kmac_verify`] for KMAC128
+pub const KMAC128_TAG_SIZE: usize = 16;
+
+/// Tag length produced and checked by [`kmac_verify`] for KMAC256
+pub const KMAC256_TAG_SIZE: usize = 32;
+
+/// Compute the KMAC tag of `data` and compare it to `expected_tag` in constant time
+///
+/// `algorithm` selects KMAC128 (16-byte tag) or KMAC256 (32-byte tag), matching the output of
+/// [`Kmac128Hash`](crate::Kmac128Hash) / [`Kmac256Hash`](crate::Kmac256Hash). A tag of any other
+/// length is rejected rather than compared, so a truncated tag never verifies.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAlgorithm`] if `algorithm` is not KMAC, [`Error::InvalidKey`] for an
+/// empty key, and [`Error::InvalidTagSize`] if `expected_tag` has the wrong length.
+pub fn kmac_verify(
+    algorithm: Algorithm,
+    key: &[u8],
+    data: &[u8],
+    customization: &[u8],
+    expected_tag: &[u8],
+) -> lib_q_core::Result<bool> {
+    let tag_size = match algorithm {
+        Algorithm::Kmac128 => KMAC128_TAG_SIZE,
+        Algorithm::Kmac256 => KMAC256_TAG_SIZE,
+        _ => {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not KMAC",
+            });
+        }
+    };
+    if key.is_empty() {
+        return Err(Error::InvalidKey {
+            key_type: "KMAC key".into(),
+            reason: "must not be empty".into(),
+        });
+    }
+    if expected_tag.len() != tag_size {
+        return Err(Error::InvalidTagSize {
+            expected: tag_size,
+            actual: expected_tag.len(),
+        });
+    }
+
+    let mut tag = [0u8; KMAC256_TAG_SIZE];
+    let tag = &mut tag[..tag_size];
+    let finalized = if algorithm == Algorithm::Kmac128 {
+        let mut kmac = Kmac128::new(key, customization);
+        kmac.update(data);
+        kmac.finalize(tag)
+    } else {
+        let mut kmac = Kmac256::new(key, customization);
+        kmac.update(data);
+        kmac.finalize(tag)
+    };
+    let matches = finalized.is_some() && Utils::constant_time_compare(tag, expected_tag);
+    tag.zeroize();
+    Ok(matches)
+}
+
 #[cfg(test)]
 mod tests {
     use hex_literal::hex;
@@ -768,4 +834,67 @@ mod tests {
         let mut out = vec![0u8; MAX_SP800185_FIXED_OUTPUT_BYTES + 1];
         assert!(kmac.finalize(&mut out).is_none());
     }
+
+    #[test]
+    fn test_kmac_verify_accepts_matching_tag() {
+        use lib_q_core::Hash;
+
+        let key = nist_kmac_key();
+        let data = nist_kmac_short_data();
+        for (algorithm, tag) in [
+            (
+                Algorithm::Kmac128,
+                crate::Kmac128Hash::new_with_key_and_custom(&key, b"tag").hash(&data),
+            ),
+            (
+                Algorithm::Kmac256,
+                crate::Kmac256Hash::new_with_key_and_custom(&key, b"tag").hash(&data),
+            ),
+        ] {
+            let tag = tag.unwrap();
+            assert_eq!(kmac_verify(algorithm, &key, &data, b"tag", &tag), Ok(true));
+            // Same tag under a different customization string must not verify.
+            assert_eq!(
+                kmac_verify(algorithm, &key, &data, b"other", &tag),
+                Ok(false)
+            );
+        }
+    }
+
+    #[test]
+    fn test_kmac_verify_rejects_flipped_bit() {
+        let key = nist_kmac_key();
+        let data = nist_kmac_long_data();
+        let mut tag = [0u8; KMAC256_TAG_SIZE];
+        let mut kmac = Kmac256::new(&key, b"");
+        kmac.update(&data);
+        kmac.finalize(&mut tag).unwrap();
+
+        tag[KMAC256_TAG_SIZE - 1] ^= 0x01;
+        assert_eq!(
+            kmac_verify(Algorithm::Kmac256, &key, &data, b"", &tag),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_kmac_verify_rejects_bad_lengths_and_keys() {
+        let key = nist_kmac_key();
+        let tag = [0u8; KMAC128_TAG_SIZE];
+        assert_eq!(
+            kmac_verify(Algorithm::Kmac256, &key, b"data", b"", &tag),
+            Err(Error::InvalidTagSize {
+                expected: KMAC256_TAG_SIZE,
+                actual: KMAC128_TAG_SIZE,
+            })
+        );
+        assert!(matches!(
+            kmac_verify(Algorithm::Kmac128, &[], b"data", b"", &tag),
+            Err(Error::InvalidKey { .. })
+        ));
+        assert!(matches!(
+            kmac_verify(Algorithm::Sha3_256, &key, b"data", b"", &tag),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+    }
 }
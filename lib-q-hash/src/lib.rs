@@ -12,6 +12,8 @@ compile_error!(
 );
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use alloc::boxed::Box;
 use alloc::vec::Vec;
@@ -60,6 +62,7 @@ pub use lib_q_sha3::{
 
 // Internal modules
 mod cshake;
+mod engine;
 mod hash_types;
 mod internal_block_api;
 mod kmac;
@@ -68,9 +71,13 @@ mod parallelhash;
 mod provider;
 mod sha2_hashes;
 mod shake;
+#[cfg(feature = "alloc")]
+mod streaming;
 mod tuplehash;
 mod turbo_shake;
 mod utils;
+#[cfg(feature = "alloc")]
+mod xof;
 
 // Re-export internal implementations
 pub use cshake::{
@@ -79,12 +86,16 @@ pub use cshake::{
     CShake256,
     CShake256Reader,
 };
+pub use engine::HashEngine;
 // Re-export SP800-185 implementations
 pub use kmac::{
+    KMAC128_TAG_SIZE,
+    KMAC256_TAG_SIZE,
     Kmac128,
     Kmac128Reader,
     Kmac256,
     Kmac256Reader,
+    kmac_verify,
 };
 pub use parallelhash::{
     ParallelHash128,
@@ -109,6 +120,8 @@ pub use shake::{
     Shake256 as InternalShake256,
     Shake256Reader as InternalShake256Reader,
 };
+#[cfg(feature = "std")]
+pub use streaming::hash_reader;
 pub use tuplehash::{
     TupleHash128,
     TupleHash128Reader,
@@ -121,7 +134,14 @@ pub use turbo_shake::{
     TurboShake256,
     TurboShake256Reader,
 };
+#[cfg(feature = "alloc")]
+pub use turbo_shake::{
+    turbo_shake128,
+    turbo_shake256,
+};
 pub use utils::MAX_SP800185_FIXED_OUTPUT_BYTES;
+#[cfg(feature = "alloc")]
+pub use xof::XofReaderDyn;
 
 // Re-export hash types
 pub use crate::hash_types::{
@@ -388,6 +408,34 @@ pub fn create_hash_context() -> Result<HashContext> {
     Ok(HashContext::with_provider(alloc::boxed::Box::new(provider)))
 }
 
+/// SHAKE128 of `data`, squeezed into an `N`-byte array
+///
+/// Same output as reading `N` bytes from a [`Shake128`] reader, without a heap allocation,
+/// so it is usable in `no_std` builds. For a length only known at run time, use
+/// [`HashEngine::hash_into`].
+///
+/// ```
+/// let tag: [u8; 16] = lib_q_hash::shake128_array(b"message");
+/// ```
+pub fn shake128_array<const N: usize>(data: &[u8]) -> [u8; N] {
+    let mut hasher = Shake128::default();
+    hasher.update(data);
+    let mut out = [0u8; N];
+    hasher.finalize_xof_into(&mut out);
+    out
+}
+
+/// SHAKE256 of `data`, squeezed into an `N`-byte array
+///
+/// Allocation-free counterpart of a [`Shake256`] read; see [`shake128_array`].
+pub fn shake256_array<const N: usize>(data: &[u8]) -> [u8; N] {
+    let mut hasher = Shake256::default();
+    hasher.update(data);
+    let mut out = [0u8; N];
+    hasher.finalize_xof_into(&mut out);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -445,6 +493,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shake_arrays_match_vec_path() {
+        fn squeezed(algorithm: HashAlgorithm, data: &[u8], len: usize) -> Vec<u8> {
+            let mut out = alloc::vec![0u8; len];
+            algorithm.xof_reader(data, None).unwrap().read(&mut out);
+            out
+        }
+
+        let data = b"fixed-size squeeze";
+        assert_eq!(
+            shake128_array::<16>(data),
+            squeezed(HashAlgorithm::Shake128, data, 16)[..]
+        );
+        assert_eq!(
+            shake128_array::<32>(data),
+            squeezed(HashAlgorithm::Shake128, data, 32)[..]
+        );
+        assert_eq!(
+            shake128_array::<64>(data),
+            squeezed(HashAlgorithm::Shake128, data, 64)[..]
+        );
+        assert_eq!(
+            shake256_array::<16>(data),
+            squeezed(HashAlgorithm::Shake256, data, 16)[..]
+        );
+        assert_eq!(
+            shake256_array::<32>(data),
+            squeezed(HashAlgorithm::Shake256, data, 32)[..]
+        );
+        assert_eq!(
+            shake256_array::<64>(data),
+            squeezed(HashAlgorithm::Shake256, data, 64)[..]
+        );
+
+        // The default-length `Hash` outputs are the same stream.
+        assert_eq!(
+            shake128_array::<16>(data),
+            Shake128Hash::new().hash(data).unwrap()[..]
+        );
+        assert_eq!(
+            shake256_array::<32>(data),
+            Shake256Hash::new().hash(data).unwrap()[..]
+        );
+    }
+
     #[test]
     fn test_cshake_implementations() {
         // Test cSHAKE256
@@ -583,6 +676,194 @@ mod tests {
         assert_eq!(digest.len(), 32);
     }
 
+    #[test]
+    fn hash_context_streaming_matches_one_shot_for_10mb_input() {
+        let data: Vec<u8> = (0..10 * 1024 * 1024u32).map(|i| (i % 251) as u8).collect();
+        let mut ctx = create_hash_context().expect("context");
+        for algorithm in [Algorithm::Sha3_256, Algorithm::Shake256] {
+            for chunk in data.chunks(64 * 1024) {
+                ctx.update(algorithm, chunk).expect("update");
+            }
+            let streamed = ctx.finalize().expect("finalize");
+            let one_shot = ctx.hash(algorithm, &data).expect("one-shot hash");
+            assert_eq!(streamed, one_shot, "{algorithm:?}");
+        }
+    }
+
+    #[test]
+    fn hash_context_streaming_rejects_algorithm_switch() {
+        let mut ctx = create_hash_context().expect("context");
+        ctx.update(Algorithm::Sha3_256, b"first").unwrap();
+        assert!(matches!(
+            ctx.update(Algorithm::Sha3_512, b"second"),
+            Err(lib_q_core::Error::InvalidState { .. })
+        ));
+        // The original stream is untouched by the rejected call.
+        assert_eq!(ctx.streaming_algorithm(), Some(Algorithm::Sha3_256));
+        let digest = ctx.finalize().unwrap();
+        assert_eq!(digest, ctx.hash(Algorithm::Sha3_256, b"first").unwrap());
+        assert!(ctx.finalize().is_err(), "stream is consumed by finalize");
+    }
+
+    #[test]
+    fn hash_context_kangarootwelve_matches_lib_q_k12() {
+        fn direct<X: Update + ExtendableOutput>(mut hasher: X, data: &[u8], len: usize) -> Vec<u8> {
+            hasher.update(data);
+            let mut out = alloc::vec![0u8; len];
+            hasher.finalize_xof_into(&mut out);
+            out
+        }
+
+        let data = b"KangarooTwelve through HashContext";
+        let mut ctx = create_hash_context().expect("context");
+
+        assert_eq!(
+            ctx.hash(Algorithm::Kt128, data).unwrap(),
+            direct(lib_q_k12::Kt128::new(b""), data, 32)
+        );
+        assert_eq!(
+            ctx.hash(Algorithm::Kt256, data).unwrap(),
+            direct(lib_q_k12::Kt256::new(b""), data, 64)
+        );
+
+        let customized = ctx
+            .hash_with_customization(Algorithm::Kt128, data, b"app")
+            .unwrap();
+        assert_eq!(customized, direct(lib_q_k12::Kt128::new(b"app"), data, 32));
+        assert_ne!(customized, ctx.hash(Algorithm::Kt128, data).unwrap());
+        assert_eq!(
+            ctx.hash_with_customization(Algorithm::Kt256, data, b"app")
+                .unwrap(),
+            direct(lib_q_k12::Kt256::new(b"app"), data, 64)
+        );
+        assert_eq!(
+            ctx.hash_with_customization(Algorithm::Kt128, data, b"")
+                .unwrap(),
+            ctx.hash(Algorithm::Kt128, data).unwrap()
+        );
+    }
+
+    #[test]
+    fn hash_context_customization_rejected_for_fixed_hashes() {
+        let mut ctx = create_hash_context().expect("context");
+        assert!(matches!(
+            ctx.hash_with_customization(Algorithm::Sha3_256, b"data", b"app"),
+            Err(lib_q_core::Error::InvalidAlgorithm { .. })
+        ));
+    }
+
+    #[test]
+    fn hash_context_tuple_keeps_field_boundaries() {
+        let mut ctx = create_hash_context().expect("context");
+
+        for algorithm in [Algorithm::TupleHash128, Algorithm::TupleHash256] {
+            let split_late = ctx.hash_tuple(algorithm, &[b"ab", b"c"], b"").unwrap();
+            let split_early = ctx.hash_tuple(algorithm, &[b"a", b"bc"], b"").unwrap();
+            assert_ne!(split_late, split_early, "{algorithm:?}");
+            assert_ne!(
+                ctx.hash_tuple(algorithm, &[b"ab", b"c"], b"App1").unwrap(),
+                split_late
+            );
+        }
+
+        let mut reference = TupleHash256::new(b"App1");
+        reference.update_tuple(&[b"ab".as_slice(), b"c"]);
+        assert_eq!(
+            ctx.hash_tuple(Algorithm::TupleHash256, &[b"ab", b"c"], b"App1")
+                .unwrap(),
+            reference.finalize_with_length(32).unwrap()
+        );
+        assert!(matches!(
+            ctx.hash_tuple(Algorithm::Sha3_256, &[b"ab", b"c"], b""),
+            Err(lib_q_core::Error::InvalidAlgorithm { .. })
+        ));
+    }
+
+    #[test]
+    fn hash_context_parallel_block_size_is_part_of_the_domain() {
+        let mut ctx = create_hash_context().expect("context");
+        let data: Vec<u8> = (0u8..=255).cycle().take(3000).collect();
+
+        for algorithm in [Algorithm::ParallelHash128, Algorithm::ParallelHash256] {
+            let small = ctx.hash_parallel(algorithm, &data, 64, b"").unwrap();
+            let large = ctx.hash_parallel(algorithm, &data, 1024, b"").unwrap();
+            assert_ne!(small, large, "{algorithm:?}");
+            assert!(matches!(
+                ctx.hash_parallel(algorithm, &data, 0, b""),
+                Err(lib_q_core::Error::InvalidParameter {
+                    parameter: "block_size",
+                    ..
+                })
+            ));
+        }
+
+        // The default block size of `hash` is 8192 bytes.
+        assert_eq!(
+            ctx.hash_parallel(Algorithm::ParallelHash128, &data, 8192, b"")
+                .unwrap(),
+            ctx.hash(Algorithm::ParallelHash128, &data).unwrap()
+        );
+        assert_eq!(
+            ctx.hash_parallel(Algorithm::ParallelHash256, &data, 1024, b"App1")
+                .unwrap(),
+            ParallelHash256Hash::new_with_custom_and_block_size(b"App1", 1024)
+                .hash(&data)
+                .unwrap()
+        );
+        assert!(matches!(
+            ctx.hash_parallel(Algorithm::TupleHash128, &data, 64, b""),
+            Err(lib_q_core::Error::InvalidAlgorithm { .. })
+        ));
+    }
+
+    #[test]
+    fn hash_context_customized_routes_cshake_and_kmac() {
+        let mut ctx = create_hash_context().expect("context");
+        let data = b"customized input";
+
+        for algorithm in [Algorithm::CShake128, Algorithm::CShake256] {
+            let a = ctx.hash_customized(algorithm, data, b"App1", None).unwrap();
+            let b = ctx.hash_customized(algorithm, data, b"App2", None).unwrap();
+            assert_ne!(a, b, "{algorithm:?}");
+            assert_eq!(
+                ctx.hash_customized(algorithm, data, b"", None).unwrap(),
+                ctx.hash(algorithm, data).unwrap()
+            );
+        }
+        assert_eq!(
+            ctx.hash_customized(Algorithm::CShake256, data, b"App1", None)
+                .unwrap(),
+            CShake256Hash::new_customized(b"App1").hash(data).unwrap()
+        );
+
+        for algorithm in [Algorithm::Kmac128, Algorithm::Kmac256] {
+            let key = [0x4Bu8; 32];
+            let a = ctx
+                .hash_customized(algorithm, data, b"App1", Some(&key))
+                .unwrap();
+            let b = ctx
+                .hash_customized(algorithm, data, b"App2", Some(&key))
+                .unwrap();
+            assert_ne!(a, b, "{algorithm:?}");
+            assert!(matches!(
+                ctx.hash_customized(algorithm, data, b"App1", None),
+                Err(lib_q_core::Error::InvalidKey { .. })
+            ));
+        }
+        assert_eq!(
+            ctx.hash_customized(Algorithm::Kmac256, data, b"S", Some(b"key"))
+                .unwrap(),
+            Kmac256Hash::new_with_key_and_custom(b"key", b"S")
+                .hash(data)
+                .unwrap()
+        );
+
+        assert!(matches!(
+            ctx.hash_customized(Algorithm::CShake256, data, b"", Some(b"key")),
+            Err(lib_q_core::Error::InvalidAlgorithm { .. })
+        ));
+    }
+
     #[test]
     fn turboshake_hasher_alg_name_and_debug() {
         let hasher = TurboShake128::<6>::default();
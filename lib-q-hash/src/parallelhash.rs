@@ -113,10 +113,13 @@ macro_rules! impl_parallelhash {
             }
 
             /// Hash a single block using SHAKE
+            ///
+            /// SP 800-185 chains `2 * security strength` bits per block, which is the sponge
+            /// capacity: 32 bytes for ParallelHash128, 64 bytes for ParallelHash256.
             fn hash_block(block: &[u8], rate: usize) -> Vec<u8> {
                 let mut shake = $shake_type::default();
                 Update::update(&mut shake, block);
-                let mut output = vec![0u8; rate / 8];
+                let mut output = vec![0u8; 200 - rate];
                 ExtendableOutput::finalize_xof_into(shake, &mut output);
                 output
             }
@@ -463,6 +466,8 @@ impl SerializableState for ParallelHash256 {
 
 #[cfg(test)]
 mod tests {
+    use hex_literal::hex;
+
     use super::*;
 
     #[test]
@@ -505,6 +510,110 @@ mod tests {
         assert_ne!(output, [0u8; 100]);
     }
 
+    /// SP 800-185 sample input: 00..07 10..17 20..27
+    fn nist_parallelhash_data() -> Vec<u8> {
+        [0x00u8, 0x10, 0x20]
+            .iter()
+            .flat_map(|&row| (0..8).map(move |i| row + i))
+            .collect()
+    }
+
+    /// SP 800-185 sample input for samples #3 and #6: 00..0B 10..1B .. 50..5B
+    fn nist_parallelhash_long_data() -> Vec<u8> {
+        (0..6u8)
+            .flat_map(|row| (0..12).map(move |i| (row << 4) + i))
+            .collect()
+    }
+
+    #[test]
+    fn test_parallelhash_nist_vectors() {
+        let data = nist_parallelhash_data();
+
+        // ParallelHash128 sample #1: B = 8, L = 256, S = ""
+        let mut parallelhash = ParallelHash128::new(b"", 8);
+        parallelhash.update(&data);
+        let mut output = [0u8; 32];
+        parallelhash.finalize(&mut output).unwrap();
+        assert_eq!(
+            output,
+            hex!("BA8DC1D1D979331D3F813603C67F72609AB5E44B94A0B8F9AF46514454A2B4F5")
+        );
+
+        // ParallelHash128 sample #2: B = 8, L = 256, S = "Parallel Data"
+        let mut parallelhash = ParallelHash128::new(b"Parallel Data", 8);
+        parallelhash.update(&data);
+        let mut output = [0u8; 32];
+        parallelhash.finalize(&mut output).unwrap();
+        assert_eq!(
+            output,
+            hex!("FC484DCB3F84DCEEDC353438151BEE58157D6EFED0445A81F165E495795B7206")
+        );
+
+        // ParallelHash128 sample #3: B = 12, L = 256, S = "Parallel Data"
+        let mut parallelhash = ParallelHash128::new(b"Parallel Data", 12);
+        parallelhash.update(&nist_parallelhash_long_data());
+        let mut output = [0u8; 32];
+        parallelhash.finalize(&mut output).unwrap();
+        assert_eq!(
+            output,
+            hex!("F7FD5312896C6685C828AF7E2ADB97E393E7F8D54E3C2EA4B95E5ACA3796E8FC")
+        );
+
+        // ParallelHash256 sample #4: B = 8, L = 512, S = ""
+        let mut parallelhash = ParallelHash256::new(b"", 8);
+        parallelhash.update(&data);
+        let mut output = [0u8; 64];
+        parallelhash.finalize(&mut output).unwrap();
+        assert_eq!(
+            output,
+            hex!(
+                "BC1EF124DA34495E948EAD207DD9842235DA432D2BBC54B4C110E64C45110553"
+                "1B7F2A3E0CE055C02805E7C2DE1FB746AF97A1DD01F43B824E31B87612410429"
+            )
+        );
+
+        // ParallelHash256 sample #5: B = 8, L = 512, S = "Parallel Data"
+        let mut parallelhash = ParallelHash256::new(b"Parallel Data", 8);
+        parallelhash.update(&data);
+        let mut output = [0u8; 64];
+        parallelhash.finalize(&mut output).unwrap();
+        assert_eq!(
+            output,
+            hex!(
+                "CDF15289B54F6212B4BC270528B49526006DD9B54E2B6ADD1EF6900DDA3963BB"
+                "33A72491F236969CA8AFAEA29C682D47A393C065B38E29FAE651A2091C833110"
+            )
+        );
+
+        // ParallelHash256 sample #6: B = 12, L = 512, S = "Parallel Data"
+        let mut parallelhash = ParallelHash256::new(b"Parallel Data", 12);
+        parallelhash.update(&nist_parallelhash_long_data());
+        let mut output = [0u8; 64];
+        parallelhash.finalize(&mut output).unwrap();
+        assert_eq!(
+            output,
+            hex!(
+                "69D0FCB764EA055DD09334BC6021CB7E4B61348DFF375DA262671CDEC3EFFA8D"
+                "1B4568A6CCE16B1CAD946DDDE27F6CE2B8DEE4CD1B24851EBF00EB90D43813E9"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parallelhash_split_updates_match_one_shot() {
+        let data = nist_parallelhash_data();
+        let mut one_shot = ParallelHash128::new(b"", 5);
+        one_shot.update(&data);
+        let mut split = ParallelHash128::new(b"", 5);
+        split.update(&data[..3]);
+        split.update(&data[3..17]);
+        split.update(&data[17..]);
+        assert_eq!(
+            one_shot.finalize_with_length(32),
+            split.finalize_with_length(32)
+        );
+    }
+
     #[test]
     fn test_parallelhash_different_block_sizes() {
         let custom = b"custom";
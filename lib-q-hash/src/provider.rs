@@ -8,8 +8,10 @@
 extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::{
+    boxed::Box,
     format,
     string::ToString,
+    vec,
     vec::Vec,
 };
 
@@ -17,6 +19,7 @@ use lib_q_core::api::{
     Algorithm,
     CryptoProvider,
     HashOperations,
+    HashState,
 };
 use lib_q_core::error::{
     Error,
@@ -25,6 +28,19 @@ use lib_q_core::error::{
 use lib_q_core::security::SecurityValidator;
 
 use crate::{
+    CShake128Hash,
+    CShake256Hash,
+    ExtendableOutput,
+    Hash,
+    Kmac128Hash,
+    Kmac256Hash,
+    Kt128,
+    Kt256,
+    ParallelHash128Hash,
+    ParallelHash256Hash,
+    TupleHash128,
+    TupleHash256,
+    Update,
     algorithm_to_hash_algorithm,
     create_hash,
 };
@@ -100,6 +116,148 @@ impl HashOperations for LibQHashProvider {
             ),
         })
     }
+
+    fn hash_state(&self, algorithm: Algorithm) -> Result<Box<dyn HashState>> {
+        self.security_validator
+            .validate_algorithm_category(algorithm, lib_q_core::api::AlgorithmCategory::Hash)?;
+
+        crate::streaming::hash_state(algorithm)
+    }
+
+    fn hash_with_customization(
+        &self,
+        algorithm: Algorithm,
+        data: &[u8],
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.hash_customized(algorithm, data, customization, None)
+    }
+
+    fn hash_customized(
+        &self,
+        algorithm: Algorithm,
+        data: &[u8],
+        customization: &[u8],
+        key: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        self.security_validator
+            .validate_algorithm_category(algorithm, lib_q_core::api::AlgorithmCategory::Hash)?;
+        self.security_validator.validate_hash_input(data)?;
+
+        match (algorithm, key) {
+            (Algorithm::Kmac128, Some(key)) => Hash::hash(
+                &Kmac128Hash::new_with_key_and_custom(key, customization),
+                data,
+            ),
+            (Algorithm::Kmac256, Some(key)) => Hash::hash(
+                &Kmac256Hash::new_with_key_and_custom(key, customization),
+                data,
+            ),
+            (Algorithm::Kmac128 | Algorithm::Kmac256, None) => Err(Error::InvalidKey {
+                key_type: "KMAC".to_string(),
+                reason: "KMAC requires a key".to_string(),
+            }),
+            (_, Some(_)) => Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm does not accept a key",
+            }),
+            (Algorithm::CShake128, None) => {
+                Hash::hash(&CShake128Hash::new_customized(customization), data)
+            }
+            (Algorithm::CShake256, None) => {
+                Hash::hash(&CShake256Hash::new_customized(customization), data)
+            }
+            (Algorithm::Kt128, None) => Ok(kt_hash(Kt128::new(customization), data, 32)),
+            (Algorithm::Kt256, None) => Ok(kt_hash(Kt256::new(customization), data, 64)),
+            (_, None) if customization.is_empty() => HashOperations::hash(self, algorithm, data),
+            (_, None) => Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm does not accept a customization string",
+            }),
+        }
+    }
+
+    fn hash_tuple(
+        &self,
+        algorithm: Algorithm,
+        fields: &[&[u8]],
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.security_validator
+            .validate_algorithm_category(algorithm, lib_q_core::api::AlgorithmCategory::Hash)?;
+        // The size policy applies to the fields together, as if they were one message.
+        let total = fields
+            .iter()
+            .try_fold(0usize, |total, field| total.checked_add(field.len()))
+            .unwrap_or(usize::MAX);
+        let max = self
+            .security_validator
+            .security_constants()
+            .max_hash_message_size();
+        if total > max {
+            return Err(Error::InvalidMessageSize { max, actual: total });
+        }
+
+        let output = match algorithm {
+            Algorithm::TupleHash128 => {
+                let mut hasher = TupleHash128::new(customization);
+                hasher.update_tuple(fields);
+                hasher.finalize_with_length(16)
+            }
+            Algorithm::TupleHash256 => {
+                let mut hasher = TupleHash256::new(customization);
+                hasher.update_tuple(fields);
+                hasher.finalize_with_length(32)
+            }
+            _ => {
+                return Err(Error::InvalidAlgorithm {
+                    algorithm: "Algorithm is not TupleHash",
+                });
+            }
+        };
+        output.ok_or_else(|| Error::InternalError {
+            operation: "tuple hash".to_string(),
+            details: "TupleHash output length exceeds the fixed-output cap".to_string(),
+        })
+    }
+
+    fn hash_parallel(
+        &self,
+        algorithm: Algorithm,
+        data: &[u8],
+        block_size: usize,
+        customization: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.security_validator
+            .validate_algorithm_category(algorithm, lib_q_core::api::AlgorithmCategory::Hash)?;
+        self.security_validator.validate_hash_input(data)?;
+        if block_size == 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "block_size",
+                reason: "ParallelHash block size must be greater than zero",
+            });
+        }
+
+        match algorithm {
+            Algorithm::ParallelHash128 => Hash::hash(
+                &ParallelHash128Hash::new_with_custom_and_block_size(customization, block_size),
+                data,
+            ),
+            Algorithm::ParallelHash256 => Hash::hash(
+                &ParallelHash256Hash::new_with_custom_and_block_size(customization, block_size),
+                data,
+            ),
+            _ => Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not ParallelHash",
+            }),
+        }
+    }
+}
+
+/// KangarooTwelve over `data` with the same output length as the one-shot wrappers
+fn kt_hash<X: Update + ExtendableOutput>(mut hasher: X, data: &[u8], output_len: usize) -> Vec<u8> {
+    hasher.update(data);
+    let mut output = vec![0u8; output_len];
+    hasher.finalize_xof_into(&mut output);
+    output
 }
 
 #[cfg(feature = "alloc")]
@@ -7,7 +7,9 @@ use alloc::vec::Vec;
 
 use digest::Digest;
 use lib_q_core::{
+    Algorithm,
     Hash,
+    Primitive,
     Result,
 };
 use sha2::{
@@ -20,7 +22,7 @@ use sha2::{
 };
 
 macro_rules! impl_sha2_fixed_output {
-    ($name:ident, $state:ty, $out_len:expr) => {
+    ($name:ident, $state:ty, $algorithm:ident, $out_len:expr) => {
         /// Fixed-output SHA-2 wrapper for the lib-q [`Hash`] trait.
         #[derive(Clone, Debug)]
         pub struct $name($state);
@@ -49,12 +51,18 @@ macro_rules! impl_sha2_fixed_output {
                 $out_len
             }
         }
+
+        impl Primitive for $name {
+            fn algorithm(&self) -> Algorithm {
+                Algorithm::$algorithm
+            }
+        }
     };
 }
 
-impl_sha2_fixed_output!(Sha224Hash, Sha224, 28);
-impl_sha2_fixed_output!(Sha256Hash, Sha256, 32);
-impl_sha2_fixed_output!(Sha384Hash, Sha384, 48);
-impl_sha2_fixed_output!(Sha512Hash, Sha512, 64);
-impl_sha2_fixed_output!(Sha512_224Hash, Sha512_224, 28);
-impl_sha2_fixed_output!(Sha512_256Hash, Sha512_256, 32);
+impl_sha2_fixed_output!(Sha224Hash, Sha224, Sha224, 28);
+impl_sha2_fixed_output!(Sha256Hash, Sha256, Sha256, 32);
+impl_sha2_fixed_output!(Sha384Hash, Sha384, Sha384, 48);
+impl_sha2_fixed_output!(Sha512Hash, Sha512, Sha512, 64);
+impl_sha2_fixed_output!(Sha512_224Hash, Sha512_224, Sha512_224, 28);
+impl_sha2_fixed_output!(Sha512_256Hash, Sha512_256, Sha512_256, 32);
@@ -154,3 +154,95 @@ impl CollisionResistance for Shake256 {
     // SHAKE256 provides 256-bit collision resistance
     type CollisionResistance = U136;
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use hex_literal::hex;
+
+    use super::*;
+
+    /// xorshift64 stream; deterministic so a failure is reproducible
+    fn pseudo_random_bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    /// Absorb `data` in `chunk`-sized updates and squeeze `out_len` bytes in odd-sized reads
+    fn squeeze<H: Default + Update + ExtendableOutput>(
+        data: &[u8],
+        chunk: usize,
+        out_len: usize,
+    ) -> Vec<u8> {
+        let mut hasher = H::default();
+        for part in data.chunks(chunk.max(1)) {
+            hasher.update(part);
+        }
+        let mut reader = hasher.finalize_xof();
+        let mut out = vec![0u8; out_len];
+        for part in out.chunks_mut(97) {
+            reader.read(part);
+        }
+        out
+    }
+
+    #[test]
+    fn test_internal_shake_matches_fips_202_kats() {
+        assert_eq!(
+            squeeze::<Shake128>(b"", 1, 32),
+            hex!("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26")
+        );
+        assert_eq!(
+            squeeze::<Shake128>(b"abc", 1, 32),
+            hex!("5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8")
+        );
+        assert_eq!(
+            squeeze::<Shake256>(b"", 1, 64),
+            hex!(
+                "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f"
+                "d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+            )
+        );
+        assert_eq!(
+            squeeze::<Shake256>(b"abc", 1, 64),
+            hex!(
+                "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739"
+                "d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4"
+            )
+        );
+    }
+
+    /// The internal sponge must agree byte-for-byte with `lib-q-sha3`, which backs the
+    /// re-exported `Shake128`/`Shake256`
+    #[test]
+    fn test_internal_shake_matches_lib_q_sha3() {
+        // Lengths around the SHAKE128 (168) and SHAKE256 (136) rates and their multiples
+        let lengths = [
+            0, 1, 135, 136, 137, 167, 168, 169, 271, 272, 336, 337, 1000, 4096,
+        ];
+        for (seed, &len) in (1u64..).zip(&lengths) {
+            let data = pseudo_random_bytes(seed, len);
+            for chunk in [1, 7, 136, 168, len] {
+                assert_eq!(
+                    squeeze::<Shake128>(&data, chunk, 500),
+                    squeeze::<lib_q_sha3::Shake128>(&data, len, 500),
+                    "SHAKE128: input {len} bytes in {chunk}-byte updates"
+                );
+                assert_eq!(
+                    squeeze::<Shake256>(&data, chunk, 500),
+                    squeeze::<lib_q_sha3::Shake256>(&data, len, 500),
+                    "SHAKE256: input {len} bytes in {chunk}-byte updates"
+                );
+            }
+        }
+    }
+}
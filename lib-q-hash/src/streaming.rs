@@ -0,0 +1,258 @@
+//! Incremental hash states backing [`HashContext::update`](lib_q_core::HashContext::update)
+//!
+//! Each state wraps the same hasher used by the one-shot wrappers in `hash_types` and
+//! `sha2_hashes`, so a streamed digest is identical to [`create_hash`](crate::create_hash)
+//! over the concatenated input.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use alloc::{
+    format,
+    vec,
+};
+
+use digest::{
+    Digest,
+    ExtendableOutput,
+    Update,
+};
+use lib_q_core::api::HashState;
+use lib_q_core::{
+    Algorithm,
+    Error,
+    Result,
+};
+
+use crate::{
+    CShake128,
+    CShake256,
+    Keccak224,
+    Keccak256,
+    Keccak384,
+    Keccak512,
+    Kt128,
+    Kt256,
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Shake128,
+    Shake256,
+    TurboShake128,
+    TurboShake256,
+};
+
+/// Fixed-output streaming state
+struct DigestState<D>(D);
+
+impl<D: Digest + Send> HashState for DigestState<D> {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().to_vec()
+    }
+}
+
+/// XOF streaming state producing the same default output length as the one-shot wrapper
+struct XofState<X> {
+    hasher: X,
+    output_len: usize,
+}
+
+impl<X: Update + ExtendableOutput + Send> HashState for XofState<X> {
+    fn update(&mut self, data: &[u8]) {
+        Update::update(&mut self.hasher, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        let mut output = vec![0u8; self.output_len];
+        self.hasher.finalize_xof_into(&mut output);
+        output
+    }
+}
+
+fn xof<X: Update + ExtendableOutput + Send + 'static>(
+    hasher: X,
+    output_len: usize,
+) -> Box<dyn HashState> {
+    Box::new(XofState { hasher, output_len })
+}
+
+/// Create a streaming state for `algorithm`
+///
+/// SP 800-185 keyed/tuple/parallel constructions (KMAC, TupleHash, ParallelHash) have no
+/// meaningful chunked form through this interface and return [`Error::NotImplemented`].
+pub(crate) fn hash_state(algorithm: Algorithm) -> Result<Box<dyn HashState>> {
+    let state: Box<dyn HashState> = match algorithm {
+        Algorithm::Sha3_224 => Box::new(DigestState(Sha3_224::default())),
+        Algorithm::Sha3_256 => Box::new(DigestState(Sha3_256::default())),
+        Algorithm::Sha3_384 => Box::new(DigestState(Sha3_384::default())),
+        Algorithm::Sha3_512 => Box::new(DigestState(Sha3_512::default())),
+        Algorithm::Keccak224 => Box::new(DigestState(Keccak224::default())),
+        Algorithm::Keccak256 => Box::new(DigestState(Keccak256::default())),
+        Algorithm::Keccak384 => Box::new(DigestState(Keccak384::default())),
+        Algorithm::Keccak512 => Box::new(DigestState(Keccak512::default())),
+        Algorithm::Sha224 => Box::new(DigestState(sha2::Sha224::default())),
+        Algorithm::Sha256 => Box::new(DigestState(sha2::Sha256::default())),
+        Algorithm::Sha384 => Box::new(DigestState(sha2::Sha384::default())),
+        Algorithm::Sha512 => Box::new(DigestState(sha2::Sha512::default())),
+        Algorithm::Sha512_224 => Box::new(DigestState(sha2::Sha512_224::default())),
+        Algorithm::Sha512_256 => Box::new(DigestState(sha2::Sha512_256::default())),
+        Algorithm::Shake128 => xof(Shake128::default(), 16),
+        Algorithm::Shake256 => xof(Shake256::default(), 32),
+        Algorithm::CShake128 => xof(CShake128::default(), 16),
+        Algorithm::CShake256 => xof(CShake256::default(), 32),
+        Algorithm::TurboShake128 => xof(TurboShake128::<0x1F>::default(), 16),
+        Algorithm::TurboShake256 => xof(TurboShake256::<0x1F>::default(), 32),
+        Algorithm::Kt128 => xof(Kt128::new(b""), 32),
+        Algorithm::Kt256 => xof(Kt256::new(b""), 64),
+        Algorithm::Kmac128 |
+        Algorithm::Kmac256 |
+        Algorithm::TupleHash128 |
+        Algorithm::TupleHash256 |
+        Algorithm::ParallelHash128 |
+        Algorithm::ParallelHash256 => {
+            return Err(Error::NotImplemented {
+                feature: format!("Incremental hashing for {algorithm:?}"),
+            });
+        }
+        _ => {
+            return Err(Error::InvalidAlgorithm {
+                algorithm: "Algorithm is not a hash algorithm",
+            });
+        }
+    };
+    Ok(state)
+}
+
+/// Block size used by [`hash_reader`]
+#[cfg(feature = "std")]
+const READ_BLOCK_SIZE: usize = 64 * 1024;
+
+/// Hash everything read from `reader` with the algorithm named `algorithm`
+///
+/// `algorithm` is one of the names from [`available_algorithms`](crate::available_algorithms).
+/// The input is read in 64 KiB blocks and fed to the same incremental state as
+/// [`HashContext::update`](lib_q_core::HashContext::update), so memory use stays bounded for
+/// arbitrarily large files and the digest equals the one-shot hash of the whole input.
+///
+/// ```
+/// let digest =
+///     lib_q_hash::hash_reader("sha3-256", &mut std::io::Cursor::new(b"abc"))?;
+/// assert_eq!(digest.len(), 32);
+/// # Ok::<(), lib_q_core::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::UnsupportedAlgorithm`] for an unknown name, [`Error::NotImplemented`] for
+/// the keyed SP 800-185 constructions (see [`hash_state`]) and [`Error::Io`] if reading fails.
+/// Reads interrupted by a signal are retried.
+#[cfg(feature = "std")]
+pub fn hash_reader<R: std::io::Read>(algorithm: &str, reader: &mut R) -> Result<Vec<u8>> {
+    let algorithm = algorithm_from_name(algorithm).ok_or_else(|| Error::UnsupportedAlgorithm {
+        algorithm: format!("Unknown hash algorithm name {algorithm:?}"),
+    })?;
+    let mut state = hash_state(algorithm)?;
+
+    let mut block = vec![0u8; READ_BLOCK_SIZE];
+    loop {
+        let read = match reader.read(&mut block) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error.into()),
+        };
+        state.update(&block[..read]);
+    }
+    Ok(state.finalize())
+}
+
+/// Inverse of the names listed by [`available_algorithms`](crate::available_algorithms)
+#[cfg(feature = "std")]
+fn algorithm_from_name(name: &str) -> Option<Algorithm> {
+    let algorithm = match name {
+        "sha3-224" => Algorithm::Sha3_224,
+        "sha3-256" => Algorithm::Sha3_256,
+        "sha3-384" => Algorithm::Sha3_384,
+        "sha3-512" => Algorithm::Sha3_512,
+        "shake128" => Algorithm::Shake128,
+        "shake256" => Algorithm::Shake256,
+        "cshake128" => Algorithm::CShake128,
+        "cshake256" => Algorithm::CShake256,
+        "kt128" | "kangarootwelve" => Algorithm::Kt128,
+        "kt256" => Algorithm::Kt256,
+        "keccak224" => Algorithm::Keccak224,
+        "keccak256" => Algorithm::Keccak256,
+        "keccak384" => Algorithm::Keccak384,
+        "keccak512" => Algorithm::Keccak512,
+        "turboshake128" => Algorithm::TurboShake128,
+        "turboshake256" => Algorithm::TurboShake256,
+        "kmac128" => Algorithm::Kmac128,
+        "kmac256" => Algorithm::Kmac256,
+        "tuplehash128" => Algorithm::TupleHash128,
+        "tuplehash256" => Algorithm::TupleHash256,
+        "parallelhash128" => Algorithm::ParallelHash128,
+        "parallelhash256" => Algorithm::ParallelHash256,
+        "sha-224" => Algorithm::Sha224,
+        "sha-256" => Algorithm::Sha256,
+        "sha-384" => Algorithm::Sha384,
+        "sha-512" => Algorithm::Sha512,
+        "sha-512/224" => Algorithm::Sha512_224,
+        "sha-512/256" => Algorithm::Sha512_256,
+        _ => return None,
+    };
+    Some(algorithm)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm_to_hash_algorithm,
+        available_algorithms,
+        create_hash,
+    };
+
+    #[test]
+    fn hash_reader_matches_one_shot_hash() {
+        // Several full blocks plus a partial one
+        let data: Vec<u8> = (0..3 * READ_BLOCK_SIZE + 1234)
+            .map(|i| (i % 251) as u8)
+            .collect();
+
+        for name in ["sha3-256", "sha3-512", "shake256", "sha-256"] {
+            let algorithm = algorithm_from_name(name).unwrap();
+            let expected = create_hash(algorithm_to_hash_algorithm(algorithm).unwrap())
+                .unwrap()
+                .hash(&data)
+                .unwrap();
+            let streamed = hash_reader(name, &mut std::io::Cursor::new(&data)).unwrap();
+            assert_eq!(streamed, expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn hash_reader_names_and_errors() {
+        for name in available_algorithms() {
+            assert!(algorithm_from_name(name).is_some(), "{name}");
+        }
+        assert!(matches!(
+            hash_reader("md5", &mut std::io::empty()),
+            Err(Error::UnsupportedAlgorithm { .. })
+        ));
+
+        struct Failing;
+        impl std::io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disk on fire"))
+            }
+        }
+        assert!(matches!(
+            hash_reader("sha3-256", &mut Failing),
+            Err(Error::Io { .. })
+        ));
+    }
+}
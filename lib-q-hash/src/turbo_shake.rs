@@ -1,3 +1,7 @@
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::fmt;
 
 use digest::block_api::{
@@ -27,11 +31,23 @@ use digest::{
     Update,
     XofReader,
 };
+#[cfg(feature = "alloc")]
+use lib_q_core::{
+    Error,
+    Result,
+};
 
+#[cfg(feature = "alloc")]
+use crate::internal_block_api::xor_block;
 use crate::internal_block_api::{
     Sha3HasherCore,
     Sha3ReaderCore,
 };
+#[cfg(feature = "alloc")]
+use crate::{
+    MAX_SP800185_FIXED_OUTPUT_BYTES,
+    PLEN,
+};
 
 const TURBO_SHAKE_ROUND_COUNT: usize = 12;
 
@@ -155,3 +171,166 @@ impl<const DS: u8> CollisionResistance for TurboShake256<DS> {
     // https://www.ietf.org/archive/id/draft-irtf-cfrg-kangarootwelve-17.html#section-7-8
     type CollisionResistance = U32;
 }
+
+/// TurboSHAKE128 of `data` with a runtime domain separation byte
+///
+/// [`TurboShake128`] fixes the domain byte at compile time; this helper takes it as a value
+/// and returns `out_len` bytes of output. Like the SP 800-185 `finalize_with_length` helpers,
+/// `out_len` is capped at [`MAX_SP800185_FIXED_OUTPUT_BYTES`]; squeeze a [`TurboShake128`]
+/// reader for longer output.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidParameter`] if `domain_sep` is outside `0x01..=0x7F`, and
+/// [`Error::InvalidMessageSize`] if `out_len` exceeds [`MAX_SP800185_FIXED_OUTPUT_BYTES`].
+#[cfg(feature = "alloc")]
+pub fn turbo_shake128(data: &[u8], domain_sep: u8, out_len: usize) -> Result<Vec<u8>> {
+    turbo_shake(168, data, domain_sep, out_len)
+}
+
+/// TurboSHAKE256 of `data` with a runtime domain separation byte
+///
+/// Runtime counterpart of [`TurboShake256`]; see [`turbo_shake128`].
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidParameter`] if `domain_sep` is outside `0x01..=0x7F`, and
+/// [`Error::InvalidMessageSize`] if `out_len` exceeds [`MAX_SP800185_FIXED_OUTPUT_BYTES`].
+#[cfg(feature = "alloc")]
+pub fn turbo_shake256(data: &[u8], domain_sep: u8, out_len: usize) -> Result<Vec<u8>> {
+    turbo_shake(136, data, domain_sep, out_len)
+}
+
+/// One-shot TurboSHAKE sponge, padding with `domain_sep` exactly like the hasher cores
+#[cfg(feature = "alloc")]
+fn turbo_shake(rate: usize, data: &[u8], domain_sep: u8, out_len: usize) -> Result<Vec<u8>> {
+    if !(0x01..=0x7F).contains(&domain_sep) {
+        return Err(Error::InvalidParameter {
+            parameter: "domain_sep",
+            reason: "TurboSHAKE domain separation byte must be in 0x01..=0x7F",
+        });
+    }
+    if out_len > MAX_SP800185_FIXED_OUTPUT_BYTES {
+        return Err(Error::InvalidMessageSize {
+            max: MAX_SP800185_FIXED_OUTPUT_BYTES,
+            actual: out_len,
+        });
+    }
+
+    let mut state = [0u64; PLEN];
+    let mut blocks = data.chunks_exact(rate);
+    for block in &mut blocks {
+        xor_block(&mut state, block);
+        lib_q_keccak::p1600(&mut state, TURBO_SHAKE_ROUND_COUNT);
+    }
+
+    let tail = blocks.remainder();
+    let mut last = [0u8; 168];
+    let last = &mut last[..rate];
+    last[..tail.len()].copy_from_slice(tail);
+    last[tail.len()] = domain_sep;
+    last[rate - 1] |= 0x80;
+    xor_block(&mut state, last);
+    lib_q_keccak::p1600(&mut state, TURBO_SHAKE_ROUND_COUNT);
+
+    let mut output = vec![0u8; out_len];
+    let mut chunks = output.chunks_mut(rate).peekable();
+    while let Some(chunk) = chunks.next() {
+        for (dst, lane) in chunk.chunks_mut(8).zip(state.iter()) {
+            dst.copy_from_slice(&lane.to_le_bytes()[..dst.len()]);
+        }
+        if chunks.peek().is_some() {
+            lib_q_keccak::p1600(&mut state, TURBO_SHAKE_ROUND_COUNT);
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    fn typed<X: Default + Update + ExtendableOutput>(data: &[u8], out_len: usize) -> Vec<u8> {
+        let mut hasher = X::default();
+        hasher.update(data);
+        let mut output = vec![0u8; out_len];
+        hasher.finalize_xof_into(&mut output);
+        output
+    }
+
+    #[test]
+    fn test_turbo_shake_known_answers() {
+        // RFC 9861: TurboSHAKE128(M = "", D = 0x1F, 32) and TurboSHAKE256(M = "", D = 0x1F, 64)
+        assert_eq!(
+            turbo_shake128(b"", 0x1F, 32).unwrap(),
+            hex!("1E415F1C5983AFF2169217277D17BB538CD945A397DDEC541F1CE41AF2C1B74C")
+        );
+        assert_eq!(
+            turbo_shake256(b"", 0x1F, 64).unwrap(),
+            hex!(
+                "367A329DAFEA871C7802EC67F905AE13C57695DC2C6663C61035F59A18F8E7DB"
+                "11EDC0E12E91EA60EB6B32DF06DD7F002FBAFABB6E13EC1CC20D995547600DB0"
+            )
+        );
+    }
+
+    #[test]
+    fn test_turbo_shake_matches_typed_hashers() {
+        // Lengths straddle the 136/168-byte rates on both the absorb and squeeze side.
+        let data: Vec<u8> = (0u8..=250).collect();
+        for len in [0, 135, 136, 167, 168, 251] {
+            assert_eq!(
+                turbo_shake128(&data[..len], 0x06, 400).unwrap(),
+                typed::<TurboShake128<0x06>>(&data[..len], 400)
+            );
+            assert_eq!(
+                turbo_shake256(&data[..len], 0x0B, 300).unwrap(),
+                typed::<TurboShake256<0x0B>>(&data[..len], 300)
+            );
+        }
+    }
+
+    #[test]
+    fn test_turbo_shake_domain_separation() {
+        let a = turbo_shake128(b"same input", 0x01, 32).unwrap();
+        let b = turbo_shake128(b"same input", 0x02, 32).unwrap();
+        assert_ne!(a, b);
+        assert_ne!(
+            turbo_shake256(b"same input", 0x1F, 32).unwrap(),
+            turbo_shake256(b"same input", 0x7F, 32).unwrap()
+        );
+
+        for domain_sep in [0x00, 0x80, 0xFF] {
+            assert!(matches!(
+                turbo_shake128(b"same input", domain_sep, 32),
+                Err(Error::InvalidParameter {
+                    parameter: "domain_sep",
+                    ..
+                })
+            ));
+            assert!(matches!(
+                turbo_shake256(b"same input", domain_sep, 32),
+                Err(Error::InvalidParameter {
+                    parameter: "domain_sep",
+                    ..
+                })
+            ));
+        }
+    }
+    #[test]
+    fn test_turbo_shake_output_length_cap() {
+        let max = MAX_SP800185_FIXED_OUTPUT_BYTES;
+        assert_eq!(turbo_shake128(b"abc", 0x1F, max).unwrap().len(), max);
+        assert_eq!(turbo_shake256(b"abc", 0x1F, max).unwrap().len(), max);
+        assert!(matches!(
+            turbo_shake128(b"abc", 0x1F, max + 1),
+            Err(Error::InvalidMessageSize { actual, .. }) if actual == max + 1
+        ));
+        assert!(matches!(
+            turbo_shake256(b"abc", 0x1F, max + 1),
+            Err(Error::InvalidMessageSize { actual, .. }) if actual == max + 1
+        ));
+    }
+}
@@ -0,0 +1,209 @@
+//! Incremental output from extendable-output functions
+//!
+//! [`HashAlgorithm::xof_reader`] absorbs the whole input and hands back a reader that can be
+//! squeezed any number of times; consecutive reads continue the same output stream, so the
+//! concatenation of all reads equals one large squeeze.
+
+use alloc::boxed::Box;
+
+use digest::{
+    CustomizedInit,
+    ExtendableOutput,
+    Update,
+    XofReader,
+};
+use lib_q_core::{
+    Error,
+    Result,
+};
+
+use crate::{
+    CShake128,
+    CShake256,
+    HashAlgorithm,
+    Kt128,
+    Kt256,
+    Shake128,
+    Shake256,
+    TurboShake128,
+    TurboShake256,
+};
+
+/// Object-safe XOF reader returned by [`HashAlgorithm::xof_reader`]
+pub trait XofReaderDyn: Send {
+    /// Fill `buf` with the next `buf.len()` bytes of output
+    fn read(&mut self, buf: &mut [u8]);
+}
+
+struct Reader<R>(R);
+
+impl<R: XofReader + Send> XofReaderDyn for Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) {
+        XofReader::read(&mut self.0, buf);
+    }
+}
+
+fn squeeze<X>(mut hasher: X, data: &[u8]) -> Box<dyn XofReaderDyn>
+where
+    X: Update + ExtendableOutput,
+    X::Reader: Send + 'static,
+{
+    hasher.update(data);
+    Box::new(Reader(hasher.finalize_xof()))
+}
+
+impl HashAlgorithm {
+    /// Absorb `data` and return a reader that squeezes output on demand
+    ///
+    /// `customization` is the cSHAKE customization string or the KangarooTwelve
+    /// customization; `None` and an empty string are equivalent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] for fixed-output algorithms (SHA-3, SHA-2,
+    /// Keccak) and for a non-empty customization on SHAKE or TurboSHAKE, and
+    /// [`Error::NotImplemented`] for the SP 800-185 constructions (KMAC, TupleHash,
+    /// ParallelHash).
+    pub fn xof_reader(
+        &self,
+        data: &[u8],
+        customization: Option<&[u8]>,
+    ) -> Result<Box<dyn XofReaderDyn>> {
+        let customization = customization.unwrap_or_default();
+        let uncustomized = || {
+            if customization.is_empty() {
+                Ok(())
+            } else {
+                Err(Error::InvalidAlgorithm {
+                    algorithm: "Customization requires cSHAKE or KangarooTwelve",
+                })
+            }
+        };
+
+        let reader = match self {
+            HashAlgorithm::Shake128 => {
+                uncustomized()?;
+                squeeze(Shake128::default(), data)
+            }
+            HashAlgorithm::Shake256 => {
+                uncustomized()?;
+                squeeze(Shake256::default(), data)
+            }
+            HashAlgorithm::TurboShake128 => {
+                uncustomized()?;
+                squeeze(TurboShake128::<0x1F>::default(), data)
+            }
+            HashAlgorithm::TurboShake256 => {
+                uncustomized()?;
+                squeeze(TurboShake256::<0x1F>::default(), data)
+            }
+            HashAlgorithm::Cshake128 => squeeze(CShake128::new_customized(customization), data),
+            HashAlgorithm::Cshake256 => squeeze(CShake256::new_customized(customization), data),
+            HashAlgorithm::Kt128 => squeeze(Kt128::new(customization), data),
+            HashAlgorithm::Kt256 => squeeze(Kt256::new(customization), data),
+            HashAlgorithm::Kmac128 |
+            HashAlgorithm::Kmac256 |
+            HashAlgorithm::TupleHash128 |
+            HashAlgorithm::TupleHash256 |
+            HashAlgorithm::ParallelHash128 |
+            HashAlgorithm::ParallelHash256 => {
+                return Err(Error::NotImplemented {
+                    feature: alloc::format!("XOF reader for {self:?}"),
+                });
+            }
+            HashAlgorithm::Sha3_224 |
+            HashAlgorithm::Sha3_256 |
+            HashAlgorithm::Sha3_384 |
+            HashAlgorithm::Sha3_512 |
+            HashAlgorithm::Keccak224 |
+            HashAlgorithm::Keccak256 |
+            HashAlgorithm::Keccak384 |
+            HashAlgorithm::Keccak512 |
+            HashAlgorithm::Sha224 |
+            HashAlgorithm::Sha256 |
+            HashAlgorithm::Sha384 |
+            HashAlgorithm::Sha512 |
+            HashAlgorithm::Sha512_224 |
+            HashAlgorithm::Sha512_256 => {
+                return Err(Error::InvalidAlgorithm {
+                    algorithm: "Algorithm is not an extendable-output function",
+                });
+            }
+        };
+        Ok(reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn incremental_reads_continue_the_output_stream() {
+        // Read sizes straddle the 136/168-byte sponge rates.
+        const READS: [usize; 4] = [1, 135, 200, 500];
+        let total: usize = READS.iter().sum();
+
+        for (algorithm, customization) in [
+            (HashAlgorithm::Shake128, None),
+            (HashAlgorithm::Shake256, None),
+            (HashAlgorithm::TurboShake128, None),
+            (HashAlgorithm::Cshake256, Some(&b"keystream"[..])),
+            (HashAlgorithm::Kt128, Some(&b"keystream"[..])),
+        ] {
+            let mut one_shot = vec![0u8; total];
+            algorithm
+                .xof_reader(b"seed material", customization)
+                .unwrap()
+                .read(&mut one_shot);
+
+            let mut reader = algorithm
+                .xof_reader(b"seed material", customization)
+                .unwrap();
+            let mut pieces = vec![0u8; total];
+            let mut offset = 0;
+            for len in READS {
+                reader.read(&mut pieces[offset..offset + len]);
+                offset += len;
+            }
+            assert_eq!(pieces, one_shot, "{algorithm:?}");
+        }
+    }
+
+    #[test]
+    fn reader_matches_one_shot_hash() {
+        let mut output = [0u8; 32];
+        HashAlgorithm::Shake256
+            .xof_reader(b"abc", None)
+            .unwrap()
+            .read(&mut output);
+        let expected = crate::create_hash(HashAlgorithm::Shake256)
+            .unwrap()
+            .hash(b"abc")
+            .unwrap();
+        assert_eq!(output.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn rejects_fixed_output_and_unsupported_inputs() {
+        assert!(matches!(
+            HashAlgorithm::Sha3_256.xof_reader(b"data", None),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+        assert!(matches!(
+            HashAlgorithm::Shake128.xof_reader(b"data", Some(b"custom")),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+        assert!(matches!(
+            HashAlgorithm::Kmac256.xof_reader(b"data", None),
+            Err(Error::NotImplemented { .. })
+        ));
+        assert!(
+            HashAlgorithm::Shake128
+                .xof_reader(b"data", Some(b""))
+                .is_ok()
+        );
+    }
+}
@@ -15,6 +15,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::{
     HpkeAead,
@@ -118,7 +119,12 @@ fn test_error_handling() -> Result<(), Box<dyn std::error::Error>> {
     let keypair2 = kem_ctx.generate_keypair(Algorithm::MlKem512, None)?;
 
     let recipient_pk1 = KemPublicKey::new(keypair1.public_key().as_bytes().to_vec());
-    let recipient_sk2 = KemSecretKey::new(keypair2.secret_key().as_bytes().to_vec());
+    let recipient_sk2 = KemSecretKey::new(
+        keypair2
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let (enc_key, ciphertext) = hpke_ctx.seal(&recipient_pk1, b"info", b"aad", b"message")?;
     let result_mismatch = hpke_ctx.open(&enc_key, &recipient_sk2, b"info", b"aad", &ciphertext);
@@ -145,7 +151,12 @@ fn test_performance() -> Result<(), Box<dyn std::error::Error>> {
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None)?;
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"Performance test message";
     let iterations = 100;
@@ -183,7 +194,12 @@ fn test_message_sizes() -> Result<(), Box<dyn std::error::Error>> {
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None)?;
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message_sizes = vec![
         (0, "Empty message"),
@@ -13,6 +13,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::HpkeContext;
 use lib_q_kem::LibQKemProvider;
@@ -31,7 +32,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("✓ Generated ML-KEM-512 key pair");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
     println!("✓ Created HPKE key objects");
 
     // Single-shot encryption/decryption
@@ -96,7 +102,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     for (algorithm, name) in algorithms {
         let keypair = kem_ctx.generate_keypair(algorithm, None)?;
         let pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-        let sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+        let sk = KemSecretKey::new(
+            keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
+        );
 
         let (enc_key, ciphertext) = hpke_ctx.seal(&pk, b"info", b"aad", b"test")?;
         let decrypted = hpke_ctx.open(&enc_key, &sk, b"info", b"aad", &ciphertext)?;
@@ -8,6 +8,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::HpkeContext;
 use lib_q_hpke::interop::{
@@ -57,7 +58,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut kem_ctx = KemContext::with_provider(Box::new(LibQKemProvider::new()?));
     let recipient = kem_ctx.generate_keypair(Algorithm::MlKem512, None)?;
     let recipient_pk = KemPublicKey::new(recipient.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let info = b"app-binding: transcript-hash-placeholder";
     let aad = b"example-aad";
@@ -112,6 +112,8 @@ pub enum KdfOperation {
     Extract,
     /// Key expansion operation
     Expand,
+    /// Single-squeeze output of an extendable-output KDF
+    Squeeze,
     /// Key validation operation
     Validation,
 }
@@ -328,6 +330,14 @@ impl std::error::Error for HpkeError {}
 impl From<HpkeError> for lib_q_core::Error {
     fn from(err: HpkeError) -> Self {
         match err {
+            HpkeError::KemError {
+                algorithm,
+                operation: KemOperation::Decapsulation,
+                cause,
+            } => lib_q_core::Error::DecapsulationFailed {
+                operation: format!("KEM {:?} decapsulation: {}", algorithm, cause),
+                retriable: false,
+            },
             HpkeError::KemError {
                 algorithm,
                 operation,
@@ -472,8 +482,15 @@ mod tests {
 
     #[test]
     fn conversion_to_core_error_maps_all_variants() {
+        let converted: CoreError =
+            HpkeError::kem_error(HpkeKem::MlKem768, KemOperation::Decapsulation, "kem").into();
+        assert!(matches!(
+            converted,
+            CoreError::DecapsulationFailed { ref operation, .. } if operation.contains("MlKem768")
+        ));
+
         let cases = vec![
-            HpkeError::kem_error(HpkeKem::MlKem768, KemOperation::Decapsulation, "kem"),
+            HpkeError::kem_error(HpkeKem::MlKem768, KemOperation::Encapsulation, "kem"),
             HpkeError::kdf_error(HpkeKdf::HkdfSha3_512, KdfOperation::Validation, "kdf"),
             HpkeError::aead_error(
                 HpkeAead::DuplexSpongeAead,
@@ -2,10 +2,13 @@
 
 #[cfg(feature = "alloc")]
 use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
 use alloc::sync::Arc;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+use lib_q_core::SecretExportConsent;
 use zeroize::Zeroizing;
 
 use crate::error::{
@@ -105,7 +108,7 @@ fn verify_sender_keypair_binding<P: HpkeCryptoProvider + ?Sized>(
     sender_sk: &lib_q_core::KemSecretKey,
     sender_pk: &lib_q_core::KemPublicKey,
 ) -> Result<(), HpkeError> {
-    let derived = provider.derive_public_key(kem, sender_sk.as_bytes())?;
+    let derived = provider.derive_public_key(kem, sender_sk.expose_secret(SecretExportConsent))?;
     let pk = sender_pk.as_bytes();
     if derived.len() != pk.len() {
         return Err(HpkeError::security_error(
@@ -122,6 +125,25 @@ fn verify_sender_keypair_binding<P: HpkeCryptoProvider + ?Sized>(
     Ok(())
 }
 
+/// RFC 9180 §5.1 `VerifyPSKInputs` for PSK-carrying modes.
+///
+/// A PSK and PSK ID must be supplied together: an empty PSK with a non-empty PSK ID (or the
+/// reverse) is rejected as inconsistent, and PSK modes additionally require both to be non-empty.
+fn verify_psk_inputs(psk: Option<&[u8]>, psk_id: Option<&[u8]>) -> Result<(), HpkeError> {
+    let psk = psk.unwrap_or_default();
+    let psk_id = psk_id.unwrap_or_default();
+    if psk.is_empty() != psk_id.is_empty() {
+        return Err(HpkeError::CryptoError(
+            "Inconsistent PSK inputs: PSK and PSK ID must both be empty or both be non-empty"
+                .into(),
+        ));
+    }
+    if psk.is_empty() {
+        return Err(HpkeError::CryptoError("PSK cannot be empty".into()));
+    }
+    Ok(())
+}
+
 /// Validate PSK parameters for a given mode
 fn validate_psk_parameters(
     mode: HpkeMode,
@@ -144,12 +166,7 @@ fn validate_psk_parameters(
                     "PSK mode requires both PSK and PSK ID".into(),
                 ));
             }
-            // Validate PSK is not empty
-            if let Some(psk_bytes) = psk &&
-                psk_bytes.is_empty()
-            {
-                return Err(HpkeError::CryptoError("PSK cannot be empty".into()));
-            }
+            verify_psk_inputs(psk, psk_id)?;
         }
         HpkeMode::Auth => {
             // Auth mode: no PSK parameters allowed
@@ -166,12 +183,7 @@ fn validate_psk_parameters(
                     "AuthPSK mode requires both PSK and PSK ID".into(),
                 ));
             }
-            // Validate PSK is not empty
-            if let Some(psk_bytes) = psk &&
-                psk_bytes.is_empty()
-            {
-                return Err(HpkeError::CryptoError("PSK cannot be empty".into()));
-            }
+            verify_psk_inputs(psk, psk_id)?;
         }
     }
     Ok(())
@@ -566,10 +578,10 @@ pub fn setup_sender_with_mode<P: HpkeCryptoProvider + ?Sized>(
         let expected_sk_len = kem_algorithm.secret_key_len();
         let expected_pk_len = kem_algorithm.public_key_len();
 
-        if sender_sk.as_bytes().len() != expected_sk_len {
+        if sender_sk.expose_secret(SecretExportConsent).len() != expected_sk_len {
             return Err(HpkeError::CryptoError(format!(
                 "Invalid sender secret key size: {} bytes (expected {})",
-                sender_sk.as_bytes().len(),
+                sender_sk.expose_secret(SecretExportConsent).len(),
                 expected_sk_len
             )));
         }
@@ -597,7 +609,7 @@ pub fn setup_sender_with_mode<P: HpkeCryptoProvider + ?Sized>(
             // Perform sender authentication using AuthEncap (RFC 9180 Section 5.1.3)
             let (auth_encapsulated_key, auth_kem_secret) = provider.auth_encapsulate(
                 kem_algorithm,
-                sender_sk.as_bytes(),
+                sender_sk.expose_secret(SecretExportConsent),
                 recipient_pk.as_bytes(),
                 rng,
             )?;
@@ -739,7 +751,7 @@ pub fn setup_receiver_with_mode<P: HpkeCryptoProvider + ?Sized>(
     // Perform KEM decapsulation on the main encapsulated key
     let mut main_shared_secret = provider.decapsulate(
         kem_algorithm,
-        recipient_sk.as_bytes(),
+        recipient_sk.expose_secret(SecretExportConsent),
         &main_encapsulated_key,
     )?;
 
@@ -752,7 +764,7 @@ pub fn setup_receiver_with_mode<P: HpkeCryptoProvider + ?Sized>(
         let auth_kem_secret = provider.auth_decapsulate(
             kem_algorithm,
             &auth_encap,
-            recipient_sk.as_bytes(),
+            recipient_sk.expose_secret(SecretExportConsent),
             sender_pk.as_bytes(),
         )?;
 
@@ -898,10 +910,10 @@ pub fn seal_with_mode<P: HpkeCryptoProvider + ?Sized>(
         let expected_sk_len = kem_algorithm.secret_key_len();
         let expected_pk_len = kem_algorithm.public_key_len();
 
-        if sender_sk.as_bytes().len() != expected_sk_len {
+        if sender_sk.expose_secret(SecretExportConsent).len() != expected_sk_len {
             return Err(HpkeError::CryptoError(format!(
                 "Invalid sender secret key size: {} bytes (expected {})",
-                sender_sk.as_bytes().len(),
+                sender_sk.expose_secret(SecretExportConsent).len(),
                 expected_sk_len
             )));
         }
@@ -929,7 +941,7 @@ pub fn seal_with_mode<P: HpkeCryptoProvider + ?Sized>(
             // Perform sender authentication
             let (auth_encapsulated_key, auth_kem_secret) = provider.auth_encapsulate(
                 kem_algorithm,
-                sender_sk.as_bytes(),
+                sender_sk.expose_secret(SecretExportConsent),
                 recipient_pk.as_bytes(),
                 rng,
             )?;
@@ -1096,6 +1108,21 @@ pub fn open_message<P: HpkeCryptoProvider + ?Sized>(
 ///
 /// Per RFC 9180 Section 5.3, `L` MUST be at most `255 * Nh` for the KDFs defined in that document,
 /// where `Nh` is the Extract output length ([`HpkeKdf::extract_len`] for this implementation).
+/// Longer requests fail with [`HpkeError::InvalidInput`]; see [`HpkeKdf::max_export_len`].
+/// This is the only place the bound is checked: the KDF providers expand to any length.
+///
+/// For the SHAKE KDFs ([`HpkeKdf::is_xof`]) the output is a single squeeze of
+///
+/// ```text
+/// SHAKE(exporter_secret || "HPKE-v1" || suite_id || "sec" || exporter_context)
+/// ```
+///
+/// via [`labeled_squeeze`]. `L` is deliberately not absorbed, so an export of `L` bytes is a
+/// prefix of any longer export with the same context. The SHA3 KDFs keep the RFC 9180
+/// `LabeledExpand`, where `L` is part of the input.
+///
+/// This changes HKDF-SHAKE exporter output from lib-q-hpke 0.0.8 and earlier, which used
+/// `LabeledExpand` for every KDF: secrets exported by an older peer will not match.
 pub fn export<P: HpkeCryptoProvider + ?Sized>(
     exporter_secret: &[u8],
     exporter_context: &[u8],
@@ -1103,26 +1130,37 @@ pub fn export<P: HpkeCryptoProvider + ?Sized>(
     cipher_suite: &HpkeCipherSuite,
     provider: &P,
 ) -> Result<Vec<u8>, HpkeError> {
-    let nh = cipher_suite.kdf.extract_len();
-    let max_l = nh
-        .checked_mul(255)
-        .ok_or_else(|| HpkeError::CryptoError("export length bound (255*Nh) overflowed".into()))?;
+    let max_l = cipher_suite.kdf.max_export_len();
     if length > max_l {
-        return Err(HpkeError::CryptoError(format!(
-            "export length {length} exceeds RFC 9180 maximum 255*Nh ({max_l})"
-        )));
+        return Err(HpkeError::InvalidInput {
+            parameter: "export length".into(),
+            value: length.to_string(),
+            expected: format!("at most 255*Nh = {max_l} bytes (RFC 9180 Section 5.3)"),
+        });
     }
 
     let suite_id = create_suite_id(cipher_suite)?;
-    let out = labeled_expand(
-        cipher_suite.kdf,
-        exporter_secret,
-        &suite_id,
-        "sec",
-        exporter_context,
-        length,
-        provider,
-    )?;
+    let out = if cipher_suite.kdf.is_xof() {
+        labeled_squeeze(
+            cipher_suite.kdf,
+            exporter_secret,
+            &suite_id,
+            "sec",
+            exporter_context,
+            length,
+            provider,
+        )?
+    } else {
+        labeled_expand(
+            cipher_suite.kdf,
+            exporter_secret,
+            &suite_id,
+            "sec",
+            exporter_context,
+            length,
+            provider,
+        )?
+    };
     Ok(out.to_vec())
 }
 
@@ -1259,11 +1297,14 @@ pub fn labeled_extract<P: HpkeCryptoProvider + ?Sized>(
 }
 
 /// Labeled expand function (RFC 9180 Section 4.1)
+///
+/// `label` is usually a string literal such as `"sec"`; byte-string labels (for example from
+/// [`lib_q_core::labels`]) are accepted as well.
 pub fn labeled_expand<P: HpkeCryptoProvider + ?Sized>(
     kdf: HpkeKdf,
     prk: &[u8],
     suite_id: &[u8],
-    label: &str,
+    label: &(impl AsRef<[u8]> + ?Sized),
     info: &[u8],
     length: usize,
     provider: &P,
@@ -1275,19 +1316,45 @@ pub fn labeled_expand<P: HpkeCryptoProvider + ?Sized>(
     })?;
 
     // Create labeled info according to RFC 9180 Section 4.1
+    let label = label.as_ref();
     let mut labeled_info = Zeroizing::new(Vec::with_capacity(
         2 + b"HPKE-v1".len() + suite_id.len() + label.len() + info.len(),
     ));
     labeled_info.extend_from_slice(&length_u16.to_be_bytes());
     labeled_info.extend_from_slice(b"HPKE-v1");
     labeled_info.extend_from_slice(suite_id);
-    labeled_info.extend_from_slice(label.as_bytes());
+    labeled_info.extend_from_slice(label);
     labeled_info.extend_from_slice(info);
 
     let okm = provider.expand(kdf, prk, labeled_info.as_slice(), length)?;
     Ok(Zeroizing::new(okm))
 }
 
+/// Labeled squeeze for XOF KDFs: [`labeled_expand`] without the `I2OSP(L, 2)` prefix
+///
+/// The output is read from one SHAKE sponge ([`KdfProvider::squeeze`]), so shorter outputs
+/// are prefixes of longer ones.
+pub fn labeled_squeeze<P: HpkeCryptoProvider + ?Sized>(
+    kdf: HpkeKdf,
+    prk: &[u8],
+    suite_id: &[u8],
+    label: &str,
+    info: &[u8],
+    length: usize,
+    provider: &P,
+) -> Result<Zeroizing<Vec<u8>>, HpkeError> {
+    let mut labeled_info = Zeroizing::new(Vec::with_capacity(
+        b"HPKE-v1".len() + suite_id.len() + label.len() + info.len(),
+    ));
+    labeled_info.extend_from_slice(b"HPKE-v1");
+    labeled_info.extend_from_slice(suite_id);
+    labeled_info.extend_from_slice(label.as_bytes());
+    labeled_info.extend_from_slice(info);
+
+    let okm = provider.squeeze(kdf, prk, labeled_info.as_slice(), length)?;
+    Ok(Zeroizing::new(okm))
+}
+
 /// Validate that the KEM context is compatible with the required algorithm
 ///
 /// This function ensures that the KEM context is properly configured and can
@@ -87,6 +87,10 @@ impl HkdfImpl {
     }
 
     /// Static HKDF-Expand function
+    ///
+    /// `output_len` is not bounded here. The one caller-controlled length, the exporter's,
+    /// is checked against `255 * Nh` by [`crate::hpke_core::export`]; the key schedule only
+    /// expands to fixed key and nonce sizes.
     pub fn expand_static(
         kdf: HpkeKdf,
         prk: &[u8],
@@ -101,16 +105,6 @@ impl HkdfImpl {
 
         #[cfg(feature = "hash")]
         {
-            // RFC 5869 / RFC 9180 §5.3: the expanded length L MUST be at most 255*Nh.
-            let max_l = kdf.extract_len().checked_mul(255).ok_or_else(|| {
-                HpkeError::CryptoError("HKDF-Expand length bound (255*Nh) overflowed".into())
-            })?;
-            if output_len > max_l {
-                return Err(HpkeError::CryptoError(format!(
-                    "HKDF-Expand length {output_len} exceeds RFC maximum 255*Nh ({max_l})"
-                )));
-            }
-
             let mut output = vec![0u8; output_len];
 
             match kdf {
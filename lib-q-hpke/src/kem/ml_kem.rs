@@ -9,6 +9,7 @@ use alloc::vec::Vec;
 use lib_q_core::{
     Algorithm,
     KemOperations,
+    SecretExportConsent,
 };
 #[cfg(feature = "ml-kem")]
 use lib_q_kem::{
@@ -83,7 +84,12 @@ impl Kem for MlKemImpl {
 
             Ok((
                 keypair.public_key().as_bytes().to_vec(),
-                Zeroizing::new(keypair.secret_key().as_bytes().to_vec()),
+                Zeroizing::new(
+                    keypair
+                        .secret_key()
+                        .expose_secret(SecretExportConsent)
+                        .to_vec(),
+                ),
             ))
         }
 
@@ -96,7 +96,9 @@ extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::{
     boxed::Box,
+    format,
     sync::Arc,
+    vec,
     vec::Vec,
 };
 
@@ -169,6 +171,8 @@ pub struct HpkeContext {
     cipher_suite: HpkeCipherSuite,
     /// PSK / AuthPSK encapsulated-key wire format (ignored for Base and Auth).
     psk_wire_format: HpkePskWireFormat,
+    /// Prefix single-shot ciphertexts with the suite identifiers (set by [`HpkeContext::with_suite`]).
+    suite_header: bool,
     hpke_crypto: Arc<dyn HpkeCryptoProvider + Send + Sync>,
     rng: Box<dyn CryptoRng + Send>,
 }
@@ -192,6 +196,7 @@ impl HpkeContext {
                 HpkeAead::Saturnin256,
             ),
             psk_wire_format: HpkePskWireFormat::default(),
+            suite_header: false,
             hpke_crypto,
             rng: Box::new(EntropyCryptoRng),
         }
@@ -207,6 +212,7 @@ impl HpkeContext {
                 HpkeAead::Saturnin256,
             ),
             psk_wire_format: HpkePskWireFormat::default(),
+            suite_header: false,
             hpke_crypto,
             rng: Box::new(EntropyCryptoRng),
         }
@@ -226,6 +232,7 @@ impl HpkeContext {
                 HpkeAead::Saturnin256,
             ),
             psk_wire_format: HpkePskWireFormat::default(),
+            suite_header: false,
             hpke_crypto,
             rng: Box::new(EntropyCryptoRng),
         }
@@ -244,6 +251,7 @@ impl HpkeContext {
                 HpkeAead::Saturnin256,
             ),
             psk_wire_format: HpkePskWireFormat::default(),
+            suite_header: false,
             hpke_crypto,
             rng: Box::new(EntropyCryptoRng),
         }
@@ -282,6 +290,72 @@ impl HpkeContext {
         self.cipher_suite = cipher_suite;
     }
 
+    /// Every suite whose KEM, KDF and AEAD the HPKE crypto provider supports
+    pub fn supported_suites(&self) -> Vec<HpkeSuite> {
+        let supported = self.hpke_crypto.supported_algorithms();
+        let mut suites = Vec::new();
+        for &kem in &supported.kems {
+            for &kdf in &supported.kdfs {
+                for &aead in &supported.aeads {
+                    suites.push(HpkeCipherSuite::new(kem, kdf, aead).into());
+                }
+            }
+        }
+        suites
+    }
+
+    /// Select the cipher suite by its identifiers and bind it into single-shot ciphertexts
+    ///
+    /// After this call [`seal`](Self::seal) prefixes the ciphertext with
+    /// [`HpkeSuite::to_bytes`], and [`open`](Self::open) rejects a ciphertext whose prefix
+    /// names a different suite before any decapsulation. The suite is already bound into the
+    /// key schedule, so this adds an explicit, early error rather than new security; the prefix
+    /// is a libQ framing and not RFC 9180 wire format, so both peers must use `with_suite`.
+    ///
+    /// Only the single-shot Base-mode [`seal`](Self::seal)/[`open`](Self::open) pair (and so
+    /// the per-recipient wraps of [`seal_multi`](Self::seal_multi)) carry the prefix. There
+    /// are no single-shot PSK, Auth or AuthPSK calls, and ciphertexts from the contexts of
+    /// [`setup_sender`](Self::setup_sender) and its mode variants are never prefixed.
+    ///
+    /// ```rust
+    /// use lib_q_hpke::{
+    ///     HpkeContext,
+    ///     HpkeSuite,
+    /// };
+    ///
+    /// // ML-KEM-768, HKDF-SHAKE256, SHAKE256 AEAD
+    /// let ctx = HpkeContext::new()
+    ///     .with_suite(HpkeSuite::new(0x0023, 0x0005, 0x0005))?;
+    /// assert_eq!(HpkeSuite::from(*ctx.cipher_suite()).kem_id, 0x0023);
+    /// assert!(
+    ///     HpkeContext::new()
+    ///         .with_suite(HpkeSuite::new(0x0020, 0x0005, 0x0005))
+    ///         .is_err()
+    /// );
+    /// # Ok::<(), lib_q_core::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::UnsupportedAlgorithm`] if an identifier is unknown or the
+    /// HPKE crypto provider does not support the combination (see
+    /// [`supported_suites`](Self::supported_suites)).
+    pub fn with_suite(mut self, suite: HpkeSuite) -> Result<Self> {
+        let cipher_suite = suite
+            .cipher_suite()
+            .filter(|cipher_suite| {
+                self.hpke_crypto
+                    .supported_algorithms()
+                    .supports_cipher_suite(cipher_suite)
+            })
+            .ok_or_else(|| lib_q_core::Error::UnsupportedAlgorithm {
+                algorithm: format!("HPKE suite {suite:?}"),
+            })?;
+        self.cipher_suite = cipher_suite;
+        self.suite_header = true;
+        Ok(self)
+    }
+
     /// PSK-mode encapsulated key wire format used for subsequent PSK / AuthPSK operations.
     #[must_use]
     pub fn psk_wire_format(&self) -> HpkePskWireFormat {
@@ -511,6 +585,9 @@ impl HpkeContext {
     }
 
     /// Single-shot encryption (seal)
+    ///
+    /// Returns `(encapsulated_key, ciphertext)`; see [`with_suite`](Self::with_suite) for the
+    /// optional suite prefix on `ciphertext`.
     pub fn seal(
         &mut self,
         recipient_pk: &KemPublicKey,
@@ -518,7 +595,7 @@ impl HpkeContext {
         aad: &[u8],
         plaintext: &[u8],
     ) -> Result<(Vec<u8>, Vec<u8>)> {
-        hpke_core::seal(
+        let (enc, ciphertext) = hpke_core::seal(
             &mut self.kem_ctx,
             recipient_pk,
             info,
@@ -527,11 +604,23 @@ impl HpkeContext {
             &self.cipher_suite,
             self.hpke_crypto.as_ref(),
             self.rng.as_mut(),
-        )
-        .map_err(|e| e.into())
+        )?;
+        if !self.suite_header {
+            return Ok((enc, ciphertext));
+        }
+        let mut framed = HpkeSuite::from(self.cipher_suite).to_bytes().to_vec();
+        framed.extend_from_slice(&ciphertext);
+        Ok((enc, framed))
     }
 
     /// Single-shot decryption (open)
+    ///
+    /// # Errors
+    ///
+    /// With [`with_suite`](Self::with_suite), returns
+    /// [`lib_q_core::Error::InvalidParameter`] before any decapsulation if `ciphertext` does
+    /// not start with this context's suite identifiers. Otherwise returns an error if
+    /// decapsulation or decryption fails.
     pub fn open(
         &mut self,
         encapsulated_key: &[u8],
@@ -540,6 +629,20 @@ impl HpkeContext {
         aad: &[u8],
         ciphertext: &[u8],
     ) -> Result<Vec<u8>> {
+        let ciphertext = if self.suite_header {
+            let expected = HpkeSuite::from(self.cipher_suite);
+            match HpkeSuite::from_bytes(ciphertext) {
+                Some(found) if found == expected => &ciphertext[HpkeSuite::ENCODED_LEN..],
+                _ => {
+                    return Err(lib_q_core::Error::InvalidParameter {
+                        parameter: "ciphertext",
+                        reason: "suite prefix does not name this context's cipher suite",
+                    });
+                }
+            }
+        } else {
+            ciphertext
+        };
         hpke_core::open(
             &mut self.kem_ctx,
             encapsulated_key,
@@ -553,6 +656,121 @@ impl HpkeContext {
         )
         .map_err(|e| e.into())
     }
+
+    /// Encrypt `plaintext` once for several recipients
+    ///
+    /// The payload is sealed a single time under a fresh random content key (with the
+    /// all-zero nonce, since the key is never reused). The content key is then wrapped for
+    /// each recipient with a Base-mode [`seal`](Self::seal) under the same `info` and `aad`.
+    /// Entry `i` of the returned list is `write_frame([enc, wrapped content key])` for
+    /// `recipients[i]`; pass it with the shared ciphertext to [`open_multi`](Self::open_multi).
+    ///
+    /// All recipients learn the same content key, so any one of them can produce payloads
+    /// the others accept. Sign the ciphertext when recipients must be able to tell the
+    /// sender apart from each other.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `recipients` is empty, if the suite's AEAD is export-only, or if
+    /// any wrap fails.
+    pub fn seal_multi(
+        &mut self,
+        recipients: &[&KemPublicKey],
+        info: &[u8],
+        aad: &[u8],
+        plaintext: &[u8],
+    ) -> Result<(Vec<u8>, Vec<Vec<u8>>)> {
+        if recipients.is_empty() {
+            return Err(
+                HpkeError::invalid_input("recipients", "0 recipients", "at least one").into(),
+            );
+        }
+        let aead = self.cipher_suite.aead;
+        if aead == HpkeAead::Export {
+            return Err(lib_q_core::Error::UnsupportedOperation {
+                operation: "seal_multi with an export-only cipher suite".into(),
+            });
+        }
+
+        let mut content_key = zeroize::Zeroizing::new(vec![0u8; aead.key_len()]);
+        self.rng.fill_bytes(content_key.as_mut_slice())?;
+        let ciphertext = hpke_core::seal_message(
+            aead,
+            content_key.as_slice(),
+            &vec![0u8; aead.nonce_len()],
+            0,
+            aad,
+            plaintext,
+            self.hpke_crypto.as_ref(),
+        )?;
+
+        let mut encapsulated_keys = Vec::with_capacity(recipients.len());
+        for recipient_pk in recipients {
+            let (enc, wrapped_key) = self.seal(recipient_pk, info, aad, content_key.as_slice())?;
+            let mut entry = Vec::new();
+            lib_q_core::codec::write_frame(&mut entry, &[&enc, &wrapped_key])?;
+            encapsulated_keys.push(entry);
+        }
+        Ok((ciphertext, encapsulated_keys))
+    }
+
+    /// Decrypt a ciphertext from [`seal_multi`](Self::seal_multi) with this recipient's entry
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::InvalidCiphertextSize`] if `encapsulated_key` does not
+    /// have the byte length of an entry for this cipher suite, and
+    /// [`lib_q_core::Error::InvalidFormat`] if it is not an `[enc, wrapped key]` frame. Any
+    /// other error means unwrapping the content key or decrypting the payload failed (wrong
+    /// key, `info`, `aad` or a tampered ciphertext).
+    pub fn open_multi(
+        &mut self,
+        encapsulated_key: &[u8],
+        recipient_sk: &KemSecretKey,
+        info: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>> {
+        let aead = self.cipher_suite.aead;
+        let enc_len = self.cipher_suite.kem.enc_len();
+        let suite_len = if self.suite_header {
+            HpkeSuite::ENCODED_LEN
+        } else {
+            0
+        };
+        let wrapped_len = suite_len + aead.key_len() + aead.tag_len();
+        let entry_len = 2 * lib_q_core::codec::LENGTH_PREFIX_SIZE + enc_len + wrapped_len;
+        if encapsulated_key.len() != entry_len {
+            return Err(lib_q_core::Error::InvalidCiphertextSize {
+                expected: entry_len,
+                actual: encapsulated_key.len(),
+            });
+        }
+        let segments = lib_q_core::codec::read_frames(encapsulated_key)?;
+        let [enc, wrapped_key] = segments.as_slice() else {
+            return Err(lib_q_core::Error::InvalidFormat {
+                context: "HPKE multi-recipient entry: expected an [enc, wrapped key] frame",
+            });
+        };
+        let content_key =
+            zeroize::Zeroizing::new(self.open(enc, recipient_sk, info, aad, wrapped_key)?);
+        if content_key.len() != aead.key_len() {
+            return Err(lib_q_core::Error::InvalidKeySize {
+                expected: aead.key_len(),
+                actual: content_key.len(),
+            });
+        }
+        hpke_core::open_message(
+            aead,
+            content_key.as_slice(),
+            &vec![0u8; aead.nonce_len()],
+            0,
+            aad,
+            ciphertext,
+            self.hpke_crypto.as_ref(),
+        )
+        .map_err(|e| e.into())
+    }
 }
 
 /// Context for HPKE sender operations
@@ -587,16 +805,64 @@ impl HpkeSenderContext {
         Ok(ciphertext)
     }
 
-    /// Export key material
+    /// Export key material (RFC 9180 Section 5.3)
+    ///
+    /// With an HKDF-SHAKE KDF the output is a single labeled squeeze and differs from the
+    /// `LabeledExpand` output of lib-q-hpke 0.0.8 and earlier; see [`hpke_core::export`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::InvalidMessageSize`] if `length` exceeds
+    /// [`HpkeKdf::max_export_len`] for the session's KDF.
     pub fn export(&self, exporter_context: &[u8], length: usize) -> Result<Vec<u8>> {
-        hpke_core::export(
-            self.exporter_secret.as_slice(),
+        export_secret(
+            &self.exporter_secret,
+            &self.cipher_suite,
+            self.hpke_crypto.as_ref(),
             exporter_context,
             length,
+        )
+    }
+
+    /// Export an `N`-byte key, e.g. `export_key::<32>(b"app key")`
+    ///
+    /// # Errors
+    ///
+    /// Same as [`export`](Self::export) with `length = N`.
+    pub fn export_key<const N: usize>(&self, exporter_context: &[u8]) -> Result<[u8; N]> {
+        Ok(export_array(self.export(exporter_context, N)?))
+    }
+
+    /// Replace the key, base nonce and exporter secret with ones exported from the current
+    /// exporter secret and reset the sequence number to 0
+    ///
+    /// The peer must call `rekey` at the same point in the message stream; ciphertexts sealed
+    /// before the rekey no longer open afterwards. A context that ran out of sequence numbers
+    /// ([`HpkeContextState::NeedsRekey`]) becomes usable again. See [`REKEY_LABELS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::InternalError`] if the context was closed, and passes
+    /// through exporter errors.
+    pub fn rekey(&mut self) -> Result<()> {
+        if self.state == HpkeContextState::Closed {
+            return Err(lib_q_core::Error::InternalError {
+                operation: "HPKE rekey".into(),
+                details: "Context has been closed".into(),
+            });
+        }
+        let (key, nonce, exporter_secret) = rekey_secrets(
+            &self.exporter_secret,
             &self.cipher_suite,
+            self.aead,
             self.hpke_crypto.as_ref(),
-        )
-        .map_err(|e| e.into())
+        )?;
+        self.key = key;
+        self.nonce = nonce;
+        self.exporter_secret = exporter_secret;
+        self.sequence_number = 0;
+        self.state = HpkeContextState::Active;
+        Ok(())
     }
 }
 
@@ -629,19 +895,142 @@ impl HpkeReceiverContext {
         Ok(plaintext)
     }
 
-    /// Export key material
+    /// Export key material (RFC 9180 Section 5.3)
+    ///
+    /// With an HKDF-SHAKE KDF the output is a single labeled squeeze and differs from the
+    /// `LabeledExpand` output of lib-q-hpke 0.0.8 and earlier; see [`hpke_core::export`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::InvalidMessageSize`] if `length` exceeds
+    /// [`HpkeKdf::max_export_len`] for the session's KDF.
     pub fn export(&self, exporter_context: &[u8], length: usize) -> Result<Vec<u8>> {
-        hpke_core::export(
-            self.exporter_secret.as_slice(),
+        export_secret(
+            &self.exporter_secret,
+            &self.cipher_suite,
+            self.hpke_crypto.as_ref(),
             exporter_context,
             length,
+        )
+    }
+
+    /// Export an `N`-byte key, e.g. `export_key::<32>(b"app key")`
+    ///
+    /// # Errors
+    ///
+    /// Same as [`export`](Self::export) with `length = N`.
+    pub fn export_key<const N: usize>(&self, exporter_context: &[u8]) -> Result<[u8; N]> {
+        Ok(export_array(self.export(exporter_context, N)?))
+    }
+
+    /// Replace the key, base nonce and exporter secret with ones exported from the current
+    /// exporter secret and reset the sequence number to 0
+    ///
+    /// The peer must call `rekey` at the same point in the message stream; ciphertexts sealed
+    /// before the rekey no longer open afterwards. A context that ran out of sequence numbers
+    /// ([`HpkeContextState::NeedsRekey`]) becomes usable again. See [`REKEY_LABELS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::InternalError`] if the context was closed, and passes
+    /// through exporter errors.
+    pub fn rekey(&mut self) -> Result<()> {
+        if self.state == HpkeContextState::Closed {
+            return Err(lib_q_core::Error::InternalError {
+                operation: "HPKE rekey".into(),
+                details: "Context has been closed".into(),
+            });
+        }
+        let (key, nonce, exporter_secret) = rekey_secrets(
+            &self.exporter_secret,
             &self.cipher_suite,
+            self.aead,
             self.hpke_crypto.as_ref(),
-        )
-        .map_err(|e| e.into())
+        )?;
+        self.key = key;
+        self.nonce = nonce;
+        self.exporter_secret = exporter_secret;
+        self.sequence_number = 0;
+        self.state = HpkeContextState::Active;
+        Ok(())
     }
 }
 
+/// Run the exporter, reporting its `255 * Nh` length bound as an invalid size
+fn export_secret<P: HpkeCryptoProvider + ?Sized>(
+    exporter_secret: &[u8],
+    cipher_suite: &HpkeCipherSuite,
+    provider: &P,
+    exporter_context: &[u8],
+    length: usize,
+) -> Result<Vec<u8>> {
+    hpke_core::export(
+        exporter_secret,
+        exporter_context,
+        length,
+        cipher_suite,
+        provider,
+    )
+    .map_err(|e| match e {
+        // `hpke_core::export` rejects no other input.
+        HpkeError::InvalidInput { .. } => lib_q_core::Error::InvalidMessageSize {
+            max: cipher_suite.kdf.max_export_len(),
+            actual: length,
+        },
+        e => e.into(),
+    })
+}
+
+/// `LabeledExpand` labels used by `rekey` for the next key, base nonce and exporter secret
+///
+/// Each rekey expands all three from the current exporter secret, so the old secret (and
+/// everything derived from it) cannot be recomputed from the new state, in the spirit of the
+/// TLS 1.3 `KeyUpdate` ratchet. The public exporter always derives under the RFC 9180 label
+/// `"sec"`: `LabeledExpand` for the SHA3 KDFs and, for the SHAKE KDFs, a
+/// [`labeled_squeeze`](hpke_core::labeled_squeeze) that does not absorb `L`. Rekeying always
+/// uses `LabeledExpand` under its own labels, so no `export` call can reproduce these values.
+/// The labels are registered in [`lib_q_core::labels`].
+pub const REKEY_LABELS: [&[u8]; 3] = [
+    lib_q_core::labels::LABEL_HPKE_REKEY_KEY,
+    lib_q_core::labels::LABEL_HPKE_REKEY_NONCE,
+    lib_q_core::labels::LABEL_HPKE_REKEY_EXPORTER,
+];
+
+/// Derive the next `(key, base nonce, exporter secret)` for `rekey`
+fn rekey_secrets<P: HpkeCryptoProvider + ?Sized>(
+    exporter_secret: &[u8],
+    cipher_suite: &HpkeCipherSuite,
+    aead: HpkeAead,
+    provider: &P,
+) -> Result<(SecretBytes, SecretBytes, SecretBytes)> {
+    let suite_id = hpke_core::create_suite_id(cipher_suite)?;
+    let [key_label, nonce_label, exporter_label] = REKEY_LABELS;
+    let expand = |label: &[u8], length: usize| -> Result<SecretBytes> {
+        hpke_core::labeled_expand(
+            cipher_suite.kdf,
+            exporter_secret,
+            &suite_id,
+            label,
+            &[],
+            length,
+            provider,
+        )
+        .map_err(Into::into)
+    };
+    Ok((
+        expand(key_label, aead.key_len())?,
+        expand(nonce_label, aead.nonce_len())?,
+        expand(exporter_label, cipher_suite.kdf.extract_len())?,
+    ))
+}
+
+fn export_array<const N: usize>(exported: Vec<u8>) -> [u8; N] {
+    let exported = zeroize::Zeroizing::new(exported);
+    let mut key = [0u8; N];
+    key.copy_from_slice(&exported);
+    key
+}
+
 /// Create a new HPKE context with default configuration
 pub fn create_hpke_context() -> HpkeContext {
     HpkeContext::new()
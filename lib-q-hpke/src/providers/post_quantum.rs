@@ -17,6 +17,7 @@ use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use lib_q_aead::create_aead;
+use lib_q_core::SecretExportConsent;
 // Use lib-q abstractions instead of direct algorithm coupling
 use lib_q_core::{
     Aead as CoreAead,
@@ -127,7 +128,12 @@ impl KemProvider for PostQuantumProvider {
             .map_err(|e| HpkeError::CryptoError(format!("KEM key generation failed: {}", e)))?;
         Ok((
             keypair.public_key().as_bytes().to_vec(),
-            Zeroizing::new(keypair.secret_key().as_bytes().to_vec()),
+            Zeroizing::new(
+                keypair
+                    .secret_key()
+                    .expose_secret(SecretExportConsent)
+                    .to_vec(),
+            ),
         ))
     }
 
@@ -3,7 +3,10 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
-use crate::error::HpkeError;
+use crate::error::{
+    HpkeError,
+    KdfOperation,
+};
 use crate::security::CryptoRng;
 use crate::types::{
     HpkeAead,
@@ -98,6 +101,32 @@ pub trait KdfProvider {
         output_len: usize,
     ) -> Result<Vec<u8>, HpkeError>;
 
+    /// Squeeze `output_len` bytes from a SHAKE sponge over `prk || info`
+    ///
+    /// Unlike [`expand`](Self::expand), the output length is not absorbed, so a shorter
+    /// output is a prefix of a longer one. Only defined for XOF KDFs ([`HpkeKdf::is_xof`]);
+    /// other KDFs fail with [`KdfOperation::Squeeze`].
+    ///
+    /// The default implementation uses the built-in SHAKE code and never calls
+    /// [`expand`](Self::expand). A provider that overrides `expand` must override `squeeze`
+    /// too, or SHAKE exports will bypass its KDF.
+    fn squeeze(
+        &self,
+        kdf: HpkeKdf,
+        prk: &[u8],
+        info: &[u8],
+        output_len: usize,
+    ) -> Result<Vec<u8>, HpkeError> {
+        if !kdf.is_xof() {
+            return Err(HpkeError::kdf_error(
+                kdf,
+                KdfOperation::Squeeze,
+                "squeeze requires an extendable-output KDF",
+            ));
+        }
+        crate::kdf::HkdfImpl::expand_static(kdf, prk, info, output_len)
+    }
+
     /// Check if the provider supports the given KDF algorithm
     fn supports_kdf(&self, kdf: HpkeKdf) -> bool;
 }
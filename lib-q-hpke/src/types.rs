@@ -86,6 +86,13 @@ impl HpkeKem {
         }
     }
 
+    /// KEM for an RFC 9180 identifier, if this crate implements it
+    pub fn from_algorithm_id(id: u16) -> Option<Self> {
+        [Self::MlKem512, Self::MlKem768, Self::MlKem1024]
+            .into_iter()
+            .find(|kem| kem.algorithm_id() == id)
+    }
+
     /// Shared secret length in bytes
     pub fn shared_secret_len(self) -> usize {
         match self {
@@ -147,6 +154,18 @@ impl HpkeKdf {
         }
     }
 
+    /// KDF for an identifier, if this crate implements it
+    pub fn from_algorithm_id(id: u16) -> Option<Self> {
+        [
+            Self::HkdfShake128,
+            Self::HkdfShake256,
+            Self::HkdfSha3_256,
+            Self::HkdfSha3_512,
+        ]
+        .into_iter()
+        .find(|kdf| kdf.algorithm_id() == id)
+    }
+
     /// Digest output length in bytes
     pub fn digest_len(self) -> usize {
         match self {
@@ -166,6 +185,18 @@ impl HpkeKdf {
             Self::HkdfSha3_512 => 64,
         }
     }
+
+    /// Largest exporter output in bytes, `255 * Nh` (RFC 9180 Section 5.3)
+    pub fn max_export_len(self) -> usize {
+        255 * self.extract_len()
+    }
+
+    /// Whether the KDF is built on an extendable-output function (SHAKE)
+    ///
+    /// XOF KDFs derive exporter output with a single squeeze; see [`crate::hpke_core::export`].
+    pub fn is_xof(self) -> bool {
+        matches!(self, Self::HkdfShake128 | Self::HkdfShake256)
+    }
 }
 
 /// Post-quantum authenticated encryption with associated data
@@ -192,6 +223,18 @@ impl HpkeAead {
         }
     }
 
+    /// AEAD for an identifier, if this crate implements it
+    pub fn from_algorithm_id(id: u16) -> Option<Self> {
+        [
+            Self::Saturnin256,
+            Self::Shake256,
+            Self::DuplexSpongeAead,
+            Self::Export,
+        ]
+        .into_iter()
+        .find(|aead| aead.algorithm_id() == id)
+    }
+
     /// Key length in bytes
     pub fn key_len(self) -> usize {
         match self {
@@ -253,6 +296,72 @@ impl HpkeCipherSuite {
     }
 }
 
+/// Cipher suite named by its wire identifiers (`kem_id`, `kdf_id`, `aead_id`)
+///
+/// This is the form peers exchange and what
+/// [`HpkeContext::with_suite`](crate::HpkeContext::with_suite) accepts; it is checked against
+/// the algorithms this crate and the HPKE crypto provider implement before use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HpkeSuite {
+    /// KEM identifier, see [`HpkeKem::algorithm_id`]
+    pub kem_id: u16,
+    /// KDF identifier, see [`HpkeKdf::algorithm_id`]
+    pub kdf_id: u16,
+    /// AEAD identifier, see [`HpkeAead::algorithm_id`]
+    pub aead_id: u16,
+}
+
+impl HpkeSuite {
+    /// Length of [`to_bytes`](Self::to_bytes)
+    pub const ENCODED_LEN: usize = 6;
+
+    /// Suite from raw identifiers (not validated)
+    pub const fn new(kem_id: u16, kdf_id: u16, aead_id: u16) -> Self {
+        Self {
+            kem_id,
+            kdf_id,
+            aead_id,
+        }
+    }
+
+    /// `kem_id || kdf_id || aead_id`, big-endian, as in [`HpkeCipherSuite::identifier`]
+    pub fn to_bytes(self) -> [u8; Self::ENCODED_LEN] {
+        let [k0, k1] = self.kem_id.to_be_bytes();
+        let [d0, d1] = self.kdf_id.to_be_bytes();
+        let [a0, a1] = self.aead_id.to_be_bytes();
+        [k0, k1, d0, d1, a0, a1]
+    }
+
+    /// Parse the first [`ENCODED_LEN`](Self::ENCODED_LEN) bytes of `bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let [k0, k1, d0, d1, a0, a1] = *bytes.first_chunk::<{ Self::ENCODED_LEN }>()?;
+        Some(Self::new(
+            u16::from_be_bytes([k0, k1]),
+            u16::from_be_bytes([d0, d1]),
+            u16::from_be_bytes([a0, a1]),
+        ))
+    }
+
+    /// The algorithms named by the identifiers, or `None` if any of them is unknown
+    pub fn cipher_suite(self) -> Option<HpkeCipherSuite> {
+        Some(HpkeCipherSuite::new(
+            HpkeKem::from_algorithm_id(self.kem_id)?,
+            HpkeKdf::from_algorithm_id(self.kdf_id)?,
+            HpkeAead::from_algorithm_id(self.aead_id)?,
+        ))
+    }
+}
+
+impl From<HpkeCipherSuite> for HpkeSuite {
+    fn from(suite: HpkeCipherSuite) -> Self {
+        Self::new(
+            suite.kem.algorithm_id(),
+            suite.kdf.algorithm_id(),
+            suite.aead.algorithm_id(),
+        )
+    }
+}
+
 /// HPKE public key
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HpkePublicKey {
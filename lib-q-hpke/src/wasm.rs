@@ -20,7 +20,11 @@ use lib_q_kem::LibQKemProvider;
 use serde::Serialize;
 use spin::Mutex;
 use wasm_bindgen::prelude::*;
-use zeroize::Zeroizing;
+use zeroize::{
+    Zeroize,
+    ZeroizeOnDrop,
+    Zeroizing,
+};
 
 use crate::providers::post_quantum::PostQuantumProvider;
 use crate::providers::traits::HpkeCryptoProvider;
@@ -216,18 +220,23 @@ struct SetupSenderOut {
     sender: SenderWire,
 }
 
-#[derive(Serialize)]
+/// Hex-encoded session secrets, wiped once serialized to JS
+#[derive(Serialize, Zeroize, ZeroizeOnDrop)]
 struct SenderWire {
     encapsulated_key_hex: String,
     shared_secret_hex: String,
     exporter_secret_hex: String,
     key_hex: String,
     nonce_hex: String,
+    #[zeroize(skip)]
     kem: &'static str,
+    #[zeroize(skip)]
     kdf: &'static str,
+    #[zeroize(skip)]
     aead: &'static str,
     sequence_number: u64,
     max_sequence_number: u64,
+    #[zeroize(skip)]
     state: &'static str,
 }
 
@@ -351,17 +360,22 @@ pub fn hpke_sender_seal(sender: JsValue, aad: &[u8], plaintext: &[u8]) -> Result
     serde_wasm_bindgen::to_value(&out).map_err(js_err)
 }
 
-#[derive(Serialize)]
+/// Hex-encoded session secrets, wiped once serialized to JS
+#[derive(Serialize, Zeroize, ZeroizeOnDrop)]
 struct ReceiverWire {
     shared_secret_hex: String,
     exporter_secret_hex: String,
     key_hex: String,
     nonce_hex: String,
+    #[zeroize(skip)]
     kem: &'static str,
+    #[zeroize(skip)]
     kdf: &'static str,
+    #[zeroize(skip)]
     aead: &'static str,
     sequence_number: u64,
     max_sequence_number: u64,
+    #[zeroize(skip)]
     state: &'static str,
 }
 
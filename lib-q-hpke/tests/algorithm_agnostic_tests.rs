@@ -6,6 +6,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::hpke_core::{
     open_with_mode,
@@ -501,7 +502,11 @@ fn test_provider_kem_algorithm_handling() {
 
         // Test decapsulation
         let decapsulated_secret = provider
-            .decapsulate(*kem, recipient_sk.as_bytes(), &encapsulated_key)
+            .decapsulate(
+                *kem,
+                recipient_sk.expose_secret(SecretExportConsent),
+                &encapsulated_key,
+            )
             .expect(&format!("Failed to decapsulate for {:?}", kem));
 
         // Verify shared secrets match
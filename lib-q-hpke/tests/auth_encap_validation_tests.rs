@@ -12,6 +12,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::HpkeKem;
 use lib_q_hpke::providers::KemProvider;
@@ -40,7 +41,9 @@ fn test_auth_encap_auth_decap_authentication() {
     // Test AuthEncap
     let auth_encap_result = hpke_provider.auth_encapsulate(
         HpkeKem::MlKem512,
-        sender_keypair.secret_key().as_bytes(),
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent),
         recipient_keypair.public_key().as_bytes(),
         &mut TestRng::default(),
     );
@@ -52,7 +55,9 @@ fn test_auth_encap_auth_decap_authentication() {
     let auth_decap_result = hpke_provider.auth_decapsulate(
         HpkeKem::MlKem512,
         &encapsulated_key,
-        recipient_keypair.secret_key().as_bytes(),
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent),
         sender_keypair.public_key().as_bytes(),
     );
 
@@ -91,7 +96,9 @@ fn test_auth_decap_fails_with_wrong_sender() {
     let (encapsulated_key, _shared_secret) = hpke_provider
         .auth_encapsulate(
             HpkeKem::MlKem512,
-            sender_keypair.secret_key().as_bytes(),
+            sender_keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent),
             recipient_keypair.public_key().as_bytes(),
             &mut TestRng::default(),
         )
@@ -101,7 +108,9 @@ fn test_auth_decap_fails_with_wrong_sender() {
     let auth_decap_result = hpke_provider.auth_decapsulate(
         HpkeKem::MlKem512,
         &encapsulated_key,
-        recipient_keypair.secret_key().as_bytes(),
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent),
         wrong_sender_keypair.public_key().as_bytes(), // Wrong sender!
     );
 
@@ -132,7 +141,9 @@ fn test_auth_encap_auth_decap_ml_kem_variants() {
     let (encapsulated_key_768, shared_secret_768) = hpke_provider
         .auth_encapsulate(
             HpkeKem::MlKem768,
-            sender_keypair_768.secret_key().as_bytes(),
+            sender_keypair_768
+                .secret_key()
+                .expose_secret(SecretExportConsent),
             recipient_keypair_768.public_key().as_bytes(),
             &mut TestRng::default(),
         )
@@ -142,7 +153,9 @@ fn test_auth_encap_auth_decap_ml_kem_variants() {
         .auth_decapsulate(
             HpkeKem::MlKem768,
             &encapsulated_key_768,
-            recipient_keypair_768.secret_key().as_bytes(),
+            recipient_keypair_768
+                .secret_key()
+                .expose_secret(SecretExportConsent),
             sender_keypair_768.public_key().as_bytes(),
         )
         .expect("AuthDecap should succeed");
@@ -164,7 +177,9 @@ fn test_auth_encap_auth_decap_ml_kem_variants() {
     let (encapsulated_key_1024, shared_secret_1024) = hpke_provider
         .auth_encapsulate(
             HpkeKem::MlKem1024,
-            sender_keypair_1024.secret_key().as_bytes(),
+            sender_keypair_1024
+                .secret_key()
+                .expose_secret(SecretExportConsent),
             recipient_keypair_1024.public_key().as_bytes(),
             &mut TestRng::default(),
         )
@@ -174,7 +189,9 @@ fn test_auth_encap_auth_decap_ml_kem_variants() {
         .auth_decapsulate(
             HpkeKem::MlKem1024,
             &encapsulated_key_1024,
-            recipient_keypair_1024.secret_key().as_bytes(),
+            recipient_keypair_1024
+                .secret_key()
+                .expose_secret(SecretExportConsent),
             sender_keypair_1024.public_key().as_bytes(),
         )
         .expect("AuthDecap should succeed");
@@ -204,7 +221,10 @@ fn test_derive_public_key() {
 
     let derived_public_key = kem_impl
         .derive_public_key(&lib_q_core::KemSecretKey::new(
-            keypair.secret_key().as_bytes().to_vec(),
+            keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
         ))
         .expect("Should be able to derive public key");
 
@@ -237,7 +257,9 @@ fn test_authentication_cryptographic_proof() {
     let (encapsulated_key, shared_secret) = hpke_provider
         .auth_encapsulate(
             HpkeKem::MlKem512,
-            sender_keypair.secret_key().as_bytes(),
+            sender_keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent),
             recipient_keypair.public_key().as_bytes(),
             &mut TestRng::default(),
         )
@@ -269,7 +291,9 @@ fn test_authentication_cryptographic_proof() {
     let auth_decap_result = hpke_provider.auth_decapsulate(
         HpkeKem::MlKem512,
         &encapsulated_key,
-        recipient_keypair.secret_key().as_bytes(),
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent),
         sender_keypair.public_key().as_bytes(),
     );
 
@@ -11,6 +11,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::{
     HpkeContext,
@@ -30,12 +31,22 @@ fn test_authpsk_mode_basic_functionality() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate sender key pair
     let sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Define PSK and PSK ID
     let psk = b"shared-secret-key-32-bytes-long";
@@ -102,12 +113,22 @@ fn test_authpsk_mode_different_psk_values() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate sender key pair
     let sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let test_cases = vec![
         (b"psk-1".as_slice(), b"id-1".as_slice()),
@@ -174,12 +195,22 @@ fn test_authpsk_mode_psk_authentication() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate sender key pair
     let sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let psk = b"correct-psk";
     let psk_id = b"correct-id";
@@ -252,12 +283,22 @@ fn test_authpsk_mode_sender_authentication() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate sender key pair
     let sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate wrong sender key pair
     let wrong_sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
@@ -335,12 +376,22 @@ fn test_authpsk_mode_different_psk_ids() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate sender key pair
     let sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let psk = b"shared-psk";
     let psk_id_1 = b"session-1";
@@ -413,12 +464,22 @@ fn test_authpsk_mode_key_export() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate sender key pair
     let sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let psk = b"export-test-psk";
     let psk_id = b"export-test-id";
@@ -483,12 +544,22 @@ fn test_authpsk_mode_multiple_messages() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate sender key pair
     let sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let psk = b"multi-message-psk";
     let psk_id = b"multi-message-id";
@@ -554,12 +625,22 @@ fn test_authpsk_mode_error_handling() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Generate sender key pair
     let sender_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test with empty PSK
     let _empty_psk_result = hpke_ctx.setup_sender_auth_psk(
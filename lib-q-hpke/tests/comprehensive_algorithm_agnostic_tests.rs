@@ -6,6 +6,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::hpke_core::{
     open_with_mode,
@@ -128,14 +129,17 @@ fn test_algorithm_combination(
             }
         };
 
-    let decapsulated_secret =
-        match provider.decapsulate(kem, recipient_sk.as_bytes(), &encapsulated_key) {
-            Ok(secret) => secret,
-            Err(e) => {
-                println!("    Decapsulation failed: {}", e);
-                return false;
-            }
-        };
+    let decapsulated_secret = match provider.decapsulate(
+        kem,
+        recipient_sk.expose_secret(SecretExportConsent),
+        &encapsulated_key,
+    ) {
+        Ok(secret) => secret,
+        Err(e) => {
+            println!("    Decapsulation failed: {}", e);
+            return false;
+        }
+    };
 
     if shared_secret != decapsulated_secret {
         println!("    Shared secrets don't match");
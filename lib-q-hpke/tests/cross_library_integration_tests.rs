@@ -12,6 +12,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::HpkeContext;
 use lib_q_hpke::types::{
@@ -38,7 +39,12 @@ fn test_hpke_with_ml_kem512() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"Hello, HPKE with ML-KEM-512!";
     let info = b"test-info";
@@ -79,7 +85,12 @@ fn test_hpke_with_ml_kem768() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"Hello, HPKE with ML-KEM-768!";
     let info = b"test-info";
@@ -116,7 +127,12 @@ fn test_hpke_with_hqc128() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"Hello, HPKE with HQC-128!";
     let info = b"test-info";
@@ -153,7 +169,12 @@ fn test_hpke_with_hqc192() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"Hello, HPKE with HQC-192!";
     let info = b"test-info";
@@ -190,7 +211,12 @@ fn test_hpke_with_hqc256() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"Hello, HPKE with HQC-256!";
     let info = b"test-info";
@@ -265,7 +291,12 @@ fn test_hpke_multiple_ml_kem_algorithms() {
             });
 
         let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-        let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+        let recipient_sk = KemSecretKey::new(
+            keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
+        );
 
         let message = format!("Hello, HPKE with {:?}!", algorithm).into_bytes();
         let info = b"test-info";
@@ -303,7 +334,12 @@ fn test_hpke_different_message_sizes() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let info = b"test-info";
     let aad = b"test-aad";
@@ -351,7 +387,12 @@ fn test_hpke_different_aad_sizes() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"Hello, HPKE!";
     let info = b"test-info";
@@ -403,7 +444,12 @@ fn test_hpke_mismatched_keys() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair1.public_key().as_bytes().to_vec());
-    let wrong_sk = KemSecretKey::new(keypair2.secret_key().as_bytes().to_vec());
+    let wrong_sk = KemSecretKey::new(
+        keypair2
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"Hello, HPKE!";
     let info = b"test-info";
@@ -7,6 +7,7 @@ use std::sync::Arc;
 use lib_q_core::{
     Algorithm,
     KemContext,
+    SecretExportConsent,
 };
 use lib_q_hpke::hpke_core::{
     setup_receiver_with_mode,
@@ -55,7 +56,10 @@ fn debug_auth_mode() {
     );
     println!(
         "Recipient SK size: {}",
-        recipient_keypair.secret_key().as_bytes().len()
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len()
     );
     println!(
         "Sender PK size: {}",
@@ -63,7 +67,10 @@ fn debug_auth_mode() {
     );
     println!(
         "Sender SK size: {}",
-        sender_keypair.secret_key().as_bytes().len()
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len()
     );
 
     // Setup sender context with Auth mode
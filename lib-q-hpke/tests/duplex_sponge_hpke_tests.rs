@@ -7,6 +7,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::providers::post_quantum::PostQuantumProvider;
 use lib_q_hpke::providers::traits::AeadProvider;
@@ -63,7 +64,12 @@ fn duplex_sponge_hpke_single_shot_roundtrip() {
         .expect("keygen");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let info = b"duplex-hpke-test";
     let aad = b"aad";
@@ -97,7 +103,12 @@ fn duplex_sponge_hpke_streaming_roundtrip() {
         .expect("keygen");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let info = b"duplex-stream";
     let mut sender = hpke_ctx
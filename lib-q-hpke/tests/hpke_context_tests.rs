@@ -10,6 +10,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::{
     HpkeAead,
@@ -18,6 +19,8 @@ use lib_q_hpke::{
     HpkeKdf,
     HpkeKem,
     HpkeMode,
+    HpkeSuite,
+    REKEY_LABELS,
 };
 use lib_q_kem::LibQKemProvider;
 
@@ -134,7 +137,12 @@ fn test_hpke_single_shot_different_sizes() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test with empty message
     let (enc_key_empty, ciphertext_empty) = hpke_ctx
@@ -187,6 +195,69 @@ fn test_hpke_single_shot_different_sizes() {
     assert_eq!(decrypted_large, large_msg);
 }
 
+/// Suites selected by identifier round-trip and reject ciphertexts from another suite
+#[test]
+fn test_hpke_with_suite_binds_suite_ids() {
+    let suite = HpkeSuite::new(0x0022, 0x0005, 0x0005);
+    let other = HpkeSuite::new(0x0022, 0x0006, 0x0005);
+    let new_ctx = |suite| {
+        let provider = Box::new(LibQKemProvider::new().expect("Failed to create KEM provider"));
+        HpkeContext::with_provider(provider).with_suite(suite)
+    };
+
+    let mut sender = new_ctx(suite).expect("suite should be supported");
+    assert!(sender.supported_suites().contains(&suite));
+    assert!(sender.supported_suites().contains(&other));
+    assert!(matches!(
+        new_ctx(HpkeSuite::new(0x0010, 0x0005, 0x0005)),
+        Err(lib_q_core::Error::UnsupportedAlgorithm { .. })
+    ));
+    assert!(matches!(
+        new_ctx(HpkeSuite::new(0x0022, 0x0005, 0x7777)),
+        Err(lib_q_core::Error::UnsupportedAlgorithm { .. })
+    ));
+
+    let mut kem_ctx = KemContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let keypair = kem_ctx
+        .generate_keypair(Algorithm::MlKem512, None)
+        .expect("Key generation should work");
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
+
+    let (enc, ciphertext) = sender
+        .seal(keypair.public_key(), b"info", b"aad", b"suite-bound")
+        .expect("seal should work");
+    assert_eq!(HpkeSuite::from_bytes(&ciphertext), Some(suite));
+
+    let mut receiver = new_ctx(suite).expect("suite should be supported");
+    let opened = receiver
+        .open(&enc, &recipient_sk, b"info", b"aad", &ciphertext)
+        .expect("matching suite should open");
+    assert_eq!(opened, b"suite-bound");
+
+    let mut mismatched = new_ctx(other).expect("suite should be supported");
+    assert!(matches!(
+        mismatched.open(&enc, &recipient_sk, b"info", b"aad", &ciphertext),
+        Err(lib_q_core::Error::InvalidParameter {
+            parameter: "ciphertext",
+            ..
+        })
+    ));
+    assert!(matches!(
+        receiver.open(&enc, &recipient_sk, b"info", b"aad", &ciphertext[..3]),
+        Err(lib_q_core::Error::InvalidParameter {
+            parameter: "ciphertext",
+            ..
+        })
+    ));
+}
+
 /// Test HPKE with different AAD values
 #[test]
 fn test_hpke_different_aad() {
@@ -201,7 +272,12 @@ fn test_hpke_different_aad() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message = b"test message";
 
@@ -316,6 +392,280 @@ fn test_hpke_key_export() {
     );
 }
 
+/// Exporter output is bounded by `255 * Nh` and available as fixed-size keys
+#[test]
+fn test_hpke_export_length_bound_and_export_key() {
+    let mut hpke_ctx = HpkeContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let mut kem_ctx = KemContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let keypair = kem_ctx
+        .generate_keypair(Algorithm::MlKem512, None)
+        .expect("Key generation should work");
+    let sender_ctx = hpke_ctx
+        .setup_sender(keypair.public_key(), b"export-info")
+        .expect("Sender setup should work");
+    let receiver_ctx = hpke_ctx
+        .setup_receiver(
+            &sender_ctx.encapsulated_key,
+            keypair.secret_key(),
+            b"export-info",
+        )
+        .expect("Receiver setup should work");
+
+    // A fixed-size key matches the variable-length export on both sides.
+    let sender_key: [u8; 32] = sender_ctx.export_key(b"app key").unwrap();
+    let receiver_key = receiver_ctx.export_key::<32>(b"app key").unwrap();
+    assert_eq!(sender_key, receiver_key);
+    assert_eq!(
+        sender_key.as_slice(),
+        sender_ctx.export(b"app key", 32).unwrap()
+    );
+
+    // The default KDF is HKDF-SHAKE256 with Nh = 32, so 8160 bytes is the maximum.
+    let max = HpkeKdf::HkdfShake256.max_export_len();
+    assert_eq!(max, 255 * 32);
+    let exported = receiver_ctx.export(b"bulk", max).unwrap();
+    assert_eq!(exported, sender_ctx.export(b"bulk", max).unwrap());
+
+    for result in [
+        sender_ctx.export(b"bulk", max + 1),
+        receiver_ctx.export(b"bulk", max + 1),
+    ] {
+        assert!(matches!(
+            result,
+            Err(lib_q_core::Error::InvalidMessageSize { max: m, actual })
+                if m == max && actual == max + 1
+        ));
+    }
+    assert!(sender_ctx.export_key::<8161>(b"bulk").is_err());
+}
+
+/// SHAKE256 exports are one squeeze, so shorter outputs are prefixes of longer ones
+#[test]
+fn test_hpke_shake256_export_is_xof() {
+    let mut hpke_ctx = HpkeContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    hpke_ctx.set_cipher_suite(HpkeCipherSuite::new(
+        HpkeKem::MlKem768,
+        HpkeKdf::HkdfShake256,
+        HpkeAead::Saturnin256,
+    ));
+    let mut kem_ctx = KemContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let keypair = kem_ctx
+        .generate_keypair(Algorithm::MlKem768, None)
+        .expect("Key generation should work");
+    let sender_ctx = hpke_ctx
+        .setup_sender(keypair.public_key(), b"xof-info")
+        .expect("Sender setup should work");
+    let receiver_ctx = hpke_ctx
+        .setup_receiver(
+            &sender_ctx.encapsulated_key,
+            keypair.secret_key(),
+            b"xof-info",
+        )
+        .expect("Receiver setup should work");
+
+    let exported_16 = sender_ctx.export(b"xof", 16).unwrap();
+    let exported_64 = sender_ctx.export(b"xof", 64).unwrap();
+    let exported_200 = sender_ctx.export(b"xof", 200).unwrap();
+    assert_eq!(exported_200.len(), 200);
+    assert_eq!(exported_16, exported_200[..16]);
+    assert_eq!(exported_64, exported_200[..64]);
+    assert_eq!(exported_200, receiver_ctx.export(b"xof", 200).unwrap());
+    assert_ne!(
+        exported_64,
+        sender_ctx.export(b"other", 200).unwrap()[..64],
+        "Different contexts should produce different streams"
+    );
+}
+
+/// Rekeying both ends in lockstep keeps them interoperable and retires the old key
+#[test]
+fn test_hpke_rekey_lockstep() {
+    let mut hpke_ctx = HpkeContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let mut kem_ctx = KemContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let keypair = kem_ctx
+        .generate_keypair(Algorithm::MlKem512, None)
+        .expect("Key generation should work");
+
+    let mut sender_ctx = hpke_ctx
+        .setup_sender(keypair.public_key(), b"rekey info")
+        .expect("Sender setup should work");
+    let mut receiver_ctx = hpke_ctx
+        .setup_receiver(
+            sender_ctx.encapsulated_key(),
+            keypair.secret_key(),
+            b"rekey info",
+        )
+        .expect("Receiver setup should work");
+
+    let before = sender_ctx.seal(b"aad", b"message 0").unwrap();
+    assert_eq!(receiver_ctx.open(b"aad", &before).unwrap(), b"message 0");
+    let stale = sender_ctx.seal(b"aad", b"sealed before rekey").unwrap();
+    let export_before = sender_ctx.export(b"app", 32).unwrap();
+    // The rekey labels are not exporter contexts, so exporting under them reveals nothing
+    let exported_under_labels: Vec<Vec<u8>> = REKEY_LABELS
+        .iter()
+        .map(|label| sender_ctx.export(label, sender_ctx.key.len()).unwrap())
+        .collect();
+
+    sender_ctx.rekey().expect("Sender rekey should work");
+    for exported in &exported_under_labels {
+        assert_ne!(sender_ctx.key.as_slice(), exported.as_slice());
+    }
+    receiver_ctx.rekey().expect("Receiver rekey should work");
+    assert_eq!(sender_ctx.sequence_number, 0);
+    assert_eq!(receiver_ctx.sequence_number, 0);
+
+    for i in 0..3u8 {
+        let message = [b'm', i];
+        let ciphertext = sender_ctx.seal(b"aad", &message).unwrap();
+        assert_eq!(receiver_ctx.open(b"aad", &ciphertext).unwrap(), message);
+    }
+    assert_eq!(
+        sender_ctx.export(b"app", 32).unwrap(),
+        receiver_ctx.export(b"app", 32).unwrap()
+    );
+    assert_ne!(sender_ctx.export(b"app", 32).unwrap(), export_before);
+
+    // A pre-rekey ciphertext fails under the new key, even at its original sequence number
+    receiver_ctx.sequence_number = 1;
+    assert!(receiver_ctx.open(b"aad", &stale).is_err());
+
+    // A second rekey ratchets forward rather than repeating the first key
+    let key_after_first = sender_ctx.key.clone();
+    sender_ctx.rekey().unwrap();
+    assert_ne!(sender_ctx.key, key_after_first);
+
+    // Rekeying revives a context that ran out of sequence numbers, but not a closed one
+    sender_ctx.max_sequence_number = 0;
+    assert!(sender_ctx.seal(b"aad", b"too many").is_err());
+    sender_ctx.max_sequence_number = u64::MAX - 1;
+    sender_ctx.rekey().unwrap();
+    assert!(sender_ctx.seal(b"aad", b"revived").is_ok());
+    sender_ctx.close();
+    assert!(sender_ctx.rekey().is_err());
+}
+
+/// One payload encryption, one wrapped content key per recipient
+#[test]
+fn test_hpke_seal_multi_three_recipients() {
+    let mut hpke_ctx = HpkeContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let mut kem_ctx = KemContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let keypairs: Vec<_> = (0..4)
+        .map(|_| {
+            kem_ctx
+                .generate_keypair(Algorithm::MlKem512, None)
+                .expect("Key generation should work")
+        })
+        .collect();
+    let (recipients, outsider) = keypairs.split_at(3);
+    let recipient_pks: Vec<&KemPublicKey> = recipients.iter().map(|kp| kp.public_key()).collect();
+
+    let message = vec![0x5Au8; 4096];
+    let (ciphertext, encapsulated_keys) = hpke_ctx
+        .seal_multi(&recipient_pks, b"multi-info", b"multi-aad", &message)
+        .expect("Multi-recipient seal should work");
+    assert_eq!(encapsulated_keys.len(), 3);
+    // The payload is encrypted once: ciphertext is plaintext plus a single tag.
+    assert!(ciphertext.len() < message.len() + 64);
+
+    for (keypair, encapsulated_key) in recipients.iter().zip(&encapsulated_keys) {
+        let opened = hpke_ctx
+            .open_multi(
+                encapsulated_key,
+                keypair.secret_key(),
+                b"multi-info",
+                b"multi-aad",
+                &ciphertext,
+            )
+            .expect("Each recipient should open");
+        assert_eq!(opened, message);
+    }
+
+    let outsider = &outsider[0];
+    for encapsulated_key in &encapsulated_keys {
+        assert!(
+            hpke_ctx
+                .open_multi(
+                    encapsulated_key,
+                    outsider.secret_key(),
+                    b"multi-info",
+                    b"multi-aad",
+                    &ciphertext,
+                )
+                .is_err()
+        );
+    }
+
+    // Wrong aad, a recipient's entry reused by another, and a malformed entry all fail.
+    assert!(
+        hpke_ctx
+            .open_multi(
+                &encapsulated_keys[0],
+                recipients[0].secret_key(),
+                b"multi-info",
+                b"other-aad",
+                &ciphertext,
+            )
+            .is_err()
+    );
+    assert!(
+        hpke_ctx
+            .open_multi(
+                &encapsulated_keys[1],
+                recipients[0].secret_key(),
+                b"multi-info",
+                b"multi-aad",
+                &ciphertext,
+            )
+            .is_err()
+    );
+    assert!(matches!(
+        hpke_ctx.open_multi(
+            &encapsulated_keys[0][..10],
+            recipients[0].secret_key(),
+            b"multi-info",
+            b"multi-aad",
+            &ciphertext,
+        ),
+        Err(lib_q_core::Error::InvalidCiphertextSize { expected, actual: 10 })
+            if expected == encapsulated_keys[0].len()
+    ));
+    // Right length, but a single segment instead of [enc, wrapped key].
+    let mut single_segment = Vec::new();
+    lib_q_core::codec::write_frame(
+        &mut single_segment,
+        &[&encapsulated_keys[0][lib_q_core::codec::LENGTH_PREFIX_SIZE..]],
+    )
+    .unwrap();
+    assert!(matches!(
+        hpke_ctx.open_multi(
+            &single_segment,
+            recipients[0].secret_key(),
+            b"multi-info",
+            b"multi-aad",
+            &ciphertext,
+        ),
+        Err(lib_q_core::Error::InvalidFormat { .. })
+    ));
+    assert!(hpke_ctx.seal_multi(&[], b"", b"", b"m").is_err());
+}
+
 /// Test HPKE error handling scenarios
 #[test]
 fn test_hpke_error_handling() {
@@ -343,7 +693,12 @@ fn test_hpke_error_handling() {
         .expect("Key generation should work");
 
     let recipient_pk1 = KemPublicKey::new(keypair1.public_key().as_bytes().to_vec());
-    let recipient_sk2 = KemSecretKey::new(keypair2.secret_key().as_bytes().to_vec());
+    let recipient_sk2 = KemSecretKey::new(
+        keypair2
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Encrypt with one key pair
     let (enc_key, ciphertext) = hpke_ctx
@@ -376,7 +731,12 @@ fn test_hpke_sequence_numbers() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Setup contexts
     let mut sender_ctx = hpke_ctx
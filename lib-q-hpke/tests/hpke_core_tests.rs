@@ -10,6 +10,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::providers::post_quantum::PostQuantumProvider;
 use lib_q_hpke::providers::traits::{
@@ -130,6 +131,34 @@ fn test_hpke_labeled_expand() {
     }
 }
 
+/// The default squeeze is XOF-only and reports its own operation otherwise
+#[test]
+fn test_hpke_squeeze_rejects_non_xof_kdf() {
+    use lib_q_hpke::{
+        HpkeError,
+        KdfOperation,
+    };
+
+    let provider = PostQuantumProvider::new();
+    let prk = vec![0x42u8; HpkeKdf::HkdfShake256.digest_len()];
+    let long = provider
+        .squeeze(HpkeKdf::HkdfShake256, &prk, b"info", 64)
+        .expect("SHAKE256 squeeze should work");
+    let short = provider
+        .squeeze(HpkeKdf::HkdfShake256, &prk, b"info", 16)
+        .expect("SHAKE256 squeeze should work");
+    assert_eq!(short, long[..16]);
+
+    let prk = vec![0x42u8; HpkeKdf::HkdfSha3_256.digest_len()];
+    assert!(matches!(
+        provider.squeeze(HpkeKdf::HkdfSha3_256, &prk, b"info", 32),
+        Err(HpkeError::KdfError {
+            operation: KdfOperation::Squeeze,
+            ..
+        })
+    ));
+}
+
 /// Test HPKE nonce computation
 #[test]
 fn test_hpke_nonce_computation() {
@@ -180,7 +209,12 @@ fn test_hpke_message_sealing() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test with different message types
     let large_message = vec![0x42u8; 10000];
@@ -246,7 +280,12 @@ fn test_hpke_different_kems() {
             .expect("Key generation should work");
 
         let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-        let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+        let recipient_sk = KemSecretKey::new(
+            keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
+        );
 
         // Test encryption/decryption
         let message = b"test message";
@@ -390,7 +429,12 @@ fn test_hpke_performance_basic() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test multiple operations to ensure consistency
     let message = b"performance test message";
@@ -428,7 +472,12 @@ fn test_hpke_edge_cases() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test with maximum length inputs
     let max_info = vec![0x42u8; 65536]; // 64KB
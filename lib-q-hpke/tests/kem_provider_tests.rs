@@ -6,6 +6,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::{
     HpkeAead,
@@ -52,7 +53,10 @@ fn test_kem_operations_with_provider() {
         "Public key should not be empty"
     );
     assert!(
-        !keypair.secret_key().as_bytes().is_empty(),
+        !keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .is_empty(),
         "Secret key should not be empty"
     );
 
@@ -63,7 +67,10 @@ fn test_kem_operations_with_provider() {
         "ML-KEM-512 public key should be 800 bytes"
     );
     assert_eq!(
-        keypair.secret_key().as_bytes().len(),
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
         1632,
         "ML-KEM-512 secret key should be 1632 bytes"
     );
@@ -85,7 +92,12 @@ fn test_hpke_operations_with_provider() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let _recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let _recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test single-shot encryption/decryption
     let message = b"Hello, HPKE!";
@@ -128,7 +140,12 @@ fn test_context_based_operations_with_provider() {
         .expect("Key generation should work");
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let _recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let _recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test sender context setup
     let mut sender_ctx = hpke_ctx
@@ -192,21 +209,39 @@ fn test_different_ml_kem_variants() {
         .generate_keypair(Algorithm::MlKem512, None)
         .expect("ML-KEM-512 key generation should work");
     assert_eq!(keypair_512.public_key().as_bytes().len(), 800);
-    assert_eq!(keypair_512.secret_key().as_bytes().len(), 1632);
+    assert_eq!(
+        keypair_512
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
+        1632
+    );
 
     // Test ML-KEM-768
     let keypair_768 = kem_ctx
         .generate_keypair(Algorithm::MlKem768, None)
         .expect("ML-KEM-768 key generation should work");
     assert_eq!(keypair_768.public_key().as_bytes().len(), 1184);
-    assert_eq!(keypair_768.secret_key().as_bytes().len(), 2400);
+    assert_eq!(
+        keypair_768
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
+        2400
+    );
 
     // Test ML-KEM-1024
     let keypair_1024 = kem_ctx
         .generate_keypair(Algorithm::MlKem1024, None)
         .expect("ML-KEM-1024 key generation should work");
     assert_eq!(keypair_1024.public_key().as_bytes().len(), 1568);
-    assert_eq!(keypair_1024.secret_key().as_bytes().len(), 3168);
+    assert_eq!(
+        keypair_1024
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
+        3168
+    );
 }
 
 /// Test cipher suite compatibility with different KEM sizes
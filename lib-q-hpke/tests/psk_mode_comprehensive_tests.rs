@@ -11,6 +11,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::{
     HpkeContext,
@@ -31,7 +32,12 @@ fn test_psk_mode_basic_functionality() {
     ));
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Define PSK and PSK ID
     let psk = b"shared-secret-key-32-bytes-long";
@@ -87,7 +93,12 @@ fn test_psk_mode_different_psk_values() {
     ));
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let test_cases = vec![
         (b"psk-1".as_slice(), b"id-1".as_slice()),
@@ -146,7 +157,12 @@ fn test_psk_mode_authentication() {
     ));
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let psk = b"correct-psk";
     let psk_id = b"correct-id";
@@ -211,7 +227,12 @@ fn test_psk_mode_different_psk_ids() {
     ));
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let psk = b"shared-psk";
     let psk_id_1 = b"session-1";
@@ -275,7 +296,12 @@ fn test_psk_mode_key_export() {
     ));
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let psk = b"export-test-psk";
     let psk_id = b"export-test-id";
@@ -332,7 +358,12 @@ fn test_psk_mode_multiple_messages() {
     ));
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let psk = b"multi-message-psk";
     let psk_id = b"multi-message-id";
@@ -390,7 +421,12 @@ fn test_psk_mode_error_handling() {
     ));
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test with empty PSK
     let empty_psk_result = hpke_ctx.setup_sender_psk(&recipient_pk, b"test info", b"", b"psk-id");
@@ -424,3 +460,116 @@ fn test_psk_mode_error_handling() {
         )
         .expect("Valid PSK receiver setup should succeed");
 }
+
+/// Test PSK mode rejects inconsistent PSK / PSK ID inputs (RFC 9180 §5.1 `VerifyPSKInputs`)
+#[test]
+fn test_psk_mode_rejects_inconsistent_psk_inputs() {
+    let provider = Box::new(LibQKemProvider::new().expect("Failed to create KEM provider"));
+    let mut hpke_ctx = HpkeContext::with_provider(provider);
+
+    // Generate recipient key pair
+    let mut kem_ctx = KemContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
+    let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
+
+    let inconsistent_inputs = [
+        (b"".as_slice(), b"psk-id".as_slice()),
+        (b"psk".as_slice(), b"".as_slice()),
+        (b"".as_slice(), b"".as_slice()),
+    ];
+
+    let sender_ctx = hpke_ctx
+        .setup_sender_psk(&recipient_pk, b"test info", b"psk", b"psk-id")
+        .expect("Valid PSK setup should succeed");
+
+    for (psk, psk_id) in inconsistent_inputs {
+        assert!(
+            hpke_ctx
+                .setup_sender_psk(&recipient_pk, b"test info", psk, psk_id)
+                .is_err(),
+            "Sender setup should reject psk={psk:?}, psk_id={psk_id:?}"
+        );
+        assert!(
+            hpke_ctx
+                .setup_receiver_psk(
+                    sender_ctx.encapsulated_key(),
+                    &recipient_sk,
+                    b"test info",
+                    psk,
+                    psk_id,
+                )
+                .is_err(),
+            "Receiver setup should reject psk={psk:?}, psk_id={psk_id:?}"
+        );
+    }
+}
+
+/// Test PSK mode with RFC 9180 wire format - wrong PSK derives a different key schedule
+#[test]
+fn test_psk_mode_mismatched_psk_rfc9180_wire_format() {
+    let provider = Box::new(LibQKemProvider::new().expect("Failed to create KEM provider"));
+    let mut hpke_ctx = HpkeContext::with_provider(provider);
+    assert_eq!(hpke_ctx.psk_wire_format(), HpkePskWireFormat::Rfc9180);
+
+    // Generate recipient key pair
+    let mut kem_ctx = KemContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
+    let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
+
+    let psk = b"correct-psk";
+    let psk_id = b"correct-id";
+
+    let mut sender_ctx = hpke_ctx
+        .setup_sender_psk(&recipient_pk, b"test info", psk, psk_id)
+        .expect("PSK setup should succeed");
+    let ciphertext = sender_ctx
+        .seal(b"aad", b"Secret message")
+        .expect("Encryption should succeed");
+
+    // Without a commitment suffix the mismatch is only detected when opening
+    let mut wrong_receiver_ctx = hpke_ctx
+        .setup_receiver_psk(
+            sender_ctx.encapsulated_key(),
+            &recipient_sk,
+            b"test info",
+            b"wrong-psk",
+            psk_id,
+        )
+        .expect("RFC 9180 wire format cannot detect a wrong PSK at setup");
+    assert!(
+        wrong_receiver_ctx.open(b"aad", &ciphertext).is_err(),
+        "Decryption with a mismatched PSK should fail"
+    );
+
+    let mut receiver_ctx = hpke_ctx
+        .setup_receiver_psk(
+            sender_ctx.encapsulated_key(),
+            &recipient_sk,
+            b"test info",
+            psk,
+            psk_id,
+        )
+        .expect("Correct PSK should allow setup");
+    assert_eq!(
+        receiver_ctx
+            .open(b"aad", &ciphertext)
+            .expect("Decryption should succeed"),
+        b"Secret message"
+    );
+}
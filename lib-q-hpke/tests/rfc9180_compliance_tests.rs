@@ -13,6 +13,7 @@
 use lib_q_core::{
     Algorithm,
     KemContext,
+    SecretExportConsent,
 };
 use lib_q_hpke::{
     HpkeAead,
@@ -180,7 +181,13 @@ fn test_kem_key_generation() {
 
     // Verify key sizes
     assert_eq!(keypair_512.public_key().as_bytes().len(), 800); // ML-KEM-512 public key size
-    assert_eq!(keypair_512.secret_key().as_bytes().len(), 1632); // ML-KEM-512 secret key size
+    assert_eq!(
+        keypair_512
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
+        1632
+    ); // ML-KEM-512 secret key size
 
     // Test ML-KEM-768 key generation
     let keypair_768 = kem_ctx
@@ -188,7 +195,13 @@ fn test_kem_key_generation() {
         .expect("ML-KEM-768 key generation should work");
 
     assert_eq!(keypair_768.public_key().as_bytes().len(), 1184); // ML-KEM-768 public key size
-    assert_eq!(keypair_768.secret_key().as_bytes().len(), 2400); // ML-KEM-768 secret key size
+    assert_eq!(
+        keypair_768
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
+        2400
+    ); // ML-KEM-768 secret key size
 
     // Test ML-KEM-1024 key generation
     let keypair_1024 = kem_ctx
@@ -196,7 +209,13 @@ fn test_kem_key_generation() {
         .expect("ML-KEM-1024 key generation should work");
 
     assert_eq!(keypair_1024.public_key().as_bytes().len(), 1568); // ML-KEM-1024 public key size
-    assert_eq!(keypair_1024.secret_key().as_bytes().len(), 3168); // ML-KEM-1024 secret key size
+    assert_eq!(
+        keypair_1024
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .len(),
+        3168
+    ); // ML-KEM-1024 secret key size
 }
 
 /// Test KEM encapsulation/decapsulation
@@ -245,8 +264,12 @@ fn test_hpke_single_shot() {
     // Convert to HPKE format
     let recipient_pk =
         lib_q_core::KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk =
-        lib_q_core::KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = lib_q_core::KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test single-shot encryption
     let (encapsulated_key, ciphertext) = hpke_ctx
@@ -294,8 +317,12 @@ fn test_hpke_context_setup() {
     // Convert to HPKE format
     let recipient_pk =
         lib_q_core::KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk =
-        lib_q_core::KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = lib_q_core::KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test sender context setup
     let _sender_ctx = hpke_ctx
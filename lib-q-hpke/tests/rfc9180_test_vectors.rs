@@ -11,6 +11,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::HpkeContext;
 use lib_q_hpke::types::{
@@ -208,7 +209,12 @@ fn test_hpke_rfc9180_compliance() {
 
         let recipient_keypair = kem_ctx.generate_keypair(kem_algorithm, None).unwrap();
         let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-        let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+        let recipient_sk = KemSecretKey::new(
+            recipient_keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
+        );
 
         let sender_keypair = if test_vector.mode.contains("Auth") {
             Some(kem_ctx.generate_keypair(kem_algorithm, None).unwrap())
@@ -223,7 +229,12 @@ fn test_hpke_rfc9180_compliance() {
         };
 
         let sender_sk = if let Some(ref keypair) = sender_keypair {
-            Some(KemSecretKey::new(keypair.secret_key().as_bytes().to_vec()))
+            Some(KemSecretKey::new(
+                keypair
+                    .secret_key()
+                    .expose_secret(SecretExportConsent)
+                    .to_vec(),
+            ))
         } else {
             None
         };
@@ -420,7 +431,12 @@ fn test_hpke_error_handling() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test with invalid encapsulated key
     let invalid_encapsulated_key = vec![0u8; 100]; // Wrong size
@@ -433,8 +449,12 @@ fn test_hpke_error_handling() {
 
     // Test with wrong recipient key
     let wrong_recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
-    let wrong_recipient_sk =
-        KemSecretKey::new(wrong_recipient_keypair.secret_key().as_bytes().to_vec());
+    let wrong_recipient_sk = KemSecretKey::new(
+        wrong_recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let mut sender_ctx = hpke_ctx.setup_sender(&recipient_pk, b"info").unwrap();
     let wrong_receiver_result =
@@ -471,7 +491,12 @@ fn test_hpke_message_sizes() {
     ));
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     let message_sizes = vec![0, 1, 16, 64, 256, 1024, 4096];
 
@@ -0,0 +1,130 @@
+//! Session secrets are wiped before their heap buffers are released
+//!
+//! A wrapping global allocator inspects the tracked buffers at `dealloc` time, so these tests
+//! observe the real drop path of sender and receiver contexts rather than calling `zeroize`
+//! by hand.
+
+#![cfg(feature = "std")]
+
+use std::alloc::{
+    GlobalAlloc,
+    Layout,
+    System,
+};
+use std::sync::atomic::{
+    AtomicU8,
+    AtomicUsize,
+    Ordering,
+};
+
+use lib_q_core::{
+    Algorithm,
+    KemContext,
+    KemPublicKey,
+};
+use lib_q_hpke::HpkeContext;
+use lib_q_kem::LibQKemProvider;
+
+const SLOTS: usize = 4;
+const PENDING: u8 = 0;
+const FREED_ZEROED: u8 = 1;
+const FREED_DIRTY: u8 = 2;
+
+static TRACKED: [AtomicUsize; SLOTS] = [const { AtomicUsize::new(0) }; SLOTS];
+static STATUS: [AtomicU8; SLOTS] = [const { AtomicU8::new(PENDING) }; SLOTS];
+
+/// Records whether each tracked buffer held only zero bytes when it was freed
+struct InspectingAllocator;
+
+unsafe impl GlobalAlloc for InspectingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        for (tracked, status) in TRACKED.iter().zip(&STATUS) {
+            if tracked
+                .compare_exchange(ptr as usize, 0, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                // SAFETY: the block is still allocated with `layout` until `System.dealloc`.
+                let bytes = unsafe { core::slice::from_raw_parts(ptr, layout.size()) };
+                let outcome = if bytes.iter().all(|&b| b == 0) {
+                    FREED_ZEROED
+                } else {
+                    FREED_DIRTY
+                };
+                status.store(outcome, Ordering::SeqCst);
+            }
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: InspectingAllocator = InspectingAllocator;
+
+/// Track up to [`SLOTS`] live buffers; one test at a time may use the slots.
+fn track(buffers: [&[u8]; SLOTS]) {
+    for ((buffer, tracked), status) in buffers.iter().zip(&TRACKED).zip(&STATUS) {
+        assert!(!buffer.is_empty(), "empty buffers own no allocation");
+        status.store(PENDING, Ordering::SeqCst);
+        tracked.store(buffer.as_ptr() as usize, Ordering::SeqCst);
+    }
+}
+
+fn statuses() -> [u8; SLOTS] {
+    core::array::from_fn(|i| STATUS[i].load(Ordering::SeqCst))
+}
+
+#[test]
+fn sender_and_receiver_secrets_are_cleared_on_drop() {
+    // A plain `Vec` is released as-is, so the hook does see stale bytes.
+    let plain = [vec![0xA5u8; 32], vec![1], vec![2], vec![3]];
+    track([&plain[0], &plain[1], &plain[2], &plain[3]]);
+    drop(plain);
+    assert_eq!(statuses(), [FREED_DIRTY; SLOTS]);
+
+    let mut kem_ctx = KemContext::with_provider(Box::new(
+        LibQKemProvider::new().expect("Failed to create KEM provider"),
+    ));
+    let keypair = kem_ctx
+        .generate_keypair(Algorithm::MlKem512, None)
+        .expect("ML-KEM-512 key generation should work");
+    let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
+
+    let mut hpke = HpkeContext::new();
+    let mut sender = hpke
+        .setup_sender(&recipient_pk, b"zeroize info")
+        .expect("sender setup");
+    let ciphertext = sender.seal(b"aad", b"secret message").expect("seal");
+    let mut receiver = hpke
+        .setup_receiver(
+            sender.encapsulated_key(),
+            keypair.secret_key(),
+            b"zeroize info",
+        )
+        .expect("receiver setup");
+    assert_eq!(
+        receiver.open(b"aad", &ciphertext).expect("open"),
+        b"secret message"
+    );
+
+    track([
+        &sender.shared_secret,
+        &sender.exporter_secret,
+        &sender.key,
+        &sender.nonce,
+    ]);
+    drop(sender);
+    assert_eq!(statuses(), [FREED_ZEROED; SLOTS], "sender secrets");
+
+    track([
+        &receiver.shared_secret,
+        &receiver.exporter_secret,
+        &receiver.key,
+        &receiver.nonce,
+    ]);
+    drop(receiver);
+    assert_eq!(statuses(), [FREED_ZEROED; SLOTS], "receiver secrets");
+}
@@ -6,6 +6,7 @@
 use lib_q_core::{
     Algorithm,
     KemContext,
+    SecretExportConsent,
 };
 use lib_q_hpke::providers::KemProvider;
 use lib_q_hpke::security::CryptoRng;
@@ -97,7 +98,9 @@ fn test_auth_encap_auth_decap_fixes() {
     // Test AuthEncap
     let auth_encap_result = hpke_provider.auth_encapsulate(
         HpkeKem::MlKem512,
-        sender_keypair.secret_key().as_bytes(),
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent),
         recipient_keypair.public_key().as_bytes(),
         &mut lib_q_hpke::security::prng::SimpleRng::new(),
     );
@@ -119,7 +122,9 @@ fn test_auth_encap_auth_decap_fixes() {
     let auth_decap_result = hpke_provider.auth_decapsulate(
         HpkeKem::MlKem512,
         &auth_encapsulated_key.0,
-        recipient_keypair.secret_key().as_bytes(),
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent),
         sender_keypair.public_key().as_bytes(),
     );
 
@@ -16,6 +16,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use lib_q_hpke::providers::{
     AeadProvider,
@@ -51,9 +52,19 @@ fn test_authentication_implementation_security() {
         .expect("Recipient key generation should work");
 
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test Auth mode setup
     let mut hpke_ctx = HpkeContext::with_provider(Box::new(
@@ -522,9 +533,19 @@ fn test_comprehensive_security_properties() {
     let recipient_keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
 
     let sender_pk = KemPublicKey::new(sender_keypair.public_key().as_bytes().to_vec());
-    let sender_sk = KemSecretKey::new(sender_keypair.secret_key().as_bytes().to_vec());
+    let sender_sk = KemSecretKey::new(
+        sender_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
     let recipient_pk = KemPublicKey::new(recipient_keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(recipient_keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        recipient_keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test all HPKE modes
     let modes = vec![
@@ -604,7 +625,12 @@ fn test_performance_security_tradeoffs() {
     let keypair = kem_ctx.generate_keypair(Algorithm::MlKem512, None).unwrap();
 
     let recipient_pk = KemPublicKey::new(keypair.public_key().as_bytes().to_vec());
-    let recipient_sk = KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Test performance with different message sizes
     let message_sizes = vec![16, 64, 256, 1024, 4096, 16384];
@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 
+use lib_q_core::SecretExportConsent;
 use lib_q_hpke::providers::post_quantum::PostQuantumProvider;
 use lib_q_hpke::providers::traits::HpkeCryptoProvider;
 use lib_q_hpke::{
@@ -377,7 +378,12 @@ fn test_setup_receiver_with_cipher_suite() {
     let keypair = kem_ctx
         .generate_keypair(lib_q_core::Algorithm::MlKem512, None)
         .expect("Key generation should work");
-    let recipient_sk = lib_q_core::KemSecretKey::new(keypair.secret_key().as_bytes().to_vec());
+    let recipient_sk = lib_q_core::KemSecretKey::new(
+        keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec(),
+    );
 
     // Create a dummy encapsulated key (in real usage, this would come from sender)
     let encapsulated_key = vec![0u8; 768]; // ML-KEM-512 encapsulated key size
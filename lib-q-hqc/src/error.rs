@@ -227,14 +227,16 @@ impl From<HqcError> for lib_q_core::Error {
             HqcError::DecryptionFailed => {
                 #[cfg(feature = "alloc")]
                 {
-                    lib_q_core::Error::DecryptionFailed {
-                        operation: String::from("HQC decapsulation"),
+                    lib_q_core::Error::DecapsulationFailed {
+                        operation: String::from("HQC decoding"),
+                        retriable: true,
                     }
                 }
                 #[cfg(not(feature = "alloc"))]
                 {
-                    lib_q_core::Error::DecryptionFailed {
-                        operation: "HQC decapsulation".into(),
+                    lib_q_core::Error::DecapsulationFailed {
+                        operation: "HQC decoding",
+                        retriable: true,
                     }
                 }
             }
@@ -483,7 +485,8 @@ impl From<lib_q_core::Error> for HqcError {
             }
             // Note: InvalidPublicKeySize and InvalidSecretKeySize don't exist in lib-q-core
             // They are mapped to InvalidKeySize in the forward direction
-            lib_q_core::Error::DecryptionFailed { .. } => HqcError::DecryptionFailed,
+            lib_q_core::Error::DecryptionFailed { .. } |
+            lib_q_core::Error::DecapsulationFailed { .. } => HqcError::DecryptionFailed,
             lib_q_core::Error::EncryptionFailed { .. } => HqcError::EncryptionFailed,
             lib_q_core::Error::KeyGenerationFailed { .. } => HqcError::KeyGenerationFailed,
             lib_q_core::Error::RandomGenerationFailed { .. } => HqcError::RandomGenerationFailed,
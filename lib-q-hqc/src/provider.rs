@@ -51,6 +51,34 @@ fn kem_encapsulation_rng(randomness: Option<&[u8]>) -> Result<lib_q_random::LibQ
     }
 }
 
+/// Map an HQC decapsulation error to a lib-Q error
+///
+/// HQC decryption fails with a small but non-negligible probability even for honestly
+/// generated ciphertexts; when the error-correcting code cannot decode, the failure is
+/// reported as a retriable [`Error::DecapsulationFailed`] so callers can tell it apart from
+/// internal errors and ask the sender for a fresh encapsulation.
+#[cfg(all(feature = "alloc", feature = "random"))]
+fn decapsulation_error(algorithm: &str, error: crate::hqc_correct::HqcError) -> Error {
+    use crate::hqc_correct::HqcError;
+    use crate::hqc_kem::HqcKemError;
+    use crate::hqc_pke::HqcPkeError;
+
+    match error {
+        HqcError::DecryptionFailed |
+        HqcError::KemError(HqcKemError::DecryptionFailed) |
+        HqcError::KemError(HqcKemError::PkeError(
+            HqcPkeError::DecryptionFailed | HqcPkeError::CodeError(_),
+        )) => Error::DecapsulationFailed {
+            operation: format!("{algorithm} decoding"),
+            retriable: true,
+        },
+        other => Error::InternalError {
+            operation: format!("{algorithm} decapsulation"),
+            details: format!("Failed to decapsulate {algorithm}: {other:?}"),
+        },
+    }
+}
+
 /// HQC provider for libQ integration
 #[derive(Debug, Clone, PartialEq)]
 pub struct LibQHqcProvider;
@@ -271,13 +299,8 @@ impl KemOperations for LibQHqcProvider {
                 let kem_ct = HqcKemCiphertext::new(c_pke, *salt);
                 let ct = Hqc1Ciphertext::new(kem_ct);
 
-                let shared_secret =
-                    Hqc1::decapsulate::<lib_q_random::LibQRng>(&sk, &ct).map_err(|e| {
-                        Error::InternalError {
-                            operation: String::from("HQC-128 decapsulation"),
-                            details: format!("Failed to decapsulate HQC-128: {:?}", e),
-                        }
-                    })?;
+                let shared_secret = Hqc1::decapsulate::<lib_q_random::LibQRng>(&sk, &ct)
+                    .map_err(|e| decapsulation_error("HQC-128", e))?;
                 Ok(Vec::from(shared_secret.as_bytes()))
             }
             Algorithm::Hqc192 => {
@@ -321,13 +344,8 @@ impl KemOperations for LibQHqcProvider {
                 let kem_ct = HqcKemCiphertext::new(c_pke, *salt);
                 let ct = Hqc3Ciphertext::new(kem_ct);
 
-                let shared_secret =
-                    Hqc3::decapsulate::<lib_q_random::LibQRng>(&sk, &ct).map_err(|e| {
-                        Error::InternalError {
-                            operation: String::from("HQC-192 decapsulation"),
-                            details: format!("Failed to decapsulate HQC-192: {:?}", e),
-                        }
-                    })?;
+                let shared_secret = Hqc3::decapsulate::<lib_q_random::LibQRng>(&sk, &ct)
+                    .map_err(|e| decapsulation_error("HQC-192", e))?;
                 Ok(Vec::from(shared_secret.as_bytes()))
             }
             Algorithm::Hqc256 => {
@@ -371,13 +389,8 @@ impl KemOperations for LibQHqcProvider {
                 let kem_ct = HqcKemCiphertext::new(c_pke, *salt);
                 let ct = Hqc5Ciphertext::new(kem_ct);
 
-                let shared_secret =
-                    Hqc5::decapsulate::<lib_q_random::LibQRng>(&sk, &ct).map_err(|e| {
-                        Error::InternalError {
-                            operation: String::from("HQC-256 decapsulation"),
-                            details: format!("Failed to decapsulate HQC-256: {:?}", e),
-                        }
-                    })?;
+                let shared_secret = Hqc5::decapsulate::<lib_q_random::LibQRng>(&sk, &ct)
+                    .map_err(|e| decapsulation_error("HQC-256", e))?;
                 Ok(Vec::from(shared_secret.as_bytes()))
             }
             _ => Err(Error::InvalidAlgorithm {
@@ -487,6 +500,37 @@ mod tests {
 
     use super::*;
 
+    #[cfg(all(feature = "alloc", feature = "random"))]
+    #[test]
+    fn test_decoding_failure_maps_to_retriable_decapsulation_failure() {
+        use crate::concatenated_code::ConcatenatedCodeError;
+        use crate::hqc_correct::HqcError;
+        use crate::hqc_kem::HqcKemError;
+        use crate::hqc_pke::HqcPkeError;
+
+        let decoding = HqcError::KemError(HqcKemError::PkeError(HqcPkeError::CodeError(
+            ConcatenatedCodeError::DecodingFailed,
+        )));
+        assert!(matches!(
+            decapsulation_error("HQC-128", decoding),
+            Error::DecapsulationFailed {
+                retriable: true,
+                ..
+            }
+        ));
+        assert!(matches!(
+            decapsulation_error("HQC-256", HqcError::DecryptionFailed),
+            Error::DecapsulationFailed {
+                retriable: true,
+                ..
+            }
+        ));
+        assert!(matches!(
+            decapsulation_error("HQC-192", HqcError::KemError(HqcKemError::HashError)),
+            Error::InternalError { .. }
+        ));
+    }
+
     #[test]
     fn test_derive_public_key_hqc128() {
         let provider = LibQHqcProvider::new().expect("Failed to create provider");
@@ -13,6 +13,7 @@ use lib_q_core::{
     KemContext,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 use wasm_bindgen::prelude::*;
 use zeroize::Zeroizing;
@@ -55,7 +56,7 @@ pub fn hqc_keygen(param: &str) -> Result<JsValue, JsValue> {
         .map_err(|e: lib_q_core::Error| js_err(e))?;
     let out = serde_json::json!({
         "publicKey": hex::encode(kp.public_key().as_bytes()),
-        "secretKey": hex::encode(kp.secret_key().as_bytes()),
+        "secretKey": hex::encode(kp.secret_key().expose_secret(SecretExportConsent)),
     });
     serde_wasm_bindgen::to_value(&out).map_err(js_err)
 }
@@ -0,0 +1,134 @@
+//! Runtime selection of the batched Keccak permutation
+//!
+//! [`permute_parallel`] takes any number of independent states and drives them through the
+//! widest permutation the running CPU supports: eight at a time on AVX-512, four at a time on
+//! AVX2, and one at a time otherwise. The CPU check is the one [`p1600x4`] and [`p1600x8`]
+//! make themselves: compile-time `target_feature` flags, or runtime detection under `std`.
+//! Every path computes exactly the same result as calling [`p1600`] on each state, so the
+//! choice only affects speed.
+//!
+//! There is no batched NEON permutation, so aarch64 always takes the scalar path; there
+//! [`p1600`] still uses the ARMv8 SHA3 instructions when the `arm64_sha3` feature is enabled.
+
+use crate::{
+    LaneSize,
+    PLEN,
+    avx2_available,
+    avx512f_available,
+    p1600,
+    p1600x4,
+    p1600x8,
+};
+
+/// Batched permutation backend chosen by [`permute_parallel`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParallelBackend {
+    /// One state at a time with [`p1600`]
+    Scalar,
+    /// Four states per AVX2 permutation ([`p1600x4`])
+    Avx2x4,
+    /// Eight states per AVX-512 permutation ([`p1600x8`])
+    Avx512x8,
+}
+
+/// Backend [`permute_parallel`] uses on this machine
+///
+/// This is the backend [`p1600x8`] and [`p1600x4`] select themselves, so a batch is never
+/// handed to a permutation that would fall back to scalar.
+pub fn parallel_backend() -> ParallelBackend {
+    if avx512f_available() {
+        ParallelBackend::Avx512x8
+    } else if avx2_available() {
+        ParallelBackend::Avx2x4
+    } else {
+        ParallelBackend::Scalar
+    }
+}
+
+/// Apply Keccak-f\[1600\] to every state, batching on the best available backend
+pub fn permute_parallel(states: &mut [[u64; PLEN]]) {
+    permute_parallel_rounds(states, u64::KECCAK_F_ROUND_COUNT);
+}
+
+/// Apply Keccak-p\[1600, `round_count`\] to every state, batching on the best available backend
+///
+/// `round_count` follows the same convention as [`p1600`]. States left over after the
+/// widest batches are processed with the narrower ones and finally one by one.
+pub fn permute_parallel_rounds(states: &mut [[u64; PLEN]], round_count: usize) {
+    let backend = parallel_backend();
+    let mut rest = states;
+
+    if backend == ParallelBackend::Avx512x8 {
+        let (batches, tail) = rest.as_chunks_mut::<8>();
+        for batch in batches {
+            p1600x8(batch, round_count);
+        }
+        rest = tail;
+    }
+    if backend != ParallelBackend::Scalar {
+        let (batches, tail) = rest.as_chunks_mut::<4>();
+        for batch in batches {
+            p1600x4(batch, round_count);
+        }
+        rest = tail;
+    }
+    for state in rest {
+        p1600(state, round_count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states(count: usize) -> [[u64; PLEN]; 13] {
+        let mut states = [[0u64; PLEN]; 13];
+        for (i, state) in states.iter_mut().take(count).enumerate() {
+            for (j, lane) in state.iter_mut().enumerate() {
+                *lane = ((i as u64) << 32 | j as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            }
+        }
+        states
+    }
+
+    #[test]
+    fn dispatched_permutation_matches_scalar() {
+        // 13 states exercise an 8-wide batch, a 4-wide batch and a scalar tail.
+        for count in [0, 1, 4, 5, 8, 13] {
+            for round_count in [12, 24] {
+                let mut dispatched = states(count);
+                permute_parallel_rounds(&mut dispatched[..count], round_count);
+
+                let mut scalar = states(count);
+                for state in &mut scalar[..count] {
+                    p1600(state, round_count);
+                }
+                assert_eq!(dispatched, scalar, "{count} states, {round_count} rounds");
+            }
+        }
+    }
+
+    #[test]
+    fn permute_parallel_is_keccak_f() {
+        let mut dispatched = states(5);
+        permute_parallel(&mut dispatched[..5]);
+        let mut expected = states(5);
+        for state in &mut expected[..5] {
+            crate::f1600(state);
+        }
+        assert_eq!(dispatched, expected);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", target_arch = "x86_64", not(cross_compile)))]
+    fn backend_follows_detected_features() {
+        let expected = if std::arch::is_x86_feature_detected!("avx512f") {
+            ParallelBackend::Avx512x8
+        } else if std::arch::is_x86_feature_detected!("avx2") {
+            ParallelBackend::Avx2x4
+        } else {
+            ParallelBackend::Scalar
+        };
+        assert_eq!(parallel_backend(), expected);
+    }
+}
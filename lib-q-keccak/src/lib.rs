@@ -145,6 +145,71 @@ mod x86_simd_avx512;
 #[cfg(all(feature = "simd", keccak_portable_simd))]
 mod advanced_simd;
 
+// CPU checks behind `p1600x4`/`p1600x8`, shared with `dispatch`: a compile-time
+// `target_feature` guarantee, otherwise runtime detection under `std`, otherwise no SIMD.
+
+/// Whether [`p1600x4`] runs on AVX2
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2", not(cross_compile)))]
+#[inline]
+pub(crate) fn avx2_available() -> bool {
+    true
+}
+
+/// Whether [`p1600x4`] runs on AVX2
+#[cfg(all(
+    target_arch = "x86_64",
+    feature = "std",
+    not(target_feature = "avx2"),
+    not(cross_compile)
+))]
+#[inline]
+pub(crate) fn avx2_available() -> bool {
+    std::arch::is_x86_feature_detected!("avx2")
+}
+
+/// Whether [`p1600x4`] runs on AVX2
+#[cfg(not(all(
+    target_arch = "x86_64",
+    not(cross_compile),
+    any(target_feature = "avx2", feature = "std")
+)))]
+#[inline]
+pub(crate) fn avx2_available() -> bool {
+    false
+}
+
+/// Whether [`p1600x8`] runs on AVX-512F
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f", not(cross_compile)))]
+#[inline]
+pub(crate) fn avx512f_available() -> bool {
+    true
+}
+
+/// Whether [`p1600x8`] runs on AVX-512F
+#[cfg(all(
+    target_arch = "x86_64",
+    feature = "std",
+    not(target_feature = "avx512f"),
+    not(cross_compile)
+))]
+#[inline]
+pub(crate) fn avx512f_available() -> bool {
+    std::arch::is_x86_feature_detected!("avx512f")
+}
+
+/// Whether [`p1600x8`] runs on AVX-512F
+#[cfg(not(all(
+    target_arch = "x86_64",
+    not(cross_compile),
+    any(target_feature = "avx512f", feature = "std")
+)))]
+#[inline]
+pub(crate) fn avx512f_available() -> bool {
+    false
+}
+
+/// Runtime selection of the batched permutation
+pub mod dispatch;
 mod features;
 mod optimized_core;
 
@@ -318,38 +383,20 @@ pub fn f1600(state: &mut [u64; PLEN]) {
 ///
 /// `round_count` follows the same convention as [`p1600`]: e.g. `24` for
 /// Keccak-f\[1600\], `12` for the TurboSHAKE/K12 reduced-round permutation.
-// The compile-time-AVX2 branch's `return` mirrors the runtime branch for symmetry/readability
-// and is only flagged `needless` in the all-compile-time-SIMD cfg (where the scalar fallback below
-// is `cfg`-excluded, making it the last statement). Suppress rather than break that symmetry.
-#[allow(clippy::needless_return)]
 pub fn p1600x4(states: &mut [[u64; PLEN]; 4], round_count: usize) {
-    // Compile-time AVX2 (e.g. `-C target-cpu=native`): sound without any runtime
-    // check, and no `std` required.
-    #[cfg(all(target_arch = "x86_64", target_feature = "avx2", not(cross_compile)))]
-    {
-        // SAFETY: the target is compiled with AVX2 enabled.
-        unsafe { x86_simd::p1600x4_avx2(states, round_count) };
-        return;
-    }
-
-    // Otherwise, detect AVX2 at runtime when `std` is available.
+    // `avx2_available` is a constant `true` under a compile-time `avx2` target feature, so the
+    // scalar fallback below is optimised out there and no `std` is required.
     #[cfg(all(
         target_arch = "x86_64",
-        feature = "std",
-        not(target_feature = "avx2"),
-        not(cross_compile)
+        not(cross_compile),
+        any(target_feature = "avx2", feature = "std")
     ))]
-    {
-        if std::arch::is_x86_feature_detected!("avx2") {
-            // SAFETY: `avx2` was just confirmed available at runtime.
-            unsafe { x86_simd::p1600x4_avx2(states, round_count) };
-            return;
-        }
+    if avx2_available() {
+        // SAFETY: AVX2 is enabled at compile time or was just confirmed at runtime.
+        unsafe { x86_simd::p1600x4_avx2(states, round_count) };
+        return;
     }
 
-    // Scalar fallback (omitted when AVX2 is a compile-time guarantee, where the
-    // first branch already returned).
-    #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2", not(cross_compile))))]
     for state in states.iter_mut() {
         p1600(state, round_count);
     }
@@ -369,35 +416,19 @@ pub fn p1600x4(states: &mut [[u64; PLEN]; 4], round_count: usize) {
 /// AVX-512 is absent on many consumer CPUs (and all AMD Zen 1–3). The batched XOF
 /// helpers therefore default to [`p1600x4`]; reach for `p1600x8` only where AVX-512
 /// is expected and has been validated on the target hardware.
-// See `p1600x4`: the compile-time-AVX-512 branch's `return` is only `needless` in the
-// all-compile-time-SIMD cfg (scalar fallback `cfg`-excluded); suppressed for symmetry/readability.
-#[allow(clippy::needless_return)]
 pub fn p1600x8(states: &mut [[u64; PLEN]; 8], round_count: usize) {
-    // Compile-time AVX-512 (e.g. `-C target-cpu=native` on an AVX-512 host).
-    #[cfg(all(target_arch = "x86_64", target_feature = "avx512f", not(cross_compile)))]
-    {
-        // SAFETY: the target is compiled with AVX-512F enabled.
-        unsafe { x86_simd_avx512::p1600x8_avx512(states, round_count) };
-        return;
-    }
-
-    // Otherwise, detect AVX-512F at runtime when `std` is available.
+    // As in `p1600x4`, the check folds to `true` under a compile-time `avx512f` target feature.
     #[cfg(all(
         target_arch = "x86_64",
-        feature = "std",
-        not(target_feature = "avx512f"),
-        not(cross_compile)
+        not(cross_compile),
+        any(target_feature = "avx512f", feature = "std")
     ))]
-    {
-        if std::arch::is_x86_feature_detected!("avx512f") {
-            // SAFETY: `avx512f` was just confirmed available at runtime.
-            unsafe { x86_simd_avx512::p1600x8_avx512(states, round_count) };
-            return;
-        }
+    if avx512f_available() {
+        // SAFETY: AVX-512F is enabled at compile time or was just confirmed at runtime.
+        unsafe { x86_simd_avx512::p1600x8_avx512(states, round_count) };
+        return;
     }
 
-    // Scalar fallback (omitted when AVX-512 is a compile-time guarantee).
-    #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f", not(cross_compile))))]
     for state in states.iter_mut() {
         p1600(state, round_count);
     }
@@ -0,0 +1,135 @@
+//! Classic McEliece (CB-KEM) implementation for the legacy `Kem` trait and `create_kem()` API.
+//!
+//! This module delegates all operations to `lib_q_cb_kem::LibQCbKemProvider`. `lib-q-cb-kem`
+//! selects its parameter set at compile time and defaults to `cbkem348864`, so only
+//! `CbKem348864Impl` is exposed here.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use lib_q_cb_kem::LibQCbKemProvider;
+pub use lib_q_cb_kem::{
+    CRYPTO_CIPHERTEXTBYTES as CB_KEM_CIPHERTEXT_SIZE,
+    CRYPTO_PUBLICKEYBYTES as CB_KEM_PUBLIC_KEY_SIZE,
+    CRYPTO_SECRETKEYBYTES as CB_KEM_SECRET_KEY_SIZE,
+};
+use lib_q_core::{
+    Algorithm,
+    Error,
+    Kem,
+    KemKeypair,
+    KemOperations,
+    KemPublicKey,
+    KemSecretKey,
+    Primitive,
+    Result,
+};
+
+fn provider() -> Result<LibQCbKemProvider> {
+    LibQCbKemProvider::new()
+}
+
+/// CB-KEM-348864 KEM implementation (NIST Level 1). Delegates to `LibQCbKemProvider`.
+///
+/// Public keys are [`CB_KEM_PUBLIC_KEY_SIZE`] (261120) bytes; keep them off the stack.
+#[derive(Debug, Clone, Default)]
+pub struct CbKem348864Impl;
+
+impl CbKem348864Impl {
+    /// Create a new CB-KEM-348864 instance.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Primitive for CbKem348864Impl {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::CbKem348864
+    }
+}
+
+impl Kem for CbKem348864Impl {
+    fn generate_keypair(&self) -> Result<KemKeypair> {
+        provider()?.generate_keypair(Algorithm::CbKem348864, None)
+    }
+
+    fn encapsulate(&self, public_key: &KemPublicKey) -> Result<(Vec<u8>, Vec<u8>)> {
+        provider()?.encapsulate(Algorithm::CbKem348864, public_key, None)
+    }
+
+    fn decapsulate(&self, secret_key: &KemSecretKey, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        provider()?.decapsulate(Algorithm::CbKem348864, secret_key, ciphertext)
+    }
+
+    fn derive_public_key(&self, secret_key: &KemSecretKey) -> Result<KemPublicKey> {
+        provider()?.derive_public_key(Algorithm::CbKem348864, secret_key)
+    }
+
+    fn auth_encapsulate(
+        &self,
+        _sender_sk: &KemSecretKey,
+        _recipient_pk: &KemPublicKey,
+    ) -> Result<(Vec<u8>, Vec<u8>)> {
+        Err(Error::NotImplemented {
+            feature: "CB-KEM authenticated encapsulation - use HPKE AuthEncap instead".to_string(),
+        })
+    }
+
+    fn auth_decapsulate(
+        &self,
+        _recipient_sk: &KemSecretKey,
+        _ciphertext: &[u8],
+        _sender_pk: &KemPublicKey,
+    ) -> Result<Vec<u8>> {
+        Err(Error::NotImplemented {
+            feature: "CB-KEM authenticated decapsulation - use HPKE AuthDecap instead".to_string(),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "cb-kem", feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cb_kem_348864_sizes_and_roundtrip() {
+        let kem = CbKem348864Impl::new();
+        let keypair = kem.generate_keypair().unwrap();
+        assert_eq!(keypair.public_key.data.len(), 261_120);
+        assert_eq!(keypair.public_key.data.len(), CB_KEM_PUBLIC_KEY_SIZE);
+        assert_eq!(keypair.secret_key.data.len(), CB_KEM_SECRET_KEY_SIZE);
+
+        let (ciphertext, shared_secret) = kem.encapsulate(&keypair.public_key).unwrap();
+        assert_eq!(ciphertext.len(), CB_KEM_CIPHERTEXT_SIZE);
+        assert_eq!(shared_secret.len(), 32);
+
+        let recovered = kem.decapsulate(&keypair.secret_key, &ciphertext).unwrap();
+        assert_eq!(recovered, shared_secret);
+
+        let boxed = crate::create_kem("CB-KEM-348864").unwrap();
+        let (ciphertext, shared_secret) = boxed.encapsulate(&keypair.public_key).unwrap();
+        assert_eq!(
+            boxed.decapsulate(&keypair.secret_key, &ciphertext).unwrap(),
+            shared_secret
+        );
+    }
+
+    #[test]
+    fn test_cb_kem_unsupported_operations() {
+        let kem = CbKem348864Impl::new();
+        let secret_key = KemSecretKey::new(Vec::new());
+        let public_key = KemPublicKey::new(Vec::new());
+        assert!(matches!(
+            kem.derive_public_key(&secret_key),
+            Err(Error::UnsupportedOperation { .. })
+        ));
+        assert!(matches!(
+            kem.auth_encapsulate(&secret_key, &public_key),
+            Err(Error::NotImplemented { .. })
+        ));
+    }
+}
@@ -3,6 +3,12 @@
 //! This module delegates all operations to `lib_q_hqc::LibQHqcProvider`. The three
 //! types (`Hqc128Impl`, `Hqc192Impl`, `Hqc256Impl`) are algorithm-specific wrappers
 //! that forward to the provider with a fixed algorithm.
+//!
+//! HQC has a small but non-negligible decryption failure rate. A ciphertext the
+//! error-correcting code cannot decode makes `decapsulate` return
+//! `Error::DecapsulationFailed { retriable: true, .. }` rather than panic; on a legitimately
+//! formed ciphertext this is astronomically rare but possible, and a fresh encapsulation
+//! will almost certainly succeed. `has_decryption_failures` returns true for all three.
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -19,6 +25,7 @@ use lib_q_core::{
     KemOperations,
     KemPublicKey,
     KemSecretKey,
+    Primitive,
     Result,
 };
 use lib_q_hqc::LibQHqcProvider;
@@ -38,6 +45,12 @@ impl Hqc128Impl {
     }
 }
 
+impl Primitive for Hqc128Impl {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Hqc128
+    }
+}
+
 impl Kem for Hqc128Impl {
     fn generate_keypair(&self) -> Result<KemKeypair> {
         provider()?.generate_keypair(Algorithm::Hqc128, None)
@@ -57,6 +70,10 @@ impl Kem for Hqc128Impl {
         provider()?.derive_public_key(Algorithm::Hqc128, secret_key)
     }
 
+    fn has_decryption_failures(&self) -> bool {
+        true
+    }
+
     fn auth_encapsulate(
         &self,
         _sender_sk: &KemSecretKey,
@@ -90,6 +107,12 @@ impl Hqc192Impl {
     }
 }
 
+impl Primitive for Hqc192Impl {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Hqc192
+    }
+}
+
 impl Kem for Hqc192Impl {
     fn generate_keypair(&self) -> Result<KemKeypair> {
         provider()?.generate_keypair(Algorithm::Hqc192, None)
@@ -109,6 +132,10 @@ impl Kem for Hqc192Impl {
         provider()?.derive_public_key(Algorithm::Hqc192, secret_key)
     }
 
+    fn has_decryption_failures(&self) -> bool {
+        true
+    }
+
     fn auth_encapsulate(
         &self,
         _sender_sk: &KemSecretKey,
@@ -142,6 +169,12 @@ impl Hqc256Impl {
     }
 }
 
+impl Primitive for Hqc256Impl {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Hqc256
+    }
+}
+
 impl Kem for Hqc256Impl {
     fn generate_keypair(&self) -> Result<KemKeypair> {
         provider()?.generate_keypair(Algorithm::Hqc256, None)
@@ -161,6 +194,10 @@ impl Kem for Hqc256Impl {
         provider()?.derive_public_key(Algorithm::Hqc256, secret_key)
     }
 
+    fn has_decryption_failures(&self) -> bool {
+        true
+    }
+
     fn auth_encapsulate(
         &self,
         _sender_sk: &KemSecretKey,
@@ -203,6 +240,37 @@ mod tests {
         out
     }
 
+    #[test]
+    fn test_hqc_into_buffers_use_default_copy() {
+        let kem = Hqc128Impl::new();
+        let keypair = kem.generate_keypair().unwrap();
+        let mut ciphertext = vec![0u8; Algorithm::Hqc128.ciphertext_len().unwrap()];
+        let mut shared_secret = [0u8; 32];
+        kem.encapsulate_into(&keypair.public_key, &mut ciphertext, &mut shared_secret)
+            .unwrap();
+
+        let mut decapsulated = [0u8; 32];
+        kem.decapsulate_into(&keypair.secret_key, &ciphertext, &mut decapsulated)
+            .unwrap();
+        assert_eq!(decapsulated, shared_secret);
+
+        assert!(matches!(
+            kem.encapsulate_into(
+                &keypair.public_key,
+                &mut ciphertext[1..],
+                &mut shared_secret
+            ),
+            Err(Error::InvalidCiphertextSize { .. })
+        ));
+        assert!(matches!(
+            kem.decapsulate_into(&keypair.secret_key, &ciphertext, &mut decapsulated[..31]),
+            Err(Error::InvalidKeySize {
+                expected: 32,
+                actual: 31
+            })
+        ));
+    }
+
     #[test]
     fn test_hqc_impl_constructors_and_roundtrip() {
         let provider = LibQHqcProvider::new().expect("LibQHqcProvider");
@@ -256,6 +324,13 @@ mod tests {
         assert_eq!(derived256.data, kp256.public_key.data);
     }
 
+    #[test]
+    fn test_hqc_reports_decryption_failures() {
+        assert!(Hqc128Impl::new().has_decryption_failures());
+        assert!(Hqc192Impl::new().has_decryption_failures());
+        assert!(Hqc256Impl::new().has_decryption_failures());
+    }
+
     #[test]
     fn test_hqc_auth_methods_not_implemented() {
         let hqc128 = Hqc128Impl::new();
@@ -0,0 +1,316 @@
+//! Hybrid X25519 + ML-KEM-768 key encapsulation
+//!
+//! Runs X25519 and ML-KEM-768 side by side and combines both shared secrets into a
+//! single 32-byte key, so the result stays secure as long as either component does.
+//! Following the draft hybrid combiner, the final secret is
+//!
+//! ```text
+//! SHAKE256(label || ss_mlkem || ss_x25519 || ct_mlkem || ct_x25519, 32)
+//! ```
+//!
+//! with `label` = [`LABEL_HYBRID_X25519_MLKEM768`](lib_q_core::labels::LABEL_HYBRID_X25519_MLKEM768).
+//!
+//! Wire layouts (ML-KEM component first):
+//!
+//! | Value       | Layout                                        | Bytes |
+//! |-------------|-----------------------------------------------|-------|
+//! | Public key  | ML-KEM-768 encapsulation key ‖ X25519 public  | 1216  |
+//! | Secret key  | ML-KEM-768 decapsulation key ‖ X25519 secret  | 2432  |
+//! | Ciphertext  | ML-KEM-768 ciphertext ‖ X25519 ephemeral key  | 1120  |
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::ToString,
+    vec::Vec,
+};
+
+use lib_q_core::labels::{
+    LABEL_HYBRID_X25519_MLKEM768,
+    hash_with_label,
+};
+use lib_q_core::{
+    Error,
+    Kem,
+    KemKeypair,
+    KemPublicKey,
+    KemSecretKey,
+    SecretExportConsent,
+};
+use lib_q_ml_kem::{
+    MLKEM768_CIPHERTEXT_SIZE,
+    MLKEM768_PUBLIC_KEY_SIZE,
+    MLKEM768_SECRET_KEY_SIZE,
+    Zeroizing,
+};
+use lib_q_random::{
+    SecureRng,
+    new_secure_rng,
+};
+use lib_q_sha3::Shake256;
+use x25519_dalek::{
+    PublicKey,
+    StaticSecret,
+};
+
+use crate::ml_kem::MlKem768Impl;
+
+/// X25519 public key, secret key and ephemeral key size in bytes
+pub const X25519_KEY_SIZE: usize = 32;
+
+/// Hybrid public key size: ML-KEM-768 encapsulation key followed by the X25519 public key
+pub const X25519_MLKEM768_PUBLIC_KEY_SIZE: usize = MLKEM768_PUBLIC_KEY_SIZE + X25519_KEY_SIZE;
+
+/// Hybrid secret key size: ML-KEM-768 decapsulation key followed by the X25519 secret key
+pub const X25519_MLKEM768_SECRET_KEY_SIZE: usize = MLKEM768_SECRET_KEY_SIZE + X25519_KEY_SIZE;
+
+/// Hybrid ciphertext size: ML-KEM-768 ciphertext followed by the X25519 ephemeral public key
+pub const X25519_MLKEM768_CIPHERTEXT_SIZE: usize = MLKEM768_CIPHERTEXT_SIZE + X25519_KEY_SIZE;
+
+/// Combined shared secret size in bytes
+pub const X25519_MLKEM768_SHARED_SECRET_SIZE: usize = 32;
+
+/// Hybrid X25519 + ML-KEM-768 KEM
+#[derive(Debug, Clone, Default)]
+pub struct X25519MlKem768Impl {
+    ml_kem: MlKem768Impl,
+}
+
+impl X25519MlKem768Impl {
+    /// Create a new hybrid X25519 + ML-KEM-768 instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Draw a fresh X25519 secret scalar from the secure RNG
+fn random_x25519_secret() -> Result<StaticSecret, Error> {
+    let mut rng = new_secure_rng().map_err(|e| Error::RandomGenerationFailed {
+        operation: format!("Failed to create secure RNG: {}", e),
+    })?;
+    let mut bytes = Zeroizing::new([0u8; X25519_KEY_SIZE]);
+    rng.fill_bytes_secure(bytes.as_mut_slice())
+        .map_err(|e| Error::RandomGenerationFailed {
+            operation: format!("X25519 secret generation: {}", e),
+        })?;
+    Ok(StaticSecret::from(*bytes))
+}
+
+/// Parse a 32-byte X25519 value
+fn x25519_bytes(slice: &[u8]) -> Result<Zeroizing<[u8; X25519_KEY_SIZE]>, Error> {
+    let mut bytes = Zeroizing::new([0u8; X25519_KEY_SIZE]);
+    if slice.len() != X25519_KEY_SIZE {
+        return Err(Error::InvalidKeySize {
+            expected: X25519_KEY_SIZE,
+            actual: slice.len(),
+        });
+    }
+    bytes.copy_from_slice(slice);
+    Ok(bytes)
+}
+
+/// X25519 Diffie-Hellman, rejecting the all-zero output produced by low-order points
+fn x25519_shared_secret(
+    secret: &StaticSecret,
+    public: &[u8],
+) -> Result<Zeroizing<[u8; X25519_KEY_SIZE]>, Error> {
+    let public = PublicKey::from(*x25519_bytes(public)?);
+    let shared = secret.diffie_hellman(&public);
+    if !shared.was_contributory() {
+        return Err(Error::InvalidKey {
+            key_type: "X25519".to_string(),
+            reason: "low-order public key".to_string(),
+        });
+    }
+    Ok(Zeroizing::new(shared.to_bytes()))
+}
+
+/// Combine the component shared secrets and ciphertexts into the final key
+fn combine(ss_mlkem: &[u8], ss_x25519: &[u8], ct_mlkem: &[u8], ct_x25519: &[u8]) -> Vec<u8> {
+    hash_with_label::<Shake256>(
+        LABEL_HYBRID_X25519_MLKEM768,
+        &[ss_mlkem, ss_x25519, ct_mlkem, ct_x25519],
+        X25519_MLKEM768_SHARED_SECRET_SIZE,
+    )
+}
+
+impl Kem for X25519MlKem768Impl {
+    fn generate_keypair(&self) -> Result<KemKeypair, Error> {
+        let ml_kem = self.ml_kem.generate_keypair()?;
+        let x25519_secret = random_x25519_secret()?;
+        let x25519_public = PublicKey::from(&x25519_secret);
+
+        let mut public_key = Vec::with_capacity(X25519_MLKEM768_PUBLIC_KEY_SIZE);
+        public_key.extend_from_slice(ml_kem.public_key.as_bytes());
+        public_key.extend_from_slice(x25519_public.as_bytes());
+
+        let mut secret_key = Vec::with_capacity(X25519_MLKEM768_SECRET_KEY_SIZE);
+        secret_key.extend_from_slice(ml_kem.secret_key.expose_secret(SecretExportConsent));
+        secret_key.extend_from_slice(x25519_secret.as_bytes());
+
+        Ok(KemKeypair {
+            public_key: KemPublicKey { data: public_key },
+            secret_key: KemSecretKey { data: secret_key },
+        })
+    }
+
+    fn encapsulate(&self, public_key: &KemPublicKey) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        if public_key.data.len() != X25519_MLKEM768_PUBLIC_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: X25519_MLKEM768_PUBLIC_KEY_SIZE,
+                actual: public_key.data.len(),
+            });
+        }
+        let (pk_mlkem, pk_x25519) = public_key.data.split_at(MLKEM768_PUBLIC_KEY_SIZE);
+
+        let (ct_mlkem, ss_mlkem) = self.ml_kem.encapsulate(&KemPublicKey {
+            data: pk_mlkem.to_vec(),
+        })?;
+        let ss_mlkem = Zeroizing::new(ss_mlkem);
+
+        let ephemeral = random_x25519_secret()?;
+        let ct_x25519 = PublicKey::from(&ephemeral);
+        let ss_x25519 = x25519_shared_secret(&ephemeral, pk_x25519)?;
+
+        let shared_secret = combine(&ss_mlkem, &*ss_x25519, &ct_mlkem, ct_x25519.as_bytes());
+
+        let mut ciphertext = ct_mlkem;
+        ciphertext.extend_from_slice(ct_x25519.as_bytes());
+        Ok((ciphertext, shared_secret))
+    }
+
+    fn decapsulate(&self, secret_key: &KemSecretKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+        if secret_key.data.len() != X25519_MLKEM768_SECRET_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: X25519_MLKEM768_SECRET_KEY_SIZE,
+                actual: secret_key.data.len(),
+            });
+        }
+        if ciphertext.len() != X25519_MLKEM768_CIPHERTEXT_SIZE {
+            return Err(Error::InvalidCiphertextSize {
+                expected: X25519_MLKEM768_CIPHERTEXT_SIZE,
+                actual: ciphertext.len(),
+            });
+        }
+        let (sk_mlkem, sk_x25519) = secret_key.data.split_at(MLKEM768_SECRET_KEY_SIZE);
+        let (ct_mlkem, ct_x25519) = ciphertext.split_at(MLKEM768_CIPHERTEXT_SIZE);
+
+        // ML-KEM decapsulation uses implicit rejection: a tampered ciphertext yields an
+        // unrelated secret, which the combiner then turns into an unrelated final key.
+        let ss_mlkem = Zeroizing::new(self.ml_kem.decapsulate(
+            &KemSecretKey {
+                data: sk_mlkem.to_vec(),
+            },
+            ct_mlkem,
+        )?);
+
+        let x25519_secret = StaticSecret::from(*x25519_bytes(sk_x25519)?);
+        let ss_x25519 = x25519_shared_secret(&x25519_secret, ct_x25519)?;
+
+        Ok(combine(&ss_mlkem, &*ss_x25519, ct_mlkem, ct_x25519))
+    }
+
+    fn derive_public_key(&self, secret_key: &KemSecretKey) -> Result<KemPublicKey, Error> {
+        if secret_key.data.len() != X25519_MLKEM768_SECRET_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: X25519_MLKEM768_SECRET_KEY_SIZE,
+                actual: secret_key.data.len(),
+            });
+        }
+        let (sk_mlkem, sk_x25519) = secret_key.data.split_at(MLKEM768_SECRET_KEY_SIZE);
+
+        let mut public_key = self
+            .ml_kem
+            .derive_public_key(&KemSecretKey {
+                data: sk_mlkem.to_vec(),
+            })?
+            .data;
+        let x25519_secret = StaticSecret::from(*x25519_bytes(sk_x25519)?);
+        public_key.extend_from_slice(PublicKey::from(&x25519_secret).as_bytes());
+
+        Ok(KemPublicKey { data: public_key })
+    }
+
+    fn auth_encapsulate(
+        &self,
+        _sender_sk: &KemSecretKey,
+        _recipient_pk: &KemPublicKey,
+    ) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        Err(Error::NotImplemented {
+            feature: "X25519-ML-KEM-768 authenticated encapsulation - use HPKE AuthEncap instead"
+                .to_string(),
+        })
+    }
+
+    fn auth_decapsulate(
+        &self,
+        _recipient_sk: &KemSecretKey,
+        _ciphertext: &[u8],
+        _sender_pk: &KemPublicKey,
+    ) -> Result<Vec<u8>, Error> {
+        Err(Error::NotImplemented {
+            feature: "X25519-ML-KEM-768 authenticated decapsulation - use HPKE AuthDecap instead"
+                .to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hybrid_round_trip() {
+        let kem = X25519MlKem768Impl::new();
+        let keypair = kem.generate_keypair().unwrap();
+        assert_eq!(
+            keypair.public_key.as_bytes().len(),
+            X25519_MLKEM768_PUBLIC_KEY_SIZE
+        );
+        assert_eq!(
+            keypair.secret_key.expose_secret(SecretExportConsent).len(),
+            X25519_MLKEM768_SECRET_KEY_SIZE
+        );
+
+        let (ciphertext, shared_secret) = kem.encapsulate(&keypair.public_key).unwrap();
+        assert_eq!(ciphertext.len(), X25519_MLKEM768_CIPHERTEXT_SIZE);
+        assert_eq!(shared_secret.len(), X25519_MLKEM768_SHARED_SECRET_SIZE);
+
+        let decapsulated = kem.decapsulate(&keypair.secret_key, &ciphertext).unwrap();
+        assert_eq!(shared_secret, decapsulated);
+
+        let derived = kem.derive_public_key(&keypair.secret_key).unwrap();
+        assert_eq!(derived.as_bytes(), keypair.public_key.as_bytes());
+    }
+
+    #[test]
+    fn test_hybrid_rejects_corrupted_ml_kem_half() {
+        let kem = X25519MlKem768Impl::new();
+        let keypair = kem.generate_keypair().unwrap();
+        let (mut ciphertext, shared_secret) = kem.encapsulate(&keypair.public_key).unwrap();
+
+        // Leave the X25519 ephemeral key intact so only the post-quantum half is wrong.
+        // ML-KEM rejects implicitly, so decapsulation succeeds with an unrelated secret.
+        ciphertext[0] ^= 0x01;
+        let decapsulated = kem.decapsulate(&keypair.secret_key, &ciphertext).unwrap();
+        assert_ne!(shared_secret, decapsulated);
+    }
+
+    #[test]
+    fn test_hybrid_rejects_wrong_sizes() {
+        let kem = X25519MlKem768Impl::new();
+        let keypair = kem.generate_keypair().unwrap();
+
+        let short_pk = KemPublicKey {
+            data: keypair.public_key.as_bytes()[..MLKEM768_PUBLIC_KEY_SIZE].to_vec(),
+        };
+        assert!(matches!(
+            kem.encapsulate(&short_pk),
+            Err(Error::InvalidKeySize { .. })
+        ));
+        assert!(matches!(
+            kem.decapsulate(&keypair.secret_key, &[0u8; MLKEM768_CIPHERTEXT_SIZE]),
+            Err(Error::InvalidCiphertextSize { .. })
+        ));
+    }
+}
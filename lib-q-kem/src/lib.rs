@@ -15,6 +15,9 @@
 //! ## Supported Algorithms
 //!
 //! - **ML-KEM**: CRYSTALS-ML-KEM (Levels 1, 3, 4)
+//! - **X25519-ML-KEM-768**: hybrid classical + post-quantum KEM (`hybrid` feature)
+//! - **HQC**: code-based KEM, HQC-128/192/256 (`hqc` feature)
+//! - **CB-KEM-348864**: Classic McEliece, code-based KEM with 261120-byte public keys (`cb-kem` feature)
 //!
 //! ## Feature Support
 //!
@@ -60,7 +63,7 @@
 //!     ctx.set_provider(Box::new(LibQKemProvider::new()?));
 //!
 //!     // Provide randomness externally (required in no_std environments)
-//!     let key_randomness = [0u8; 32]; // Get from hardware RNG
+//!     let key_randomness = [0u8; 64]; // FIPS 203 seed d ‖ z from a hardware RNG
 //!
 //!     // Generate keypair with external randomness
 //!     let keypair = ctx.generate_keypair(Algorithm::MlKem512, Some(&key_randomness))?;
@@ -82,6 +85,9 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
 // Re-export core types for public use
 pub use lib_q_core::{
     Algorithm,
@@ -102,10 +108,18 @@ pub mod provider;
 // Algorithm implementations
 #[cfg(feature = "ml-kem")]
 pub mod ml_kem;
+#[cfg(feature = "ml-kem")]
+pub use ml_kem::validate_encapsulation_key;
 
 #[cfg(feature = "hqc")]
 pub mod hqc;
 
+#[cfg(feature = "cb-kem")]
+pub mod cb_kem;
+
+#[cfg(feature = "hybrid")]
+pub mod hybrid;
+
 // Re-export provider
 #[cfg(feature = "alloc")]
 pub use provider::LibQKemProvider;
@@ -179,6 +193,14 @@ pub fn create_kem(algorithm: &str) -> Result<Box<dyn Kem>> {
         #[cfg(feature = "ml-kem")]
         "ml-kem-1024" | "ML-KEM-1024" => Ok(Box::new(ml_kem::MlKem1024Impl::default())),
 
+        #[cfg(feature = "hybrid")]
+        "x25519-ml-kem-768" | "X25519-ML-KEM-768" => {
+            Ok(Box::new(hybrid::X25519MlKem768Impl::default()))
+        }
+
+        #[cfg(feature = "cb-kem")]
+        "CB-KEM-348864" | "cb-kem-348864" => Ok(Box::new(cb_kem::CbKem348864Impl)),
+
         #[cfg(feature = "hqc")]
         "HQC-128" | "hqc-128" => Ok(Box::new(hqc::Hqc128Impl)),
         #[cfg(feature = "hqc")]
@@ -204,6 +226,35 @@ pub fn create_kem_context(algorithm: Algorithm) -> Result<KemContext> {
     Ok(KemContext::new())
 }
 
+/// Encapsulate to every public key in `pk_list` with `kem`
+///
+/// Returns the `(ciphertext, shared_secret)` pairs in input order. Each element is an
+/// independent [`Kem::encapsulate`] call with its own fresh randomness. With the `rayon`
+/// feature the list is spread across the rayon thread pool; otherwise it is processed
+/// sequentially.
+///
+/// # Errors
+///
+/// Returns an encapsulation error if any key fails, e.g. when it has the wrong size for `kem`.
+/// With the `rayon` feature, which error is reported when several keys fail is unspecified.
+#[cfg(feature = "alloc")]
+pub fn encapsulate_batch<K>(kem: &K, pk_list: &[KemPublicKey]) -> Result<Vec<(Vec<u8>, Vec<u8>)>>
+where
+    K: Kem + Sync + ?Sized,
+{
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        pk_list.par_iter().map(|pk| kem.encapsulate(pk)).collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        pk_list.iter().map(|pk| kem.encapsulate(pk)).collect()
+    }
+}
+
 /// WASM-friendly wrapper for KEM operations
 #[cfg(feature = "wasm")]
 pub mod wasm {
@@ -2,6 +2,10 @@
 //!
 //! This module provides a clean, secure implementation of ML-KEM that
 //! eliminates the deprecated API usage and custom authentication issues.
+//!
+//! Keys, ciphertexts and shared secrets are the FIPS 203 encodings produced by `lib-q-ml-kem`
+//! (`ByteEncode`, little-endian bit order within each coefficient) and are copied through
+//! unchanged, so they can be exchanged freely with code using `lib-q-ml-kem` directly.
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -13,20 +17,26 @@ use alloc::{
 };
 
 use lib_q_core::{
+    Algorithm,
     Error,
     Kem,
     KemKeypair,
     KemPublicKey,
     KemSecretKey,
+    Primitive,
     SecurityLevel,
+    check_kem_output_len,
+    check_shared_secret_len,
 };
 use lib_q_ml_kem::array::Array;
 use lib_q_ml_kem::{
     ArraySize,
+    Ciphertext,
     Decapsulate,
     Encapsulate,
     EncodedSizeUser,
     KemCore,
+    MLKEM_SHARED_KEY_SIZE,
     MLKEM512_CIPHERTEXT_SIZE,
     MLKEM512_PUBLIC_KEY_SIZE,
     MLKEM512_SECRET_KEY_SIZE,
@@ -39,10 +49,19 @@ use lib_q_ml_kem::{
     MlKem512,
     MlKem768,
     MlKem1024,
+    Seed,
     Zeroizing,
 };
+#[cfg(feature = "deterministic")]
+use lib_q_ml_kem::{
+    B32,
+    EncapsulateDeterministic,
+};
 use lib_q_random::new_secure_rng;
 
+/// Size of the FIPS 203 key-generation seed `d ‖ z`
+pub const KEYGEN_SEED_SIZE: usize = 64;
+
 /// Copy a [`Zeroizing`] ML-KEM wire encoding (e.g. from [`EncodedSizeUser::as_bytes`]) into a `Vec`.
 #[inline]
 fn kem_zeroizing_encoding_to_vec<S: ArraySize>(encoded: Zeroizing<Array<u8, S>>) -> Vec<u8> {
@@ -72,6 +91,56 @@ fn secure_array_from_slice<const N: usize>(slice: &[u8]) -> Result<Zeroizing<[u8
     Ok(array)
 }
 
+/// Copy a 64-byte key-generation seed into a [`Zeroizing`] [`Seed`].
+fn keygen_seed(seed: &[u8]) -> Result<Zeroizing<Seed>, Error> {
+    let seed = Seed::try_from(seed).map_err(|_| Error::InvalidRandomnessSize {
+        expected: KEYGEN_SEED_SIZE,
+        actual: seed.len(),
+    })?;
+    Ok(Zeroizing::new(seed))
+}
+
+fn non_canonical_encapsulation_key() -> Error {
+    Error::InvalidKey {
+        key_type: "ML-KEM encapsulation key".to_string(),
+        reason: "coefficient not reduced modulo q (FIPS 203 modulus check)".to_string(),
+    }
+}
+
+/// A ciphertext that cannot be decoded for the parameter set; never retriable, since
+/// re-sending the same bytes fails the same way
+fn malformed_ciphertext() -> Error {
+    Error::DecapsulationFailed {
+        operation: "ML-KEM ciphertext decoding".to_string(),
+        retriable: false,
+    }
+}
+
+/// Check that `public_key` is a valid ML-KEM encapsulation key for `algorithm`
+///
+/// Performs the FIPS 203 §7.2 encapsulation key checks (length and modulus check) that
+/// [`Kem::encapsulate`] applies before encrypting, so keys received from a peer can be
+/// rejected up front. The checks are those of [`KemPublicKey::from_bytes_checked`].
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAlgorithm`] if `algorithm` is not an ML-KEM parameter set,
+/// [`Error::InvalidKeySize`] if the key has the wrong length, and [`Error::InvalidKey`] if a
+/// coefficient is not reduced modulo `q`.
+pub fn validate_encapsulation_key(
+    public_key: &KemPublicKey,
+    algorithm: Algorithm,
+) -> Result<(), Error> {
+    match algorithm {
+        Algorithm::MlKem512 | Algorithm::MlKem768 | Algorithm::MlKem1024 => {
+            KemPublicKey::from_bytes_checked(public_key.as_bytes(), algorithm).map(drop)
+        }
+        _ => Err(Error::InvalidAlgorithm {
+            algorithm: "Encapsulation key validation requires an ML-KEM algorithm",
+        }),
+    }
+}
+
 /// ML-KEM 512 implementation (FIPS 203 Level 1)
 #[derive(Debug, Clone)]
 pub struct MlKem512Impl {
@@ -92,6 +161,108 @@ impl MlKem512Impl {
     pub fn security_level(&self) -> SecurityLevel {
         self.security_level
     }
+
+    /// Derive the key pair from a 64-byte FIPS 203 key-generation seed `d ‖ z`
+    ///
+    /// The same seed always yields the same key pair.
+    pub fn generate_keypair_from_seed(&self, seed: &[u8]) -> Result<KemKeypair, Error> {
+        let seed = keygen_seed(seed)?;
+        let (dk, ek) = MlKem512::generate_from_seed(&seed);
+
+        Ok(KemKeypair {
+            public_key: KemPublicKey {
+                data: kem_zeroizing_encoding_to_vec(ek.as_bytes()),
+            },
+            secret_key: KemSecretKey {
+                data: kem_zeroizing_encoding_to_vec(dk.as_bytes()),
+            },
+        })
+    }
+
+    /// Decode an encapsulation key, applying the FIPS 203 §7.2 input checks
+    ///
+    /// Rejects keys of the wrong length and keys whose 12-bit coefficients are not reduced
+    /// modulo `q` (the "modulus check").
+    fn encapsulation_key(
+        public_key: &KemPublicKey,
+    ) -> Result<<MlKem512 as KemCore>::EncapsulationKey, Error> {
+        if public_key.data.len() != MLKEM512_PUBLIC_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: MLKEM512_PUBLIC_KEY_SIZE,
+                actual: public_key.data.len(),
+            });
+        }
+        let encoded =
+            Array::try_from(public_key.data.as_slice()).map_err(|_| Error::InvalidKeyFormat)?;
+        <MlKem512 as KemCore>::EncapsulationKey::try_from_bytes(&encoded)
+            .map_err(|_| non_canonical_encapsulation_key())
+    }
+
+    /// Check sizes and decode the decapsulation key and ciphertext
+    fn decapsulation_inputs(
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<
+        (
+            <MlKem512 as KemCore>::DecapsulationKey,
+            Ciphertext<MlKem512>,
+        ),
+        Error,
+    > {
+        // Validate secret key size
+        if secret_key.data.len() != MLKEM512_SECRET_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: MLKEM512_SECRET_KEY_SIZE,
+                actual: secret_key.data.len(),
+            });
+        }
+
+        // Use secure array conversion with proper error handling
+        let dk_array = secure_array_from_slice::<{ MLKEM512_SECRET_KEY_SIZE }>(&secret_key.data)?;
+        let dk = <MlKem512 as KemCore>::DecapsulationKey::from_bytes(
+            &Array::try_from(dk_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
+        );
+        let ct = Array::try_from(ciphertext).map_err(|_| malformed_ciphertext())?;
+        Ok((dk, ct))
+    }
+
+    /// Decapsulate and report whether the FIPS 203 re-encryption check passed
+    ///
+    /// The shared secret is the same one [`Kem::decapsulate`] returns, including the
+    /// implicit-rejection secret for a ciphertext that fails the check. The flag is computed
+    /// separately and without constant-time guarantees, so this is only for tests and
+    /// diagnostics; production code must use [`Kem::decapsulate`].
+    #[cfg(feature = "testing")]
+    pub fn decapsulate_checked(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<(Vec<u8>, bool), Error> {
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        let (shared_secret, accepted) = dk.decapsulate_checked(&ct);
+        Ok((kem_array_soft_zero_to_vec(shared_secret), accepted))
+    }
+
+    /// The implicit-rejection secret [`Kem::decapsulate`] returns for a rejected `ciphertext`
+    ///
+    /// This is the FIPS 203 pseudo-random key `J(z ‖ c)`, keyed by the secret `z` stored in the
+    /// decapsulation key. Negative tests can assert that decapsulating a tampered ciphertext
+    /// yields exactly this value instead of the real shared secret.
+    #[cfg(feature = "testing")]
+    pub fn decapsulate_reject_value(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        Ok(kem_array_soft_zero_to_vec(dk.decapsulate_reject_value(&ct)))
+    }
+}
+
+impl Primitive for MlKem512Impl {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::MlKem512
+    }
 }
 
 impl Kem for MlKem512Impl {
@@ -116,21 +287,7 @@ impl Kem for MlKem512Impl {
     }
 
     fn encapsulate(&self, public_key: &KemPublicKey) -> Result<(Vec<u8>, Vec<u8>), Error> {
-        // Validate public key size
-        if public_key.data.len() != MLKEM512_PUBLIC_KEY_SIZE {
-            return Err(Error::InvalidKeySize {
-                expected: MLKEM512_PUBLIC_KEY_SIZE,
-                actual: public_key.data.len(),
-            });
-        }
-
-        // Use secure array conversion with proper error handling
-        let ek_array = secure_array_from_slice::<{ MLKEM512_PUBLIC_KEY_SIZE }>(&public_key.data)?;
-
-        // Use the proper non-deprecated API
-        let ek = <MlKem512 as KemCore>::EncapsulationKey::from_bytes(
-            &Array::try_from(ek_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-        );
+        let ek = Self::encapsulation_key(public_key)?;
 
         let mut rng = new_secure_rng().map_err(|e| Error::RandomGenerationFailed {
             operation: format!("Failed to create secure RNG: {}", e),
@@ -147,41 +304,77 @@ impl Kem for MlKem512Impl {
         ))
     }
 
+    #[cfg(feature = "deterministic")]
+    fn encapsulate_deterministic(
+        &self,
+        public_key: &KemPublicKey,
+        m: &[u8; 32],
+    ) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        let ek = Self::encapsulation_key(public_key)?;
+        let m = Zeroizing::new(B32::from(*m));
+        let (ciphertext, shared_secret) =
+            ek.encapsulate_deterministic(&m)
+                .map_err(|_| Error::EncryptionFailed {
+                    operation: "ML-KEM 512 deterministic encapsulation".to_string(),
+                })?;
+
+        Ok((
+            kem_array_soft_zero_to_vec(ciphertext),
+            kem_array_soft_zero_to_vec(shared_secret),
+        ))
+    }
+
     fn decapsulate(&self, secret_key: &KemSecretKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
-        // Validate secret key size
-        if secret_key.data.len() != MLKEM512_SECRET_KEY_SIZE {
-            return Err(Error::InvalidKeySize {
-                expected: MLKEM512_SECRET_KEY_SIZE,
-                actual: secret_key.data.len(),
-            });
-        }
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        // Decapsulation itself cannot fail: an invalid ciphertext yields the implicit-rejection
+        // secret (FIPS 203 §6.3).
+        let Ok(shared_secret) = dk.decapsulate(&ct);
 
-        // Validate ciphertext size
-        if ciphertext.len() != MLKEM512_CIPHERTEXT_SIZE {
-            return Err(Error::InvalidCiphertextSize {
-                expected: MLKEM512_CIPHERTEXT_SIZE,
-                actual: ciphertext.len(),
-            });
-        }
+        Ok(kem_array_soft_zero_to_vec(shared_secret))
+    }
 
-        // Use secure array conversion with proper error handling
-        let dk_array = secure_array_from_slice::<{ MLKEM512_SECRET_KEY_SIZE }>(&secret_key.data)?;
-        let ct_array = secure_array_from_slice::<{ MLKEM512_CIPHERTEXT_SIZE }>(ciphertext)?;
+    fn encapsulate_into(
+        &self,
+        public_key: &KemPublicKey,
+        ciphertext_out: &mut [u8],
+        shared_secret_out: &mut [u8],
+    ) -> Result<(), Error> {
+        check_kem_output_len(
+            ciphertext_out,
+            MLKEM512_CIPHERTEXT_SIZE,
+            shared_secret_out,
+            MLKEM_SHARED_KEY_SIZE,
+        )?;
+        let ek = Self::encapsulation_key(public_key)?;
 
-        // Use the proper non-deprecated API
-        let dk = <MlKem512 as KemCore>::DecapsulationKey::from_bytes(
-            &Array::try_from(dk_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-        );
+        let mut rng = new_secure_rng().map_err(|e| Error::RandomGenerationFailed {
+            operation: format!("Failed to create secure RNG: {}", e),
+        })?;
+        let (ciphertext, shared_secret) =
+            ek.encapsulate(&mut rng)
+                .map_err(|_| Error::EncryptionFailed {
+                    operation: "ML-KEM 512 encapsulation".to_string(),
+                })?;
+        let shared_secret = Zeroizing::new(shared_secret);
 
-        let shared_secret = dk
-            .decapsulate(
-                &Array::try_from(ct_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-            )
-            .map_err(|_| Error::DecryptionFailed {
-                operation: "ML-KEM 512 decapsulation".to_string(),
-            })?;
+        ciphertext_out.copy_from_slice(&ciphertext);
+        shared_secret_out.copy_from_slice(&shared_secret);
+        Ok(())
+    }
 
-        Ok(kem_array_soft_zero_to_vec(shared_secret))
+    fn decapsulate_into(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+        shared_secret_out: &mut [u8],
+    ) -> Result<(), Error> {
+        check_shared_secret_len(shared_secret_out, MLKEM_SHARED_KEY_SIZE)?;
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        let Ok(shared_secret) = dk.decapsulate(&ct);
+        let shared_secret = Zeroizing::new(shared_secret);
+
+        shared_secret_out.copy_from_slice(&shared_secret);
+        Ok(())
     }
 
     fn derive_public_key(&self, secret_key: &KemSecretKey) -> Result<KemPublicKey, Error> {
@@ -257,6 +450,108 @@ impl MlKem768Impl {
     pub fn security_level(&self) -> SecurityLevel {
         self.security_level
     }
+
+    /// Derive the key pair from a 64-byte FIPS 203 key-generation seed `d ‖ z`
+    ///
+    /// The same seed always yields the same key pair.
+    pub fn generate_keypair_from_seed(&self, seed: &[u8]) -> Result<KemKeypair, Error> {
+        let seed = keygen_seed(seed)?;
+        let (dk, ek) = MlKem768::generate_from_seed(&seed);
+
+        Ok(KemKeypair {
+            public_key: KemPublicKey {
+                data: kem_zeroizing_encoding_to_vec(ek.as_bytes()),
+            },
+            secret_key: KemSecretKey {
+                data: kem_zeroizing_encoding_to_vec(dk.as_bytes()),
+            },
+        })
+    }
+
+    /// Decode an encapsulation key, applying the FIPS 203 §7.2 input checks
+    ///
+    /// Rejects keys of the wrong length and keys whose 12-bit coefficients are not reduced
+    /// modulo `q` (the "modulus check").
+    fn encapsulation_key(
+        public_key: &KemPublicKey,
+    ) -> Result<<MlKem768 as KemCore>::EncapsulationKey, Error> {
+        if public_key.data.len() != MLKEM768_PUBLIC_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: MLKEM768_PUBLIC_KEY_SIZE,
+                actual: public_key.data.len(),
+            });
+        }
+        let encoded =
+            Array::try_from(public_key.data.as_slice()).map_err(|_| Error::InvalidKeyFormat)?;
+        <MlKem768 as KemCore>::EncapsulationKey::try_from_bytes(&encoded)
+            .map_err(|_| non_canonical_encapsulation_key())
+    }
+
+    /// Check sizes and decode the decapsulation key and ciphertext
+    fn decapsulation_inputs(
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<
+        (
+            <MlKem768 as KemCore>::DecapsulationKey,
+            Ciphertext<MlKem768>,
+        ),
+        Error,
+    > {
+        // Validate secret key size
+        if secret_key.data.len() != MLKEM768_SECRET_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: MLKEM768_SECRET_KEY_SIZE,
+                actual: secret_key.data.len(),
+            });
+        }
+
+        // Use secure array conversion with proper error handling
+        let dk_array = secure_array_from_slice::<{ MLKEM768_SECRET_KEY_SIZE }>(&secret_key.data)?;
+        let dk = <MlKem768 as KemCore>::DecapsulationKey::from_bytes(
+            &Array::try_from(dk_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
+        );
+        let ct = Array::try_from(ciphertext).map_err(|_| malformed_ciphertext())?;
+        Ok((dk, ct))
+    }
+
+    /// Decapsulate and report whether the FIPS 203 re-encryption check passed
+    ///
+    /// The shared secret is the same one [`Kem::decapsulate`] returns, including the
+    /// implicit-rejection secret for a ciphertext that fails the check. The flag is computed
+    /// separately and without constant-time guarantees, so this is only for tests and
+    /// diagnostics; production code must use [`Kem::decapsulate`].
+    #[cfg(feature = "testing")]
+    pub fn decapsulate_checked(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<(Vec<u8>, bool), Error> {
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        let (shared_secret, accepted) = dk.decapsulate_checked(&ct);
+        Ok((kem_array_soft_zero_to_vec(shared_secret), accepted))
+    }
+
+    /// The implicit-rejection secret [`Kem::decapsulate`] returns for a rejected `ciphertext`
+    ///
+    /// This is the FIPS 203 pseudo-random key `J(z ‖ c)`, keyed by the secret `z` stored in the
+    /// decapsulation key. Negative tests can assert that decapsulating a tampered ciphertext
+    /// yields exactly this value instead of the real shared secret.
+    #[cfg(feature = "testing")]
+    pub fn decapsulate_reject_value(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        Ok(kem_array_soft_zero_to_vec(dk.decapsulate_reject_value(&ct)))
+    }
+}
+
+impl Primitive for MlKem768Impl {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::MlKem768
+    }
 }
 
 impl Kem for MlKem768Impl {
@@ -281,21 +576,7 @@ impl Kem for MlKem768Impl {
     }
 
     fn encapsulate(&self, public_key: &KemPublicKey) -> Result<(Vec<u8>, Vec<u8>), Error> {
-        // Validate public key size
-        if public_key.data.len() != MLKEM768_PUBLIC_KEY_SIZE {
-            return Err(Error::InvalidKeySize {
-                expected: MLKEM768_PUBLIC_KEY_SIZE,
-                actual: public_key.data.len(),
-            });
-        }
-
-        // Use secure array conversion with proper error handling
-        let ek_array = secure_array_from_slice::<{ MLKEM768_PUBLIC_KEY_SIZE }>(&public_key.data)?;
-
-        // Use the proper non-deprecated API
-        let ek = <MlKem768 as KemCore>::EncapsulationKey::from_bytes(
-            &Array::try_from(ek_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-        );
+        let ek = Self::encapsulation_key(public_key)?;
 
         let mut rng = new_secure_rng().map_err(|e| Error::RandomGenerationFailed {
             operation: format!("Failed to create secure RNG: {}", e),
@@ -312,41 +593,77 @@ impl Kem for MlKem768Impl {
         ))
     }
 
+    #[cfg(feature = "deterministic")]
+    fn encapsulate_deterministic(
+        &self,
+        public_key: &KemPublicKey,
+        m: &[u8; 32],
+    ) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        let ek = Self::encapsulation_key(public_key)?;
+        let m = Zeroizing::new(B32::from(*m));
+        let (ciphertext, shared_secret) =
+            ek.encapsulate_deterministic(&m)
+                .map_err(|_| Error::EncryptionFailed {
+                    operation: "ML-KEM 768 deterministic encapsulation".to_string(),
+                })?;
+
+        Ok((
+            kem_array_soft_zero_to_vec(ciphertext),
+            kem_array_soft_zero_to_vec(shared_secret),
+        ))
+    }
+
     fn decapsulate(&self, secret_key: &KemSecretKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
-        // Validate secret key size
-        if secret_key.data.len() != MLKEM768_SECRET_KEY_SIZE {
-            return Err(Error::InvalidKeySize {
-                expected: MLKEM768_SECRET_KEY_SIZE,
-                actual: secret_key.data.len(),
-            });
-        }
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        // Decapsulation itself cannot fail: an invalid ciphertext yields the implicit-rejection
+        // secret (FIPS 203 §6.3).
+        let Ok(shared_secret) = dk.decapsulate(&ct);
 
-        // Validate ciphertext size
-        if ciphertext.len() != MLKEM768_CIPHERTEXT_SIZE {
-            return Err(Error::InvalidCiphertextSize {
-                expected: MLKEM768_CIPHERTEXT_SIZE,
-                actual: ciphertext.len(),
-            });
-        }
+        Ok(kem_array_soft_zero_to_vec(shared_secret))
+    }
 
-        // Use secure array conversion with proper error handling
-        let dk_array = secure_array_from_slice::<{ MLKEM768_SECRET_KEY_SIZE }>(&secret_key.data)?;
-        let ct_array = secure_array_from_slice::<{ MLKEM768_CIPHERTEXT_SIZE }>(ciphertext)?;
+    fn encapsulate_into(
+        &self,
+        public_key: &KemPublicKey,
+        ciphertext_out: &mut [u8],
+        shared_secret_out: &mut [u8],
+    ) -> Result<(), Error> {
+        check_kem_output_len(
+            ciphertext_out,
+            MLKEM768_CIPHERTEXT_SIZE,
+            shared_secret_out,
+            MLKEM_SHARED_KEY_SIZE,
+        )?;
+        let ek = Self::encapsulation_key(public_key)?;
 
-        // Use the proper non-deprecated API
-        let dk = <MlKem768 as KemCore>::DecapsulationKey::from_bytes(
-            &Array::try_from(dk_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-        );
+        let mut rng = new_secure_rng().map_err(|e| Error::RandomGenerationFailed {
+            operation: format!("Failed to create secure RNG: {}", e),
+        })?;
+        let (ciphertext, shared_secret) =
+            ek.encapsulate(&mut rng)
+                .map_err(|_| Error::EncryptionFailed {
+                    operation: "ML-KEM 768 encapsulation".to_string(),
+                })?;
+        let shared_secret = Zeroizing::new(shared_secret);
 
-        let shared_secret = dk
-            .decapsulate(
-                &Array::try_from(ct_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-            )
-            .map_err(|_| Error::DecryptionFailed {
-                operation: "ML-KEM 768 decapsulation".to_string(),
-            })?;
+        ciphertext_out.copy_from_slice(&ciphertext);
+        shared_secret_out.copy_from_slice(&shared_secret);
+        Ok(())
+    }
 
-        Ok(kem_array_soft_zero_to_vec(shared_secret))
+    fn decapsulate_into(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+        shared_secret_out: &mut [u8],
+    ) -> Result<(), Error> {
+        check_shared_secret_len(shared_secret_out, MLKEM_SHARED_KEY_SIZE)?;
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        let Ok(shared_secret) = dk.decapsulate(&ct);
+        let shared_secret = Zeroizing::new(shared_secret);
+
+        shared_secret_out.copy_from_slice(&shared_secret);
+        Ok(())
     }
 
     fn derive_public_key(&self, secret_key: &KemSecretKey) -> Result<KemPublicKey, Error> {
@@ -422,6 +739,108 @@ impl MlKem1024Impl {
     pub fn security_level(&self) -> SecurityLevel {
         self.security_level
     }
+
+    /// Derive the key pair from a 64-byte FIPS 203 key-generation seed `d ‖ z`
+    ///
+    /// The same seed always yields the same key pair.
+    pub fn generate_keypair_from_seed(&self, seed: &[u8]) -> Result<KemKeypair, Error> {
+        let seed = keygen_seed(seed)?;
+        let (dk, ek) = MlKem1024::generate_from_seed(&seed);
+
+        Ok(KemKeypair {
+            public_key: KemPublicKey {
+                data: kem_zeroizing_encoding_to_vec(ek.as_bytes()),
+            },
+            secret_key: KemSecretKey {
+                data: kem_zeroizing_encoding_to_vec(dk.as_bytes()),
+            },
+        })
+    }
+
+    /// Decode an encapsulation key, applying the FIPS 203 §7.2 input checks
+    ///
+    /// Rejects keys of the wrong length and keys whose 12-bit coefficients are not reduced
+    /// modulo `q` (the "modulus check").
+    fn encapsulation_key(
+        public_key: &KemPublicKey,
+    ) -> Result<<MlKem1024 as KemCore>::EncapsulationKey, Error> {
+        if public_key.data.len() != MLKEM1024_PUBLIC_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: MLKEM1024_PUBLIC_KEY_SIZE,
+                actual: public_key.data.len(),
+            });
+        }
+        let encoded =
+            Array::try_from(public_key.data.as_slice()).map_err(|_| Error::InvalidKeyFormat)?;
+        <MlKem1024 as KemCore>::EncapsulationKey::try_from_bytes(&encoded)
+            .map_err(|_| non_canonical_encapsulation_key())
+    }
+
+    /// Check sizes and decode the decapsulation key and ciphertext
+    fn decapsulation_inputs(
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<
+        (
+            <MlKem1024 as KemCore>::DecapsulationKey,
+            Ciphertext<MlKem1024>,
+        ),
+        Error,
+    > {
+        // Validate secret key size
+        if secret_key.data.len() != MLKEM1024_SECRET_KEY_SIZE {
+            return Err(Error::InvalidKeySize {
+                expected: MLKEM1024_SECRET_KEY_SIZE,
+                actual: secret_key.data.len(),
+            });
+        }
+
+        // Use secure array conversion with proper error handling
+        let dk_array = secure_array_from_slice::<{ MLKEM1024_SECRET_KEY_SIZE }>(&secret_key.data)?;
+        let dk = <MlKem1024 as KemCore>::DecapsulationKey::from_bytes(
+            &Array::try_from(dk_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
+        );
+        let ct = Array::try_from(ciphertext).map_err(|_| malformed_ciphertext())?;
+        Ok((dk, ct))
+    }
+
+    /// Decapsulate and report whether the FIPS 203 re-encryption check passed
+    ///
+    /// The shared secret is the same one [`Kem::decapsulate`] returns, including the
+    /// implicit-rejection secret for a ciphertext that fails the check. The flag is computed
+    /// separately and without constant-time guarantees, so this is only for tests and
+    /// diagnostics; production code must use [`Kem::decapsulate`].
+    #[cfg(feature = "testing")]
+    pub fn decapsulate_checked(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<(Vec<u8>, bool), Error> {
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        let (shared_secret, accepted) = dk.decapsulate_checked(&ct);
+        Ok((kem_array_soft_zero_to_vec(shared_secret), accepted))
+    }
+
+    /// The implicit-rejection secret [`Kem::decapsulate`] returns for a rejected `ciphertext`
+    ///
+    /// This is the FIPS 203 pseudo-random key `J(z ‖ c)`, keyed by the secret `z` stored in the
+    /// decapsulation key. Negative tests can assert that decapsulating a tampered ciphertext
+    /// yields exactly this value instead of the real shared secret.
+    #[cfg(feature = "testing")]
+    pub fn decapsulate_reject_value(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        Ok(kem_array_soft_zero_to_vec(dk.decapsulate_reject_value(&ct)))
+    }
+}
+
+impl Primitive for MlKem1024Impl {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::MlKem1024
+    }
 }
 
 impl Kem for MlKem1024Impl {
@@ -446,21 +865,7 @@ impl Kem for MlKem1024Impl {
     }
 
     fn encapsulate(&self, public_key: &KemPublicKey) -> Result<(Vec<u8>, Vec<u8>), Error> {
-        // Validate public key size
-        if public_key.data.len() != MLKEM1024_PUBLIC_KEY_SIZE {
-            return Err(Error::InvalidKeySize {
-                expected: MLKEM1024_PUBLIC_KEY_SIZE,
-                actual: public_key.data.len(),
-            });
-        }
-
-        // Use secure array conversion with proper error handling
-        let ek_array = secure_array_from_slice::<{ MLKEM1024_PUBLIC_KEY_SIZE }>(&public_key.data)?;
-
-        // Use the proper non-deprecated API
-        let ek = <MlKem1024 as KemCore>::EncapsulationKey::from_bytes(
-            &Array::try_from(ek_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-        );
+        let ek = Self::encapsulation_key(public_key)?;
 
         let mut rng = new_secure_rng().map_err(|e| Error::RandomGenerationFailed {
             operation: format!("Failed to create secure RNG: {}", e),
@@ -477,41 +882,77 @@ impl Kem for MlKem1024Impl {
         ))
     }
 
+    #[cfg(feature = "deterministic")]
+    fn encapsulate_deterministic(
+        &self,
+        public_key: &KemPublicKey,
+        m: &[u8; 32],
+    ) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        let ek = Self::encapsulation_key(public_key)?;
+        let m = Zeroizing::new(B32::from(*m));
+        let (ciphertext, shared_secret) =
+            ek.encapsulate_deterministic(&m)
+                .map_err(|_| Error::EncryptionFailed {
+                    operation: "ML-KEM 1024 deterministic encapsulation".to_string(),
+                })?;
+
+        Ok((
+            kem_array_soft_zero_to_vec(ciphertext),
+            kem_array_soft_zero_to_vec(shared_secret),
+        ))
+    }
+
     fn decapsulate(&self, secret_key: &KemSecretKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
-        // Validate secret key size
-        if secret_key.data.len() != MLKEM1024_SECRET_KEY_SIZE {
-            return Err(Error::InvalidKeySize {
-                expected: MLKEM1024_SECRET_KEY_SIZE,
-                actual: secret_key.data.len(),
-            });
-        }
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        // Decapsulation itself cannot fail: an invalid ciphertext yields the implicit-rejection
+        // secret (FIPS 203 §6.3).
+        let Ok(shared_secret) = dk.decapsulate(&ct);
 
-        // Validate ciphertext size
-        if ciphertext.len() != MLKEM1024_CIPHERTEXT_SIZE {
-            return Err(Error::InvalidCiphertextSize {
-                expected: MLKEM1024_CIPHERTEXT_SIZE,
-                actual: ciphertext.len(),
-            });
-        }
+        Ok(kem_array_soft_zero_to_vec(shared_secret))
+    }
 
-        // Use secure array conversion with proper error handling
-        let dk_array = secure_array_from_slice::<{ MLKEM1024_SECRET_KEY_SIZE }>(&secret_key.data)?;
-        let ct_array = secure_array_from_slice::<{ MLKEM1024_CIPHERTEXT_SIZE }>(ciphertext)?;
+    fn encapsulate_into(
+        &self,
+        public_key: &KemPublicKey,
+        ciphertext_out: &mut [u8],
+        shared_secret_out: &mut [u8],
+    ) -> Result<(), Error> {
+        check_kem_output_len(
+            ciphertext_out,
+            MLKEM1024_CIPHERTEXT_SIZE,
+            shared_secret_out,
+            MLKEM_SHARED_KEY_SIZE,
+        )?;
+        let ek = Self::encapsulation_key(public_key)?;
 
-        // Use the proper non-deprecated API
-        let dk = <MlKem1024 as KemCore>::DecapsulationKey::from_bytes(
-            &Array::try_from(dk_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-        );
+        let mut rng = new_secure_rng().map_err(|e| Error::RandomGenerationFailed {
+            operation: format!("Failed to create secure RNG: {}", e),
+        })?;
+        let (ciphertext, shared_secret) =
+            ek.encapsulate(&mut rng)
+                .map_err(|_| Error::EncryptionFailed {
+                    operation: "ML-KEM 1024 encapsulation".to_string(),
+                })?;
+        let shared_secret = Zeroizing::new(shared_secret);
 
-        let shared_secret = dk
-            .decapsulate(
-                &Array::try_from(ct_array.as_slice()).map_err(|_| Error::InvalidKeyFormat)?,
-            )
-            .map_err(|_| Error::DecryptionFailed {
-                operation: "ML-KEM 1024 decapsulation".to_string(),
-            })?;
+        ciphertext_out.copy_from_slice(&ciphertext);
+        shared_secret_out.copy_from_slice(&shared_secret);
+        Ok(())
+    }
 
-        Ok(kem_array_soft_zero_to_vec(shared_secret))
+    fn decapsulate_into(
+        &self,
+        secret_key: &KemSecretKey,
+        ciphertext: &[u8],
+        shared_secret_out: &mut [u8],
+    ) -> Result<(), Error> {
+        check_shared_secret_len(shared_secret_out, MLKEM_SHARED_KEY_SIZE)?;
+        let (dk, ct) = Self::decapsulation_inputs(secret_key, ciphertext)?;
+        let Ok(shared_secret) = dk.decapsulate(&ct);
+        let shared_secret = Zeroizing::new(shared_secret);
+
+        shared_secret_out.copy_from_slice(&shared_secret);
+        Ok(())
     }
 
     fn derive_public_key(&self, secret_key: &KemSecretKey) -> Result<KemPublicKey, Error> {
@@ -591,6 +1032,28 @@ mod tests {
         assert_eq!(kem.security_level(), SecurityLevel::Level4);
     }
 
+    #[test]
+    fn test_internal_errors_map_to_specific_core_variants() {
+        let key_error = Error::from(lib_q_ml_kem::Error::InvalidKey);
+        assert!(matches!(
+            &key_error,
+            Error::InvalidKey { reason, .. } if reason.contains("FIPS 203")
+        ));
+
+        // A ciphertext that fails to decode is a decapsulation failure, never an
+        // algorithm error.
+        let kem = MlKem768Impl::default();
+        let keypair = kem.generate_keypair().unwrap();
+        let truncated = alloc::vec![0u8; MLKEM768_CIPHERTEXT_SIZE - 1];
+        let result = kem.decapsulate(&keypair.secret_key, &truncated);
+        assert!(matches!(
+            &result,
+            Err(Error::DecapsulationFailed { operation, retriable: false })
+                if operation.contains("ciphertext")
+        ));
+        assert!(!matches!(result, Err(Error::InvalidAlgorithm { .. })));
+    }
+
     #[test]
     fn test_secure_array_from_slice() {
         let data = alloc::vec![1, 2, 3, 4];
@@ -630,6 +1093,92 @@ mod tests {
         assert_eq!(shared_secret1.len(), MLKEM_SHARED_KEY_SIZE);
     }
 
+    #[test]
+    fn test_into_buffers_match_allocating_api() {
+        fn check(kem: &impl Kem, ciphertext_len: usize) {
+            let keypair = kem.generate_keypair().unwrap();
+            let mut ciphertext = alloc::vec![0u8; ciphertext_len];
+            let mut shared_secret = [0u8; MLKEM_SHARED_KEY_SIZE];
+            kem.encapsulate_into(&keypair.public_key, &mut ciphertext, &mut shared_secret)
+                .unwrap();
+            assert_eq!(
+                kem.decapsulate(&keypair.secret_key, &ciphertext).unwrap(),
+                shared_secret
+            );
+
+            let (allocated, expected) = kem.encapsulate(&keypair.public_key).unwrap();
+            let mut decapsulated = [0u8; MLKEM_SHARED_KEY_SIZE];
+            kem.decapsulate_into(&keypair.secret_key, &allocated, &mut decapsulated)
+                .unwrap();
+            assert_eq!(decapsulated[..], expected[..]);
+
+            let mut short = alloc::vec![0u8; ciphertext_len - 1];
+            assert!(matches!(
+                kem.encapsulate_into(&keypair.public_key, &mut short, &mut shared_secret),
+                Err(Error::InvalidCiphertextSize { expected, actual })
+                    if expected == ciphertext_len && actual == ciphertext_len - 1
+            ));
+            let mut long_secret = [0xAA; MLKEM_SHARED_KEY_SIZE + 1];
+            assert!(matches!(
+                kem.encapsulate_into(&keypair.public_key, &mut ciphertext, &mut long_secret),
+                Err(Error::InvalidKeySize { expected, .. }) if expected == MLKEM_SHARED_KEY_SIZE
+            ));
+            assert!(matches!(
+                kem.decapsulate_into(&keypair.secret_key, &allocated, &mut long_secret[..16]),
+                Err(Error::InvalidKeySize { actual: 16, .. })
+            ));
+            assert!(long_secret.iter().all(|&b| b == 0xAA));
+        }
+
+        check(&MlKem512Impl::default(), MLKEM512_CIPHERTEXT_SIZE);
+        check(&MlKem768Impl::default(), MLKEM768_CIPHERTEXT_SIZE);
+        check(&MlKem1024Impl::default(), MLKEM1024_CIPHERTEXT_SIZE);
+    }
+
+    #[test]
+    fn test_algorithm_size_metadata_matches_ml_kem() {
+        fn check(kem: &impl Kem, algorithm: Algorithm, sizes: [usize; 3]) {
+            let [public_key, secret_key, ciphertext] = sizes;
+            assert_eq!(algorithm.public_key_len(), Some(public_key));
+            assert_eq!(algorithm.secret_key_len(), Some(secret_key));
+            assert_eq!(algorithm.ciphertext_len(), Some(ciphertext));
+
+            let keypair = kem.generate_keypair().unwrap();
+            let (enc, _) = kem.encapsulate(&keypair.public_key).unwrap();
+            assert_eq!(keypair.public_key.data.len(), public_key);
+            assert_eq!(keypair.secret_key.data.len(), secret_key);
+            assert_eq!(enc.len(), ciphertext);
+        }
+
+        check(
+            &MlKem512Impl::default(),
+            Algorithm::MlKem512,
+            [
+                MLKEM512_PUBLIC_KEY_SIZE,
+                MLKEM512_SECRET_KEY_SIZE,
+                MLKEM512_CIPHERTEXT_SIZE,
+            ],
+        );
+        check(
+            &MlKem768Impl::default(),
+            Algorithm::MlKem768,
+            [
+                MLKEM768_PUBLIC_KEY_SIZE,
+                MLKEM768_SECRET_KEY_SIZE,
+                MLKEM768_CIPHERTEXT_SIZE,
+            ],
+        );
+        check(
+            &MlKem1024Impl::default(),
+            Algorithm::MlKem1024,
+            [
+                MLKEM1024_PUBLIC_KEY_SIZE,
+                MLKEM1024_SECRET_KEY_SIZE,
+                MLKEM1024_CIPHERTEXT_SIZE,
+            ],
+        );
+    }
+
     #[test]
     fn test_authentication_not_implemented() {
         let kem = MlKem512Impl::default();
@@ -669,6 +1218,84 @@ mod tests {
         assert_eq!(derived1024.data, keypair1024.public_key.data);
     }
 
+    #[test]
+    fn test_encapsulation_key_modulus_check() {
+        let kem = MlKem768Impl::default();
+        let keypair = kem.generate_keypair().unwrap();
+        assert!(validate_encapsulation_key(&keypair.public_key, Algorithm::MlKem768).is_ok());
+
+        // Bytes 1..3 hold the high nibble of coefficient 0 and all of coefficient 1; an all-ones
+        // high byte makes coefficient 1 at least 0xff0, well above q = 3329.
+        let mut bad_pk = keypair.public_key.clone();
+        bad_pk.data[2] = 0xFF;
+        assert!(matches!(
+            validate_encapsulation_key(&bad_pk, Algorithm::MlKem768),
+            Err(Error::InvalidKey { .. })
+        ));
+        assert!(matches!(
+            kem.encapsulate(&bad_pk),
+            Err(Error::InvalidKey { .. })
+        ));
+
+        assert!(matches!(
+            validate_encapsulation_key(&keypair.public_key, Algorithm::MlKem512),
+            Err(Error::InvalidKeySize { .. })
+        ));
+        assert!(matches!(
+            validate_encapsulation_key(&keypair.public_key, Algorithm::MlDsa65),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_decapsulate_checked_flags_implicit_rejection() {
+        let kem = MlKem512Impl::default();
+        let keypair = kem.generate_keypair().unwrap();
+        let (mut ciphertext, shared_secret) = kem.encapsulate(&keypair.public_key).unwrap();
+
+        let (secret, accepted) = kem
+            .decapsulate_checked(&keypair.secret_key, &ciphertext)
+            .unwrap();
+        assert!(accepted);
+        assert_eq!(secret, shared_secret);
+
+        ciphertext[0] ^= 0x01;
+        let (secret, accepted) = kem
+            .decapsulate_checked(&keypair.secret_key, &ciphertext)
+            .unwrap();
+        assert!(!accepted);
+        assert_eq!(secret.len(), MLKEM_SHARED_KEY_SIZE);
+        assert_ne!(secret, shared_secret);
+        assert_eq!(
+            secret,
+            kem.decapsulate(&keypair.secret_key, &ciphertext).unwrap()
+        );
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_decapsulate_reject_value_matches_tampered_decapsulation() {
+        let kem = MlKem768Impl::default();
+        let keypair = kem.generate_keypair().unwrap();
+        let (mut ciphertext, shared_secret) = kem.encapsulate(&keypair.public_key).unwrap();
+
+        ciphertext[17] ^= 0x80;
+        let reject_value = kem
+            .decapsulate_reject_value(&keypair.secret_key, &ciphertext)
+            .unwrap();
+        assert_eq!(reject_value.len(), MLKEM_SHARED_KEY_SIZE);
+        assert_eq!(
+            kem.decapsulate(&keypair.secret_key, &ciphertext).unwrap(),
+            reject_value
+        );
+        assert_ne!(reject_value, shared_secret);
+        assert!(matches!(
+            kem.decapsulate_reject_value(&keypair.secret_key, &ciphertext[1..]),
+            Err(Error::DecapsulationFailed { .. })
+        ));
+    }
+
     #[test]
     fn test_ml_kem_768_and_1024_error_paths_and_auth_decapsulate() {
         let kem768 = MlKem768Impl::default();
@@ -708,8 +1335,7 @@ mod tests {
         let decapsulate_err = kem1024.decapsulate(&keypair1024.secret_key, &bad_ct1024);
         assert!(matches!(
             decapsulate_err,
-            Err(Error::InvalidCiphertextSize { expected, actual })
-                if expected == MLKEM1024_CIPHERTEXT_SIZE && actual == MLKEM1024_CIPHERTEXT_SIZE - 1
+            Err(Error::DecapsulationFailed { .. })
         ));
         let auth_decap_err_1024 = kem1024.auth_decapsulate(
             &keypair1024.secret_key,
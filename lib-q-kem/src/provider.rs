@@ -34,6 +34,7 @@ use lib_q_core::traits::{
     KemKeypair,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
 };
 // Import HQC implementations
 #[cfg(feature = "hqc")]
@@ -111,17 +112,26 @@ impl KemOperations for LibQKemProvider {
             #[cfg(feature = "ml-kem")]
             Algorithm::MlKem512 => {
                 let kem = MlKem512Impl::default();
-                kem.generate_keypair()
+                match randomness {
+                    Some(seed) => kem.generate_keypair_from_seed(seed),
+                    None => kem.generate_keypair(),
+                }
             }
             #[cfg(feature = "ml-kem")]
             Algorithm::MlKem768 => {
                 let kem = MlKem768Impl::default();
-                kem.generate_keypair()
+                match randomness {
+                    Some(seed) => kem.generate_keypair_from_seed(seed),
+                    None => kem.generate_keypair(),
+                }
             }
             #[cfg(feature = "ml-kem")]
             Algorithm::MlKem1024 => {
                 let kem = MlKem1024Impl::default();
-                kem.generate_keypair()
+                match randomness {
+                    Some(seed) => kem.generate_keypair_from_seed(seed),
+                    None => kem.generate_keypair(),
+                }
             }
 
             // CB-KEM algorithms
@@ -264,7 +274,7 @@ impl KemOperations for LibQKemProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Validate ciphertext
         self.security_validator
@@ -346,7 +356,7 @@ impl KemOperations for LibQKemProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Route to specific algorithm implementation
         match algorithm {
@@ -10,6 +10,7 @@ use lib_q_core::{
     KemOperations,
     KemPublicKey,
     KemSecretKey,
+    SecretExportConsent,
     SecurityLevel,
 };
 #[cfg(all(feature = "alloc", feature = "ml-kem"))]
@@ -96,6 +97,34 @@ fn test_ml_kem_availability() {
     assert!(provider.is_ok(), "Failed to create KEM provider");
 }
 
+/// Generated public keys pass the FIPS 203 encapsulation key check
+#[test]
+#[cfg(all(feature = "alloc", feature = "ml-kem"))]
+fn test_ml_kem_public_key_from_bytes_checked() {
+    let provider = LibQKemProvider::new().unwrap();
+
+    for config in ML_KEM_CONFIGS {
+        let keypair = provider.generate_keypair(config.algorithm, None).unwrap();
+        let bytes = keypair.public_key().as_bytes();
+        let checked = KemPublicKey::from_bytes_checked(bytes, config.algorithm).unwrap();
+        assert_eq!(checked.as_bytes(), bytes);
+
+        assert!(matches!(
+            KemPublicKey::from_bytes_checked(&bytes[1..], config.algorithm),
+            Err(Error::InvalidKeySize { .. })
+        ));
+
+        // Force the first coefficient of `t` to 0xFFF (>= q).
+        let mut unreduced = bytes.to_vec();
+        unreduced[0] = 0xFF;
+        unreduced[1] |= 0x0F;
+        assert!(matches!(
+            KemPublicKey::from_bytes_checked(&unreduced, config.algorithm),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+}
+
 /// Test key generation for all ML-KEM variants
 #[test]
 #[cfg(all(feature = "alloc", feature = "ml-kem"))]
@@ -113,7 +142,10 @@ fn test_ml_kem_key_generation() {
             config.algorithm
         );
         assert_eq!(
-            keypair.secret_key().as_bytes().len(),
+            keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .len(),
             config.secret_key_size,
             "Invalid secret key size for {:?}",
             config.algorithm
@@ -121,7 +153,13 @@ fn test_ml_kem_key_generation() {
 
         // Verify keys are not all zeros
         assert!(!keypair.public_key().as_bytes().iter().all(|&b| b == 0));
-        assert!(!keypair.secret_key().as_bytes().iter().all(|&b| b == 0));
+        assert!(
+            !keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .iter()
+                .all(|&b| b == 0)
+        );
     }
 }
 
@@ -227,6 +265,37 @@ fn test_ml_kem_public_key_derivation() {
     }
 }
 
+/// The encapsulation key embedded in a generated secret key matches the generated public key
+#[test]
+#[cfg(all(feature = "alloc", feature = "ml-kem"))]
+fn test_ml_kem_secret_key_derive_public() {
+    let provider = LibQKemProvider::new().unwrap();
+
+    for config in ML_KEM_CONFIGS {
+        let keypair = provider.generate_keypair(config.algorithm, None).unwrap();
+        let derived = keypair
+            .secret_key()
+            .derive_public(config.algorithm)
+            .unwrap();
+        assert_eq!(derived.as_bytes(), keypair.public_key().as_bytes());
+
+        let secret = keypair.secret_key().expose_secret(SecretExportConsent);
+        let truncated = KemSecretKey::new(secret[1..].to_vec());
+        assert!(matches!(
+            truncated.derive_public(config.algorithm),
+            Err(Error::InvalidKeySize { .. })
+        ));
+    }
+
+    let keypair = provider
+        .generate_keypair(Algorithm::MlKem768, None)
+        .unwrap();
+    assert!(matches!(
+        keypair.secret_key().derive_public(Algorithm::MlDsa65),
+        Err(Error::InvalidAlgorithm { .. })
+    ));
+}
+
 /// Test error handling for invalid key sizes
 #[test]
 #[cfg(all(feature = "alloc", feature = "ml-kem"))]
@@ -466,3 +535,162 @@ fn test_ml_kem_error_messages() {
     assert!(error_msg.contains("expected 768"));
     assert!(error_msg.contains("got 100"));
 }
+
+/// Key generation from an injected seeded RNG is reproducible
+#[test]
+#[cfg(all(feature = "alloc", feature = "ml-kem"))]
+fn test_ml_kem_keygen_with_injected_rng_is_reproducible() {
+    use lib_q_core::KemContext;
+    use lib_q_random::new_deterministic_rng;
+
+    let mut ctx = KemContext::with_provider(Box::new(LibQKemProvider::new().unwrap()));
+    let mut keygen = |seed: [u8; 32]| {
+        let mut rng = new_deterministic_rng(seed);
+        ctx.generate_keypair_with_rng(Algorithm::MlKem768, &mut rng)
+            .unwrap()
+    };
+
+    let first = keygen([0x5A; 32]);
+    let second = keygen([0x5A; 32]);
+    assert_eq!(first.public_key().as_bytes().len(), 1184);
+    assert_eq!(
+        first.public_key().as_bytes(),
+        second.public_key().as_bytes()
+    );
+    assert_eq!(
+        first.secret_key().expose_secret(SecretExportConsent),
+        second.secret_key().expose_secret(SecretExportConsent)
+    );
+    assert_ne!(
+        keygen([0xA5; 32]).public_key().as_bytes(),
+        first.public_key().as_bytes()
+    );
+
+    // The reproducible key pair is a working ML-KEM key pair.
+    let (ciphertext, shared_secret) = ctx
+        .encapsulate(Algorithm::MlKem768, first.public_key(), None)
+        .unwrap();
+    assert_eq!(
+        ctx.decapsulate(Algorithm::MlKem768, first.secret_key(), &ciphertext)
+            .unwrap(),
+        shared_secret
+    );
+
+    let mut rng = new_deterministic_rng([1; 32]);
+    assert!(matches!(
+        ctx.generate_keypair_with_rng(Algorithm::Hqc128, &mut rng),
+        Err(Error::UnsupportedOperation { .. })
+    ));
+}
+
+/// The zeroizing key pair path returns a working public key and wrapped secret key
+#[test]
+#[cfg(all(feature = "alloc", feature = "ml-kem"))]
+fn test_ml_kem_generate_keypair_zeroizing() {
+    use lib_q_core::KemContext;
+
+    let mut ctx = KemContext::with_provider(Box::new(LibQKemProvider::new().unwrap()));
+    let (public_key, secret) = ctx.generate_keypair_zeroizing(Algorithm::MlKem768).unwrap();
+    assert_eq!(public_key.as_bytes().len(), 1184);
+    assert_eq!(secret.len(), 2400);
+
+    let (ciphertext, shared_secret) = ctx
+        .encapsulate(Algorithm::MlKem768, &public_key, None)
+        .unwrap();
+    let secret_key = KemSecretKey::new(secret.to_vec());
+    assert_eq!(
+        ctx.decapsulate(Algorithm::MlKem768, &secret_key, &ciphertext)
+            .unwrap(),
+        shared_secret
+    );
+
+    assert!(matches!(
+        ctx.generate_keypair_zeroizing(Algorithm::MlDsa65),
+        Err(Error::InvalidAlgorithm { .. })
+    ));
+}
+
+/// The `lib-q-kem` wrapper and the `lib-q-ml-kem` API exchange the same FIPS 203 encodings
+///
+/// Keys, ciphertexts and shared secrets pass between the two layers in both directions without
+/// any reordering or reframing of bytes.
+#[test]
+#[cfg(all(feature = "alloc", feature = "ml-kem"))]
+fn test_ml_kem_wrapper_is_byte_compatible_with_lib_q_ml_kem() {
+    use lib_q_core::Kem;
+    use lib_q_kem::ml_kem::MlKem768Impl;
+    use lib_q_ml_kem::array::Array;
+    use lib_q_ml_kem::{
+        Decapsulate,
+        Encapsulate,
+        EncodedSizeUser,
+        KemCore,
+        MlKem768,
+    };
+    use lib_q_random::new_deterministic_rng;
+
+    let wrapper = MlKem768Impl::default();
+    let mut rng = new_deterministic_rng([0x3C; 32]);
+
+    // Wrapper key pair, raw encapsulation, wrapper decapsulation.
+    let keypair = wrapper.generate_keypair().unwrap();
+    let ek = <MlKem768 as KemCore>::EncapsulationKey::try_from_bytes(
+        &Array::try_from(keypair.public_key().as_bytes()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(ek.as_bytes().as_slice(), keypair.public_key().as_bytes());
+    let (ciphertext, shared_secret) = ek.encapsulate(&mut rng).unwrap();
+    assert_eq!(
+        wrapper
+            .decapsulate(keypair.secret_key(), ciphertext.as_slice())
+            .unwrap(),
+        shared_secret.as_slice()
+    );
+
+    // Raw key pair, wrapper encapsulation, raw decapsulation.
+    let (dk, ek) = MlKem768::generate(&mut rng);
+    let public_key = KemPublicKey::new(ek.as_bytes().to_vec());
+    let secret_key = KemSecretKey::new(dk.as_bytes().to_vec());
+    let (ciphertext, shared_secret) = wrapper.encapsulate(&public_key).unwrap();
+    let raw_secret = dk
+        .decapsulate(&Array::try_from(ciphertext.as_slice()).unwrap())
+        .unwrap();
+    assert_eq!(raw_secret.as_slice(), shared_secret);
+    assert_eq!(
+        wrapper.decapsulate(&secret_key, &ciphertext).unwrap(),
+        shared_secret
+    );
+}
+
+/// Batch encapsulation yields independent results that each decapsulate correctly
+///
+/// Run with and without the `rayon` feature to cover both the parallel and the sequential path.
+#[test]
+#[cfg(all(feature = "alloc", feature = "ml-kem"))]
+fn test_ml_kem_encapsulate_batch_decapsulates() {
+    use lib_q_core::Kem;
+    use lib_q_kem::encapsulate_batch;
+    use lib_q_kem::ml_kem::MlKem768Impl;
+
+    let kem = MlKem768Impl::default();
+    let keypairs: Vec<_> = (0..8).map(|_| kem.generate_keypair().unwrap()).collect();
+    // The same key twice must still get fresh randomness for each encapsulation.
+    let mut pk_list: Vec<KemPublicKey> =
+        keypairs.iter().map(|kp| kp.public_key().clone()).collect();
+    pk_list.push(keypairs[0].public_key().clone());
+
+    let results = encapsulate_batch(&kem, &pk_list).unwrap();
+    assert_eq!(results.len(), pk_list.len());
+    for (i, (ciphertext, shared_secret)) in results.iter().enumerate() {
+        let keypair = &keypairs[i % keypairs.len()];
+        assert_eq!(
+            &kem.decapsulate(keypair.secret_key(), ciphertext).unwrap(),
+            shared_secret
+        );
+    }
+    assert_ne!(results[0].0, results[8].0);
+    assert_ne!(results[0].1, results[8].1);
+
+    pk_list.push(KemPublicKey::new(vec![0u8; 10]));
+    assert!(encapsulate_batch(&kem, &pk_list).is_err());
+}
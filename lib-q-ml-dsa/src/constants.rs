@@ -24,7 +24,8 @@ pub const KEY_GENERATION_RANDOMNESS_SIZE: usize = 32;
 /// Number of bytes of entropy required for signing.
 pub const SIGNING_RANDOMNESS_SIZE: usize = 32;
 
-pub(crate) const MESSAGE_REPRESENTATIVE_SIZE: usize = 64;
+/// Number of bytes in the message representative `mu` (FIPS 204, Algorithm 7).
+pub const MESSAGE_REPRESENTATIVE_SIZE: usize = 64;
 pub(crate) const MASK_SEED_SIZE: usize = 64;
 
 pub(crate) const REJECTION_SAMPLE_BOUND_SIGN: usize = 814;
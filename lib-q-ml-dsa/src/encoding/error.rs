@@ -36,7 +36,7 @@ fn chunk_size(eta: Eta) -> usize {
 
 #[cfg_attr(tarpaulin, inline(never))]
 #[cfg_attr(not(tarpaulin), inline(always))]
-fn deserialize<SIMDUnit: Operations>(
+pub(crate) fn deserialize<SIMDUnit: Operations>(
     eta: Eta,
     serialized: &[u8],
     result: &mut PolynomialRingElement<SIMDUnit>,
@@ -38,12 +38,16 @@ mod specs;
 pub mod types;
 
 // Re-export hash functions for derive_message_representative
-pub use pre_hash::DomainSeparationContext;
+pub use pre_hash::{
+    DomainSeparationContext,
+    PreHashAlgorithm,
+};
 // Public interface
 pub use types::*;
 
 pub use crate::constants::{
     KEY_GENERATION_RANDOMNESS_SIZE,
+    MESSAGE_REPRESENTATIVE_SIZE,
     SIGNING_RANDOMNESS_SIZE,
 };
 
@@ -8,6 +8,7 @@ pub use ml_dsa_44::{
 use crate::constants::*;
 use crate::types::*;
 use crate::{
+    PreHashAlgorithm,
     SigningError,
     VerificationError,
 };
@@ -268,6 +269,23 @@ pub fn generate_key_pair(randomness: [u8; KEY_GENERATION_RANDOMNESS_SIZE]) -> ML
     }
 }
 
+/// Recompute the ML-DSA 44 verification key from a signing key
+///
+/// FIPS 204 signing keys hold the seed `rho`, `s1` and `s2`, so the
+/// verification key can be rebuilt without the key generation randomness.
+///
+/// This function returns an [`MLDSA44VerificationKey`].
+#[cfg(not(eurydice))]
+pub fn derive_verification_key(signing_key: &MLDSA44SigningKey) -> MLDSA44VerificationKey {
+    let mut verification_key = [0u8; ml_dsa_44::VERIFICATION_KEY_SIZE];
+    crate::ml_dsa_generic::ml_dsa_44::derive_verification_key::<
+        crate::simd::portable::PortableSIMDUnit,
+        crate::samplex4::portable::PortableSampler,
+    >(signing_key.as_ref(), &mut verification_key);
+
+    MLDSAVerificationKey::new(verification_key)
+}
+
 /// Sign with ML-DSA 44
 ///
 /// Sign a `message` with the ML-DSA `signing_key`.
@@ -433,3 +451,137 @@ instantiate! {portable, "Portable ML-DSA 44"}
 instantiate! {avx2, "AVX2 Optimised ML-DSA 44"}
 #[cfg(feature = "simd128")]
 instantiate! {neon, "Neon Optimised ML-DSA 44"}
+
+/// Sign an externally computed digest with HashML-DSA 44
+///
+/// `digest` must already be the output of the hash function identified by
+/// `pre_hash` and have exactly [`PreHashAlgorithm::digest_len`] bytes; its
+/// OID is bound into the signature as in Algorithm 4 of FIPS 204.
+///
+/// The parameter `context` is used for domain separation
+/// and is a byte string of length at most 255 bytes. It
+/// may also be empty.
+#[cfg(not(eurydice))]
+pub fn sign_pre_hashed_digest(
+    signing_key: &MLDSA44SigningKey,
+    digest: &[u8],
+    context: &[u8],
+    pre_hash: PreHashAlgorithm,
+    randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+) -> Result<MLDSA44Signature, SigningError> {
+    crate::ml_dsa_generic::ml_dsa_44::sign_pre_hashed_digest::<
+        crate::simd::portable::PortableSIMDUnit,
+        crate::samplex4::portable::PortableSampler,
+        crate::hash_functions::portable::Shake128X4,
+        crate::hash_functions::portable::Shake256,
+        crate::hash_functions::portable::Shake256Xof,
+        crate::hash_functions::portable::Shake256X4,
+    >(signing_key.as_ref(), digest, context, pre_hash, randomness)
+}
+
+/// Verify a HashML-DSA-44 Signature over an externally computed digest
+///
+/// Returns `Ok` when the `signature` is valid for `digest` under the
+/// declared `pre_hash` algorithm, and a [`VerificationError`] otherwise.
+#[cfg(not(eurydice))]
+pub fn verify_pre_hashed_digest(
+    verification_key: &MLDSA44VerificationKey,
+    digest: &[u8],
+    context: &[u8],
+    pre_hash: PreHashAlgorithm,
+    signature: &MLDSA44Signature,
+) -> Result<(), VerificationError> {
+    crate::ml_dsa_generic::ml_dsa_44::verify_pre_hashed_digest::<
+        crate::simd::portable::PortableSIMDUnit,
+        crate::samplex4::portable::PortableSampler,
+        crate::hash_functions::portable::Shake128X4,
+        crate::hash_functions::portable::Shake256,
+        crate::hash_functions::portable::Shake256Xof,
+    >(
+        verification_key.as_ref(),
+        digest,
+        context,
+        pre_hash,
+        signature.as_ref(),
+    )
+}
+
+/// Sign a precomputed message representative with ML-DSA 44
+///
+/// `message_representative` is `mu = SHAKE256(tr || M', 64)` from Algorithm 7
+/// of FIPS 204, where `tr` is the 64-byte SHAKE256 hash of the verification
+/// key and `M'` the domain-separated message. Computing `mu` incrementally
+/// lets callers sign a message without holding it in memory; the signature is
+/// identical to [`portable::sign`] over the same message, context and
+/// randomness.
+#[cfg(not(eurydice))]
+pub fn sign_message_representative(
+    signing_key: &MLDSA44SigningKey,
+    message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+    randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+) -> Result<MLDSA44Signature, SigningError> {
+    let mut signature = MLDSA44Signature::zero();
+    crate::ml_dsa_generic::ml_dsa_44::sign_message_representative::<
+        crate::simd::portable::PortableSIMDUnit,
+        crate::samplex4::portable::PortableSampler,
+        crate::hash_functions::portable::Shake128X4,
+        crate::hash_functions::portable::Shake256,
+        crate::hash_functions::portable::Shake256Xof,
+        crate::hash_functions::portable::Shake256X4,
+    >(
+        signing_key.as_ref(),
+        message_representative,
+        randomness,
+        &mut signature.value,
+    )?;
+    Ok(signature)
+}
+
+/// Verify an ML-DSA-44 Signature against a precomputed message representative
+///
+/// See [`sign_message_representative`] for how `mu` is derived. Returns `Ok`
+/// when the `signature` is valid for `message_representative` and
+/// `verification_key`, and a [`VerificationError`] otherwise.
+#[cfg(not(eurydice))]
+pub fn verify_message_representative(
+    verification_key: &MLDSA44VerificationKey,
+    message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+    signature: &MLDSA44Signature,
+) -> Result<(), VerificationError> {
+    crate::ml_dsa_generic::ml_dsa_44::verify_message_representative::<
+        crate::simd::portable::PortableSIMDUnit,
+        crate::samplex4::portable::PortableSampler,
+        crate::hash_functions::portable::Shake128X4,
+        crate::hash_functions::portable::Shake256,
+        crate::hash_functions::portable::Shake256Xof,
+    >(
+        verification_key.as_ref(),
+        message_representative,
+        signature.as_ref(),
+    )
+}
+
+/// Verify several ML-DSA-44 signatures under one verification key
+///
+/// The key is decoded, its matrix `A` expanded and its hash computed once,
+/// then reused for every `(message, signature)` pair in `items`. On return,
+/// `results[i]` is `true` exactly when `items[i]` verifies; entries beyond
+/// the shorter of the two slices are left untouched.
+///
+/// The parameter `context` is shared by all items and is a byte string of
+/// length at most 255 bytes. It may also be empty.
+#[cfg(not(eurydice))]
+pub fn verify_batch(
+    verification_key: &MLDSA44VerificationKey,
+    context: &[u8],
+    items: &[(&[u8], &MLDSA44Signature)],
+    results: &mut [bool],
+) -> Result<(), VerificationError> {
+    crate::ml_dsa_generic::ml_dsa_44::verify_batch::<
+        crate::simd::portable::PortableSIMDUnit,
+        crate::samplex4::portable::PortableSampler,
+        crate::hash_functions::portable::Shake128X4,
+        crate::hash_functions::portable::Shake256,
+        crate::hash_functions::portable::Shake256Xof,
+    >(verification_key.as_ref(), context, items, results)
+}
@@ -37,6 +37,7 @@ pub(crate) mod ml_dsa_44 {
     use crate::pre_hash::{
         DomainSeparationContext,
         PreHash,
+        PreHashAlgorithm,
     };
     use crate::sample::{
         sample_challenge_ring_element,
@@ -160,6 +161,61 @@ pub(crate) mod ml_dsa_44 {
         }
     }
 
+    /// Recompute the verification key `(rho, t1)` from a signing key.
+    ///
+    /// The signing key only stores a hash of the verification key, so `t1` is
+    /// rebuilt as in key generation from `A` (expanded from `rho`), `s1` and `s2`.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn derive_verification_key<SIMDUnit: Operations, Sampler: X4Sampler>(
+        signing_key: &[u8; SIGNING_KEY_SIZE],
+        verification_key: &mut [u8; VERIFICATION_KEY_SIZE],
+    ) {
+        let (seed_for_a, remaining_serialized) = signing_key.split_at(SEED_FOR_A_SIZE);
+        let remaining_serialized =
+            &remaining_serialized[SEED_FOR_SIGNING_SIZE + BYTES_FOR_VERIFICATION_KEY_HASH..];
+        let s1_s2_serialized = &remaining_serialized[..ERROR_RING_ELEMENT_SIZE * ROW_COLUMN];
+
+        let mut s1_s2 = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_COLUMN];
+        for (i, bytes) in s1_s2_serialized
+            .as_chunks::<ERROR_RING_ELEMENT_SIZE>()
+            .0
+            .iter()
+            .enumerate()
+        {
+            encoding::error::deserialize::<SIMDUnit>(ETA, bytes, &mut s1_s2[i]);
+        }
+
+        let mut t0 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        {
+            let mut a_as_ntt = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+            Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut a_as_ntt);
+
+            let mut s1_ntt = [PolynomialRingElement::<SIMDUnit>::zero(); COLUMNS_IN_A];
+            s1_ntt.copy_from_slice(&s1_s2[0..COLUMNS_IN_A]);
+            for elem in s1_ntt.iter_mut() {
+                ntt(elem);
+            }
+            compute_as1_plus_s2::<SIMDUnit>(
+                ROWS_IN_A,
+                COLUMNS_IN_A,
+                &mut a_as_ntt,
+                &s1_ntt,
+                &s1_s2,
+                &mut t0,
+            );
+        }
+
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        power2round_vector::<SIMDUnit>(&mut t0, &mut t1);
+
+        encoding::verification_key::generate_serialized::<SIMDUnit>(
+            seed_for_a,
+            &t1,
+            verification_key,
+        );
+    }
+
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub(crate) fn sign_internal<
@@ -175,6 +231,50 @@ pub(crate) mod ml_dsa_44 {
         domain_separation_context: Option<DomainSeparationContext>,
         randomness: [u8; SIGNING_RANDOMNESS_SIZE],
         signature: &mut [u8; SIGNATURE_SIZE],
+    ) -> Result<(), SigningError> {
+        let verification_key_hash = &signing_key[SEED_FOR_A_SIZE + SEED_FOR_SIGNING_SIZE..]
+            [..BYTES_FOR_VERIFICATION_KEY_HASH];
+        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
+        derive_message_representative::<Shake256Xof>(
+            verification_key_hash,
+            &domain_separation_context,
+            message,
+            &mut message_representative,
+        );
+
+        #[cfg_attr(not(feature = "zeroize"), allow(clippy::let_and_return))]
+        let result = sign_message_representative::<
+            SIMDUnit,
+            Sampler,
+            Shake128X4,
+            Shake256,
+            Shake256Xof,
+            Shake256X4,
+        >(signing_key, &message_representative, randomness, signature);
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            message_representative.zeroize();
+        }
+        result
+    }
+
+    /// Signing core of Algorithm 7 in FIPS 204, starting from the message
+    /// representative `mu` instead of the message.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn sign_message_representative<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+        Shake256X4: shake256::XofX4,
+    >(
+        signing_key: &[u8],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+        signature: &mut [u8; SIGNATURE_SIZE],
     ) -> Result<(), SigningError> {
         #[cfg(feature = "zeroize")]
         let mut randomness = randomness;
@@ -183,7 +283,7 @@ pub(crate) mod ml_dsa_44 {
         let (seed_for_a, remaining_serialized) = signing_key.split_at(SEED_FOR_A_SIZE);
         let (seed_for_signing, remaining_serialized) =
             remaining_serialized.split_at(SEED_FOR_SIGNING_SIZE);
-        let (verification_key_hash, remaining_serialized) =
+        let (_verification_key_hash, remaining_serialized) =
             remaining_serialized.split_at(BYTES_FOR_VERIFICATION_KEY_HASH);
 
         let (s1_serialized, remaining_serialized) =
@@ -234,20 +334,12 @@ pub(crate) mod ml_dsa_44 {
         let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
         Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut matrix);
 
-        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
-        derive_message_representative::<Shake256Xof>(
-            verification_key_hash,
-            &domain_separation_context,
-            message,
-            &mut message_representative,
-        );
-
         let mut mask_seed = [0; MASK_SEED_SIZE];
         {
             let mut shake = Shake256Xof::init();
             shake.absorb(seed_for_signing);
             shake.absorb(&randomness);
-            shake.absorb_final(&message_representative);
+            shake.absorb_final(message_representative);
 
             shake.squeeze(&mut mask_seed);
         }
@@ -314,7 +406,7 @@ pub(crate) mod ml_dsa_44 {
                 );
 
                 let mut shake = Shake256Xof::init();
-                shake.absorb(&message_representative);
+                shake.absorb(message_representative);
                 shake.absorb_final(&commitment_serialized);
 
                 shake.squeeze(&mut commitment_hash_candidate);
@@ -480,7 +572,6 @@ pub(crate) mod ml_dsa_44 {
             use zeroize::Zeroize;
             randomness.zeroize();
             mask_seed.zeroize();
-            message_representative.zeroize();
             for elem in matrix.iter_mut() {
                 *elem = PolynomialRingElement::<SIMDUnit>::zero();
             }
@@ -567,14 +658,124 @@ pub(crate) mod ml_dsa_44 {
         domain_separation_context: Option<DomainSeparationContext>,
         signature_serialized: &[u8; SIGNATURE_SIZE],
     ) -> Result<(), VerificationError> {
-        let (seed_for_a, t1_serialized) = verification_key.split_at(SEED_FOR_A_SIZE);
         let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
+            &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
+        );
+        verify_expanded::<SIMDUnit, Shake256, Shake256Xof>(
+            &t1,
+            &matrix,
+            &verification_key_hash,
+            message,
+            domain_separation_context,
+            signature_serialized,
+        )
+    }
+
+    /// Decode `t1`, expand `A` and hash the verification key once so that
+    /// several signatures under the same key can reuse the result.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn expand_verification_key<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        t1: &mut [PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &mut [PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        verification_key_hash: &mut [u8; BYTES_FOR_VERIFICATION_KEY_HASH],
+    ) {
+        let (seed_for_a, t1_serialized) = verification_key.split_at(SEED_FOR_A_SIZE);
         encoding::verification_key::deserialize::<SIMDUnit>(
             ROWS_IN_A,
             VERIFICATION_KEY_SIZE,
             t1_serialized,
+            t1,
+        );
+        Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, matrix);
+        Shake256::shake256(verification_key, verification_key_hash);
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn verify_expanded<
+        SIMDUnit: Operations,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        expanded_t1: &[PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &[PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        verification_key_hash: &[u8; BYTES_FOR_VERIFICATION_KEY_HASH],
+        message: &[u8],
+        domain_separation_context: Option<DomainSeparationContext>,
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
+        derive_message_representative::<Shake256Xof>(
+            verification_key_hash,
+            &domain_separation_context,
+            message,
+            &mut message_representative,
+        );
+        verify_expanded_message_representative::<SIMDUnit, Shake256, Shake256Xof>(
+            expanded_t1,
+            matrix,
+            &message_representative,
+            signature_serialized,
+        )
+    }
+
+    /// Verify a signature against a precomputed message representative `mu`.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_message_representative<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
             &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
         );
+        verify_expanded_message_representative::<SIMDUnit, Shake256, Shake256Xof>(
+            &t1,
+            &matrix,
+            message_representative,
+            signature_serialized,
+        )
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn verify_expanded_message_representative<
+        SIMDUnit: Operations,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        expanded_t1: &[PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &[PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut t1 = *expanded_t1;
 
         let mut deserialized_commitment_hash = [0u8; COMMITMENT_HASH_SIZE];
         let mut deserialized_signer_response = [PolynomialRingElement::zero(); COLUMNS_IN_A];
@@ -604,19 +805,6 @@ pub(crate) mod ml_dsa_44 {
         ) {
             return Err(VerificationError::SignerResponseExceedsBoundError);
         }
-        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
-        Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut matrix);
-
-        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
-        Shake256::shake256(verification_key, &mut verification_key_hash);
-
-        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
-        derive_message_representative::<Shake256Xof>(
-            &verification_key_hash,
-            &domain_separation_context,
-            message,
-            &mut message_representative,
-        );
 
         let mut verifier_challenge = PolynomialRingElement::zero();
         sample_challenge_ring_element::<SIMDUnit, Shake256>(
@@ -633,7 +821,7 @@ pub(crate) mod ml_dsa_44 {
         compute_w_approx::<SIMDUnit>(
             ROWS_IN_A,
             COLUMNS_IN_A,
-            &matrix,
+            matrix,
             &deserialized_signer_response,
             &verifier_challenge,
             &mut t1,
@@ -651,7 +839,7 @@ pub(crate) mod ml_dsa_44 {
             );
 
             let mut shake = Shake256Xof::init();
-            shake.absorb(&message_representative);
+            shake.absorb(message_representative);
             shake.absorb_final(&commitment_serialized);
 
             shake.squeeze(&mut recomputed_commitment_hash);
@@ -833,6 +1021,53 @@ pub(crate) mod ml_dsa_44 {
         )
     }
 
+    /// Verify several signatures under one verification key, expanding the
+    /// key only once. `results[i]` is set for `items[i]`; extra entries in
+    /// either slice are ignored.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_batch<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        context: &[u8],
+        items: &[(&[u8], &MLDSASignature<SIGNATURE_SIZE>)],
+        results: &mut [bool],
+    ) -> Result<(), VerificationError> {
+        if context.len() > CONTEXT_MAX_LEN {
+            return Err(VerificationError::VerificationContextTooLongError);
+        }
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
+            &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
+        );
+        for ((message, signature), result) in items.iter().zip(results.iter_mut()) {
+            let domain_separation_context = match DomainSeparationContext::new(context, None) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(VerificationError::VerificationContextTooLongError),
+            };
+            *result = verify_expanded::<SIMDUnit, Shake256, Shake256Xof>(
+                &t1,
+                &matrix,
+                &verification_key_hash,
+                message,
+                Some(domain_separation_context),
+                signature.as_ref(),
+            )
+            .is_ok();
+        }
+        Ok(())
+    }
+
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub(crate) fn verify_pre_hashed<
@@ -863,6 +1098,74 @@ pub(crate) mod ml_dsa_44 {
             signature_serialized,
         )
     }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn sign_pre_hashed_digest<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+        Shake256X4: shake256::XofX4,
+    >(
+        signing_key: &[u8],
+        digest: &[u8],
+        context: &[u8],
+        pre_hash: PreHashAlgorithm,
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+    ) -> Result<MLDSASignature<SIGNATURE_SIZE>, SigningError> {
+        if digest.len() != pre_hash.digest_len() {
+            return Err(SigningError::PreHashDigestLengthError);
+        }
+        let domain_separation_context =
+            match DomainSeparationContext::new(context, Some(pre_hash.oid())) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(SigningError::ContextTooLongError),
+            };
+        let mut signature = MLDSASignature::zero();
+        match sign_internal::<SIMDUnit, Sampler, Shake128X4, Shake256, Shake256Xof, Shake256X4>(
+            signing_key,
+            digest,
+            Some(domain_separation_context),
+            randomness,
+            &mut signature.value,
+        ) {
+            Ok(_) => Ok(signature),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_pre_hashed_digest<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        verification_key_serialized: &[u8; VERIFICATION_KEY_SIZE],
+        digest: &[u8],
+        context: &[u8],
+        pre_hash: PreHashAlgorithm,
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        if digest.len() != pre_hash.digest_len() {
+            return Err(VerificationError::VerificationPreHashDigestLengthError);
+        }
+        let domain_separation_context =
+            match DomainSeparationContext::new(context, Some(pre_hash.oid())) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(VerificationError::VerificationContextTooLongError),
+            };
+        verify_internal::<SIMDUnit, Sampler, Shake128X4, Shake256, Shake256Xof>(
+            verification_key_serialized,
+            digest,
+            Some(domain_separation_context),
+            signature_serialized,
+        )
+    }
 }
 
 #[cfg(feature = "mldsa65")]
@@ -895,6 +1198,7 @@ pub(crate) mod ml_dsa_65 {
     use crate::pre_hash::{
         DomainSeparationContext,
         PreHash,
+        PreHashAlgorithm,
     };
     use crate::sample::{
         sample_challenge_ring_element,
@@ -1018,6 +1322,61 @@ pub(crate) mod ml_dsa_65 {
         }
     }
 
+    /// Recompute the verification key `(rho, t1)` from a signing key.
+    ///
+    /// The signing key only stores a hash of the verification key, so `t1` is
+    /// rebuilt as in key generation from `A` (expanded from `rho`), `s1` and `s2`.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn derive_verification_key<SIMDUnit: Operations, Sampler: X4Sampler>(
+        signing_key: &[u8; SIGNING_KEY_SIZE],
+        verification_key: &mut [u8; VERIFICATION_KEY_SIZE],
+    ) {
+        let (seed_for_a, remaining_serialized) = signing_key.split_at(SEED_FOR_A_SIZE);
+        let remaining_serialized =
+            &remaining_serialized[SEED_FOR_SIGNING_SIZE + BYTES_FOR_VERIFICATION_KEY_HASH..];
+        let s1_s2_serialized = &remaining_serialized[..ERROR_RING_ELEMENT_SIZE * ROW_COLUMN];
+
+        let mut s1_s2 = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_COLUMN];
+        for (i, bytes) in s1_s2_serialized
+            .as_chunks::<ERROR_RING_ELEMENT_SIZE>()
+            .0
+            .iter()
+            .enumerate()
+        {
+            encoding::error::deserialize::<SIMDUnit>(ETA, bytes, &mut s1_s2[i]);
+        }
+
+        let mut t0 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        {
+            let mut a_as_ntt = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+            Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut a_as_ntt);
+
+            let mut s1_ntt = [PolynomialRingElement::<SIMDUnit>::zero(); COLUMNS_IN_A];
+            s1_ntt.copy_from_slice(&s1_s2[0..COLUMNS_IN_A]);
+            for elem in s1_ntt.iter_mut() {
+                ntt(elem);
+            }
+            compute_as1_plus_s2::<SIMDUnit>(
+                ROWS_IN_A,
+                COLUMNS_IN_A,
+                &mut a_as_ntt,
+                &s1_ntt,
+                &s1_s2,
+                &mut t0,
+            );
+        }
+
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        power2round_vector::<SIMDUnit>(&mut t0, &mut t1);
+
+        encoding::verification_key::generate_serialized::<SIMDUnit>(
+            seed_for_a,
+            &t1,
+            verification_key,
+        );
+    }
+
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub(crate) fn sign_internal<
@@ -1033,6 +1392,50 @@ pub(crate) mod ml_dsa_65 {
         domain_separation_context: Option<DomainSeparationContext>,
         randomness: [u8; SIGNING_RANDOMNESS_SIZE],
         signature: &mut [u8; SIGNATURE_SIZE],
+    ) -> Result<(), SigningError> {
+        let verification_key_hash = &signing_key[SEED_FOR_A_SIZE + SEED_FOR_SIGNING_SIZE..]
+            [..BYTES_FOR_VERIFICATION_KEY_HASH];
+        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
+        derive_message_representative::<Shake256Xof>(
+            verification_key_hash,
+            &domain_separation_context,
+            message,
+            &mut message_representative,
+        );
+
+        #[cfg_attr(not(feature = "zeroize"), allow(clippy::let_and_return))]
+        let result = sign_message_representative::<
+            SIMDUnit,
+            Sampler,
+            Shake128X4,
+            Shake256,
+            Shake256Xof,
+            Shake256X4,
+        >(signing_key, &message_representative, randomness, signature);
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            message_representative.zeroize();
+        }
+        result
+    }
+
+    /// Signing core of Algorithm 7 in FIPS 204, starting from the message
+    /// representative `mu` instead of the message.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn sign_message_representative<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+        Shake256X4: shake256::XofX4,
+    >(
+        signing_key: &[u8],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+        signature: &mut [u8; SIGNATURE_SIZE],
     ) -> Result<(), SigningError> {
         #[cfg(feature = "zeroize")]
         let mut randomness = randomness;
@@ -1041,7 +1444,7 @@ pub(crate) mod ml_dsa_65 {
         let (seed_for_a, remaining_serialized) = signing_key.split_at(SEED_FOR_A_SIZE);
         let (seed_for_signing, remaining_serialized) =
             remaining_serialized.split_at(SEED_FOR_SIGNING_SIZE);
-        let (verification_key_hash, remaining_serialized) =
+        let (_verification_key_hash, remaining_serialized) =
             remaining_serialized.split_at(BYTES_FOR_VERIFICATION_KEY_HASH);
 
         let (s1_serialized, remaining_serialized) =
@@ -1092,20 +1495,12 @@ pub(crate) mod ml_dsa_65 {
         let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
         Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut matrix);
 
-        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
-        derive_message_representative::<Shake256Xof>(
-            verification_key_hash,
-            &domain_separation_context,
-            message,
-            &mut message_representative,
-        );
-
         let mut mask_seed = [0; MASK_SEED_SIZE];
         {
             let mut shake = Shake256Xof::init();
             shake.absorb(seed_for_signing);
             shake.absorb(&randomness);
-            shake.absorb_final(&message_representative);
+            shake.absorb_final(message_representative);
 
             shake.squeeze(&mut mask_seed);
         }
@@ -1172,7 +1567,7 @@ pub(crate) mod ml_dsa_65 {
                 );
 
                 let mut shake = Shake256Xof::init();
-                shake.absorb(&message_representative);
+                shake.absorb(message_representative);
                 shake.absorb_final(&commitment_serialized);
 
                 shake.squeeze(&mut commitment_hash_candidate);
@@ -1338,7 +1733,6 @@ pub(crate) mod ml_dsa_65 {
             use zeroize::Zeroize;
             randomness.zeroize();
             mask_seed.zeroize();
-            message_representative.zeroize();
             for elem in matrix.iter_mut() {
                 *elem = PolynomialRingElement::<SIMDUnit>::zero();
             }
@@ -1425,14 +1819,124 @@ pub(crate) mod ml_dsa_65 {
         domain_separation_context: Option<DomainSeparationContext>,
         signature_serialized: &[u8; SIGNATURE_SIZE],
     ) -> Result<(), VerificationError> {
-        let (seed_for_a, t1_serialized) = verification_key.split_at(SEED_FOR_A_SIZE);
         let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
+            &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
+        );
+        verify_expanded::<SIMDUnit, Shake256, Shake256Xof>(
+            &t1,
+            &matrix,
+            &verification_key_hash,
+            message,
+            domain_separation_context,
+            signature_serialized,
+        )
+    }
+
+    /// Decode `t1`, expand `A` and hash the verification key once so that
+    /// several signatures under the same key can reuse the result.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn expand_verification_key<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        t1: &mut [PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &mut [PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        verification_key_hash: &mut [u8; BYTES_FOR_VERIFICATION_KEY_HASH],
+    ) {
+        let (seed_for_a, t1_serialized) = verification_key.split_at(SEED_FOR_A_SIZE);
         encoding::verification_key::deserialize::<SIMDUnit>(
             ROWS_IN_A,
             VERIFICATION_KEY_SIZE,
             t1_serialized,
+            t1,
+        );
+        Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, matrix);
+        Shake256::shake256(verification_key, verification_key_hash);
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn verify_expanded<
+        SIMDUnit: Operations,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        expanded_t1: &[PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &[PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        verification_key_hash: &[u8; BYTES_FOR_VERIFICATION_KEY_HASH],
+        message: &[u8],
+        domain_separation_context: Option<DomainSeparationContext>,
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
+        derive_message_representative::<Shake256Xof>(
+            verification_key_hash,
+            &domain_separation_context,
+            message,
+            &mut message_representative,
+        );
+        verify_expanded_message_representative::<SIMDUnit, Shake256, Shake256Xof>(
+            expanded_t1,
+            matrix,
+            &message_representative,
+            signature_serialized,
+        )
+    }
+
+    /// Verify a signature against a precomputed message representative `mu`.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_message_representative<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
             &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
         );
+        verify_expanded_message_representative::<SIMDUnit, Shake256, Shake256Xof>(
+            &t1,
+            &matrix,
+            message_representative,
+            signature_serialized,
+        )
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn verify_expanded_message_representative<
+        SIMDUnit: Operations,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        expanded_t1: &[PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &[PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut t1 = *expanded_t1;
 
         let mut deserialized_commitment_hash = [0u8; COMMITMENT_HASH_SIZE];
         let mut deserialized_signer_response = [PolynomialRingElement::zero(); COLUMNS_IN_A];
@@ -1462,19 +1966,6 @@ pub(crate) mod ml_dsa_65 {
         ) {
             return Err(VerificationError::SignerResponseExceedsBoundError);
         }
-        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
-        Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut matrix);
-
-        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
-        Shake256::shake256(verification_key, &mut verification_key_hash);
-
-        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
-        derive_message_representative::<Shake256Xof>(
-            &verification_key_hash,
-            &domain_separation_context,
-            message,
-            &mut message_representative,
-        );
 
         let mut verifier_challenge = PolynomialRingElement::zero();
         sample_challenge_ring_element::<SIMDUnit, Shake256>(
@@ -1491,7 +1982,7 @@ pub(crate) mod ml_dsa_65 {
         compute_w_approx::<SIMDUnit>(
             ROWS_IN_A,
             COLUMNS_IN_A,
-            &matrix,
+            matrix,
             &deserialized_signer_response,
             &verifier_challenge,
             &mut t1,
@@ -1509,7 +2000,7 @@ pub(crate) mod ml_dsa_65 {
             );
 
             let mut shake = Shake256Xof::init();
-            shake.absorb(&message_representative);
+            shake.absorb(message_representative);
             shake.absorb_final(&commitment_serialized);
 
             shake.squeeze(&mut recomputed_commitment_hash);
@@ -1691,32 +2182,147 @@ pub(crate) mod ml_dsa_65 {
         )
     }
 
+    /// Verify several signatures under one verification key, expanding the
+    /// key only once. `results[i]` is set for `items[i]`; extra entries in
+    /// either slice are ignored.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_batch<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        context: &[u8],
+        items: &[(&[u8], &MLDSASignature<SIGNATURE_SIZE>)],
+        results: &mut [bool],
+    ) -> Result<(), VerificationError> {
+        if context.len() > CONTEXT_MAX_LEN {
+            return Err(VerificationError::VerificationContextTooLongError);
+        }
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
+            &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
+        );
+        for ((message, signature), result) in items.iter().zip(results.iter_mut()) {
+            let domain_separation_context = match DomainSeparationContext::new(context, None) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(VerificationError::VerificationContextTooLongError),
+            };
+            *result = verify_expanded::<SIMDUnit, Shake256, Shake256Xof>(
+                &t1,
+                &matrix,
+                &verification_key_hash,
+                message,
+                Some(domain_separation_context),
+                signature.as_ref(),
+            )
+            .is_ok();
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_pre_hashed<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128: shake128::Xof,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+        PH: PreHash,
+    >(
+        verification_key_serialized: &[u8; VERIFICATION_KEY_SIZE],
+        message: &[u8],
+        context: &[u8],
+        pre_hash_buffer: &mut [u8],
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        PH::hash::<Shake128>(message, pre_hash_buffer);
+        let domain_separation_context = match DomainSeparationContext::new(context, Some(PH::oid()))
+        {
+            Ok(dsc) => dsc,
+            Err(_) => return Err(VerificationError::VerificationContextTooLongError),
+        };
+        verify_internal::<SIMDUnit, Sampler, Shake128X4, Shake256, Shake256Xof>(
+            verification_key_serialized,
+            pre_hash_buffer,
+            Some(domain_separation_context),
+            signature_serialized,
+        )
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn sign_pre_hashed_digest<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+        Shake256X4: shake256::XofX4,
+    >(
+        signing_key: &[u8],
+        digest: &[u8],
+        context: &[u8],
+        pre_hash: PreHashAlgorithm,
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+    ) -> Result<MLDSASignature<SIGNATURE_SIZE>, SigningError> {
+        if digest.len() != pre_hash.digest_len() {
+            return Err(SigningError::PreHashDigestLengthError);
+        }
+        let domain_separation_context =
+            match DomainSeparationContext::new(context, Some(pre_hash.oid())) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(SigningError::ContextTooLongError),
+            };
+        let mut signature = MLDSASignature::zero();
+        match sign_internal::<SIMDUnit, Sampler, Shake128X4, Shake256, Shake256Xof, Shake256X4>(
+            signing_key,
+            digest,
+            Some(domain_separation_context),
+            randomness,
+            &mut signature.value,
+        ) {
+            Ok(_) => Ok(signature),
+            Err(e) => Err(e),
+        }
+    }
+
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
-    pub(crate) fn verify_pre_hashed<
+    pub(crate) fn verify_pre_hashed_digest<
         SIMDUnit: Operations,
         Sampler: X4Sampler,
-        Shake128: shake128::Xof,
         Shake128X4: shake128::XofX4,
         Shake256: shake256::DsaXof,
         Shake256Xof: shake256::Xof,
-        PH: PreHash,
     >(
         verification_key_serialized: &[u8; VERIFICATION_KEY_SIZE],
-        message: &[u8],
+        digest: &[u8],
         context: &[u8],
-        pre_hash_buffer: &mut [u8],
+        pre_hash: PreHashAlgorithm,
         signature_serialized: &[u8; SIGNATURE_SIZE],
     ) -> Result<(), VerificationError> {
-        PH::hash::<Shake128>(message, pre_hash_buffer);
-        let domain_separation_context = match DomainSeparationContext::new(context, Some(PH::oid()))
-        {
-            Ok(dsc) => dsc,
-            Err(_) => return Err(VerificationError::VerificationContextTooLongError),
-        };
+        if digest.len() != pre_hash.digest_len() {
+            return Err(VerificationError::VerificationPreHashDigestLengthError);
+        }
+        let domain_separation_context =
+            match DomainSeparationContext::new(context, Some(pre_hash.oid())) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(VerificationError::VerificationContextTooLongError),
+            };
         verify_internal::<SIMDUnit, Sampler, Shake128X4, Shake256, Shake256Xof>(
             verification_key_serialized,
-            pre_hash_buffer,
+            digest,
             Some(domain_separation_context),
             signature_serialized,
         )
@@ -1798,6 +2404,7 @@ pub(crate) mod ml_dsa_87 {
     use crate::pre_hash::{
         DomainSeparationContext,
         PreHash,
+        PreHashAlgorithm,
     };
     use crate::sample::{
         sample_challenge_ring_element,
@@ -1921,6 +2528,61 @@ pub(crate) mod ml_dsa_87 {
         }
     }
 
+    /// Recompute the verification key `(rho, t1)` from a signing key.
+    ///
+    /// The signing key only stores a hash of the verification key, so `t1` is
+    /// rebuilt as in key generation from `A` (expanded from `rho`), `s1` and `s2`.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn derive_verification_key<SIMDUnit: Operations, Sampler: X4Sampler>(
+        signing_key: &[u8; SIGNING_KEY_SIZE],
+        verification_key: &mut [u8; VERIFICATION_KEY_SIZE],
+    ) {
+        let (seed_for_a, remaining_serialized) = signing_key.split_at(SEED_FOR_A_SIZE);
+        let remaining_serialized =
+            &remaining_serialized[SEED_FOR_SIGNING_SIZE + BYTES_FOR_VERIFICATION_KEY_HASH..];
+        let s1_s2_serialized = &remaining_serialized[..ERROR_RING_ELEMENT_SIZE * ROW_COLUMN];
+
+        let mut s1_s2 = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_COLUMN];
+        for (i, bytes) in s1_s2_serialized
+            .as_chunks::<ERROR_RING_ELEMENT_SIZE>()
+            .0
+            .iter()
+            .enumerate()
+        {
+            encoding::error::deserialize::<SIMDUnit>(ETA, bytes, &mut s1_s2[i]);
+        }
+
+        let mut t0 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        {
+            let mut a_as_ntt = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+            Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut a_as_ntt);
+
+            let mut s1_ntt = [PolynomialRingElement::<SIMDUnit>::zero(); COLUMNS_IN_A];
+            s1_ntt.copy_from_slice(&s1_s2[0..COLUMNS_IN_A]);
+            for elem in s1_ntt.iter_mut() {
+                ntt(elem);
+            }
+            compute_as1_plus_s2::<SIMDUnit>(
+                ROWS_IN_A,
+                COLUMNS_IN_A,
+                &mut a_as_ntt,
+                &s1_ntt,
+                &s1_s2,
+                &mut t0,
+            );
+        }
+
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        power2round_vector::<SIMDUnit>(&mut t0, &mut t1);
+
+        encoding::verification_key::generate_serialized::<SIMDUnit>(
+            seed_for_a,
+            &t1,
+            verification_key,
+        );
+    }
+
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub(crate) fn sign_internal<
@@ -1936,6 +2598,50 @@ pub(crate) mod ml_dsa_87 {
         domain_separation_context: Option<DomainSeparationContext>,
         randomness: [u8; SIGNING_RANDOMNESS_SIZE],
         signature: &mut [u8; SIGNATURE_SIZE],
+    ) -> Result<(), SigningError> {
+        let verification_key_hash = &signing_key[SEED_FOR_A_SIZE + SEED_FOR_SIGNING_SIZE..]
+            [..BYTES_FOR_VERIFICATION_KEY_HASH];
+        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
+        derive_message_representative::<Shake256Xof>(
+            verification_key_hash,
+            &domain_separation_context,
+            message,
+            &mut message_representative,
+        );
+
+        #[cfg_attr(not(feature = "zeroize"), allow(clippy::let_and_return))]
+        let result = sign_message_representative::<
+            SIMDUnit,
+            Sampler,
+            Shake128X4,
+            Shake256,
+            Shake256Xof,
+            Shake256X4,
+        >(signing_key, &message_representative, randomness, signature);
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            message_representative.zeroize();
+        }
+        result
+    }
+
+    /// Signing core of Algorithm 7 in FIPS 204, starting from the message
+    /// representative `mu` instead of the message.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn sign_message_representative<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+        Shake256X4: shake256::XofX4,
+    >(
+        signing_key: &[u8],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+        signature: &mut [u8; SIGNATURE_SIZE],
     ) -> Result<(), SigningError> {
         #[cfg(feature = "zeroize")]
         let mut randomness = randomness;
@@ -1944,7 +2650,7 @@ pub(crate) mod ml_dsa_87 {
         let (seed_for_a, remaining_serialized) = signing_key.split_at(SEED_FOR_A_SIZE);
         let (seed_for_signing, remaining_serialized) =
             remaining_serialized.split_at(SEED_FOR_SIGNING_SIZE);
-        let (verification_key_hash, remaining_serialized) =
+        let (_verification_key_hash, remaining_serialized) =
             remaining_serialized.split_at(BYTES_FOR_VERIFICATION_KEY_HASH);
 
         let (s1_serialized, remaining_serialized) =
@@ -1995,20 +2701,12 @@ pub(crate) mod ml_dsa_87 {
         let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
         Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut matrix);
 
-        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
-        derive_message_representative::<Shake256Xof>(
-            verification_key_hash,
-            &domain_separation_context,
-            message,
-            &mut message_representative,
-        );
-
         let mut mask_seed = [0; MASK_SEED_SIZE];
         {
             let mut shake = Shake256Xof::init();
             shake.absorb(seed_for_signing);
             shake.absorb(&randomness);
-            shake.absorb_final(&message_representative);
+            shake.absorb_final(message_representative);
 
             shake.squeeze(&mut mask_seed);
         }
@@ -2075,7 +2773,7 @@ pub(crate) mod ml_dsa_87 {
                 );
 
                 let mut shake = Shake256Xof::init();
-                shake.absorb(&message_representative);
+                shake.absorb(message_representative);
                 shake.absorb_final(&commitment_serialized);
 
                 shake.squeeze(&mut commitment_hash_candidate);
@@ -2241,7 +2939,6 @@ pub(crate) mod ml_dsa_87 {
             use zeroize::Zeroize;
             randomness.zeroize();
             mask_seed.zeroize();
-            message_representative.zeroize();
             for elem in matrix.iter_mut() {
                 *elem = PolynomialRingElement::<SIMDUnit>::zero();
             }
@@ -2328,14 +3025,124 @@ pub(crate) mod ml_dsa_87 {
         domain_separation_context: Option<DomainSeparationContext>,
         signature_serialized: &[u8; SIGNATURE_SIZE],
     ) -> Result<(), VerificationError> {
-        let (seed_for_a, t1_serialized) = verification_key.split_at(SEED_FOR_A_SIZE);
         let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
+            &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
+        );
+        verify_expanded::<SIMDUnit, Shake256, Shake256Xof>(
+            &t1,
+            &matrix,
+            &verification_key_hash,
+            message,
+            domain_separation_context,
+            signature_serialized,
+        )
+    }
+
+    /// Decode `t1`, expand `A` and hash the verification key once so that
+    /// several signatures under the same key can reuse the result.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn expand_verification_key<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        t1: &mut [PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &mut [PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        verification_key_hash: &mut [u8; BYTES_FOR_VERIFICATION_KEY_HASH],
+    ) {
+        let (seed_for_a, t1_serialized) = verification_key.split_at(SEED_FOR_A_SIZE);
         encoding::verification_key::deserialize::<SIMDUnit>(
             ROWS_IN_A,
             VERIFICATION_KEY_SIZE,
             t1_serialized,
+            t1,
+        );
+        Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, matrix);
+        Shake256::shake256(verification_key, verification_key_hash);
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn verify_expanded<
+        SIMDUnit: Operations,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        expanded_t1: &[PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &[PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        verification_key_hash: &[u8; BYTES_FOR_VERIFICATION_KEY_HASH],
+        message: &[u8],
+        domain_separation_context: Option<DomainSeparationContext>,
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
+        derive_message_representative::<Shake256Xof>(
+            verification_key_hash,
+            &domain_separation_context,
+            message,
+            &mut message_representative,
+        );
+        verify_expanded_message_representative::<SIMDUnit, Shake256, Shake256Xof>(
+            expanded_t1,
+            matrix,
+            &message_representative,
+            signature_serialized,
+        )
+    }
+
+    /// Verify a signature against a precomputed message representative `mu`.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_message_representative<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
             &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
         );
+        verify_expanded_message_representative::<SIMDUnit, Shake256, Shake256Xof>(
+            &t1,
+            &matrix,
+            message_representative,
+            signature_serialized,
+        )
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn verify_expanded_message_representative<
+        SIMDUnit: Operations,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        expanded_t1: &[PolynomialRingElement<SIMDUnit>; ROWS_IN_A],
+        matrix: &[PolynomialRingElement<SIMDUnit>; ROW_X_COLUMN],
+        message_representative: &[u8; MESSAGE_REPRESENTATIVE_SIZE],
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        let mut t1 = *expanded_t1;
 
         let mut deserialized_commitment_hash = [0u8; COMMITMENT_HASH_SIZE];
         let mut deserialized_signer_response = [PolynomialRingElement::zero(); COLUMNS_IN_A];
@@ -2365,19 +3172,6 @@ pub(crate) mod ml_dsa_87 {
         ) {
             return Err(VerificationError::SignerResponseExceedsBoundError);
         }
-        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
-        Sampler::matrix_flat::<SIMDUnit>(COLUMNS_IN_A, seed_for_a, &mut matrix);
-
-        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
-        Shake256::shake256(verification_key, &mut verification_key_hash);
-
-        let mut message_representative = [0; MESSAGE_REPRESENTATIVE_SIZE];
-        derive_message_representative::<Shake256Xof>(
-            &verification_key_hash,
-            &domain_separation_context,
-            message,
-            &mut message_representative,
-        );
 
         let mut verifier_challenge = PolynomialRingElement::zero();
         sample_challenge_ring_element::<SIMDUnit, Shake256>(
@@ -2394,7 +3188,7 @@ pub(crate) mod ml_dsa_87 {
         compute_w_approx::<SIMDUnit>(
             ROWS_IN_A,
             COLUMNS_IN_A,
-            &matrix,
+            matrix,
             &deserialized_signer_response,
             &verifier_challenge,
             &mut t1,
@@ -2412,7 +3206,7 @@ pub(crate) mod ml_dsa_87 {
             );
 
             let mut shake = Shake256Xof::init();
-            shake.absorb(&message_representative);
+            shake.absorb(message_representative);
             shake.absorb_final(&commitment_serialized);
 
             shake.squeeze(&mut recomputed_commitment_hash);
@@ -2594,6 +3388,53 @@ pub(crate) mod ml_dsa_87 {
         )
     }
 
+    /// Verify several signatures under one verification key, expanding the
+    /// key only once. `results[i]` is set for `items[i]`; extra entries in
+    /// either slice are ignored.
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_batch<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        verification_key: &[u8; VERIFICATION_KEY_SIZE],
+        context: &[u8],
+        items: &[(&[u8], &MLDSASignature<SIGNATURE_SIZE>)],
+        results: &mut [bool],
+    ) -> Result<(), VerificationError> {
+        if context.len() > CONTEXT_MAX_LEN {
+            return Err(VerificationError::VerificationContextTooLongError);
+        }
+        let mut t1 = [PolynomialRingElement::<SIMDUnit>::zero(); ROWS_IN_A];
+        let mut matrix = [PolynomialRingElement::<SIMDUnit>::zero(); ROW_X_COLUMN];
+        let mut verification_key_hash = [0; BYTES_FOR_VERIFICATION_KEY_HASH];
+        expand_verification_key::<SIMDUnit, Sampler, Shake128X4, Shake256>(
+            verification_key,
+            &mut t1,
+            &mut matrix,
+            &mut verification_key_hash,
+        );
+        for ((message, signature), result) in items.iter().zip(results.iter_mut()) {
+            let domain_separation_context = match DomainSeparationContext::new(context, None) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(VerificationError::VerificationContextTooLongError),
+            };
+            *result = verify_expanded::<SIMDUnit, Shake256, Shake256Xof>(
+                &t1,
+                &matrix,
+                &verification_key_hash,
+                message,
+                Some(domain_separation_context),
+                signature.as_ref(),
+            )
+            .is_ok();
+        }
+        Ok(())
+    }
+
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub(crate) fn verify_pre_hashed<
@@ -2624,4 +3465,72 @@ pub(crate) mod ml_dsa_87 {
             signature_serialized,
         )
     }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn sign_pre_hashed_digest<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+        Shake256X4: shake256::XofX4,
+    >(
+        signing_key: &[u8],
+        digest: &[u8],
+        context: &[u8],
+        pre_hash: PreHashAlgorithm,
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+    ) -> Result<MLDSASignature<SIGNATURE_SIZE>, SigningError> {
+        if digest.len() != pre_hash.digest_len() {
+            return Err(SigningError::PreHashDigestLengthError);
+        }
+        let domain_separation_context =
+            match DomainSeparationContext::new(context, Some(pre_hash.oid())) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(SigningError::ContextTooLongError),
+            };
+        let mut signature = MLDSASignature::zero();
+        match sign_internal::<SIMDUnit, Sampler, Shake128X4, Shake256, Shake256Xof, Shake256X4>(
+            signing_key,
+            digest,
+            Some(domain_separation_context),
+            randomness,
+            &mut signature.value,
+        ) {
+            Ok(_) => Ok(signature),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[cfg_attr(tarpaulin, inline(never))]
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn verify_pre_hashed_digest<
+        SIMDUnit: Operations,
+        Sampler: X4Sampler,
+        Shake128X4: shake128::XofX4,
+        Shake256: shake256::DsaXof,
+        Shake256Xof: shake256::Xof,
+    >(
+        verification_key_serialized: &[u8; VERIFICATION_KEY_SIZE],
+        digest: &[u8],
+        context: &[u8],
+        pre_hash: PreHashAlgorithm,
+        signature_serialized: &[u8; SIGNATURE_SIZE],
+    ) -> Result<(), VerificationError> {
+        if digest.len() != pre_hash.digest_len() {
+            return Err(VerificationError::VerificationPreHashDigestLengthError);
+        }
+        let domain_separation_context =
+            match DomainSeparationContext::new(context, Some(pre_hash.oid())) {
+                Ok(dsc) => dsc,
+                Err(_) => return Err(VerificationError::VerificationContextTooLongError),
+            };
+        verify_internal::<SIMDUnit, Sampler, Shake128X4, Shake256, Shake256Xof>(
+            verification_key_serialized,
+            digest,
+            Some(domain_separation_context),
+            signature_serialized,
+        )
+    }
 }
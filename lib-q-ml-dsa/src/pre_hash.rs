@@ -45,6 +45,54 @@ impl PreHash for SHAKE128_PH {
     }
 }
 
+const SHA3_256_OID: PreHashOID = [
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x08,
+];
+const SHA3_384_OID: PreHashOID = [
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x09,
+];
+const SHA3_512_OID: PreHashOID = [
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0A,
+];
+const SHAKE256_OID: PreHashOID = [
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0C,
+];
+
+/// Hash functions and XOFs whose digests may be signed directly with
+/// HashML-DSA, when the caller has already computed the pre-hash.
+///
+/// Digest lengths follow Table 3 of FIPS 204; SHAKE256 uses a 512-bit
+/// output.
+#[cfg_attr(not(eurydice), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PreHashAlgorithm {
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Shake256,
+}
+
+impl PreHashAlgorithm {
+    /// The DER-encoded OID that is bound into the signed message representative.
+    pub const fn oid(self) -> PreHashOID {
+        match self {
+            PreHashAlgorithm::Sha3_256 => SHA3_256_OID,
+            PreHashAlgorithm::Sha3_384 => SHA3_384_OID,
+            PreHashAlgorithm::Sha3_512 => SHA3_512_OID,
+            PreHashAlgorithm::Shake256 => SHAKE256_OID,
+        }
+    }
+
+    /// The digest length in bytes expected for this hash function.
+    pub const fn digest_len(self) -> usize {
+        match self {
+            PreHashAlgorithm::Sha3_256 => 32,
+            PreHashAlgorithm::Sha3_384 => 48,
+            PreHashAlgorithm::Sha3_512 | PreHashAlgorithm::Shake256 => 64,
+        }
+    }
+}
+
 /// Binds the context string to an optional pre-hash OID identifying
 /// the hash function or XOF used for pre-hashing.
 pub struct DomainSeparationContext<'a> {
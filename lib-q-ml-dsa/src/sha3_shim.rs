@@ -12,6 +12,15 @@ use lib_q_sha3::digest::{
     Update,
     XofReader,
 };
+#[cfg(any(
+    test,
+    all(feature = "simd256", target_arch = "x86_64"),
+    all(feature = "simd128", target_arch = "aarch64")
+))]
+use lib_q_sha3::digest::{
+    block_api::BlockSizeUser,
+    typenum::Unsigned,
+};
 use lib_q_sha3::{
     Shake128,
     Shake128Reader,
@@ -19,6 +28,17 @@ use lib_q_sha3::{
     Shake256Reader,
 };
 
+/// SHAKE128 rate in bytes (168), the block size of [`Shake128`]
+#[cfg(any(test, all(feature = "simd256", target_arch = "x86_64")))]
+const SHAKE128_RATE: usize = <<Shake128 as BlockSizeUser>::BlockSize as Unsigned>::USIZE;
+/// SHAKE256 rate in bytes (136), the block size of [`Shake256`]
+#[cfg(any(
+    test,
+    all(feature = "simd256", target_arch = "x86_64"),
+    all(feature = "simd128", target_arch = "aarch64")
+))]
+const SHAKE256_RATE: usize = <<Shake256 as BlockSizeUser>::BlockSize as Unsigned>::USIZE;
+
 /// A portable SHAKE128 implementation compatible with libcrux API.
 #[cfg_attr(tarpaulin, inline(never))]
 #[cfg_attr(not(tarpaulin), inline(always))]
@@ -36,6 +56,10 @@ pub fn shake256(out: &mut [u8], input: &[u8]) {
 }
 
 /// The Keccak state for the incremental API, compatible with libcrux.
+///
+/// During signing the sponge holds secret-derived input. With the `zeroize` feature the
+/// wrapped `lib_q_sha3` hashers and readers clear their Keccak state on drop, which makes this
+/// type [`ZeroizeOnDrop`](zeroize::ZeroizeOnDrop).
 #[derive(Clone)]
 pub enum KeccakState {
     Shake128 {
@@ -66,20 +90,17 @@ impl KeccakState {
     }
 
     /// Absorb input data (compatible with libcrux API).
+    ///
+    /// Absorbing after output has been squeezed extends the input; the next squeeze
+    /// restarts the output stream over everything absorbed so far.
     pub fn absorb(&mut self, input: &[u8]) {
         match self {
             KeccakState::Shake128 { hasher, reader } => {
-                // If we already have a reader, we can't absorb more data
-                if reader.is_some() {
-                    panic!("Cannot absorb after finalization");
-                }
+                *reader = None;
                 hasher.update(input);
             }
             KeccakState::Shake256 { hasher, reader } => {
-                // If we already have a reader, we can't absorb more data
-                if reader.is_some() {
-                    panic!("Cannot absorb after finalization");
-                }
+                *reader = None;
                 hasher.update(input);
             }
         }
@@ -90,46 +111,30 @@ impl KeccakState {
         self.absorb(input);
     }
 
-    /// Finalize the state and prepare for squeezing.
-    /// This should be called once after all absorption is complete.
-    fn finalize(&mut self) {
-        match self {
-            KeccakState::Shake128 { hasher, reader } => {
-                if reader.is_none() {
-                    *reader = Some(hasher.clone().finalize_xof());
-                }
-            }
-            KeccakState::Shake256 { hasher, reader } => {
-                if reader.is_none() {
-                    *reader = Some(hasher.clone().finalize_xof());
-                }
-            }
-        }
-    }
-
     /// Squeeze output data (compatible with libcrux API).
+    ///
+    /// The first squeeze finalizes the absorbed input; later squeezes continue the same
+    /// output stream, so `out` may have any length.
     pub fn squeeze(&mut self, out: &mut [u8]) {
-        self.finalize();
         match self {
-            KeccakState::Shake128 { reader, .. } => {
-                if let Some(reader) = reader {
-                    reader.read(out);
-                } else {
-                    panic!("Reader not initialized");
-                }
-            }
-            KeccakState::Shake256 { reader, .. } => {
-                if let Some(reader) = reader {
-                    reader.read(out);
-                } else {
-                    panic!("Reader not initialized");
-                }
-            }
+            KeccakState::Shake128 { hasher, reader } => reader
+                .get_or_insert_with(|| hasher.clone().finalize_xof())
+                .read(out),
+            KeccakState::Shake256 { hasher, reader } => reader
+                .get_or_insert_with(|| hasher.clone().finalize_xof())
+                .read(out),
         }
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for KeccakState {}
+
 /// Incremental SHA3 API compatible with libcrux.
+///
+/// The block-named squeeze functions accept any output length and continue the output
+/// stream, and the `absorb_final` functions re-initialize a state of the other SHAKE
+/// variant instead of failing, so no input can make these functions panic.
 pub mod incremental {
     use super::*;
 
@@ -144,85 +149,32 @@ pub mod incremental {
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn shake128_absorb_final(s: &mut KeccakState, data: &[u8]) {
-        match s {
-            KeccakState::Shake128 { hasher, reader } => {
-                // If we already have a reader, we can't absorb more data
-                if reader.is_some() {
-                    panic!("Cannot absorb after finalization");
-                }
-                hasher.update(data);
-            }
-            _ => panic!("Invalid state for SHAKE-128 operation"),
+        if !matches!(s, KeccakState::Shake128 { .. }) {
+            *s = KeccakState::new_shake128();
         }
+        s.absorb(data);
     }
 
-    /// Squeeze three blocks for SHAKE-128.
+    /// Squeeze three blocks (504 bytes) for SHAKE-128.
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     #[allow(dead_code)]
     pub fn shake128_squeeze_first_three_blocks(s: &mut KeccakState, out: &mut [u8]) {
-        debug_assert!(out.len() == 168 * 3); // 3 blocks of 168 bytes
-        match s {
-            KeccakState::Shake128 { hasher, reader } => {
-                // Initialize reader if not already done
-                if reader.is_none() {
-                    *reader = Some(hasher.clone().finalize_xof());
-                }
-                // Read the first 3 blocks
-                if let Some(reader) = reader {
-                    reader.read(out);
-                } else {
-                    panic!("Reader not initialized");
-                }
-            }
-            _ => panic!("Invalid state for SHAKE-128 operation"),
-        }
+        s.squeeze(out);
     }
 
-    /// Squeeze first five blocks for SHAKE-128.
-    /// This function maintains state properly by creating a reader and reading the first 5 blocks.
+    /// Squeeze the first five blocks (840 bytes) for SHAKE-128.
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn shake128_squeeze_first_five_blocks(s: &mut KeccakState, out: &mut [u8]) {
-        debug_assert!(out.len() == 168 * 5); // 5 blocks of 168 bytes
-        match s {
-            KeccakState::Shake128 { hasher, reader } => {
-                // Initialize reader if not already done
-                if reader.is_none() {
-                    *reader = Some(hasher.clone().finalize_xof());
-                }
-                // Read the first 5 blocks
-                if let Some(reader) = reader {
-                    reader.read(out);
-                } else {
-                    panic!("Reader not initialized");
-                }
-            }
-            _ => panic!("Invalid state for SHAKE-128 operation"),
-        }
+        s.squeeze(out);
     }
 
-    /// Squeeze next block for SHAKE-128.
-    /// This function should be called after squeeze_first_five_blocks to get the next block.
+    /// Squeeze the next block (168 bytes) for SHAKE-128.
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn shake128_squeeze_next_block(s: &mut KeccakState, out: &mut [u8]) {
-        debug_assert!(out.len() == 168); // 1 block of 168 bytes
-        match s {
-            KeccakState::Shake128 { hasher, reader } => {
-                // Initialize reader if not already done
-                if reader.is_none() {
-                    *reader = Some(hasher.clone().finalize_xof());
-                }
-                // Read the next block
-                if let Some(reader) = reader {
-                    reader.read(out);
-                } else {
-                    panic!("Reader not initialized");
-                }
-            }
-            _ => panic!("Invalid state for SHAKE-128 operation"),
-        }
+        s.squeeze(out);
     }
 
     /// Create a new SHAKE-256 state object.
@@ -236,60 +188,24 @@ pub mod incremental {
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn shake256_absorb_final(s: &mut KeccakState, data: &[u8]) {
-        match s {
-            KeccakState::Shake256 { hasher, reader } => {
-                // If we already have a reader, we can't absorb more data
-                if reader.is_some() {
-                    panic!("Cannot absorb after finalization");
-                }
-                hasher.update(data);
-            }
-            _ => panic!("Invalid state for SHAKE-256 operation"),
+        if !matches!(s, KeccakState::Shake256 { .. }) {
+            *s = KeccakState::new_shake256();
         }
+        s.absorb(data);
     }
 
-    /// Squeeze the first SHAKE-256 block.
+    /// Squeeze the first SHAKE-256 block (136 bytes).
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn shake256_squeeze_first_block(s: &mut KeccakState, out: &mut [u8]) {
-        debug_assert!(out.len() == 136); // 1 block of 136 bytes
-        match s {
-            KeccakState::Shake256 { hasher, reader } => {
-                // Initialize reader if not already done
-                if reader.is_none() {
-                    *reader = Some(hasher.clone().finalize_xof());
-                }
-                // Read the first block
-                if let Some(reader) = reader {
-                    reader.read(out);
-                } else {
-                    panic!("Reader not initialized");
-                }
-            }
-            _ => panic!("Invalid state for SHAKE-256 operation"),
-        }
+        s.squeeze(out);
     }
 
-    /// Squeeze the next SHAKE-256 block.
+    /// Squeeze the next SHAKE-256 block (136 bytes).
     #[cfg_attr(tarpaulin, inline(never))]
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn shake256_squeeze_next_block(s: &mut KeccakState, out: &mut [u8]) {
-        debug_assert!(out.len() == 136); // 1 block of 136 bytes
-        match s {
-            KeccakState::Shake256 { hasher, reader } => {
-                // Initialize reader if not already done
-                if reader.is_none() {
-                    *reader = Some(hasher.clone().finalize_xof());
-                }
-                // Read the next block
-                if let Some(reader) = reader {
-                    reader.read(out);
-                } else {
-                    panic!("Reader not initialized");
-                }
-            }
-            _ => panic!("Invalid state for SHAKE-256 operation"),
-        }
+        s.squeeze(out);
     }
 }
 
@@ -325,10 +241,19 @@ pub mod avx2 {
             // reader). The previous hand-rolled body absorbed past the rate, squeezed the capacity
             // lanes, and capped output at 200 bytes with no inter-block permutation — so ExpandMask's
             // 576/640-byte mask requests returned garbage, making every avx2 signing attempt reject.
-            lib_q_sha3::parallel::shake256_x4(
-                [input0, input1, input2, input3],
-                [out0, out1, out2, out3],
-            );
+            let inputs = [input0, input1, input2, input3];
+            let outputs = [out0, out1, out2, out3];
+            // The batched sponge needs lockstep block schedules; ML-DSA always hashes equal
+            // lengths, anything else is hashed lane by lane.
+            if inputs.iter().all(|i| i.len() == input0.len()) &&
+                outputs.iter().all(|o| o.len() == outputs[0].len())
+            {
+                lib_q_sha3::parallel::shake256_x4(inputs, outputs);
+            } else {
+                for (input, output) in inputs.into_iter().zip(outputs) {
+                    super::super::shake256(output, input);
+                }
+            }
         }
 
         /// Incremental 4-way SHAKE driven by [`lib_q_keccak::p1600x4`] — true SIMD when AVX2 is
@@ -338,29 +263,50 @@ pub mod avx2 {
         /// (matrix Â expansion + per-rejection mask `y` sampling both stream through here).
         ///
         /// Output matches the scalar reader byte-for-byte: `absorb_final` pads and runs the final
-        /// permutation (state → block 0), `squeeze_first_*` extracts block 0 then permutes between
-        /// further blocks, and `squeeze_next_*` permutes before extracting (continuation). All SLH-
-        /// style seeds here are a single rate block, so the multi-block absorb path is just for safety.
+        /// permutation (state → block 0), and every squeeze continues from the current offset in the
+        /// rate block, permuting whenever a block is used up — so output lengths need not be block
+        /// multiples. All SLH-style seeds here are a single rate block, so the multi-block absorb
+        /// path is just for safety.
         pub mod incremental {
             use lib_q_keccak::{
                 p1600,
                 p1600x4,
             };
 
-            const RATE_128: usize = 168;
-            const RATE_256: usize = 136;
+            use super::super::super::{
+                SHAKE128_RATE as RATE_128,
+                SHAKE256_RATE as RATE_256,
+            };
             const SHAKE_DS: u8 = 0x1F;
             const ROUNDS: usize = 24;
 
             /// Four independent Keccak-f\[1600\] states; lane `k` carries input/output stream `k`.
+            ///
+            /// The lanes hold secret-derived data while signing; with the `zeroize` feature they
+            /// are cleared on drop.
             pub struct KeccakStateX4 {
                 states: [[u64; 25]; 4],
+                /// Bytes of the current rate block already squeezed.
+                offset: usize,
+            }
+
+            #[cfg(feature = "zeroize")]
+            impl Drop for KeccakStateX4 {
+                fn drop(&mut self) {
+                    use zeroize::Zeroize;
+                    self.states.zeroize();
+                    self.offset.zeroize();
+                }
             }
 
+            #[cfg(feature = "zeroize")]
+            impl zeroize::ZeroizeOnDrop for KeccakStateX4 {}
+
             impl KeccakStateX4 {
                 pub fn new() -> Self {
                     Self {
                         states: [[0u64; 25]; 4],
+                        offset: 0,
                     }
                 }
             }
@@ -391,19 +337,29 @@ pub mod avx2 {
                 }
             }
 
-            /// Extract `out.len()` (≤ RATE) bytes from the rate region of one state, little-endian.
+            /// Extract `out.len()` bytes starting at byte `start` of one state, little-endian.
             #[inline]
-            fn squeeze_block(state: &[u64; 25], out: &mut [u8]) {
-                for (chunk, s) in out.chunks_mut(8).zip(state.iter()) {
-                    chunk.copy_from_slice(&s.to_le_bytes()[..chunk.len()]);
+            fn squeeze_bytes(state: &[u64; 25], start: usize, out: &mut [u8]) {
+                if start.is_multiple_of(8) {
+                    for (chunk, s) in out.chunks_mut(8).zip(&state[start / 8..]) {
+                        chunk.copy_from_slice(&s.to_le_bytes()[..chunk.len()]);
+                    }
+                } else {
+                    for (i, byte) in out.iter_mut().enumerate() {
+                        let idx = start + i;
+                        *byte = (state[idx / 8] >> (8 * (idx % 8))) as u8;
+                    }
                 }
             }
 
             /// Absorb each input (one rate block in practice), apply SHAKE pad10*1, and run the final
             /// permutation across all four lanes — leaving the states positioned at output block 0.
+            /// Any previous contents of the state are discarded.
             #[inline]
-            fn absorb_final<const RATE: usize>(states: &mut [[u64; 25]; 4], inputs: [&[u8]; 4]) {
-                for (state, input) in states.iter_mut().zip(inputs.iter()) {
+            fn absorb_final<const RATE: usize>(s: &mut KeccakStateX4, inputs: [&[u8]; 4]) {
+                s.states = [[0u64; 25]; 4];
+                s.offset = 0;
+                for (state, input) in s.states.iter_mut().zip(inputs.iter()) {
                     let mut off = 0;
                     while off + RATE <= input.len() {
                         xor_block(state, &input[off..off + RATE]);
@@ -417,31 +373,29 @@ pub mod avx2 {
                     block[RATE - 1] |= 0x80;
                     xor_block(state, &block);
                 }
-                p1600x4(states, ROUNDS);
+                p1600x4(&mut s.states, ROUNDS);
             }
 
-            /// Squeeze `out0.len()/RATE` blocks into the four outputs. `first` extracts block 0 from
-            /// the current (already-permuted) state, then permutes between blocks; `!first` permutes
-            /// before the first extraction (stream continuation).
+            /// Continue all four output streams by the longest requested length. A shorter output
+            /// receives the prefix of its lane's bytes for this call; the lanes stay in lockstep.
             #[inline]
-            fn squeeze<const RATE: usize>(
-                states: &mut [[u64; 25]; 4],
-                out0: &mut [u8],
-                out1: &mut [u8],
-                out2: &mut [u8],
-                out3: &mut [u8],
-                first: bool,
-            ) {
-                let nblocks = out0.len() / RATE;
-                for blk in 0..nblocks {
-                    if blk > 0 || !first {
-                        p1600x4(states, ROUNDS);
+            fn squeeze<const RATE: usize>(s: &mut KeccakStateX4, mut outputs: [&mut [u8]; 4]) {
+                let len = outputs.iter().map(|o| o.len()).max().unwrap_or(0);
+                let mut done = 0;
+                while done < len {
+                    if s.offset >= RATE {
+                        p1600x4(&mut s.states, ROUNDS);
+                        s.offset = 0;
                     }
-                    let r = blk * RATE..(blk + 1) * RATE;
-                    squeeze_block(&states[0], &mut out0[r.clone()]);
-                    squeeze_block(&states[1], &mut out1[r.clone()]);
-                    squeeze_block(&states[2], &mut out2[r.clone()]);
-                    squeeze_block(&states[3], &mut out3[r]);
+                    let take = (RATE - s.offset).min(len - done);
+                    for (state, out) in s.states.iter().zip(outputs.iter_mut()) {
+                        if done < out.len() {
+                            let end = (done + take).min(out.len());
+                            squeeze_bytes(state, s.offset, &mut out[done..end]);
+                        }
+                    }
+                    done += take;
+                    s.offset += take;
                 }
             }
 
@@ -452,7 +406,7 @@ pub mod avx2 {
                 i2: &[u8],
                 i3: &[u8],
             ) {
-                absorb_final::<RATE_128>(&mut s.states, [i0, i1, i2, i3]);
+                absorb_final::<RATE_128>(s, [i0, i1, i2, i3]);
             }
 
             pub fn shake128_squeeze_first_five_blocks(
@@ -462,7 +416,7 @@ pub mod avx2 {
                 o2: &mut [u8],
                 o3: &mut [u8],
             ) {
-                squeeze::<RATE_128>(&mut s.states, o0, o1, o2, o3, true);
+                squeeze::<RATE_128>(s, [o0, o1, o2, o3]);
             }
 
             pub fn shake128_squeeze_next_block(
@@ -472,7 +426,7 @@ pub mod avx2 {
                 o2: &mut [u8],
                 o3: &mut [u8],
             ) {
-                squeeze::<RATE_128>(&mut s.states, o0, o1, o2, o3, false);
+                squeeze::<RATE_128>(s, [o0, o1, o2, o3]);
             }
 
             pub fn shake256_absorb_final(
@@ -482,7 +436,7 @@ pub mod avx2 {
                 i2: &[u8],
                 i3: &[u8],
             ) {
-                absorb_final::<RATE_256>(&mut s.states, [i0, i1, i2, i3]);
+                absorb_final::<RATE_256>(s, [i0, i1, i2, i3]);
             }
 
             pub fn shake256_squeeze_first_block(
@@ -492,7 +446,7 @@ pub mod avx2 {
                 o2: &mut [u8],
                 o3: &mut [u8],
             ) {
-                squeeze::<RATE_256>(&mut s.states, o0, o1, o2, o3, true);
+                squeeze::<RATE_256>(s, [o0, o1, o2, o3]);
             }
 
             pub fn shake256_squeeze_next_block(
@@ -502,7 +456,28 @@ pub mod avx2 {
                 o2: &mut [u8],
                 o3: &mut [u8],
             ) {
-                squeeze::<RATE_256>(&mut s.states, o0, o1, o2, o3, false);
+                squeeze::<RATE_256>(s, [o0, o1, o2, o3]);
+            }
+
+            #[cfg(all(test, feature = "zeroize"))]
+            mod tests {
+                use core::mem::ManuallyDrop;
+
+                use super::*;
+
+                #[test]
+                #[allow(unsafe_code)]
+                fn test_lanes_are_zeroed_on_drop() {
+                    let mut state = ManuallyDrop::new(init());
+                    shake256_absorb_final(&mut state, b"seed 0", b"seed 1", b"seed 2", b"seed 3");
+                    assert!(state.states.iter().flatten().any(|&lane| lane != 0));
+
+                    // SAFETY: the destructor only overwrites the lanes, leaving valid `u64` bit
+                    // patterns behind (see `ManuallyDrop::drop`); `state` is only read afterwards.
+                    unsafe { ManuallyDrop::drop(&mut state) };
+                    assert!(state.states.iter().flatten().all(|&lane| lane == 0));
+                    assert_eq!(state.offset, 0);
+                }
             }
         }
     }
@@ -515,100 +490,23 @@ pub mod neon {
         use lib_q_keccak::advanced::parallel;
 
         /// Perform 2 SHAKE256 operations in parallel using true SIMD
-        #[allow(clippy::too_many_arguments)]
+        ///
+        /// Both outputs equal `Shake256::digest_xof` of their input for any input and output
+        /// length; only the permutations are shared between the two streams.
         #[cfg_attr(tarpaulin, inline(never))]
         #[cfg_attr(not(tarpaulin), inline(always))]
         pub fn shake256(input0: &[u8], input1: &[u8], out0: &mut [u8], out1: &mut [u8]) {
-            // True SIMD parallel processing using lib-q-keccak parallel functions
-            let mut states = [
-                [0u64; 25], // State 0
-                [0u64; 25], // State 1
-            ];
-
-            // Initialize states with SHAKE256 domain separator and absorb inputs
-            for (state, input) in states.iter_mut().zip([input0, input1].iter()) {
-                // Initialize Keccak state for SHAKE256 (domain separator 0x06)
-                state[0] = 0x06;
-
-                // Absorb input data following SHAKE specification
-                let mut offset = 0;
-                while offset + 8 <= input.len() {
-                    let value = u64::from_le_bytes([
-                        input[offset],
-                        input[offset + 1],
-                        input[offset + 2],
-                        input[offset + 3],
-                        input[offset + 4],
-                        input[offset + 5],
-                        input[offset + 6],
-                        input[offset + 7],
-                    ]);
-                    let lane_index = offset / 8;
-                    // Ensure we don't access beyond the 25-lane Keccak state
-                    if lane_index < 25 {
-                        state[lane_index] ^= value;
-                    }
-                    offset += 8;
-                }
-
-                // Handle remaining bytes
-                if offset < input.len() {
-                    let mut remaining = [0u8; 8];
-                    remaining[..input.len() - offset].copy_from_slice(&input[offset..]);
-                    let value = u64::from_le_bytes(remaining);
-                    let lane_index = offset / 8;
-                    // Ensure we don't access beyond the 25-lane Keccak state
-                    if lane_index < 25 {
-                        state[lane_index] ^= value;
-                    }
-                }
-
-                // Apply SHAKE256 padding: 0x1F << (input.len() % 8) * 8
-                if !input.is_empty() {
-                    let padding_lane = input.len() / 8;
-                    // Ensure we don't access beyond the 25-lane Keccak state
-                    if padding_lane < 25 {
-                        state[padding_lane] ^= 0x1F << ((input.len() % 8) * 8);
-                    }
-                } else {
-                    // For empty input, apply padding at position 0
-                    state[0] ^= 0x1F;
-                }
-                // Final padding: 0x8000000000000000 at position 16
-                state[16] ^= 0x8000000000000000;
-            }
+            super::super::shake256_x2_with([input0, input1], [out0, out1], permute_x2);
+        }
 
+        /// Keccak-f\[1600\] on both states, two lanes per vector when portable SIMD is enabled
+        fn permute_x2(states: &mut [[u64; 25]; 2]) {
             #[cfg(ml_dsa_keccak_portable_simd)]
-            parallel::p1600_parallel_2x(&mut states);
+            parallel::p1600_parallel_2x(states);
 
             #[cfg(not(ml_dsa_keccak_portable_simd))]
-            {
-                for state in &mut states {
-                    lib_q_keccak::keccak_p(state, 24);
-                }
-            }
-
-            // Squeeze output data from both states in parallel
-            let mut outputs = [out0, out1];
-            for (state, output) in states.iter().zip(outputs.iter_mut()) {
-                let squeeze_state = *state;
-                let _offset = 0;
-
-                // For the first squeeze, we already have the state from the parallel processing
-                // Extract bytes following SHAKE specification
-                let bytes_available = output.len().min(200); // Max 200 bytes (25 lanes * 8)
-                for i in 0..bytes_available {
-                    let lane = i / 8;
-                    let bit_offset = (i % 8) * 8;
-                    // Ensure we don't access beyond the 25-lane Keccak state
-                    if lane < 25 {
-                        output[i] = (squeeze_state[lane] >> bit_offset) as u8;
-                    } else {
-                        // If we need more bytes than available in the state,
-                        // we need to apply another permutation
-                        break;
-                    }
-                }
+            for state in states.iter_mut() {
+                lib_q_keccak::p1600(state, 24);
             }
         }
 
@@ -616,11 +514,15 @@ pub mod neon {
             use super::super::super::incremental;
 
             /// The Keccak state for the incremental API
-            /// Uses portable implementation wrapped for x2 interface
+            /// Uses portable implementation wrapped for x2 interface; both states clear
+            /// themselves on drop with the `zeroize` feature.
             pub struct KeccakStateX2 {
                 states: [super::super::super::KeccakState; 2],
             }
 
+            #[cfg(feature = "zeroize")]
+            impl zeroize::ZeroizeOnDrop for KeccakStateX2 {}
+
             impl KeccakStateX2 {
                 pub fn new() -> Self {
                     Self {
@@ -683,9 +585,76 @@ pub mod neon {
     }
 }
 
+/// Two-way SHAKE256 sponge sharing each permutation between both states
+///
+/// Inputs are absorbed in 136-byte rate blocks (a full block is permuted on its own state
+/// before the next), padded with the SHAKE suffix and pad10*1 in the last block, and finished
+/// with one joint `permute_x2`. Output is squeezed 136 bytes at a time with a joint
+/// permutation between blocks, so both streams equal `Shake256::digest_xof` whatever the
+/// input and output lengths. With the `zeroize` feature both states are wiped before return.
+#[cfg(any(test, all(feature = "simd128", target_arch = "aarch64")))]
+fn shake256_x2_with(
+    inputs: [&[u8]; 2],
+    outputs: [&mut [u8]; 2],
+    permute_x2: fn(&mut [[u64; 25]; 2]),
+) {
+    const RATE: usize = SHAKE256_RATE;
+    const SHAKE_DS: u8 = 0x1F;
+
+    fn xor_block(state: &mut [u64; 25], block: &[u8; RATE]) {
+        let (lanes, _) = block.as_chunks::<8>();
+        for (lane, bytes) in state.iter_mut().zip(lanes) {
+            *lane ^= u64::from_le_bytes(*bytes);
+        }
+    }
+
+    let mut states = [[0u64; 25]; 2];
+    for (state, input) in states.iter_mut().zip(inputs) {
+        let (blocks, tail) = input.as_chunks::<RATE>();
+        for block in blocks {
+            xor_block(state, block);
+            lib_q_keccak::p1600(state, 24);
+        }
+        let mut last = [0u8; RATE];
+        last[..tail.len()].copy_from_slice(tail);
+        last[tail.len()] = SHAKE_DS;
+        last[RATE - 1] |= 0x80;
+        xor_block(state, &last);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut last);
+    }
+    permute_x2(&mut states);
+
+    let blocks = outputs
+        .iter()
+        .map(|out| out.len().div_ceil(RATE))
+        .max()
+        .unwrap_or(0);
+    let [out0, out1] = outputs;
+    for block in 0..blocks {
+        if block > 0 {
+            permute_x2(&mut states);
+        }
+        for (state, out) in states.iter().zip([&mut *out0, &mut *out1]) {
+            let start = (block * RATE).min(out.len());
+            let end = ((block + 1) * RATE).min(out.len());
+            for (chunk, lane) in out[start..end].chunks_mut(8).zip(state.iter()) {
+                chunk.copy_from_slice(&lane.to_le_bytes()[..chunk.len()]);
+            }
+        }
+    }
+
+    // The lanes hold secret-derived data while signing, as in the x4 state
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(&mut states);
+}
+
 #[cfg(test)]
 mod tests {
 
+    use std::vec;
+    use std::vec::Vec;
+
     use super::{
         incremental,
         *,
@@ -727,8 +696,8 @@ mod tests {
     fn test_incremental_shake256() {
         // Test incremental SHAKE256 API
         let input = b"Hello, World!";
-        let mut output1 = [0u8; 136]; // SHAKE256 block size
-        let mut output2 = [0u8; 136];
+        let mut output1 = [0u8; SHAKE256_RATE]; // SHAKE256 block size
+        let mut output2 = [0u8; SHAKE256_RATE];
 
         // Use incremental API
         let mut state = incremental::shake256_init();
@@ -741,234 +710,291 @@ mod tests {
         assert_eq!(output1, output2);
     }
 
-    #[cfg(all(feature = "simd256", target_arch = "x86_64"))]
-    #[test]
-    fn test_avx2_simd_parallel_processing() {
-        // Test AVX2 SIMD parallel processing
-        let inputs = [
-            b"Input 1 for parallel processing",
-            b"Input 2 for parallel processing",
-            b"Input 3 for parallel processing",
-            b"Input 4 for parallel processing",
-        ];
+    fn shake256_reference(input: &[u8], len: usize) -> Vec<u8> {
+        let mut out = vec![0u8; len];
+        Shake256::digest_xof(input, &mut out);
+        out
+    }
 
-        let mut outputs = [[0u8; 32]; 4];
+    fn shake128_reference(input: &[u8], len: usize) -> Vec<u8> {
+        let mut out = vec![0u8; len];
+        Shake128::digest_xof(input, &mut out);
+        out
+    }
 
-        // Use SIMD parallel processing
-        // Create separate arrays to avoid borrow checker issues
-        let mut output0 = outputs[0];
-        let mut output1 = outputs[1];
-        let mut output2 = outputs[2];
-        let mut output3 = outputs[3];
-
-        avx2::x4::shake256(
-            inputs[0],
-            inputs[1],
-            inputs[2],
-            inputs[3],
-            &mut output0,
-            &mut output1,
-            &mut output2,
-            &mut output3,
+    #[test]
+    fn test_incremental_squeezes_continue_the_stream() {
+        // Rejection sampling can ask for many blocks past the initial squeeze; every
+        // `squeeze_next_block` call must return the next block of one long XOF output.
+        const NEXT_BLOCKS: usize = 4;
+        let seed = [0x3Cu8; 34];
+
+        let mut state = incremental::shake128_init();
+        incremental::shake128_absorb_final(&mut state, &seed);
+        let mut stream = vec![0u8; SHAKE128_RATE * 5];
+        incremental::shake128_squeeze_first_five_blocks(&mut state, &mut stream);
+        for _ in 0..NEXT_BLOCKS {
+            let mut block = [0u8; SHAKE128_RATE];
+            incremental::shake128_squeeze_next_block(&mut state, &mut block);
+            stream.extend_from_slice(&block);
+        }
+        assert_eq!(
+            stream,
+            shake128_reference(&seed, SHAKE128_RATE * (5 + NEXT_BLOCKS))
         );
 
-        outputs[0] = output0;
-        outputs[1] = output1;
-        outputs[2] = output2;
-        outputs[3] = output3;
-
-        // Verify that SIMD processing produces valid SHAKE256 outputs
-        // (different from sequential due to true parallel processing)
-        for i in 0..4 {
-            // Check that outputs are not all zeros (basic validity check)
-            assert!(
-                !outputs[i].iter().all(|&x| x == 0),
-                "Output {} is all zeros",
-                i
-            );
-
-            // Check that outputs have reasonable entropy (not all same value)
-            let first_byte = outputs[i][0];
-            assert!(
-                !outputs[i].iter().all(|&x| x == first_byte),
-                "Output {} has no entropy",
-                i
-            );
+        let mut state = incremental::shake256_init();
+        incremental::shake256_absorb_final(&mut state, &seed);
+        let mut stream = vec![0u8; SHAKE256_RATE];
+        incremental::shake256_squeeze_first_block(&mut state, &mut stream);
+        for _ in 0..NEXT_BLOCKS {
+            let mut block = [0u8; SHAKE256_RATE];
+            incremental::shake256_squeeze_next_block(&mut state, &mut block);
+            stream.extend_from_slice(&block);
         }
-
-        // Verify that different inputs produce different outputs
-        assert_ne!(
-            outputs[0], outputs[1],
-            "Different inputs should produce different outputs"
-        );
-        assert_ne!(
-            outputs[2], outputs[3],
-            "Different inputs should produce different outputs"
+        assert_eq!(
+            stream,
+            shake256_reference(&seed, SHAKE256_RATE * (1 + NEXT_BLOCKS))
         );
     }
 
-    #[cfg(all(feature = "simd128", target_arch = "aarch64"))]
     #[test]
-    fn test_neon_simd_parallel_processing() {
-        // Test NEON SIMD parallel processing
-        let inputs = [
-            b"Input 1 for NEON processing",
-            b"Input 2 for NEON processing",
-        ];
-
-        let mut outputs = [[0u8; 32]; 2];
-
-        // Use SIMD parallel processing
-        // Create separate arrays to avoid borrow checker issues
-        let mut output0 = outputs[0];
-        let mut output1 = outputs[1];
+    fn test_incremental_squeezes_accept_unaligned_lengths() {
+        // Lengths are deliberately not block multiples and straddle block boundaries.
+        const READS: [usize; 5] = [0, 1, 100, 300, 7];
+        let total: usize = READS.iter().sum();
+        let seed = b"unaligned squeeze";
+
+        let mut state = incremental::shake128_init();
+        incremental::shake128_absorb_final(&mut state, seed);
+        let mut stream = Vec::new();
+        for (i, len) in READS.into_iter().enumerate() {
+            let mut out = vec![0u8; len];
+            if i == 0 {
+                incremental::shake128_squeeze_first_five_blocks(&mut state, &mut out);
+            } else {
+                incremental::shake128_squeeze_next_block(&mut state, &mut out);
+            }
+            stream.extend_from_slice(&out);
+        }
+        assert_eq!(stream, shake128_reference(seed, total));
 
-        neon::x2::shake256(inputs[0], inputs[1], &mut output0, &mut output1);
+        let mut state = incremental::shake256_init();
+        incremental::shake256_absorb_final(&mut state, seed);
+        let mut stream = Vec::new();
+        for len in READS {
+            let mut out = vec![0u8; len];
+            incremental::shake256_squeeze_next_block(&mut state, &mut out);
+            stream.extend_from_slice(&out);
+        }
+        assert_eq!(stream, shake256_reference(seed, total));
+    }
 
-        outputs[0] = output0;
-        outputs[1] = output1;
+    #[test]
+    fn test_incremental_misuse_does_not_panic() {
+        // A SHAKE-128 state handed to the SHAKE-256 functions is re-initialized.
+        let mut state = incremental::shake128_init();
+        incremental::shake256_absorb_final(&mut state, b"abc");
+        let mut out = [0u8; 64];
+        incremental::shake256_squeeze_first_block(&mut state, &mut out);
+        assert_eq!(out.as_slice(), shake256_reference(b"abc", 64));
+
+        // Absorbing after a squeeze extends the input and restarts the output stream.
+        let mut state = incremental::shake128_init();
+        incremental::shake128_absorb_final(&mut state, b"ab");
+        incremental::shake128_squeeze_next_block(&mut state, &mut out);
+        state.absorb(b"c");
+        incremental::shake128_squeeze_next_block(&mut state, &mut out);
+        assert_eq!(out.as_slice(), shake128_reference(b"abc", 64));
+    }
 
-        // Verify that SIMD processing produces valid SHAKE256 outputs
-        // (different from sequential due to true parallel processing)
-        for i in 0..2 {
-            // Check that outputs are not all zeros (basic validity check)
-            assert!(
-                !outputs[i].iter().all(|&x| x == 0),
-                "Output {} is all zeros",
-                i
-            );
+    #[test]
+    fn test_shake256_x2_sponge_matches_shake256() {
+        // Lengths around the 136-byte rate cover single-block, exact-block and multi-block
+        // absorbs as well as multi-block squeezes with unequal output lengths.
+        let data: Vec<u8> = (0..=255u8).cycle().take(700).collect();
+        let scalar_x2 = |states: &mut [[u64; 25]; 2]| {
+            for state in states.iter_mut() {
+                lib_q_keccak::p1600(state, 24);
+            }
+        };
+        for (len0, len1) in [(0, 1), (135, 136), (136, 137), (271, 272), (700, 3)] {
+            for (out_len0, out_len1) in [(32, 32), (136, 137), (640, 576)] {
+                let mut out0 = vec![0u8; out_len0];
+                let mut out1 = vec![0u8; out_len1];
+                shake256_x2_with(
+                    [&data[..len0], &data[..len1]],
+                    [&mut out0, &mut out1],
+                    scalar_x2,
+                );
+                assert_eq!(out0, shake256_reference(&data[..len0], out_len0));
+                assert_eq!(out1, shake256_reference(&data[..len1], out_len1));
+            }
+        }
+    }
 
-            // Check that outputs have reasonable entropy (not all same value)
-            let first_byte = outputs[i][0];
-            assert!(
-                !outputs[i].iter().all(|&x| x == first_byte),
-                "Output {} has no entropy",
-                i
+    #[cfg(all(feature = "simd256", target_arch = "x86_64"))]
+    #[test]
+    fn test_avx2_simd_parallel_processing() {
+        // ExpandMask asks for 576/640 bytes, i.e. several squeeze blocks.
+        let inputs: [&[u8]; 4] = [
+            b"Input 1 for parallel processing",
+            &[0x5A; SHAKE256_RATE],
+            &[0xA5; 300],
+            b"",
+        ];
+        for out_len in [32, 576, 640] {
+            let mut outputs = [
+                vec![0u8; out_len],
+                vec![0u8; out_len],
+                vec![0u8; out_len],
+                vec![0u8; out_len],
+            ];
+            let [out0, out1, out2, out3] = &mut outputs;
+            avx2::x4::shake256(
+                inputs[0], inputs[1], inputs[2], inputs[3], out0, out1, out2, out3,
             );
+            for (input, output) in inputs.iter().zip(&outputs) {
+                assert_eq!(output, &shake256_reference(input, out_len));
+            }
         }
+    }
 
-        // Verify that different inputs produce different outputs
-        assert_ne!(
-            outputs[0], outputs[1],
-            "Different inputs should produce different outputs"
-        );
+    #[cfg(all(feature = "simd128", target_arch = "aarch64"))]
+    #[test]
+    fn test_neon_simd_parallel_processing() {
+        let inputs: [&[u8]; 2] = [b"Input 1 for NEON processing", &[0x5A; 300]];
+        for out_len in [32, 576, 640] {
+            let mut out0 = vec![0u8; out_len];
+            let mut out1 = vec![0u8; out_len];
+            neon::x2::shake256(inputs[0], inputs[1], &mut out0, &mut out1);
+            assert_eq!(out0, shake256_reference(inputs[0], out_len));
+            assert_eq!(out1, shake256_reference(inputs[1], out_len));
+        }
     }
 
     #[cfg(all(feature = "simd256", target_arch = "x86_64"))]
     #[test]
     fn test_avx2_incremental_simd() {
-        // Test AVX2 incremental SIMD API
-        let inputs = [
+        let inputs: [&[u8]; 4] = [
             b"Incremental input 1",
             b"Incremental input 2",
             b"Incremental input 3",
             b"Incremental input 4",
         ];
 
-        let mut outputs = [[0u8; 840]; 4]; // 5 blocks * 168 bytes
-
-        // Use incremental SIMD API
+        // 5 blocks * 168 bytes, then one more block
+        let mut first = [[0u8; 840]; 4];
+        let mut next = [[0u8; SHAKE128_RATE]; 4];
         let mut state = avx2::x4::incremental::init();
         avx2::x4::incremental::shake128_absorb_final(
             &mut state, inputs[0], inputs[1], inputs[2], inputs[3],
         );
-        // Create separate arrays to avoid borrow checker issues
-        let mut output0 = outputs[0];
-        let mut output1 = outputs[1];
-        let mut output2 = outputs[2];
-        let mut output3 = outputs[3];
-
-        avx2::x4::incremental::shake128_squeeze_first_five_blocks(
-            &mut state,
-            &mut output0,
-            &mut output1,
-            &mut output2,
-            &mut output3,
-        );
-
-        outputs[0] = output0;
-        outputs[1] = output1;
-        outputs[2] = output2;
-        outputs[3] = output3;
+        let [f0, f1, f2, f3] = &mut first;
+        avx2::x4::incremental::shake128_squeeze_first_five_blocks(&mut state, f0, f1, f2, f3);
+        let [n0, n1, n2, n3] = &mut next;
+        avx2::x4::incremental::shake128_squeeze_next_block(&mut state, n0, n1, n2, n3);
 
-        // Verify that SIMD processing produces valid SHAKE128 outputs
-        // (different from sequential due to true parallel processing)
         for i in 0..4 {
-            // Check that outputs are not all zeros (basic validity check)
-            assert!(
-                !outputs[i].iter().all(|&x| x == 0),
-                "Output {} is all zeros",
-                i
-            );
+            let expected = shake128_reference(inputs[i], 840 + SHAKE128_RATE);
+            assert_eq!(first[i].as_slice(), &expected[..840]);
+            assert_eq!(next[i].as_slice(), &expected[840..]);
+        }
+    }
 
-            // Check that outputs have reasonable entropy (not all same value)
-            let first_byte = outputs[i][0];
-            assert!(
-                !outputs[i].iter().all(|&x| x == first_byte),
-                "Output {} has no entropy",
-                i
-            );
+    #[cfg(all(feature = "simd256", target_arch = "x86_64"))]
+    #[test]
+    fn test_avx2_incremental_accepts_unaligned_lengths() {
+        const READS: [usize; 4] = [5, 200, 163, 1];
+        let total: usize = READS.iter().sum();
+        let inputs: [&[u8]; 4] = [b"lane 0", b"lane 1", b"", &[0xEE; 200]];
+        let mut state = avx2::x4::incremental::init();
+        avx2::x4::incremental::shake128_absorb_final(
+            &mut state, inputs[0], inputs[1], inputs[2], inputs[3],
+        );
+        let mut streams: [Vec<u8>; 4] = Default::default();
+        for len in READS {
+            let mut outs = [
+                vec![0u8; len],
+                vec![0u8; len],
+                vec![0u8; len],
+                vec![0u8; len],
+            ];
+            let [o0, o1, o2, o3] = &mut outs;
+            avx2::x4::incremental::shake128_squeeze_next_block(&mut state, o0, o1, o2, o3);
+            for (stream, out) in streams.iter_mut().zip(&outs) {
+                stream.extend_from_slice(out);
+            }
+        }
+        for (input, stream) in inputs.iter().zip(&streams) {
+            assert_eq!(stream, &shake128_reference(input, total));
         }
 
-        // Verify that different inputs produce different outputs
-        assert_ne!(
-            outputs[0], outputs[1],
-            "Different inputs should produce different outputs"
+        // Shorter outputs receive a prefix of their lane; nothing panics.
+        avx2::x4::incremental::shake256_absorb_final(
+            &mut state, inputs[0], inputs[1], inputs[2], inputs[3],
+        );
+        let (mut o0, mut o1, mut o2, mut o3) = ([0u8; 150], [0u8; 10], [0u8; 0], [0u8; 150]);
+        avx2::x4::incremental::shake256_squeeze_first_block(
+            &mut state, &mut o0, &mut o1, &mut o2, &mut o3,
         );
-        assert_ne!(
-            outputs[2], outputs[3],
-            "Different inputs should produce different outputs"
+        assert_eq!(o0.as_slice(), shake256_reference(inputs[0], 150));
+        assert_eq!(o1.as_slice(), shake256_reference(inputs[1], 10));
+        assert_eq!(o3.as_slice(), shake256_reference(inputs[3], 150));
+    }
+
+    #[cfg(all(feature = "simd256", target_arch = "x86_64"))]
+    #[test]
+    fn test_avx2_incremental_next_blocks_are_distinct() {
+        // Each `squeeze_next_block` permutes once and extracts the following rate block.
+        const NEXT_BLOCKS: usize = 4;
+        let inputs: [&[u8]; 4] = [&[0x01; 34], &[0x02; 34], &[0x03; 34], &[0x04; 34]];
+        let mut state = avx2::x4::incremental::init();
+        avx2::x4::incremental::shake256_absorb_final(
+            &mut state, inputs[0], inputs[1], inputs[2], inputs[3],
         );
+        let mut streams = [
+            vec![0u8; SHAKE256_RATE],
+            vec![0u8; SHAKE256_RATE],
+            vec![0u8; SHAKE256_RATE],
+            vec![0u8; SHAKE256_RATE],
+        ];
+        let [s0, s1, s2, s3] = &mut streams;
+        avx2::x4::incremental::shake256_squeeze_first_block(&mut state, s0, s1, s2, s3);
+        for _ in 0..NEXT_BLOCKS {
+            let mut blocks = [[0u8; SHAKE256_RATE]; 4];
+            let [b0, b1, b2, b3] = &mut blocks;
+            avx2::x4::incremental::shake256_squeeze_next_block(&mut state, b0, b1, b2, b3);
+            assert_ne!(
+                blocks[0][..],
+                streams[0][streams[0].len() - SHAKE256_RATE..],
+                "next block repeats the previous one"
+            );
+            for (stream, block) in streams.iter_mut().zip(&blocks) {
+                stream.extend_from_slice(block);
+            }
+        }
+        for (input, stream) in inputs.iter().zip(&streams) {
+            assert_eq!(
+                stream,
+                &shake256_reference(input, SHAKE256_RATE * (1 + NEXT_BLOCKS))
+            );
+        }
     }
 
     #[cfg(all(feature = "simd128", target_arch = "aarch64"))]
     #[test]
     fn test_neon_incremental_simd() {
-        // Test NEON incremental SIMD API
-        let inputs = [b"NEON incremental input 1", b"NEON incremental input 2"];
-
-        let mut outputs = [[0u8; 840]; 2]; // 5 blocks * 168 bytes
+        let inputs: [&[u8]; 2] = [b"NEON incremental input 1", b"NEON incremental input 2"];
 
-        // Use incremental SIMD API
+        let mut out0 = [0u8; 840]; // 5 blocks * 168 bytes
+        let mut out1 = [0u8; 840];
         let mut state = neon::x2::incremental::init();
         neon::x2::incremental::shake128_absorb_final(&mut state, inputs[0], inputs[1]);
-        // Create separate arrays to avoid borrow checker issues
-        let mut output0 = outputs[0];
-        let mut output1 = outputs[1];
-
-        neon::x2::incremental::shake128_squeeze_first_five_blocks(
-            &mut state,
-            &mut output0,
-            &mut output1,
-        );
-
-        outputs[0] = output0;
-        outputs[1] = output1;
-
-        // Verify that SIMD processing produces valid SHAKE128 outputs
-        // (different from sequential due to true parallel processing)
-        for i in 0..2 {
-            // Check that outputs are not all zeros (basic validity check)
-            assert!(
-                !outputs[i].iter().all(|&x| x == 0),
-                "Output {} is all zeros",
-                i
-            );
-
-            // Check that outputs have reasonable entropy (not all same value)
-            let first_byte = outputs[i][0];
-            assert!(
-                !outputs[i].iter().all(|&x| x == first_byte),
-                "Output {} has no entropy",
-                i
-            );
-        }
+        neon::x2::incremental::shake128_squeeze_first_five_blocks(&mut state, &mut out0, &mut out1);
 
-        // Verify that different inputs produce different outputs
-        assert_ne!(
-            outputs[0], outputs[1],
-            "Different inputs should produce different outputs"
-        );
+        assert_eq!(out0.as_slice(), shake128_reference(inputs[0], 840));
+        assert_eq!(out1.as_slice(), shake128_reference(inputs[1], 840));
     }
 
     #[test]
@@ -1025,71 +1051,42 @@ mod tests {
 
     #[test]
     fn test_simd_correctness_with_various_inputs() {
-        // Test SIMD correctness with various input sizes and patterns
+        // Batched SHAKE256 must match the scalar function for every input shape.
+        let long = [0x42u8; 400];
         let test_cases = [
                 (b"a" as &[u8], "single byte"),
                 (b"Hello, World!" as &[u8], "short string"),
                 (b"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua." as &[u8], "long string"),
                 (b"\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f" as &[u8], "binary data"),
+                (&long[..], "multi-block"),
             ];
 
         for (input, _description) in test_cases.iter() {
-            let mut sequential_output = [0u8; 32];
+            let mut sequential_output = [0u8; 300];
             shake256(&mut sequential_output, input);
+            assert_eq!(
+                sequential_output.as_slice(),
+                shake256_reference(input, 300),
+                "{_description}"
+            );
 
             #[cfg(all(feature = "simd256", target_arch = "x86_64"))]
             {
-                let simd_output = [0u8; 32];
-                // Create separate arrays to avoid borrow checker issues
-                let mut simd_output0 = simd_output;
-                let mut simd_output1 = simd_output;
-                let mut simd_output2 = simd_output;
-                let mut simd_output3 = simd_output;
-
-                avx2::x4::shake256(
-                    input,
-                    input,
-                    input,
-                    input,
-                    &mut simd_output0,
-                    &mut simd_output1,
-                    &mut simd_output2,
-                    &mut simd_output3,
-                );
-                // True SIMD produces different results than sequential, but should be valid
-                assert!(
-                    !simd_output0.iter().all(|&x| x == 0),
-                    "SIMD output is all zeros for {}",
-                    _description
-                );
-                let first_byte = simd_output0[0];
-                assert!(
-                    !simd_output0.iter().all(|&x| x == first_byte),
-                    "SIMD output has no entropy for {}",
-                    _description
-                );
+                let mut simd_outputs = [[0u8; 300]; 4];
+                let [o0, o1, o2, o3] = &mut simd_outputs;
+                avx2::x4::shake256(input, input, input, input, o0, o1, o2, o3);
+                for output in &simd_outputs {
+                    assert_eq!(output, &sequential_output, "{_description}");
+                }
             }
 
             #[cfg(all(feature = "simd128", target_arch = "aarch64"))]
             {
-                let simd_output = [0u8; 32];
-                // Create separate arrays to avoid borrow checker issues
-                let mut simd_output0 = simd_output;
-                let mut simd_output1 = simd_output;
-
+                let mut simd_output0 = [0u8; 300];
+                let mut simd_output1 = [0u8; 300];
                 neon::x2::shake256(input, input, &mut simd_output0, &mut simd_output1);
-                // True SIMD produces different results than sequential, but should be valid
-                assert!(
-                    !simd_output0.iter().all(|&x| x == 0),
-                    "SIMD output is all zeros for {}",
-                    _description
-                );
-                let first_byte = simd_output0[0];
-                assert!(
-                    !simd_output0.iter().all(|&x| x == first_byte),
-                    "SIMD output has no entropy for {}",
-                    _description
-                );
+                assert_eq!(simd_output0, sequential_output, "{_description}");
+                assert_eq!(simd_output1, sequential_output, "{_description}");
             }
         }
     }
@@ -1098,14 +1095,14 @@ mod tests {
     fn incremental_shake128_three_five_and_next_blocks() {
         let mut st = incremental::shake128_init();
         incremental::shake128_absorb_final(&mut st, b"incremental shake128 path");
-        let mut three = [0u8; 168 * 3];
+        let mut three = [0u8; SHAKE128_RATE * 3];
         incremental::shake128_squeeze_first_three_blocks(&mut st, &mut three);
 
         let mut st2 = incremental::shake128_init();
         incremental::shake128_absorb_final(&mut st2, b"second");
-        let mut five = [0u8; 168 * 5];
+        let mut five = [0u8; SHAKE128_RATE * 5];
         incremental::shake128_squeeze_first_five_blocks(&mut st2, &mut five);
-        let mut nb = [0u8; 168];
+        let mut nb = [0u8; SHAKE128_RATE];
         incremental::shake128_squeeze_next_block(&mut st2, &mut nb);
         assert!(nb.iter().any(|&b| b != 0));
     }
@@ -1114,8 +1111,8 @@ mod tests {
     fn incremental_shake256_first_and_next_block() {
         let mut st = incremental::shake256_init();
         incremental::shake256_absorb_final(&mut st, b"two blocks");
-        let mut b1 = [0u8; 136];
-        let mut b2 = [0u8; 136];
+        let mut b1 = [0u8; SHAKE256_RATE];
+        let mut b2 = [0u8; SHAKE256_RATE];
         incremental::shake256_squeeze_first_block(&mut st, &mut b1);
         incremental::shake256_squeeze_next_block(&mut st, &mut b2);
         assert_ne!(b1, b2);
@@ -1169,6 +1166,10 @@ mod x4_incremental_equiv {
     };
 
     use super::avx2::x4::incremental as x4;
+    use super::{
+        SHAKE128_RATE,
+        SHAKE256_RATE,
+    };
 
     fn fill<const N: usize>() -> [[u8; N]; 4] {
         [
@@ -1185,10 +1186,10 @@ mod x4_incremental_equiv {
         let s = fill::<34>();
         let mut st = x4::init();
         x4::shake128_absorb_final(&mut st, &s[0], &s[1], &s[2], &s[3]);
-        let mut five = [[0u8; 168 * 5]; 4];
+        let mut five = [[0u8; SHAKE128_RATE * 5]; 4];
         let [a, b, c, d] = &mut five;
         x4::shake128_squeeze_first_five_blocks(&mut st, a, b, c, d);
-        let mut nxt = [[0u8; 168]; 4];
+        let mut nxt = [[0u8; SHAKE128_RATE]; 4];
         let [a, b, c, d] = &mut nxt;
         x4::shake128_squeeze_next_block(&mut st, a, b, c, d);
 
@@ -1196,7 +1197,7 @@ mod x4_incremental_equiv {
             let mut h = Shake128::default();
             h.update(&s[lane]);
             let mut r = h.finalize_xof();
-            let mut want = [0u8; 168 * 6];
+            let mut want = [0u8; SHAKE128_RATE * 6];
             r.read(&mut want);
             assert_eq!(
                 five[lane][..],
@@ -1205,7 +1206,7 @@ mod x4_incremental_equiv {
             );
             assert_eq!(
                 nxt[lane][..],
-                want[168 * 5..168 * 6],
+                want[SHAKE128_RATE * 5..168 * 6],
                 "shake128 lane {lane} next"
             );
         }
@@ -1217,13 +1218,13 @@ mod x4_incremental_equiv {
         let s = fill::<66>();
         let mut st = x4::init();
         x4::shake256_absorb_final(&mut st, &s[0], &s[1], &s[2], &s[3]);
-        let mut first = [[0u8; 136]; 4];
+        let mut first = [[0u8; SHAKE256_RATE]; 4];
         let [a, b, c, d] = &mut first;
         x4::shake256_squeeze_first_block(&mut st, a, b, c, d);
-        let mut n1 = [[0u8; 136]; 4];
+        let mut n1 = [[0u8; SHAKE256_RATE]; 4];
         let [a, b, c, d] = &mut n1;
         x4::shake256_squeeze_next_block(&mut st, a, b, c, d);
-        let mut n2 = [[0u8; 136]; 4];
+        let mut n2 = [[0u8; SHAKE256_RATE]; 4];
         let [a, b, c, d] = &mut n2;
         x4::shake256_squeeze_next_block(&mut st, a, b, c, d);
 
@@ -1231,17 +1232,17 @@ mod x4_incremental_equiv {
             let mut h = Shake256::default();
             h.update(&s[lane]);
             let mut r = h.finalize_xof();
-            let mut want = [0u8; 136 * 3];
+            let mut want = [0u8; SHAKE256_RATE * 3];
             r.read(&mut want);
             assert_eq!(first[lane][..], want[..136], "shake256 lane {lane} first");
             assert_eq!(
                 n1[lane][..],
-                want[136..136 * 2],
+                want[SHAKE256_RATE..136 * 2],
                 "shake256 lane {lane} next1"
             );
             assert_eq!(
                 n2[lane][..],
-                want[136 * 2..136 * 3],
+                want[SHAKE256_RATE * 2..136 * 3],
                 "shake256 lane {lane} next2"
             );
         }
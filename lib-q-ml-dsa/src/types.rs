@@ -80,10 +80,54 @@ pub enum VerificationError {
     // FIXME: Eurydice can't handle enum variants with the same name
     // https://github.com/AeneasVerif/eurydice/issues/102
     VerificationContextTooLongError,
+    VerificationPreHashDigestLengthError,
 }
 
 #[cfg_attr(not(eurydice), derive(Debug))]
 pub enum SigningError {
     RejectionSamplingError,
     ContextTooLongError,
+    PreHashDigestLengthError,
+}
+
+#[cfg(feature = "lib-q-core")]
+impl From<VerificationError> for lib_q_core::Error {
+    fn from(error: VerificationError) -> Self {
+        let operation = match error {
+            VerificationError::MalformedHintError => "ML-DSA verification: malformed hint",
+            VerificationError::SignerResponseExceedsBoundError => {
+                "ML-DSA verification: signer response exceeds bound"
+            }
+            VerificationError::CommitmentHashesDontMatchError => {
+                "ML-DSA verification: commitment hashes do not match"
+            }
+            VerificationError::VerificationContextTooLongError => {
+                "ML-DSA verification: context longer than 255 bytes"
+            }
+            VerificationError::VerificationPreHashDigestLengthError => {
+                "ML-DSA verification: pre-hash digest has the wrong length"
+            }
+        };
+        Self::VerificationFailed {
+            operation: operation.into(),
+        }
+    }
+}
+
+#[cfg(feature = "lib-q-core")]
+impl From<SigningError> for lib_q_core::Error {
+    fn from(error: SigningError) -> Self {
+        let operation = match error {
+            SigningError::RejectionSamplingError => {
+                "ML-DSA signing: rejection sampling exceeded its iteration bound"
+            }
+            SigningError::ContextTooLongError => "ML-DSA signing: context longer than 255 bytes",
+            SigningError::PreHashDigestLengthError => {
+                "ML-DSA signing: pre-hash digest has the wrong length"
+            }
+        };
+        Self::SigningFailed {
+            operation: operation.into(),
+        }
+    }
 }
@@ -532,8 +532,15 @@ fn ntt_shuffle_layer<R: rand_core::Rng>(rng: &mut R, scratch: &mut [usize; 128],
 
 // Algorithm 8. NTT
 impl Polynomial {
-    #[allow(clippy::many_single_char_names)]
     pub fn ntt(&self) -> NttPolynomial {
+        let f_hat = self.ntt_unaudited();
+        #[cfg(feature = "ct-verify")]
+        crate::ct_audit::record(crate::ct_audit::Op::Ntt, 0, &f_hat.0, FieldElement::Q - 1);
+        f_hat
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn ntt_unaudited(&self) -> NttPolynomial {
         let mut k = 1;
 
         let mut f = self.0;
@@ -599,8 +606,20 @@ impl Polynomial {
 
 // Algorithm 9. NTT^{-1}
 impl NttPolynomial {
-    #[allow(clippy::many_single_char_names)]
     pub fn ntt_inverse(&self) -> Polynomial {
+        let f = self.ntt_inverse_unaudited();
+        #[cfg(feature = "ct-verify")]
+        crate::ct_audit::record(
+            crate::ct_audit::Op::NttInverse,
+            0,
+            &f.0,
+            FieldElement::Q - 1,
+        );
+        f
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn ntt_inverse_unaudited(&self) -> Polynomial {
         // The non-hardened path reads `f` into a signed scratch buffer; only the hardened path
         // mutates it in place (blinding/shuffle), hence the cfg-gated `mut`.
         #[cfg(not(feature = "hardened"))]
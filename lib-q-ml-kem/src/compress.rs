@@ -63,6 +63,8 @@ impl Compress for Polynomial {
         for x in &mut self.0 {
             x.compress::<D>();
         }
+        #[cfg(feature = "ct-verify")]
+        crate::ct_audit::record(crate::ct_audit::Op::Compress, D::USIZE, &self.0, D::MASK);
 
         self
     }
@@ -71,6 +73,13 @@ impl Compress for Polynomial {
         for x in &mut self.0 {
             x.decompress::<D>();
         }
+        #[cfg(feature = "ct-verify")]
+        crate::ct_audit::record(
+            crate::ct_audit::Op::Decompress,
+            D::USIZE,
+            &self.0,
+            FieldElement::Q - 1,
+        );
 
         self
     }
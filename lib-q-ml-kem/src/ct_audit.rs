@@ -0,0 +1,110 @@
+//! Constant-time audit hook (feature `ct-verify`)
+//!
+//! The NTT, inverse NTT, compression and decompression report every call here. Only the
+//! public shape of a call is recorded (which operation, and the compression factor), never
+//! coefficient values, and two things are tracked:
+//!
+//! - **Trace**: a running fingerprint of the call sequence. For one parameter set it must be
+//!   identical for every key and ciphertext, including ciphertexts that take the implicit
+//!   rejection path; a difference means control flow depended on secret data.
+//! - **Flags**: each wrapper checks the output range its callers index with (`< q` after the
+//!   NTTs and decompression, `< 2^d` after compression) using [`subtle`] comparisons. The
+//!   outcome is accumulated as a [`Choice`] rather than branched on, so auditing does not
+//!   introduce the secret-dependent branches it is looking for.
+//!
+//! The state is a set of global atomics so it also works in `no_std`; audit one operation at
+//! a time. This feature only adds bookkeeping and never changes results; leave it off in
+//! release builds.
+
+use core::sync::atomic::{
+    AtomicU64,
+    Ordering,
+};
+
+use subtle::{
+    Choice,
+    ConstantTimeGreater,
+};
+
+use crate::algebra::{
+    FieldElement,
+    Integer,
+};
+
+static NTT: AtomicU64 = AtomicU64::new(0);
+static NTT_INVERSE: AtomicU64 = AtomicU64::new(0);
+static COMPRESS: AtomicU64 = AtomicU64::new(0);
+static DECOMPRESS: AtomicU64 = AtomicU64::new(0);
+static TRACE: AtomicU64 = AtomicU64::new(TRACE_SEED);
+static FLAGGED: AtomicU64 = AtomicU64::new(0);
+
+const TRACE_SEED: u64 = 0xCBF2_9CE4_8422_2325;
+const TRACE_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+/// Counters and trace collected since the last [`reset`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CtReport {
+    /// Forward NTTs of single polynomials
+    pub ntt: u64,
+    /// Inverse NTTs of single polynomials
+    pub ntt_inverse: u64,
+    /// Polynomial compressions
+    pub compress: u64,
+    /// Polynomial decompressions
+    pub decompress: u64,
+    /// Fingerprint of the call sequence; equal traces mean equal control flow
+    pub trace: u64,
+    /// Outputs outside the range later used as an index or encoding width
+    pub flagged: u64,
+}
+
+/// Clear all counters and the trace
+pub fn reset() {
+    for counter in [&NTT, &NTT_INVERSE, &COMPRESS, &DECOMPRESS, &FLAGGED] {
+        counter.store(0, Ordering::SeqCst);
+    }
+    TRACE.store(TRACE_SEED, Ordering::SeqCst);
+}
+
+/// Snapshot of the audit state
+pub fn report() -> CtReport {
+    CtReport {
+        ntt: NTT.load(Ordering::SeqCst),
+        ntt_inverse: NTT_INVERSE.load(Ordering::SeqCst),
+        compress: COMPRESS.load(Ordering::SeqCst),
+        decompress: DECOMPRESS.load(Ordering::SeqCst),
+        trace: TRACE.load(Ordering::SeqCst),
+        flagged: FLAGGED.load(Ordering::SeqCst),
+    }
+}
+
+/// Public operation kinds folded into the trace
+#[derive(Clone, Copy)]
+pub(crate) enum Op {
+    Ntt = 1,
+    NttInverse = 2,
+    Compress = 3,
+    Decompress = 4,
+}
+
+/// Record a call to `op` with compression factor `d` (0 for the NTTs) whose outputs must all
+/// be at most `max`
+pub(crate) fn record(op: Op, d: usize, coefficients: &[FieldElement], max: Integer) {
+    let counter = match op {
+        Op::Ntt => &NTT,
+        Op::NttInverse => &NTT_INVERSE,
+        Op::Compress => &COMPRESS,
+        Op::Decompress => &DECOMPRESS,
+    };
+    counter.fetch_add(1, Ordering::SeqCst);
+
+    let event = ((op as u64) << 8) | d as u64;
+    // Operations are audited one at a time, so a plain load/store cannot race.
+    let trace = TRACE.load(Ordering::SeqCst);
+    TRACE.store((trace ^ event).wrapping_mul(TRACE_PRIME), Ordering::SeqCst);
+
+    let out_of_range = coefficients
+        .iter()
+        .fold(Choice::from(0), |acc, x| acc | x.0.ct_gt(&max));
+    FLAGGED.fetch_add(u64::from(out_of_range.unwrap_u8()), Ordering::SeqCst);
+}
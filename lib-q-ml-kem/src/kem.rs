@@ -198,6 +198,38 @@ impl<P> DecapsulationKey<P>
 where
     P: KemParams,
 {
+    /// Decapsulate and report whether the re-encryption check passed.
+    ///
+    /// Returns exactly what [`Decapsulate::decapsulate`](crate::Decapsulate::decapsulate)
+    /// returns, including the implicit-rejection key `K̄` for a ciphertext that fails the
+    /// check, together with `true` if the ciphertext was accepted. The flag is recomputed with
+    /// a variable-time comparison after the constant-time decapsulation and exists only for
+    /// tests and diagnostics.
+    #[cfg(feature = "testing")]
+    pub fn decapsulate_checked(
+        &self,
+        encapsulated_key: &EncodedCiphertext<P>,
+    ) -> (SharedKey, bool) {
+        use crate::Decapsulate;
+
+        let Ok(shared_key) = self.decapsulate(encapsulated_key);
+        let mp = SecretB32::new(self.dk_pke.decrypt(encapsulated_key));
+        let (_, rp) = G(&[&*mp, &self.ek.h]);
+        let cp = self.ek.ek_pke.encrypt(&mp, &SecretB32::new(rp));
+        (shared_key, cp == *encapsulated_key)
+    }
+
+    /// The implicit-rejection key `K̄ = J(z ‖ c)` for `encapsulated_key`.
+    ///
+    /// This is what [`Decapsulate::decapsulate`](crate::Decapsulate::decapsulate) returns when
+    /// the re-encryption check fails, computed directly so negative tests can compare against
+    /// it. It reveals nothing about whether `encapsulated_key` is valid and exists only for
+    /// tests and protocol analysis.
+    #[cfg(feature = "testing")]
+    pub fn decapsulate_reject_value(&self, encapsulated_key: &EncodedCiphertext<P>) -> SharedKey {
+        J(&[self.z.as_slice(), encapsulated_key.as_ref()])
+    }
+
     /// Get the [`EncapsulationKey`] which corresponds to this [`DecapsulationKey`].
     pub fn encapsulation_key(&self) -> &EncapsulationKey<P> {
         &self.ek
@@ -64,6 +64,10 @@ mod encode;
 /// Section 5. The K-PKE Component Scheme
 mod pke;
 
+/// Constant-time audit hook for the NTT and compression (feature `ct-verify`).
+#[cfg(feature = "ct-verify")]
+pub mod ct_audit;
+
 /// Centralised OS entropy adapter for the hardened path (feature `hardened`).
 /// Single location that calls `getrandom` directly; all other hardened code imports from here.
 #[cfg(feature = "hardened")]
@@ -173,6 +177,18 @@ pub enum Error {
     InvalidKey,
 }
 
+#[cfg(feature = "lib-q-core")]
+impl From<Error> for lib_q_core::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::InvalidKey => Self::InvalidKey {
+                key_type: "ML-KEM key".into(),
+                reason: "failed FIPS 203 input validation (modulus or hash check)".into(),
+            },
+        }
+    }
+}
+
 /// A value that can be encapsulated to.  Note that this interface is not safe: In order for the
 /// KEM to be secure, the `m` input must be randomly generated.
 #[cfg(feature = "deterministic")]
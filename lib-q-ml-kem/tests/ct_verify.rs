@@ -0,0 +1,39 @@
+//! `ct-verify` audit of ML-KEM decapsulation.
+
+use lib_q_ml_kem::{
+    Decapsulate,
+    Encapsulate,
+    KemCore,
+    MlKem768,
+    ct_audit,
+};
+use lib_q_random::new_secure_rng;
+
+#[test]
+fn ct_verify_decapsulate_trace_matches_on_rejection_and_outputs_stay_in_range() {
+    let mut rng = new_secure_rng().expect("secure rng");
+    let (dk, ek) = MlKem768::generate(&mut rng);
+    let (ct, _) = ek.encapsulate(&mut rng).expect("encap");
+
+    ct_audit::reset();
+    let _ = dk.decapsulate(&ct);
+    let valid = ct_audit::report();
+
+    // Implicit rejection must follow the same path as a valid ciphertext
+    let mut bad = ct;
+    bad[0] ^= 1;
+    ct_audit::reset();
+    let _ = dk.decapsulate(&bad);
+    let rejected = ct_audit::report();
+
+    assert_eq!(valid.flagged, 0, "valid ciphertext flagged: {valid:?}");
+    assert_eq!(
+        rejected.flagged, 0,
+        "rejected ciphertext flagged: {rejected:?}"
+    );
+    assert!(
+        valid.ntt > 0 && valid.compress > 0,
+        "audit hook not reached: {valid:?}"
+    );
+    assert_eq!(valid, rejected);
+}
@@ -95,4 +95,38 @@ impl CpuFeatures {
     pub fn has_sve(&self) -> bool {
         self.sve
     }
+
+    /// Number of 64-bit lanes in the widest usable SIMD register
+    ///
+    /// 8 for AVX-512, 4 for AVX2, 2 for NEON or SVE (its minimum vector length), and 1
+    /// when only scalar code can run. This is how many Keccak states a lane-parallel
+    /// permutation processes at once.
+    pub fn simd_lane_width(&self) -> usize {
+        if self.avx512 {
+            8
+        } else if self.avx2 {
+            4
+        } else if self.neon || self.sve {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+/// CPU features of the running machine
+///
+/// With `std` the features are detected once and cached, so every call returns the same
+/// value; without `std` this is [`CpuFeatures::new`], which only reflects compile-time
+/// `target_feature` flags.
+pub fn detected_features() -> CpuFeatures {
+    #[cfg(feature = "std")]
+    {
+        static DETECTED: std::sync::OnceLock<CpuFeatures> = std::sync::OnceLock::new();
+        *DETECTED.get_or_init(CpuFeatures::new)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        CpuFeatures::new()
+    }
 }
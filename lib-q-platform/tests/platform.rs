@@ -7,6 +7,7 @@ use lib_q_platform::{
     Platform,
     SimdLevel,
     SimdSupport,
+    detected_features,
     simd128_support,
     simd256_support,
     simd512_support,
@@ -21,6 +22,55 @@ fn cpu_features_accessors_match_fields() {
     assert_eq!(f.has_sve(), f.sve);
 }
 
+#[test]
+fn detected_features_are_stable_across_calls() {
+    let first = detected_features();
+    for _ in 0..4 {
+        assert_eq!(detected_features(), first);
+    }
+    assert_eq!(first, CpuFeatures::new());
+    assert_eq!(
+        detected_features().simd_lane_width(),
+        first.simd_lane_width()
+    );
+}
+
+#[test]
+fn simd_lane_width_follows_widest_feature() {
+    let scalar = CpuFeatures {
+        avx2: false,
+        avx512: false,
+        neon: false,
+        sve: false,
+    };
+    assert_eq!(scalar.simd_lane_width(), 1);
+    assert_eq!(
+        CpuFeatures {
+            neon: true,
+            ..scalar
+        }
+        .simd_lane_width(),
+        2
+    );
+    assert_eq!(
+        CpuFeatures {
+            avx2: true,
+            ..scalar
+        }
+        .simd_lane_width(),
+        4
+    );
+    assert_eq!(
+        CpuFeatures {
+            avx2: true,
+            avx512: true,
+            ..scalar
+        }
+        .simd_lane_width(),
+        8
+    );
+}
+
 #[test]
 fn cpu_features_default_matches_new() {
     assert_eq!(CpuFeatures::default(), CpuFeatures::new());
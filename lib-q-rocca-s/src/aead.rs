@@ -31,6 +31,7 @@ use lib_q_core::{
     Error,
     Nonce,
     Result,
+    SecretExportConsent,
 };
 use zeroize::Zeroizing;
 
@@ -69,10 +70,10 @@ impl RoccaSAead {
     }
 
     fn stage_key_nonce(key: &AeadKey, nonce: &Nonce) -> Result<StagedKeyNonce> {
-        if key.as_bytes().len() != Self::key_size() {
+        if key.expose_secret(SecretExportConsent).len() != Self::key_size() {
             return Err(Error::InvalidKeySize {
                 expected: Self::key_size(),
-                actual: key.as_bytes().len(),
+                actual: key.expose_secret(SecretExportConsent).len(),
             });
         }
         if nonce.as_bytes().len() != Self::nonce_size() {
@@ -82,7 +83,7 @@ impl RoccaSAead {
             });
         }
         let mut k = Zeroizing::new([0u8; 32]);
-        k.copy_from_slice(key.as_bytes());
+        k.copy_from_slice(key.expose_secret(SecretExportConsent));
         let mut n = Zeroizing::new([0u8; 16]);
         n.copy_from_slice(nonce.as_bytes());
         Ok((k, n))
@@ -20,6 +20,7 @@ use lib_q_core::{
     Error,
     Nonce,
     Result,
+    SecretExportConsent,
 };
 use zeroize::{
     Zeroize,
@@ -67,7 +68,7 @@ impl Aead for RomulusNAead {
         plaintext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
-        let kb = key.as_bytes();
+        let kb = key.expose_secret(SecretExportConsent);
         if kb.len() != Self::key_size() {
             return Err(Error::InvalidKeySize {
                 expected: Self::key_size(),
@@ -113,7 +114,7 @@ impl Aead for RomulusNAead {
         ciphertext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
-        let kb = key.as_bytes();
+        let kb = key.expose_secret(SecretExportConsent);
         if kb.len() != Self::key_size() {
             return Err(Error::InvalidKeySize {
                 expected: Self::key_size(),
@@ -161,7 +162,7 @@ impl AeadDecryptSemantic for RomulusNAead {
         ciphertext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<DecryptSemanticOutcome> {
-        let kb = key.as_bytes();
+        let kb = key.expose_secret(SecretExportConsent);
         if kb.len() != Self::key_size() {
             return Err(Error::InvalidKeySize {
                 expected: Self::key_size(),
@@ -331,7 +332,12 @@ impl Aead for RomulusMAead {
         plaintext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
-        self.encrypt_bytes(key.as_bytes(), nonce.as_bytes(), plaintext, associated_data)
+        self.encrypt_bytes(
+            key.expose_secret(SecretExportConsent),
+            nonce.as_bytes(),
+            plaintext,
+            associated_data,
+        )
     }
 
     fn decrypt(
@@ -342,7 +348,7 @@ impl Aead for RomulusMAead {
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
         self.decrypt_bytes(
-            key.as_bytes(),
+            key.expose_secret(SecretExportConsent),
             nonce.as_bytes(),
             ciphertext,
             associated_data,
@@ -358,7 +364,7 @@ impl AeadDecryptSemantic for RomulusMAead {
         ciphertext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<DecryptSemanticOutcome> {
-        let kb = key.as_bytes();
+        let kb = key.expose_secret(SecretExportConsent);
         if kb.len() != Self::key_size() {
             return Err(Error::InvalidKeySize {
                 expected: Self::key_size(),
@@ -75,6 +75,7 @@ use lib_q_core::{
     Error,
     Nonce,
     Result,
+    SecretExportConsent,
 };
 use zeroize::{
     Zeroize,
@@ -500,7 +501,12 @@ impl Aead for SaturninAead {
         plaintext: &[u8],
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
-        self.encrypt_bytes(key.as_bytes(), nonce.as_bytes(), plaintext, associated_data)
+        self.encrypt_bytes(
+            key.expose_secret(SecretExportConsent),
+            nonce.as_bytes(),
+            plaintext,
+            associated_data,
+        )
     }
 
     /// Decrypt and verify data (Layer A); shares one decrypt core with [`lib_q_core::AeadDecryptSemantic`].
@@ -512,7 +518,7 @@ impl Aead for SaturninAead {
         associated_data: Option<&[u8]>,
     ) -> Result<Vec<u8>> {
         self.decrypt_bytes(
-            key.as_bytes(),
+            key.expose_secret(SecretExportConsent),
             nonce.as_bytes(),
             ciphertext,
             associated_data,
@@ -530,7 +536,7 @@ impl AeadDecryptSemantic for SaturninAead {
         associated_data: Option<&[u8]>,
     ) -> Result<DecryptSemanticOutcome> {
         self.decrypt_core(
-            key.as_bytes(),
+            key.expose_secret(SecretExportConsent),
             nonce.as_bytes(),
             ciphertext,
             associated_data,
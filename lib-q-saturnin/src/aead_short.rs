@@ -73,6 +73,7 @@ use lib_q_core::{
     Error,
     Nonce,
     Result,
+    SecretExportConsent,
 };
 use zeroize::{
     Zeroize,
@@ -318,7 +319,7 @@ impl SaturninShortAead {
             });
         }
 
-        let key32 = Self::validate_key(key.as_bytes())?;
+        let key32 = Self::validate_key(key.expose_secret(SecretExportConsent))?;
         self.validate_nonce(nonce.as_bytes())?;
 
         let mut block: [u8; 32] =
@@ -350,7 +351,7 @@ impl SaturninShortAead {
         Self::reject_associated_data(ad)?;
         self.reject_plaintext_len(plaintext.len())?;
 
-        let key32 = Self::validate_key(key.as_bytes())?;
+        let key32 = Self::validate_key(key.expose_secret(SecretExportConsent))?;
         self.validate_nonce(nonce.as_bytes())?;
 
         let mut block = self.encode_block(nonce.as_bytes(), plaintext);
@@ -77,6 +77,7 @@ use lib_q_core::{
     Error,
     Nonce,
     Result,
+    SecretExportConsent,
 };
 use zeroize::{
     Zeroize,
@@ -199,10 +200,10 @@ impl SaturninQcb {
     }
 
     fn validate_lengths(key: &AeadKey, nonce: &Nonce) -> Result<()> {
-        if key.as_bytes().len() != Self::key_size() {
+        if key.expose_secret(SecretExportConsent).len() != Self::key_size() {
             return Err(Error::InvalidKeySize {
                 expected: Self::key_size(),
-                actual: key.as_bytes().len(),
+                actual: key.expose_secret(SecretExportConsent).len(),
             });
         }
         if nonce.as_bytes().len() != Self::nonce_size() {
@@ -247,7 +248,7 @@ impl SaturninQcb {
         let m = body_len / BLOCK;
 
         let mut key_staged = Zeroizing::new([0u8; 32]);
-        key_staged.copy_from_slice(key.as_bytes());
+        key_staged.copy_from_slice(key.expose_secret(SecretExportConsent));
         let mut nonce16 = Zeroizing::new([0u8; 16]);
         nonce16.copy_from_slice(nonce.as_bytes());
         let ad = associated_data.unwrap_or(&[]);
@@ -314,7 +315,7 @@ impl Aead for SaturninQcb {
         Self::validate_lengths(key, nonce)?;
 
         let mut key_staged = Zeroizing::new([0u8; 32]);
-        key_staged.copy_from_slice(key.as_bytes());
+        key_staged.copy_from_slice(key.expose_secret(SecretExportConsent));
         let mut nonce16 = Zeroizing::new([0u8; 16]);
         nonce16.copy_from_slice(nonce.as_bytes());
         let ad = associated_data.unwrap_or(&[]);
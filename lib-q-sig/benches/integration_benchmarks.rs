@@ -13,12 +13,15 @@ use criterion::{
     criterion_group,
     criterion_main,
 };
-#[cfg(feature = "slh-dsa")]
-use lib_q_core::SigSecretKey;
 use lib_q_core::{
     Algorithm,
     SignatureOperations,
 };
+#[cfg(feature = "slh-dsa")]
+use lib_q_core::{
+    SecretExportConsent,
+    SigSecretKey,
+};
 use lib_q_sig::LibQSignatureProvider;
 
 /// Key-generation entropy for SLH-DSA (3×N with N ≤ 32).
@@ -350,7 +353,10 @@ fn benchmark_provider_memory_usage(c: &mut Criterion) {
                     .generate_keypair(algorithm, Some(&key_randomness))
                     .expect("Key generation should succeed");
                 let public_key_size = keypair.public_key().as_bytes().len();
-                let secret_key_size = keypair.secret_key().as_bytes().len();
+                let secret_key_size = keypair
+                    .secret_key()
+                    .expose_secret(SecretExportConsent)
+                    .len();
                 black_box((public_key_size, secret_key_size))
             })
         });
@@ -20,6 +20,7 @@ use criterion::{
 };
 use lib_q_core::{
     Algorithm,
+    SecretExportConsent,
     Signature,
     SignatureOperations,
 };
@@ -376,7 +377,11 @@ fn benchmark_memory_usage(c: &mut Criterion) {
             b.iter(|| {
                 let keypair = ml_dsa_44.generate_keypair().unwrap();
                 black_box(
-                    keypair.public_key().as_bytes().len() + keypair.secret_key().as_bytes().len(),
+                    keypair.public_key().as_bytes().len() +
+                        keypair
+                            .secret_key()
+                            .expose_secret(SecretExportConsent)
+                            .len(),
                 )
             })
         });
@@ -385,7 +390,11 @@ fn benchmark_memory_usage(c: &mut Criterion) {
             b.iter(|| {
                 let keypair = ml_dsa_65.generate_keypair().unwrap();
                 black_box(
-                    keypair.public_key().as_bytes().len() + keypair.secret_key().as_bytes().len(),
+                    keypair.public_key().as_bytes().len() +
+                        keypair
+                            .secret_key()
+                            .expose_secret(SecretExportConsent)
+                            .len(),
                 )
             })
         });
@@ -394,7 +403,11 @@ fn benchmark_memory_usage(c: &mut Criterion) {
             b.iter(|| {
                 let keypair = ml_dsa_87.generate_keypair().unwrap();
                 black_box(
-                    keypair.public_key().as_bytes().len() + keypair.secret_key().as_bytes().len(),
+                    keypair.public_key().as_bytes().len() +
+                        keypair
+                            .secret_key()
+                            .expose_secret(SecretExportConsent)
+                            .len(),
                 )
             })
         });
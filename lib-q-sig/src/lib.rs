@@ -257,6 +257,8 @@ pub mod fn_dsa;
 #[cfg(feature = "slh-dsa")]
 pub mod slh_dsa;
 
+#[cfg(feature = "ml-dsa")]
+pub use ml_dsa::SigSecretKeyExt;
 // Re-export provider
 #[cfg(feature = "alloc")]
 pub use provider::LibQSignatureProvider;
@@ -323,6 +325,9 @@ pub fn available_algorithms() -> &'static [&'static str] {
 }
 
 /// Create a signature instance by algorithm name (legacy compatibility)
+///
+/// Pre-standard names are accepted as aliases: `falcon*` maps to FN-DSA (FIPS 206) and
+/// `sphincs-shake-128f` to SLH-DSA-SHAKE256-128f (FIPS 205).
 #[cfg(feature = "std")]
 pub fn create_signature(algorithm: &str) -> Result<Box<dyn Signature>> {
     match algorithm {
@@ -334,11 +339,15 @@ pub fn create_signature(algorithm: &str) -> Result<Box<dyn Signature>> {
         "mldsa87" | "ML-DSA-87" => Ok(Box::new(ml_dsa::MlDsa::ml_dsa_87())),
 
         #[cfg(feature = "fn-dsa")]
-        "fn-dsa" | "FN-DSA" => Ok(Box::new(fn_dsa::FnDsa::level1())),
+        "fn-dsa" | "FN-DSA" | "falcon" => Ok(Box::new(fn_dsa::FnDsa::level1())),
         #[cfg(feature = "fn-dsa")]
-        "fn-dsa-512" | "FN-DSA-512" => Ok(Box::new(fn_dsa::FnDsa512::new())),
+        "fn-dsa-512" | "FN-DSA-512" | "falcon-512" | "Falcon-512" => {
+            Ok(Box::new(fn_dsa::FnDsa512::new()))
+        }
         #[cfg(feature = "fn-dsa")]
-        "fn-dsa-1024" | "FN-DSA-1024" => Ok(Box::new(fn_dsa::FnDsa1024::new())),
+        "fn-dsa-1024" | "FN-DSA-1024" | "falcon-1024" | "Falcon-1024" => {
+            Ok(Box::new(fn_dsa::FnDsa1024::new()))
+        }
 
         #[cfg(feature = "slh-dsa")]
         "slh-dsa" |
@@ -354,6 +363,11 @@ pub fn create_signature(algorithm: &str) -> Result<Box<dyn Signature>> {
         "slh-dsa-shake256-192f-robust" |
         "SLH-DSA-SHAKE256-256f-Robust" |
         "slh-dsa-shake256-256f-robust" => Ok(Box::new(slh_dsa::SlhDsa::new())),
+        // SPHINCS+ is the pre-standard name of SLH-DSA; `SlhDsa` signs with SHAKE256-128f.
+        #[cfg(feature = "slh-dsa")]
+        "sphincs-shake-128f" | "sphincs+-shake-128f" | "SPHINCS+-SHAKE-128f" => {
+            Ok(Box::new(slh_dsa::SlhDsa::new()))
+        }
 
         _ => Err(Error::InvalidAlgorithm {
             algorithm: "Unknown algorithm",
@@ -83,6 +83,8 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 #[cfg(not(feature = "std"))]
+use alloc::format;
+#[cfg(not(feature = "std"))]
 use alloc::string::ToString;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
@@ -92,22 +94,35 @@ use std::vec::Vec;
 #[cfg(feature = "wasm")]
 use js_sys::Uint8Array;
 use lib_q_core::{
+    Algorithm,
+    Primitive,
     Result,
+    SecretExportConsent,
     SigKeypair,
     SigPublicKey,
     SigSecretKey,
     Signature,
+    validate_signature_context,
 };
+#[cfg(feature = "alloc")]
+use lib_q_ml_dsa::constants::MESSAGE_REPRESENTATIVE_SIZE;
 use lib_q_ml_dsa::constants::{
     KEY_GENERATION_RANDOMNESS_SIZE,
     SIGNING_RANDOMNESS_SIZE,
 };
 use lib_q_ml_dsa::types::*;
 use lib_q_ml_dsa::{
+    PreHashAlgorithm,
     ml_dsa_44,
     ml_dsa_65,
     ml_dsa_87,
 };
+#[cfg(feature = "alloc")]
+use lib_q_sha3::{
+    ExtendableOutput,
+    Shake256,
+    Update,
+};
 // WASM support
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
@@ -136,6 +151,28 @@ const MLDSA87_VERIFICATION_KEY_SIZE: usize = 2592;
 const MLDSA87_SIGNING_KEY_SIZE: usize = 4896;
 const MLDSA87_SIGNATURE_SIZE: usize = 4627;
 
+/// Map a hash identifier to its HashML-DSA pre-hash and check the digest length against it
+fn validate_prehash(digest: &[u8], hash: Algorithm) -> Result<PreHashAlgorithm> {
+    let pre_hash = match hash {
+        Algorithm::Sha3_256 => PreHashAlgorithm::Sha3_256,
+        Algorithm::Sha3_384 => PreHashAlgorithm::Sha3_384,
+        Algorithm::Sha3_512 => PreHashAlgorithm::Sha3_512,
+        Algorithm::Shake256 => PreHashAlgorithm::Shake256,
+        _ => {
+            return Err(lib_q_core::Error::InvalidAlgorithm {
+                algorithm: "HashML-DSA pre-hash must be SHA3-256, SHA3-384, SHA3-512 or SHAKE256",
+            });
+        }
+    };
+    if digest.len() != pre_hash.digest_len() {
+        return Err(lib_q_core::Error::InvalidHashSize {
+            expected: pre_hash.digest_len(),
+            actual: digest.len(),
+        });
+    }
+    Ok(pre_hash)
+}
+
 /// ML-DSA variants with their parameter sets
 #[derive(Debug, Clone, Copy)]
 pub enum MlDsaVariant {
@@ -263,6 +300,30 @@ impl MlDsa {
         message: &[u8],
         randomness: [u8; SIGNING_RANDOMNESS_SIZE],
     ) -> Result<Vec<u8>> {
+        self.sign_with_context_and_randomness(secret_key, message, &[], randomness)
+    }
+
+    /// Sign a message under a FIPS 204 context string with provided randomness (no_std compatible)
+    ///
+    /// The context (at most
+    /// [`MAX_SIGNATURE_CONTEXT_SIZE`](lib_q_core::MAX_SIGNATURE_CONTEXT_SIZE) bytes) is bound
+    /// into the signature for domain separation; verification must use the same context. An
+    /// empty context is identical to [`sign_with_randomness`](Self::sign_with_randomness).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidContextSize`](lib_q_core::Error::InvalidContextSize) when `context`
+    /// is longer than [`MAX_SIGNATURE_CONTEXT_SIZE`](lib_q_core::MAX_SIGNATURE_CONTEXT_SIZE) bytes.
+    #[cfg(feature = "alloc")]
+    pub fn sign_with_context_and_randomness(
+        &self,
+        secret_key: &SigSecretKey,
+        message: &[u8],
+        context: &[u8],
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+    ) -> Result<Vec<u8>> {
+        validate_signature_context(context)?;
+
         // Validate secret key size for the specific variant
         let expected_sk_size = match self.variant {
             MlDsaVariant::MlDsa44 => MLDSA44_SIGNING_KEY_SIZE,
@@ -270,10 +331,10 @@ impl MlDsa {
             MlDsaVariant::MlDsa87 => MLDSA87_SIGNING_KEY_SIZE,
         };
 
-        if secret_key.as_bytes().len() != expected_sk_size {
+        if secret_key.expose_secret(SecretExportConsent).len() != expected_sk_size {
             return Err(lib_q_core::Error::InvalidKeySize {
                 expected: expected_sk_size,
-                actual: secret_key.as_bytes().len(),
+                actual: secret_key.expose_secret(SecretExportConsent).len(),
             });
         }
 
@@ -283,58 +344,40 @@ impl MlDsa {
                 let mut signing_key = MLDSASigningKey::zero();
                 signing_key
                     .as_mut_slice()
-                    .copy_from_slice(secret_key.as_bytes());
+                    .copy_from_slice(secret_key.expose_secret(SecretExportConsent));
 
-                let sig_result = ml_dsa_44::portable::sign(
-                    &signing_key,
-                    message,
-                    &[], // empty context
-                    randomness,
-                );
+                let sig_result =
+                    ml_dsa_44::portable::sign(&signing_key, message, context, randomness);
                 signing_key.as_mut_slice().zeroize();
                 sig_result
                     .map(|signature| signature.as_slice().to_vec())
-                    .map_err(|_| lib_q_core::Error::SigningFailed {
-                        operation: "ml-dsa-44 signing".to_string(),
-                    })?
+                    .map_err(lib_q_core::Error::from)?
             }
             MlDsaVariant::MlDsa65 => {
                 let mut signing_key = MLDSASigningKey::zero();
                 signing_key
                     .as_mut_slice()
-                    .copy_from_slice(secret_key.as_bytes());
+                    .copy_from_slice(secret_key.expose_secret(SecretExportConsent));
 
-                let sig_result = ml_dsa_65::portable::sign(
-                    &signing_key,
-                    message,
-                    &[], // empty context
-                    randomness,
-                );
+                let sig_result =
+                    ml_dsa_65::portable::sign(&signing_key, message, context, randomness);
                 signing_key.as_mut_slice().zeroize();
                 sig_result
                     .map(|signature| signature.as_slice().to_vec())
-                    .map_err(|_| lib_q_core::Error::SigningFailed {
-                        operation: "ml-dsa-65 signing".to_string(),
-                    })?
+                    .map_err(lib_q_core::Error::from)?
             }
             MlDsaVariant::MlDsa87 => {
                 let mut signing_key = MLDSASigningKey::zero();
                 signing_key
                     .as_mut_slice()
-                    .copy_from_slice(secret_key.as_bytes());
+                    .copy_from_slice(secret_key.expose_secret(SecretExportConsent));
 
-                let sig_result = ml_dsa_87::portable::sign(
-                    &signing_key,
-                    message,
-                    &[], // empty context
-                    randomness,
-                );
+                let sig_result =
+                    ml_dsa_87::portable::sign(&signing_key, message, context, randomness);
                 signing_key.as_mut_slice().zeroize();
                 sig_result
                     .map(|signature| signature.as_slice().to_vec())
-                    .map_err(|_| lib_q_core::Error::SigningFailed {
-                        operation: "ml-dsa-87 signing".to_string(),
-                    })?
+                    .map_err(lib_q_core::Error::from)?
             }
         };
 
@@ -354,81 +397,634 @@ impl MlDsa {
     }
 }
 
-impl Default for MlDsa {
-    fn default() -> Self {
-        Self::ml_dsa_65() // Default to ML-DSA-65 for 192-bit security
-    }
-}
-
-impl Signature for MlDsa {
-    fn generate_keypair(&self) -> Result<SigKeypair> {
+impl MlDsa {
+    /// Sign a message under a FIPS 204 context string
+    ///
+    /// Randomness is drawn from the OS RNG (requires `std`). A signature made under one
+    /// context only verifies under the same context via
+    /// [`verify_with_context`](Self::verify_with_context); an empty context is identical to
+    /// [`Signature::sign`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidContextSize`](lib_q_core::Error::InvalidContextSize) when `context`
+    /// is longer than [`MAX_SIGNATURE_CONTEXT_SIZE`](lib_q_core::MAX_SIGNATURE_CONTEXT_SIZE) bytes.
+    #[cfg(feature = "alloc")]
+    #[allow(unused_variables)]
+    pub fn sign_with_context(
+        &self,
+        secret_key: &SigSecretKey,
+        message: &[u8],
+        context: &[u8],
+    ) -> Result<Vec<u8>> {
         #[cfg(feature = "std")]
         {
             use lib_q_core::Utils;
 
-            // Generate cryptographically secure random seed
-            let seed = Utils::random_bytes(KEY_GENERATION_RANDOMNESS_SIZE).map_err(|_| {
+            // Generate cryptographically secure random seed for signing
+            let randomness = Utils::random_bytes(SIGNING_RANDOMNESS_SIZE).map_err(|_| {
                 lib_q_core::Error::RandomGenerationFailed {
-                    operation: "ml-dsa key generation".to_string(),
+                    operation: "ml-dsa signing".to_string(),
                 }
             })?;
 
-            let seed_len = seed.len();
-            let seed_array: [u8; KEY_GENERATION_RANDOMNESS_SIZE] =
-                seed.try_into()
+            let randomness_len = randomness.len();
+            let randomness_array: [u8; SIGNING_RANDOMNESS_SIZE] =
+                randomness
+                    .try_into()
                     .map_err(|_| lib_q_core::Error::InvalidKeySize {
-                        expected: KEY_GENERATION_RANDOMNESS_SIZE,
-                        actual: seed_len,
+                        expected: SIGNING_RANDOMNESS_SIZE,
+                        actual: randomness_len,
                     })?;
 
-            self.generate_keypair_with_randomness(seed_array)
+            self.sign_with_context_and_randomness(secret_key, message, context, randomness_array)
         }
 
         #[cfg(not(feature = "std"))]
         {
-            // In no_std mode, return error - key generation requires randomness
+            // In no_std mode, return error - signing requires randomness
             Err(lib_q_core::Error::RandomGenerationFailed {
-                operation: "ml-dsa key generation requires std feature or external randomness"
-                    .to_string(),
+                operation: "ml-dsa signing requires std feature or external randomness".to_string(),
             })
         }
     }
 
+    /// Verify a signature made under a FIPS 204 context string
+    ///
+    /// Returns `Ok(false)` when the signature was produced under a different context.
+    /// Size rules match [`Signature::verify`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidContextSize`](lib_q_core::Error::InvalidContextSize) when `context`
+    /// is longer than [`MAX_SIGNATURE_CONTEXT_SIZE`](lib_q_core::MAX_SIGNATURE_CONTEXT_SIZE) bytes.
+    pub fn verify_with_context(
+        &self,
+        public_key: &SigPublicKey,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> Result<bool> {
+        validate_signature_context(context)?;
+
+        use lib_q_ml_dsa::types::{
+            MLDSASignature,
+            MLDSAVerificationKey,
+        };
+
+        // Convert public key bytes to ML-DSA verification key
+        let public_key_bytes = public_key.as_bytes();
+        let expected_vk_size = match self.variant {
+            MlDsaVariant::MlDsa44 => MLDSA44_VERIFICATION_KEY_SIZE,
+            MlDsaVariant::MlDsa65 => MLDSA65_VERIFICATION_KEY_SIZE,
+            MlDsaVariant::MlDsa87 => MLDSA87_VERIFICATION_KEY_SIZE,
+        };
+
+        // Validate public key size first
+        if public_key_bytes.len() != expected_vk_size {
+            return Err(lib_q_core::Error::InvalidKeySize {
+                expected: expected_vk_size,
+                actual: public_key_bytes.len(),
+            });
+        }
+
+        let expected_sig_size = match self.variant {
+            MlDsaVariant::MlDsa44 => MLDSA44_SIGNATURE_SIZE,
+            MlDsaVariant::MlDsa65 => MLDSA65_SIGNATURE_SIZE,
+            MlDsaVariant::MlDsa87 => MLDSA87_SIGNATURE_SIZE,
+        };
+        if signature.len() != expected_sig_size {
+            return Err(lib_q_core::Error::InvalidSignatureSize {
+                expected: expected_sig_size,
+                actual: signature.len(),
+            });
+        }
+
+        // Create verification key and verify for the specific variant
+        let result = match self.variant {
+            MlDsaVariant::MlDsa44 => {
+                let mut vk_bytes = [0u8; MLDSA44_VERIFICATION_KEY_SIZE];
+                vk_bytes.copy_from_slice(public_key_bytes);
+                let verification_key = MLDSAVerificationKey::new(vk_bytes);
+
+                let mut sig_bytes = [0u8; MLDSA44_SIGNATURE_SIZE];
+                sig_bytes.copy_from_slice(signature);
+                let ml_dsa_signature = MLDSASignature::new(sig_bytes);
+
+                ml_dsa_44::portable::verify(&verification_key, message, context, &ml_dsa_signature)
+                    .is_ok()
+            }
+            MlDsaVariant::MlDsa65 => {
+                let mut vk_bytes = [0u8; MLDSA65_VERIFICATION_KEY_SIZE];
+                vk_bytes.copy_from_slice(public_key_bytes);
+                let verification_key = MLDSAVerificationKey::new(vk_bytes);
+
+                let mut sig_bytes = [0u8; MLDSA65_SIGNATURE_SIZE];
+                sig_bytes.copy_from_slice(signature);
+                let ml_dsa_signature = MLDSASignature::new(sig_bytes);
+
+                ml_dsa_65::portable::verify(&verification_key, message, context, &ml_dsa_signature)
+                    .is_ok()
+            }
+            MlDsaVariant::MlDsa87 => {
+                let mut vk_bytes = [0u8; MLDSA87_VERIFICATION_KEY_SIZE];
+                vk_bytes.copy_from_slice(public_key_bytes);
+                let verification_key = MLDSAVerificationKey::new(vk_bytes);
+
+                let mut sig_bytes = [0u8; MLDSA87_SIGNATURE_SIZE];
+                sig_bytes.copy_from_slice(signature);
+                let ml_dsa_signature = MLDSASignature::new(sig_bytes);
+
+                ml_dsa_87::portable::verify(&verification_key, message, context, &ml_dsa_signature)
+                    .is_ok()
+            }
+        };
+
+        Ok(result)
+    }
+
+    /// Sign an already-computed message digest with HashML-DSA (FIPS 204 §5.4)
+    ///
+    /// `hash` identifies the function that produced `digest` (SHA3-256, SHA3-384, SHA3-512 or
+    /// SHAKE256 with a 64-byte output); its OID is bound into the signature, so the result only
+    /// verifies through [`verify_prehash`](Self::verify_prehash) with the same identifier.
+    /// HashML-DSA signatures are not interchangeable with signatures over the message itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlgorithm`](lib_q_core::Error::InvalidAlgorithm) for any other `hash`
+    /// and [`InvalidHashSize`](lib_q_core::Error::InvalidHashSize) when `digest` does not have
+    /// that function's output length.
     #[cfg(feature = "alloc")]
-    #[allow(unused_variables)]
-    fn sign(&self, secret_key: &SigSecretKey, message: &[u8]) -> Result<Vec<u8>> {
+    pub fn sign_prehash_with_randomness(
+        &self,
+        secret_key: &SigSecretKey,
+        digest: &[u8],
+        hash: Algorithm,
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+    ) -> Result<Vec<u8>> {
+        let pre_hash = validate_prehash(digest, hash)?;
+
+        let expected_sk_size = match self.variant {
+            MlDsaVariant::MlDsa44 => MLDSA44_SIGNING_KEY_SIZE,
+            MlDsaVariant::MlDsa65 => MLDSA65_SIGNING_KEY_SIZE,
+            MlDsaVariant::MlDsa87 => MLDSA87_SIGNING_KEY_SIZE,
+        };
+        if secret_key.expose_secret(SecretExportConsent).len() != expected_sk_size {
+            return Err(lib_q_core::Error::InvalidKeySize {
+                expected: expected_sk_size,
+                actual: secret_key.expose_secret(SecretExportConsent).len(),
+            });
+        }
+
+        let signature = match self.variant {
+            MlDsaVariant::MlDsa44 => {
+                let mut signing_key = MLDSASigningKey::zero();
+                signing_key
+                    .as_mut_slice()
+                    .copy_from_slice(secret_key.expose_secret(SecretExportConsent));
+                let sig_result = ml_dsa_44::sign_pre_hashed_digest(
+                    &signing_key,
+                    digest,
+                    &[],
+                    pre_hash,
+                    randomness,
+                );
+                signing_key.as_mut_slice().zeroize();
+                sig_result.map(|signature| signature.as_slice().to_vec())
+            }
+            MlDsaVariant::MlDsa65 => {
+                let mut signing_key = MLDSASigningKey::zero();
+                signing_key
+                    .as_mut_slice()
+                    .copy_from_slice(secret_key.expose_secret(SecretExportConsent));
+                let sig_result = ml_dsa_65::sign_pre_hashed_digest(
+                    &signing_key,
+                    digest,
+                    &[],
+                    pre_hash,
+                    randomness,
+                );
+                signing_key.as_mut_slice().zeroize();
+                sig_result.map(|signature| signature.as_slice().to_vec())
+            }
+            MlDsaVariant::MlDsa87 => {
+                let mut signing_key = MLDSASigningKey::zero();
+                signing_key
+                    .as_mut_slice()
+                    .copy_from_slice(secret_key.expose_secret(SecretExportConsent));
+                let sig_result = ml_dsa_87::sign_pre_hashed_digest(
+                    &signing_key,
+                    digest,
+                    &[],
+                    pre_hash,
+                    randomness,
+                );
+                signing_key.as_mut_slice().zeroize();
+                sig_result.map(|signature| signature.as_slice().to_vec())
+            }
+        };
+
+        signature.map_err(lib_q_core::Error::from)
+    }
+
+    /// Sign an already-computed message digest with HashML-DSA using OS randomness
+    ///
+    /// See [`sign_prehash_with_randomness`](Self::sign_prehash_with_randomness) for the accepted
+    /// hash identifiers and errors.
+    #[cfg(feature = "std")]
+    pub fn sign_prehash(
+        &self,
+        secret_key: &SigSecretKey,
+        digest: &[u8],
+        hash: Algorithm,
+    ) -> Result<Vec<u8>> {
+        use lib_q_core::Utils;
+
+        let randomness = Utils::random_bytes(SIGNING_RANDOMNESS_SIZE).map_err(|_| {
+            lib_q_core::Error::RandomGenerationFailed {
+                operation: "hash-ml-dsa signing".to_string(),
+            }
+        })?;
+        let randomness_len = randomness.len();
+        let randomness_array: [u8; SIGNING_RANDOMNESS_SIZE] =
+            randomness
+                .try_into()
+                .map_err(|_| lib_q_core::Error::InvalidKeySize {
+                    expected: SIGNING_RANDOMNESS_SIZE,
+                    actual: randomness_len,
+                })?;
+
+        self.sign_prehash_with_randomness(secret_key, digest, hash, randomness_array)
+    }
+
+    /// Verify a HashML-DSA signature over an already-computed message digest
+    ///
+    /// Returns `Ok(false)` when the signature does not match, including when it was produced
+    /// under a different `hash` identifier. Size rules match [`Signature::verify`].
+    ///
+    /// # Errors
+    ///
+    /// Same `hash` and digest-length errors as
+    /// [`sign_prehash_with_randomness`](Self::sign_prehash_with_randomness).
+    pub fn verify_prehash(
+        &self,
+        public_key: &SigPublicKey,
+        digest: &[u8],
+        hash: Algorithm,
+        signature: &[u8],
+    ) -> Result<bool> {
+        let pre_hash = validate_prehash(digest, hash)?;
+
+        let public_key_bytes = public_key.as_bytes();
+        let expected_vk_size = match self.variant {
+            MlDsaVariant::MlDsa44 => MLDSA44_VERIFICATION_KEY_SIZE,
+            MlDsaVariant::MlDsa65 => MLDSA65_VERIFICATION_KEY_SIZE,
+            MlDsaVariant::MlDsa87 => MLDSA87_VERIFICATION_KEY_SIZE,
+        };
+        if public_key_bytes.len() != expected_vk_size {
+            return Err(lib_q_core::Error::InvalidKeySize {
+                expected: expected_vk_size,
+                actual: public_key_bytes.len(),
+            });
+        }
+
+        let expected_sig_size = match self.variant {
+            MlDsaVariant::MlDsa44 => MLDSA44_SIGNATURE_SIZE,
+            MlDsaVariant::MlDsa65 => MLDSA65_SIGNATURE_SIZE,
+            MlDsaVariant::MlDsa87 => MLDSA87_SIGNATURE_SIZE,
+        };
+        if signature.len() != expected_sig_size {
+            return Err(lib_q_core::Error::InvalidSignatureSize {
+                expected: expected_sig_size,
+                actual: signature.len(),
+            });
+        }
+
+        let result = match self.variant {
+            MlDsaVariant::MlDsa44 => {
+                let mut vk_bytes = [0u8; MLDSA44_VERIFICATION_KEY_SIZE];
+                vk_bytes.copy_from_slice(public_key_bytes);
+                let mut sig_bytes = [0u8; MLDSA44_SIGNATURE_SIZE];
+                sig_bytes.copy_from_slice(signature);
+                ml_dsa_44::verify_pre_hashed_digest(
+                    &MLDSAVerificationKey::new(vk_bytes),
+                    digest,
+                    &[],
+                    pre_hash,
+                    &MLDSASignature::new(sig_bytes),
+                )
+                .is_ok()
+            }
+            MlDsaVariant::MlDsa65 => {
+                let mut vk_bytes = [0u8; MLDSA65_VERIFICATION_KEY_SIZE];
+                vk_bytes.copy_from_slice(public_key_bytes);
+                let mut sig_bytes = [0u8; MLDSA65_SIGNATURE_SIZE];
+                sig_bytes.copy_from_slice(signature);
+                ml_dsa_65::verify_pre_hashed_digest(
+                    &MLDSAVerificationKey::new(vk_bytes),
+                    digest,
+                    &[],
+                    pre_hash,
+                    &MLDSASignature::new(sig_bytes),
+                )
+                .is_ok()
+            }
+            MlDsaVariant::MlDsa87 => {
+                let mut vk_bytes = [0u8; MLDSA87_VERIFICATION_KEY_SIZE];
+                vk_bytes.copy_from_slice(public_key_bytes);
+                let mut sig_bytes = [0u8; MLDSA87_SIGNATURE_SIZE];
+                sig_bytes.copy_from_slice(signature);
+                ml_dsa_87::verify_pre_hashed_digest(
+                    &MLDSAVerificationKey::new(vk_bytes),
+                    digest,
+                    &[],
+                    pre_hash,
+                    &MLDSASignature::new(sig_bytes),
+                )
+                .is_ok()
+            }
+        };
+
+        Ok(result)
+    }
+
+    /// Verify many `(public_key, message, signature)` items
+    ///
+    /// The result is aligned index-for-index with `items`. Items are grouped by public key so
+    /// each distinct key is decoded and its matrix `A` expanded only once. Items whose key or
+    /// signature has the wrong size for this variant are reported as `false`.
+    pub fn verify_batch(&self, items: &[(SigPublicKey, Vec<u8>, Vec<u8>)]) -> Result<Vec<bool>> {
+        let results = match self.variant {
+            MlDsaVariant::MlDsa44 => verify_batch_grouped(items, |vk, batch, out| {
+                ml_dsa_44::verify_batch(vk, &[], batch, out)
+            }),
+            MlDsaVariant::MlDsa65 => verify_batch_grouped(items, |vk, batch, out| {
+                ml_dsa_65::verify_batch(vk, &[], batch, out)
+            }),
+            MlDsaVariant::MlDsa87 => verify_batch_grouped(items, |vk, batch, out| {
+                ml_dsa_87::verify_batch(vk, &[], batch, out)
+            }),
+        };
+        results.map_err(lib_q_core::Error::from)
+    }
+
+    /// Recompute the public key belonging to `secret_key`
+    ///
+    /// ML-DSA secret keys hold the seed for the matrix `A` together with `s1` and `s2`, so
+    /// the public key can be rebuilt when only the signing key was persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidKeySize`](lib_q_core::Error::InvalidKeySize) if the secret key length
+    /// does not match this variant.
+    pub fn derive_public_key(&self, secret_key: &SigSecretKey) -> Result<SigPublicKey> {
+        let secret_key = secret_key.expose_secret(SecretExportConsent);
+        let public_key = match self.variant {
+            MlDsaVariant::MlDsa44 => derive_fixed(secret_key, ml_dsa_44::derive_verification_key),
+            MlDsaVariant::MlDsa65 => derive_fixed(secret_key, ml_dsa_65::derive_verification_key),
+            MlDsaVariant::MlDsa87 => derive_fixed(secret_key, ml_dsa_87::derive_verification_key),
+        }?;
+        Ok(SigPublicKey::new(public_key))
+    }
+}
+
+/// Copy `secret_key` into a fixed-size signing key and recompute its verification key
+fn derive_fixed<const SK_SIZE: usize, const VK_SIZE: usize>(
+    secret_key: &[u8],
+    derive: impl Fn(&MLDSASigningKey<SK_SIZE>) -> MLDSAVerificationKey<VK_SIZE>,
+) -> Result<Vec<u8>> {
+    if secret_key.len() != SK_SIZE {
+        return Err(lib_q_core::Error::InvalidKeySize {
+            expected: SK_SIZE,
+            actual: secret_key.len(),
+        });
+    }
+    let mut signing_key = MLDSASigningKey::zero();
+    signing_key.as_mut_slice().copy_from_slice(secret_key);
+    let verification_key = derive(&signing_key);
+    signing_key.as_mut_slice().zeroize();
+    Ok(verification_key.as_slice().to_vec())
+}
+
+/// Public key recovery on [`SigSecretKey`]
+///
+/// `SigSecretKey` is defined in `lib-q-core`, which does not depend on the signature
+/// implementations, so the method is provided by this extension trait.
+pub trait SigSecretKeyExt {
+    /// Recompute the public key of this secret key for `algorithm`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlgorithm`](lib_q_core::Error::InvalidAlgorithm) for non-signature
+    /// algorithms, [`UnsupportedAlgorithm`](lib_q_core::Error::UnsupportedAlgorithm) for
+    /// signature schemes other than ML-DSA and
+    /// [`InvalidKeySize`](lib_q_core::Error::InvalidKeySize) if the key length does not
+    /// match `algorithm`.
+    fn derive_public(&self, algorithm: Algorithm) -> Result<SigPublicKey>;
+}
+
+impl SigSecretKeyExt for SigSecretKey {
+    fn derive_public(&self, algorithm: Algorithm) -> Result<SigPublicKey> {
+        let dsa = match algorithm {
+            Algorithm::MlDsa44 => MlDsa::ml_dsa_44(),
+            Algorithm::MlDsa65 => MlDsa::ml_dsa_65(),
+            Algorithm::MlDsa87 => MlDsa::ml_dsa_87(),
+            _ if algorithm.category() != lib_q_core::AlgorithmCategory::Signature => {
+                return Err(lib_q_core::Error::InvalidAlgorithm {
+                    algorithm: "Algorithm is not a signature algorithm",
+                });
+            }
+            _ => {
+                return Err(lib_q_core::Error::UnsupportedAlgorithm {
+                    algorithm: format!("{algorithm:?} public key recovery is not supported"),
+                });
+            }
+        };
+        dsa.derive_public_key(self)
+    }
+}
+
+/// Group well-formed batch items by public key and verify each group with one key expansion
+fn verify_batch_grouped<const VK_SIZE: usize, const SIG_SIZE: usize>(
+    items: &[(SigPublicKey, Vec<u8>, Vec<u8>)],
+    verify_group: impl Fn(
+        &MLDSAVerificationKey<VK_SIZE>,
+        &[(&[u8], &MLDSASignature<SIG_SIZE>)],
+        &mut [bool],
+    ) -> core::result::Result<(), VerificationError>,
+) -> core::result::Result<Vec<bool>, VerificationError> {
+    let mut results = Vec::new();
+    results.resize(items.len(), false);
+
+    let mut indices: Vec<usize> = (0..items.len())
+        .filter(|&i| items[i].0.as_bytes().len() == VK_SIZE && items[i].2.len() == SIG_SIZE)
+        .collect();
+    indices.sort_by(|&a, &b| items[a].0.as_bytes().cmp(items[b].0.as_bytes()));
+
+    for group in indices.chunk_by(|&a, &b| items[a].0.as_bytes() == items[b].0.as_bytes()) {
+        let mut vk_bytes = [0u8; VK_SIZE];
+        vk_bytes.copy_from_slice(items[group[0]].0.as_bytes());
+        let verification_key = MLDSAVerificationKey::new(vk_bytes);
+
+        let signatures: Vec<MLDSASignature<SIG_SIZE>> = group
+            .iter()
+            .map(|&i| {
+                let mut sig_bytes = [0u8; SIG_SIZE];
+                sig_bytes.copy_from_slice(&items[i].2);
+                MLDSASignature::new(sig_bytes)
+            })
+            .collect();
+        let batch: Vec<(&[u8], &MLDSASignature<SIG_SIZE>)> = group
+            .iter()
+            .zip(signatures.iter())
+            .map(|(&i, signature)| (items[i].1.as_slice(), signature))
+            .collect();
+
+        let mut group_results = Vec::new();
+        group_results.resize(group.len(), false);
+        verify_group(&verification_key, &batch, &mut group_results)?;
+        for (&i, ok) in group.iter().zip(group_results) {
+            results[i] = ok;
+        }
+    }
+
+    Ok(results)
+}
+
+/// Verify an ML-DSA signature without using the heap
+///
+/// The parameter set is selected by the length of `public_key` (1312, 1952 or 2592 bytes for
+/// ML-DSA-44, -65 and -87), and `signature` must have the matching length. Key and signature
+/// are decoded into fixed-size stack buffers and no call, including the error paths, allocates,
+/// so this suits verifiers with a small or absent heap budget. It is available with only the
+/// `ml-dsa` feature (`default-features = false`), without `alloc` or `std`. The context string
+/// is empty, as for [`Signature::verify`].
+///
+/// # Errors
+///
+/// Returns [`InvalidFormat`](lib_q_core::Error::InvalidFormat) when the length of
+/// `public_key` matches no parameter set and
+/// [`InvalidSignatureSize`](lib_q_core::Error::InvalidSignatureSize) when `signature` has the
+/// wrong length for the selected one. A well-formed but invalid signature is `Ok(false)`.
+pub fn verify_no_alloc(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool> {
+    match public_key.len() {
+        MLDSA44_VERIFICATION_KEY_SIZE => {
+            verify_fixed::<MLDSA44_VERIFICATION_KEY_SIZE, MLDSA44_SIGNATURE_SIZE>(
+                public_key,
+                message,
+                signature,
+                ml_dsa_44::portable::verify,
+            )
+        }
+        MLDSA65_VERIFICATION_KEY_SIZE => {
+            verify_fixed::<MLDSA65_VERIFICATION_KEY_SIZE, MLDSA65_SIGNATURE_SIZE>(
+                public_key,
+                message,
+                signature,
+                ml_dsa_65::portable::verify,
+            )
+        }
+        MLDSA87_VERIFICATION_KEY_SIZE => {
+            verify_fixed::<MLDSA87_VERIFICATION_KEY_SIZE, MLDSA87_SIGNATURE_SIZE>(
+                public_key,
+                message,
+                signature,
+                ml_dsa_87::portable::verify,
+            )
+        }
+        _ => Err(lib_q_core::Error::InvalidFormat {
+            context: "ML-DSA public key: length matches no parameter set",
+        }),
+    }
+}
+
+/// Copy `public_key` and `signature` into stack arrays and run `verify`
+fn verify_fixed<const VK_SIZE: usize, const SIG_SIZE: usize>(
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    verify: impl Fn(
+        &MLDSAVerificationKey<VK_SIZE>,
+        &[u8],
+        &[u8],
+        &MLDSASignature<SIG_SIZE>,
+    ) -> core::result::Result<(), VerificationError>,
+) -> Result<bool> {
+    let signature: [u8; SIG_SIZE] =
+        signature
+            .try_into()
+            .map_err(|_| lib_q_core::Error::InvalidSignatureSize {
+                expected: SIG_SIZE,
+                actual: signature.len(),
+            })?;
+    let mut vk_bytes = [0u8; VK_SIZE];
+    vk_bytes.copy_from_slice(public_key);
+
+    Ok(verify(
+        &MLDSAVerificationKey::new(vk_bytes),
+        message,
+        &[],
+        &MLDSASignature::new(signature),
+    )
+    .is_ok())
+}
+
+impl Default for MlDsa {
+    fn default() -> Self {
+        Self::ml_dsa_65() // Default to ML-DSA-65 for 192-bit security
+    }
+}
+
+impl Primitive for MlDsa {
+    fn algorithm(&self) -> Algorithm {
+        match self.variant {
+            MlDsaVariant::MlDsa44 => Algorithm::MlDsa44,
+            MlDsaVariant::MlDsa65 => Algorithm::MlDsa65,
+            MlDsaVariant::MlDsa87 => Algorithm::MlDsa87,
+        }
+    }
+}
+
+impl Signature for MlDsa {
+    fn generate_keypair(&self) -> Result<SigKeypair> {
         #[cfg(feature = "std")]
         {
             use lib_q_core::Utils;
 
-            // Generate cryptographically secure random seed for signing
-            let randomness = Utils::random_bytes(SIGNING_RANDOMNESS_SIZE).map_err(|_| {
+            // Generate cryptographically secure random seed
+            let seed = Utils::random_bytes(KEY_GENERATION_RANDOMNESS_SIZE).map_err(|_| {
                 lib_q_core::Error::RandomGenerationFailed {
-                    operation: "ml-dsa signing".to_string(),
+                    operation: "ml-dsa key generation".to_string(),
                 }
             })?;
 
-            let randomness_len = randomness.len();
-            let randomness_array: [u8; SIGNING_RANDOMNESS_SIZE] =
-                randomness
-                    .try_into()
+            let seed_len = seed.len();
+            let seed_array: [u8; KEY_GENERATION_RANDOMNESS_SIZE] =
+                seed.try_into()
                     .map_err(|_| lib_q_core::Error::InvalidKeySize {
-                        expected: SIGNING_RANDOMNESS_SIZE,
-                        actual: randomness_len,
+                        expected: KEY_GENERATION_RANDOMNESS_SIZE,
+                        actual: seed_len,
                     })?;
 
-            self.sign_with_randomness(secret_key, message, randomness_array)
+            self.generate_keypair_with_randomness(seed_array)
         }
 
         #[cfg(not(feature = "std"))]
         {
-            // In no_std mode, return error - signing requires randomness
+            // In no_std mode, return error - key generation requires randomness
             Err(lib_q_core::Error::RandomGenerationFailed {
-                operation: "ml-dsa signing requires std feature or external randomness".to_string(),
+                operation: "ml-dsa key generation requires std feature or external randomness"
+                    .to_string(),
             })
         }
     }
 
+    #[cfg(feature = "alloc")]
+    fn sign(&self, secret_key: &SigSecretKey, message: &[u8]) -> Result<Vec<u8>> {
+        self.sign_with_context(secret_key, message, &[])
+    }
+
     #[cfg(not(feature = "alloc"))]
     fn sign(&self, _secret_key: &SigSecretKey, _message: &[u8]) -> Result<Vec<u8>> {
         // In no_std mode without alloc, we cannot return Vec<u8>
@@ -442,107 +1038,390 @@ impl Signature for MlDsa {
     /// `signature` must equal the fixed ML-DSA serialized length for this variant. Shorter or longer
     /// inputs must not be padded or truncated; they are rejected with [`InvalidSignatureSize`](lib_q_core::Error::InvalidSignatureSize).
     fn verify(&self, public_key: &SigPublicKey, message: &[u8], signature: &[u8]) -> Result<bool> {
-        use lib_q_ml_dsa::types::{
-            MLDSASignature,
-            MLDSAVerificationKey,
-        };
+        self.verify_with_context(public_key, message, &[], signature)
+    }
 
-        // Convert public key bytes to ML-DSA verification key
-        let public_key_bytes = public_key.as_bytes();
-        let expected_vk_size = match self.variant {
+    fn public_key_size(&self) -> usize {
+        match self.variant {
             MlDsaVariant::MlDsa44 => MLDSA44_VERIFICATION_KEY_SIZE,
             MlDsaVariant::MlDsa65 => MLDSA65_VERIFICATION_KEY_SIZE,
             MlDsaVariant::MlDsa87 => MLDSA87_VERIFICATION_KEY_SIZE,
-        };
+        }
+    }
 
-        // Validate public key size first
-        if public_key_bytes.len() != expected_vk_size {
-            return Err(lib_q_core::Error::InvalidKeySize {
-                expected: expected_vk_size,
-                actual: public_key_bytes.len(),
-            });
+    fn secret_key_size(&self) -> usize {
+        match self.variant {
+            MlDsaVariant::MlDsa44 => MLDSA44_SIGNING_KEY_SIZE,
+            MlDsaVariant::MlDsa65 => MLDSA65_SIGNING_KEY_SIZE,
+            MlDsaVariant::MlDsa87 => MLDSA87_SIGNING_KEY_SIZE,
         }
+    }
 
-        let expected_sig_size = match self.variant {
+    fn signature_size(&self) -> usize {
+        match self.variant {
             MlDsaVariant::MlDsa44 => MLDSA44_SIGNATURE_SIZE,
             MlDsaVariant::MlDsa65 => MLDSA65_SIGNATURE_SIZE,
             MlDsaVariant::MlDsa87 => MLDSA87_SIGNATURE_SIZE,
+        }
+    }
+}
+
+/// Offset and length of `tr`, the verification key hash, inside an ML-DSA signing key
+#[cfg(feature = "alloc")]
+const SIGNING_KEY_TR_OFFSET: usize = 64;
+#[cfg(feature = "alloc")]
+const TR_SIZE: usize = 64;
+
+/// Start the FIPS 204 message representative hash `SHAKE256(tr || 0 || |ctx| || ctx || M)`
+///
+/// The message `M` is absorbed afterwards, in as many pieces as the caller likes.
+#[cfg(feature = "alloc")]
+fn message_representative_hasher(tr: &[u8], context: &[u8]) -> Result<Shake256> {
+    validate_signature_context(context)?;
+    let mut hasher = Shake256::default();
+    hasher.update(tr);
+    hasher.update(&[0, context.len() as u8]);
+    hasher.update(context);
+    Ok(hasher)
+}
+
+/// Incremental ML-DSA signer for messages that arrive in chunks
+///
+/// ML-DSA only reads the message through the SHAKE256 message representative `mu`, so the
+/// message can be absorbed piece by piece and never has to be held in memory. The parameter
+/// set is taken from the secret key length. The signature equals what
+/// [`MlDsa::sign_with_context_and_randomness`] produces over the concatenated chunks and
+/// verifies with [`MlDsa::verify_with_context`] or [`MlDsaVerifier`].
+///
+/// ```rust
+/// use lib_q_sig::ml_dsa::{
+///     MlDsa,
+///     MlDsaSigner,
+///     MlDsaVerifier,
+/// };
+///
+/// let ml_dsa = MlDsa::ml_dsa_65();
+/// let keypair = ml_dsa.generate_keypair_with_randomness([7u8; 32]).unwrap();
+///
+/// let mut signer = MlDsaSigner::new(keypair.secret_key()).unwrap();
+/// signer.update(b"first chunk, ");
+/// signer.update(b"second chunk");
+/// let signature = signer.finalize_deterministic().unwrap();
+///
+/// let mut verifier = MlDsaVerifier::new(keypair.public_key()).unwrap();
+/// verifier.update(b"first chunk, second chunk");
+/// assert!(verifier.finalize_verify(&signature).unwrap());
+/// ```
+#[cfg(feature = "alloc")]
+pub struct MlDsaSigner<'a> {
+    variant: MlDsaVariant,
+    secret_key: &'a SigSecretKey,
+    hasher: Shake256,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> MlDsaSigner<'a> {
+    /// Start signing with an empty context
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidKey`](lib_q_core::Error::InvalidKey) when the key length matches no
+    /// ML-DSA parameter set.
+    pub fn new(secret_key: &'a SigSecretKey) -> Result<Self> {
+        Self::with_context(secret_key, &[])
+    }
+
+    /// Start signing under a FIPS 204 context string
+    ///
+    /// # Errors
+    ///
+    /// As [`new`](Self::new), plus [`InvalidContextSize`](lib_q_core::Error::InvalidContextSize)
+    /// when `context` is longer than
+    /// [`MAX_SIGNATURE_CONTEXT_SIZE`](lib_q_core::MAX_SIGNATURE_CONTEXT_SIZE) bytes.
+    pub fn with_context(secret_key: &'a SigSecretKey, context: &[u8]) -> Result<Self> {
+        let key = secret_key.expose_secret(SecretExportConsent);
+        let variant = match key.len() {
+            MLDSA44_SIGNING_KEY_SIZE => MlDsaVariant::MlDsa44,
+            MLDSA65_SIGNING_KEY_SIZE => MlDsaVariant::MlDsa65,
+            MLDSA87_SIGNING_KEY_SIZE => MlDsaVariant::MlDsa87,
+            actual => {
+                return Err(lib_q_core::Error::InvalidKey {
+                    key_type: "ML-DSA secret key".to_string(),
+                    reason: format!("{actual} bytes matches no ML-DSA parameter set"),
+                });
+            }
         };
-        if signature.len() != expected_sig_size {
+        let tr = &key[SIGNING_KEY_TR_OFFSET..SIGNING_KEY_TR_OFFSET + TR_SIZE];
+        Ok(Self {
+            variant,
+            secret_key,
+            hasher: message_representative_hasher(tr, context)?,
+        })
+    }
+
+    /// Absorb the next chunk of the message
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    /// Sign the absorbed message with randomness from the OS RNG (requires `std`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RandomGenerationFailed`](lib_q_core::Error::RandomGenerationFailed) when no
+    /// randomness is available, and passes through signing errors.
+    pub fn finalize(self) -> Result<Vec<u8>> {
+        let randomness = lib_q_core::Utils::random_bytes(SIGNING_RANDOMNESS_SIZE)?;
+        let randomness: [u8; SIGNING_RANDOMNESS_SIZE] =
+            randomness
+                .try_into()
+                .map_err(|_| lib_q_core::Error::RandomGenerationFailed {
+                    operation: "ml-dsa signing".to_string(),
+                })?;
+        self.finalize_with_randomness(randomness)
+    }
+
+    /// Sign the absorbed message with the deterministic variant (all-zero randomness)
+    pub fn finalize_deterministic(self) -> Result<Vec<u8>> {
+        self.finalize_with_randomness([0u8; SIGNING_RANDOMNESS_SIZE])
+    }
+
+    /// Sign the absorbed message with caller-provided randomness (no_std compatible)
+    pub fn finalize_with_randomness(
+        self,
+        randomness: [u8; SIGNING_RANDOMNESS_SIZE],
+    ) -> Result<Vec<u8>> {
+        let mut mu = [0u8; MESSAGE_REPRESENTATIVE_SIZE];
+        self.hasher.finalize_xof_into(&mut mu);
+
+        let key = self.secret_key.expose_secret(SecretExportConsent);
+        let signature = match self.variant {
+            MlDsaVariant::MlDsa44 => {
+                let mut signing_key = MLDSASigningKey::zero();
+                signing_key.as_mut_slice().copy_from_slice(key);
+                let sig_result =
+                    ml_dsa_44::sign_message_representative(&signing_key, &mu, randomness);
+                signing_key.as_mut_slice().zeroize();
+                sig_result.map(|signature| signature.as_slice().to_vec())
+            }
+            MlDsaVariant::MlDsa65 => {
+                let mut signing_key = MLDSASigningKey::zero();
+                signing_key.as_mut_slice().copy_from_slice(key);
+                let sig_result =
+                    ml_dsa_65::sign_message_representative(&signing_key, &mu, randomness);
+                signing_key.as_mut_slice().zeroize();
+                sig_result.map(|signature| signature.as_slice().to_vec())
+            }
+            MlDsaVariant::MlDsa87 => {
+                let mut signing_key = MLDSASigningKey::zero();
+                signing_key.as_mut_slice().copy_from_slice(key);
+                let sig_result =
+                    ml_dsa_87::sign_message_representative(&signing_key, &mu, randomness);
+                signing_key.as_mut_slice().zeroize();
+                sig_result.map(|signature| signature.as_slice().to_vec())
+            }
+        };
+        mu.zeroize();
+        signature.map_err(lib_q_core::Error::from)
+    }
+}
+
+/// Incremental ML-DSA verifier, the counterpart of [`MlDsaSigner`]
+///
+/// The parameter set is taken from the public key length; the context must match the one
+/// the message was signed under.
+#[cfg(feature = "alloc")]
+pub struct MlDsaVerifier<'a> {
+    variant: MlDsaVariant,
+    public_key: &'a SigPublicKey,
+    hasher: Shake256,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> MlDsaVerifier<'a> {
+    /// Start verifying with an empty context
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidKey`](lib_q_core::Error::InvalidKey) when the key length matches no
+    /// ML-DSA parameter set.
+    pub fn new(public_key: &'a SigPublicKey) -> Result<Self> {
+        Self::with_context(public_key, &[])
+    }
+
+    /// Start verifying under a FIPS 204 context string
+    ///
+    /// # Errors
+    ///
+    /// As [`new`](Self::new), plus [`InvalidContextSize`](lib_q_core::Error::InvalidContextSize)
+    /// when `context` is longer than
+    /// [`MAX_SIGNATURE_CONTEXT_SIZE`](lib_q_core::MAX_SIGNATURE_CONTEXT_SIZE) bytes.
+    pub fn with_context(public_key: &'a SigPublicKey, context: &[u8]) -> Result<Self> {
+        let variant = match public_key.as_bytes().len() {
+            MLDSA44_VERIFICATION_KEY_SIZE => MlDsaVariant::MlDsa44,
+            MLDSA65_VERIFICATION_KEY_SIZE => MlDsaVariant::MlDsa65,
+            MLDSA87_VERIFICATION_KEY_SIZE => MlDsaVariant::MlDsa87,
+            actual => {
+                return Err(lib_q_core::Error::InvalidKey {
+                    key_type: "ML-DSA public key".to_string(),
+                    reason: format!("{actual} bytes matches no ML-DSA parameter set"),
+                });
+            }
+        };
+        let mut tr = [0u8; TR_SIZE];
+        let mut shake = Shake256::default();
+        shake.update(public_key.as_bytes());
+        shake.finalize_xof_into(&mut tr);
+        Ok(Self {
+            variant,
+            public_key,
+            hasher: message_representative_hasher(&tr, context)?,
+        })
+    }
+
+    /// Absorb the next chunk of the message
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    /// Check `signature` against the absorbed message
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSignatureSize`](lib_q_core::Error::InvalidSignatureSize) when
+    /// `signature` does not have this parameter set's length; an invalid signature of the
+    /// right length yields `Ok(false)`.
+    pub fn finalize_verify(self, signature: &[u8]) -> Result<bool> {
+        let expected = MlDsa::new(self.variant).signature_size();
+        if signature.len() != expected {
             return Err(lib_q_core::Error::InvalidSignatureSize {
-                expected: expected_sig_size,
+                expected,
                 actual: signature.len(),
             });
         }
+        let mut mu = [0u8; MESSAGE_REPRESENTATIVE_SIZE];
+        self.hasher.finalize_xof_into(&mut mu);
 
-        // Create verification key and verify for the specific variant
-        let result = match self.variant {
+        let vk = self.public_key.as_bytes();
+        let valid = match self.variant {
             MlDsaVariant::MlDsa44 => {
                 let mut vk_bytes = [0u8; MLDSA44_VERIFICATION_KEY_SIZE];
-                vk_bytes.copy_from_slice(public_key_bytes);
-                let verification_key = MLDSAVerificationKey::new(vk_bytes);
-
+                vk_bytes.copy_from_slice(vk);
                 let mut sig_bytes = [0u8; MLDSA44_SIGNATURE_SIZE];
                 sig_bytes.copy_from_slice(signature);
-                let ml_dsa_signature = MLDSASignature::new(sig_bytes);
-
-                ml_dsa_44::portable::verify(
-                    &verification_key,
-                    message,
-                    &[], // empty context
-                    &ml_dsa_signature,
+                ml_dsa_44::verify_message_representative(
+                    &MLDSAVerificationKey::new(vk_bytes),
+                    &mu,
+                    &MLDSASignature::new(sig_bytes),
                 )
-                .is_ok()
             }
             MlDsaVariant::MlDsa65 => {
                 let mut vk_bytes = [0u8; MLDSA65_VERIFICATION_KEY_SIZE];
-                vk_bytes.copy_from_slice(public_key_bytes);
-                let verification_key = MLDSAVerificationKey::new(vk_bytes);
-
+                vk_bytes.copy_from_slice(vk);
                 let mut sig_bytes = [0u8; MLDSA65_SIGNATURE_SIZE];
                 sig_bytes.copy_from_slice(signature);
-                let ml_dsa_signature = MLDSASignature::new(sig_bytes);
-
-                ml_dsa_65::portable::verify(
-                    &verification_key,
-                    message,
-                    &[], // empty context
-                    &ml_dsa_signature,
+                ml_dsa_65::verify_message_representative(
+                    &MLDSAVerificationKey::new(vk_bytes),
+                    &mu,
+                    &MLDSASignature::new(sig_bytes),
                 )
-                .is_ok()
             }
             MlDsaVariant::MlDsa87 => {
                 let mut vk_bytes = [0u8; MLDSA87_VERIFICATION_KEY_SIZE];
-                vk_bytes.copy_from_slice(public_key_bytes);
-                let verification_key = MLDSAVerificationKey::new(vk_bytes);
-
+                vk_bytes.copy_from_slice(vk);
                 let mut sig_bytes = [0u8; MLDSA87_SIGNATURE_SIZE];
                 sig_bytes.copy_from_slice(signature);
-                let ml_dsa_signature = MLDSASignature::new(sig_bytes);
-
-                ml_dsa_87::portable::verify(
-                    &verification_key,
-                    message,
-                    &[], // empty context
-                    &ml_dsa_signature,
+                ml_dsa_87::verify_message_representative(
+                    &MLDSAVerificationKey::new(vk_bytes),
+                    &mu,
+                    &MLDSASignature::new(sig_bytes),
                 )
-                .is_ok()
             }
         };
-
-        Ok(result)
+        Ok(valid.is_ok())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use lib_q_core::{
+        MAX_SIGNATURE_CONTEXT_SIZE,
         SigPublicKey,
         SigSecretKey,
     };
 
     use super::*;
 
+    #[test]
+    fn internal_errors_keep_their_cause() {
+        use lib_q_ml_dsa::{
+            SigningError,
+            VerificationError,
+        };
+
+        let verify = lib_q_core::Error::from(VerificationError::CommitmentHashesDontMatchError);
+        assert!(matches!(
+            &verify,
+            lib_q_core::Error::VerificationFailed { operation }
+                if operation.contains("commitment hashes")
+        ));
+        let sign = lib_q_core::Error::from(SigningError::ContextTooLongError);
+        assert!(matches!(
+            &sign,
+            lib_q_core::Error::SigningFailed { operation } if operation.contains("context")
+        ));
+    }
+
+    #[test]
+    fn test_streamed_signing_matches_one_shot() {
+        let ml_dsa = MlDsa::ml_dsa_65();
+        let keypair = ml_dsa.generate_keypair_with_randomness([3u8; 32]).unwrap();
+        let message: Vec<u8> = (0..5 * 1024 * 1024u32).map(|i| (i % 251) as u8).collect();
+
+        let mut signer = MlDsaSigner::new(keypair.secret_key()).unwrap();
+        for chunk in message.chunks(64 * 1024 + 7) {
+            signer.update(chunk);
+        }
+        let streamed = signer.finalize_deterministic().unwrap();
+        let one_shot = ml_dsa
+            .sign_with_randomness(
+                keypair.secret_key(),
+                &message,
+                [0u8; SIGNING_RANDOMNESS_SIZE],
+            )
+            .unwrap();
+        assert_eq!(streamed, one_shot);
+        assert!(
+            ml_dsa
+                .verify(keypair.public_key(), &message, &streamed)
+                .unwrap()
+        );
+
+        let mut verifier = MlDsaVerifier::new(keypair.public_key()).unwrap();
+        for chunk in message.chunks(1 << 20) {
+            verifier.update(chunk);
+        }
+        assert!(verifier.finalize_verify(&streamed).unwrap());
+
+        let mut verifier = MlDsaVerifier::with_context(keypair.public_key(), b"other").unwrap();
+        verifier.update(&message);
+        assert!(!verifier.finalize_verify(&streamed).unwrap());
+    }
+
+    #[test]
+    fn test_streaming_rejects_unknown_key_lengths() {
+        let secret_key = SigSecretKey::new(vec![0u8; 100]);
+        assert!(matches!(
+            MlDsaSigner::new(&secret_key),
+            Err(lib_q_core::Error::InvalidKey { .. })
+        ));
+        let public_key = SigPublicKey::new(vec![0u8; 100]);
+        assert!(matches!(
+            MlDsaVerifier::new(&public_key),
+            Err(lib_q_core::Error::InvalidKey { .. })
+        ));
+    }
+
     #[test]
     fn test_ml_dsa_variants() {
         // Test that all variants can be created
@@ -591,15 +1470,24 @@ mod tests {
 
         // Verify secret key sizes
         assert_eq!(
-            keypair_44.secret_key().as_bytes().len(),
+            keypair_44
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .len(),
             MLDSA44_SIGNING_KEY_SIZE
         );
         assert_eq!(
-            keypair_65.secret_key().as_bytes().len(),
+            keypair_65
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .len(),
             MLDSA65_SIGNING_KEY_SIZE
         );
         assert_eq!(
-            keypair_87.secret_key().as_bytes().len(),
+            keypair_87
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .len(),
             MLDSA87_SIGNING_KEY_SIZE
         );
     }
@@ -631,6 +1519,28 @@ mod tests {
         assert!(is_valid);
     }
 
+    #[test]
+    fn reported_sizes_match_generated_keys_and_signatures() {
+        for dsa in [MlDsa::ml_dsa_44(), MlDsa::ml_dsa_65(), MlDsa::ml_dsa_87()] {
+            let keypair = dsa
+                .generate_keypair_with_randomness([5u8; KEY_GENERATION_RANDOMNESS_SIZE])
+                .unwrap();
+            let signature = dsa
+                .sign_with_randomness(keypair.secret_key(), b"m", [0u8; SIGNING_RANDOMNESS_SIZE])
+                .unwrap();
+
+            assert_eq!(dsa.public_key_size(), keypair.public_key().as_bytes().len());
+            assert_eq!(
+                dsa.secret_key_size(),
+                keypair
+                    .secret_key()
+                    .expose_secret(SecretExportConsent)
+                    .len()
+            );
+            assert_eq!(dsa.signature_size(), signature.len());
+        }
+    }
+
     #[test]
     fn sign_with_randomness_rejects_wrong_secret_key_length() {
         let dsa44 = MlDsa::ml_dsa_44();
@@ -646,6 +1556,42 @@ mod tests {
         assert!(matches!(r, Err(lib_q_core::Error::InvalidKeySize { .. })));
     }
 
+    #[test]
+    fn derive_public_recovers_the_generated_public_key() {
+        for (algorithm, dsa) in [
+            (Algorithm::MlDsa44, MlDsa::ml_dsa_44()),
+            (Algorithm::MlDsa65, MlDsa::ml_dsa_65()),
+            (Algorithm::MlDsa87, MlDsa::ml_dsa_87()),
+        ] {
+            let kp = dsa
+                .generate_keypair_with_randomness([6u8; KEY_GENERATION_RANDOMNESS_SIZE])
+                .unwrap();
+            let public_key = kp.secret_key().derive_public(algorithm).unwrap();
+            assert_eq!(public_key.as_bytes(), kp.public_key().as_bytes());
+
+            let sig = dsa
+                .sign_with_randomness(kp.secret_key(), b"m", [7u8; SIGNING_RANDOMNESS_SIZE])
+                .unwrap();
+            assert!(dsa.verify(&public_key, b"m", &sig).unwrap());
+        }
+
+        let kp44 = MlDsa::ml_dsa_44()
+            .generate_keypair_with_randomness([8u8; KEY_GENERATION_RANDOMNESS_SIZE])
+            .unwrap();
+        assert!(matches!(
+            kp44.secret_key().derive_public(Algorithm::MlDsa65),
+            Err(lib_q_core::Error::InvalidKeySize { .. })
+        ));
+        assert!(matches!(
+            kp44.secret_key().derive_public(Algorithm::FnDsa512),
+            Err(lib_q_core::Error::UnsupportedAlgorithm { .. })
+        ));
+        assert!(matches!(
+            kp44.secret_key().derive_public(Algorithm::MlKem768),
+            Err(lib_q_core::Error::InvalidAlgorithm { .. })
+        ));
+    }
+
     #[test]
     fn verify_rejects_bad_public_key_and_signature_sizes() {
         let dsa = MlDsa::ml_dsa_65();
@@ -664,6 +1610,151 @@ mod tests {
             Err(lib_q_core::Error::InvalidSignatureSize { .. })
         ));
     }
+
+    #[test]
+    fn context_strings_bind_signatures() {
+        let dsa = MlDsa::ml_dsa_65();
+        let kp = dsa
+            .generate_keypair_with_randomness([5u8; KEY_GENERATION_RANDOMNESS_SIZE])
+            .unwrap();
+        let rnd = [6u8; SIGNING_RANDOMNESS_SIZE];
+
+        // Empty context is identical to plain signing.
+        let plain = dsa
+            .sign_with_randomness(kp.secret_key(), b"msg", rnd)
+            .unwrap();
+        let empty = dsa
+            .sign_with_context_and_randomness(kp.secret_key(), b"msg", &[], rnd)
+            .unwrap();
+        assert_eq!(plain, empty);
+        assert!(
+            dsa.verify_with_context(kp.public_key(), b"msg", &[], &plain)
+                .unwrap()
+        );
+
+        let sig_a = dsa
+            .sign_with_context_and_randomness(kp.secret_key(), b"msg", b"context-A", rnd)
+            .unwrap();
+        assert!(
+            dsa.verify_with_context(kp.public_key(), b"msg", b"context-A", &sig_a)
+                .unwrap()
+        );
+        assert!(
+            !dsa.verify_with_context(kp.public_key(), b"msg", b"context-B", &sig_a)
+                .unwrap()
+        );
+        assert!(!dsa.verify(kp.public_key(), b"msg", &sig_a).unwrap());
+    }
+
+    #[test]
+    fn context_string_length_limit() {
+        let dsa = MlDsa::ml_dsa_44();
+        let kp = dsa
+            .generate_keypair_with_randomness([7u8; KEY_GENERATION_RANDOMNESS_SIZE])
+            .unwrap();
+        let rnd = [8u8; SIGNING_RANDOMNESS_SIZE];
+
+        let max = [0xA5u8; MAX_SIGNATURE_CONTEXT_SIZE];
+        let sig = dsa
+            .sign_with_context_and_randomness(kp.secret_key(), b"msg", &max, rnd)
+            .unwrap();
+        assert!(
+            dsa.verify_with_context(kp.public_key(), b"msg", &max, &sig)
+                .unwrap()
+        );
+
+        let over = [0xA5u8; MAX_SIGNATURE_CONTEXT_SIZE + 1];
+        assert!(matches!(
+            dsa.sign_with_context_and_randomness(kp.secret_key(), b"msg", &over, rnd),
+            Err(lib_q_core::Error::InvalidContextSize {
+                max: MAX_SIGNATURE_CONTEXT_SIZE,
+                actual: 256
+            })
+        ));
+        assert!(matches!(
+            dsa.verify_with_context(kp.public_key(), b"msg", &over, &sig),
+            Err(lib_q_core::Error::InvalidContextSize { .. })
+        ));
+    }
+    #[test]
+    fn prehash_signatures_bind_hash_identifier() {
+        let dsa = MlDsa::ml_dsa_65();
+        let kp = dsa
+            .generate_keypair_with_randomness([9u8; KEY_GENERATION_RANDOMNESS_SIZE])
+            .unwrap();
+        let rnd = [10u8; SIGNING_RANDOMNESS_SIZE];
+        // Stands in for a SHA3-256 digest computed elsewhere.
+        let digest = [0x42u8; 32];
+
+        let sig = dsa
+            .sign_prehash_with_randomness(kp.secret_key(), &digest, Algorithm::Sha3_256, rnd)
+            .unwrap();
+        assert!(
+            dsa.verify_prehash(kp.public_key(), &digest, Algorithm::Sha3_256, &sig)
+                .unwrap()
+        );
+
+        // A different hash OID with a digest of the same length must not verify.
+        let mut digest64 = [0u8; 64];
+        digest64[..32].copy_from_slice(&digest);
+        let sig64 = dsa
+            .sign_prehash_with_randomness(kp.secret_key(), &digest64, Algorithm::Sha3_512, rnd)
+            .unwrap();
+        assert!(
+            !dsa.verify_prehash(kp.public_key(), &digest64, Algorithm::Shake256, &sig64)
+                .unwrap()
+        );
+        // Nor is a HashML-DSA signature valid as a pure signature over the digest.
+        assert!(!dsa.verify(kp.public_key(), &digest, &sig).unwrap());
+
+        assert!(matches!(
+            dsa.verify_prehash(kp.public_key(), &digest, Algorithm::Sha3_512, &sig),
+            Err(lib_q_core::Error::InvalidHashSize {
+                expected: 64,
+                actual: 32
+            })
+        ));
+        assert!(matches!(
+            dsa.sign_prehash_with_randomness(kp.secret_key(), &digest, Algorithm::Sha256, rnd),
+            Err(lib_q_core::Error::InvalidAlgorithm { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_batch_matches_individual_verification() {
+        let dsa = MlDsa::ml_dsa_44();
+        let kp_a = dsa
+            .generate_keypair_with_randomness([11u8; KEY_GENERATION_RANDOMNESS_SIZE])
+            .unwrap();
+        let kp_b = dsa
+            .generate_keypair_with_randomness([12u8; KEY_GENERATION_RANDOMNESS_SIZE])
+            .unwrap();
+        let rnd = [13u8; SIGNING_RANDOMNESS_SIZE];
+        let sign = |kp: &SigKeypair, msg: &[u8]| {
+            dsa.sign_with_randomness(kp.secret_key(), msg, rnd).unwrap()
+        };
+
+        let sig_a1 = sign(&kp_a, b"a1");
+        let sig_b1 = sign(&kp_b, b"b1");
+        let sig_a2 = sign(&kp_a, b"a2");
+        let mut tampered = sign(&kp_b, b"b2");
+        tampered[0] ^= 1;
+
+        let items = vec![
+            (kp_a.public_key().clone(), b"a1".to_vec(), sig_a1.clone()),
+            (kp_b.public_key().clone(), b"b1".to_vec(), sig_b1),
+            (kp_a.public_key().clone(), b"wrong".to_vec(), sig_a2.clone()),
+            (kp_b.public_key().clone(), b"b2".to_vec(), tampered),
+            (kp_a.public_key().clone(), b"a2".to_vec(), sig_a2),
+            (kp_b.public_key().clone(), b"a1".to_vec(), sig_a1),
+            (kp_a.public_key().clone(), b"a1".to_vec(), vec![0u8; 16]),
+        ];
+        assert_eq!(
+            dsa.verify_batch(&items).unwrap(),
+            vec![true, true, false, false, true, false, false]
+        );
+        assert!(dsa.verify_batch(&[]).unwrap().is_empty());
+    }
 }
 
 #[test]
@@ -679,7 +1770,12 @@ fn test_keypair_generation() {
     let keypair = ml_dsa.generate_keypair().unwrap();
 
     assert!(!keypair.public_key().as_bytes().is_empty());
-    assert!(!keypair.secret_key().as_bytes().is_empty());
+    assert!(
+        !keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .is_empty()
+    );
 }
 
 #[cfg(feature = "std")]
@@ -749,10 +1845,13 @@ impl MlDsa {
                 .map_err(|e| JsValue::from_str(&e.to_string()))?
         };
 
-        Ok(WasmMlDsaKeyPair::new(
-            Uint8Array::from(keypair.public_key().as_bytes()),
-            Uint8Array::from(keypair.secret_key().as_bytes()),
-        ))
+        Ok(WasmMlDsaKeyPair {
+            public_key: Uint8Array::from(keypair.public_key().as_bytes()),
+            secret_key: keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
+        })
     }
 
     /// Sign a message in WASM (JavaScript) environment
@@ -834,11 +1933,14 @@ impl MlDsa {
 }
 
 /// WASM-compatible ML-DSA key pair
+///
+/// The secret key is kept in Rust memory and zeroized on drop; JavaScript only ever sees
+/// copies made by the `secret_key` getter.
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub struct WasmMlDsaKeyPair {
     public_key: Uint8Array,
-    secret_key: Uint8Array,
+    secret_key: Vec<u8>,
 }
 
 #[cfg(feature = "wasm")]
@@ -848,7 +1950,7 @@ impl WasmMlDsaKeyPair {
     pub fn new(public_key: Uint8Array, secret_key: Uint8Array) -> WasmMlDsaKeyPair {
         WasmMlDsaKeyPair {
             public_key,
-            secret_key,
+            secret_key: lib_q_core::wasm_common::conversions::uint8array_to_vec(&secret_key),
         }
     }
 
@@ -857,8 +1959,23 @@ impl WasmMlDsaKeyPair {
         self.public_key.clone()
     }
 
+    /// Copy of the secret key; empty after [`zeroize`](Self::zeroize)
     #[wasm_bindgen(getter)]
     pub fn secret_key(&self) -> Uint8Array {
-        self.secret_key.clone()
+        lib_q_core::wasm_common::conversions::vec_to_uint8array(&self.secret_key)
+    }
+
+    /// Overwrite the secret key with zeros
+    ///
+    /// Safe to call more than once; `secret_key` returns an empty array afterwards.
+    pub fn zeroize(&mut self) {
+        lib_q_core::wasm_common::zeroize_secret(&mut self.secret_key);
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl Drop for WasmMlDsaKeyPair {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
@@ -49,11 +49,15 @@ use alloc::string::ToString;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+#[cfg(feature = "alloc")]
+use lib_q_core::SecretExportConsent;
 #[cfg(feature = "alloc")]
 use lib_q_core::api::{
     Algorithm,
     CryptoProvider,
+    PUBLIC_KEY_FINGERPRINT_SIZE,
     SignatureOperations,
+    verify_each,
 };
 #[cfg(feature = "alloc")]
 use lib_q_core::error::{
@@ -70,6 +74,12 @@ use lib_q_core::traits::{
     SigPublicKey,
     SigSecretKey,
 };
+#[cfg(feature = "alloc")]
+use lib_q_sha3::{
+    ExtendableOutput,
+    Shake256,
+    Update,
+};
 
 #[cfg(feature = "fn-dsa")]
 use crate::fn_dsa::{
@@ -78,7 +88,7 @@ use crate::fn_dsa::{
     FnDsa1024,
 };
 // Import algorithm implementations
-#[cfg(feature = "ml-dsa")]
+#[cfg(all(feature = "alloc", feature = "ml-dsa"))]
 use crate::ml_dsa::MlDsa;
 #[cfg(feature = "slh-dsa")]
 use crate::slh_dsa::SlhDsa;
@@ -274,7 +284,7 @@ impl SignatureOperations for LibQSignatureProvider {
 
         // Validate secret key
         self.security_validator
-            .validate_secret_key(algorithm, secret_key.as_bytes())?;
+            .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
 
         // Validate message
         self.security_validator
@@ -490,6 +500,126 @@ impl SignatureOperations for LibQSignatureProvider {
             }),
         }
     }
+
+    fn sign_with_context(
+        &self,
+        algorithm: Algorithm,
+        secret_key: &SigSecretKey,
+        message: &[u8],
+        context: &[u8],
+        randomness: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        #[cfg(feature = "ml-dsa")]
+        if let Some(ml_dsa) = ml_dsa_for(algorithm) {
+            self.security_validator
+                .validate_secret_key(algorithm, secret_key.expose_secret(SecretExportConsent))?;
+            self.security_validator
+                .validate_signature_message(message)?;
+
+            return match randomness {
+                Some(rng) => {
+                    self.security_validator.validate_randomness(rng)?;
+                    let rng_array: [u8; 32] =
+                        rng.try_into().map_err(|_| Error::InvalidKeySize {
+                            expected: 32,
+                            actual: rng.len(),
+                        })?;
+                    ml_dsa.sign_with_context_and_randomness(secret_key, message, context, rng_array)
+                }
+                None => ml_dsa.sign_with_context(secret_key, message, context),
+            };
+        }
+
+        if context.is_empty() {
+            return self.sign(algorithm, secret_key, message, randomness);
+        }
+        Err(Error::NotImplemented {
+            feature: "Signature context strings are only supported for ML-DSA".into(),
+        })
+    }
+
+    fn verify_with_context(
+        &self,
+        algorithm: Algorithm,
+        public_key: &SigPublicKey,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> Result<bool> {
+        #[cfg(feature = "ml-dsa")]
+        if let Some(ml_dsa) = ml_dsa_for(algorithm) {
+            self.security_validator
+                .validate_public_key(algorithm, public_key.as_bytes())?;
+            self.security_validator
+                .validate_signature_message(message)?;
+            self.security_validator
+                .validate_signature(algorithm, signature)?;
+
+            return ml_dsa.verify_with_context(public_key, message, context, signature);
+        }
+
+        if context.is_empty() {
+            return self.verify(algorithm, public_key, message, signature);
+        }
+        Err(Error::NotImplemented {
+            feature: "Signature context strings are only supported for ML-DSA".into(),
+        })
+    }
+
+    fn public_key_fingerprint(
+        &self,
+        algorithm: Algorithm,
+        public_key: &SigPublicKey,
+    ) -> Result<[u8; PUBLIC_KEY_FINGERPRINT_SIZE]> {
+        self.security_validator.validate_algorithm_category(
+            algorithm,
+            lib_q_core::api::AlgorithmCategory::Signature,
+        )?;
+        Ok(shake256_fingerprint(public_key.as_bytes()))
+    }
+
+    fn verify_batch(
+        &self,
+        algorithm: Algorithm,
+        items: &[(SigPublicKey, Vec<u8>, Vec<u8>)],
+    ) -> Result<Vec<bool>> {
+        self.security_validator.validate_algorithm_category(
+            algorithm,
+            lib_q_core::api::AlgorithmCategory::Signature,
+        )?;
+
+        #[cfg(feature = "ml-dsa")]
+        if let Some(ml_dsa) = ml_dsa_for(algorithm) {
+            for (_, message, _) in items {
+                self.security_validator
+                    .validate_signature_message(message)?;
+            }
+            return ml_dsa.verify_batch(items);
+        }
+
+        verify_each(self, algorithm, items)
+    }
+}
+
+/// First [`PUBLIC_KEY_FINGERPRINT_SIZE`] bytes of SHAKE256 over `public_key`
+#[cfg(feature = "alloc")]
+fn shake256_fingerprint(public_key: &[u8]) -> [u8; PUBLIC_KEY_FINGERPRINT_SIZE] {
+    let mut shake = Shake256::default();
+    shake.update(public_key);
+    let mut fingerprint = [0u8; PUBLIC_KEY_FINGERPRINT_SIZE];
+    shake.finalize_xof_into(&mut fingerprint);
+    fingerprint
+}
+
+/// ML-DSA instance for `algorithm`, or `None` for other signature families
+#[cfg(all(feature = "alloc", feature = "ml-dsa"))]
+fn ml_dsa_for(algorithm: Algorithm) -> Option<MlDsa> {
+    match algorithm {
+        Algorithm::MlDsa44 => Some(MlDsa::ml_dsa_44()),
+        Algorithm::MlDsa65 => Some(MlDsa::ml_dsa_65()),
+        Algorithm::MlDsa87 => Some(MlDsa::ml_dsa_87()),
+        _ => None,
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -743,4 +873,69 @@ mod tests {
             "provider should verify its own ML-DSA-44 signatures"
         );
     }
+
+    #[cfg(feature = "ml-dsa")]
+    #[test]
+    fn test_sign_with_fingerprint_identifies_the_key() {
+        use alloc::boxed::Box;
+
+        use lib_q_core::SignatureContext;
+
+        let context =
+            SignatureContext::with_provider(Box::new(LibQSignatureProvider::new().unwrap()));
+        let mut keygen =
+            SignatureContext::with_provider(Box::new(LibQSignatureProvider::new().unwrap()));
+        let first = keygen.generate_keypair(Algorithm::MlDsa65, None).unwrap();
+        let second = keygen.generate_keypair(Algorithm::MlDsa65, None).unwrap();
+
+        let (signature, fingerprint) = context
+            .sign_with_fingerprint(
+                Algorithm::MlDsa65,
+                first.secret_key(),
+                first.public_key(),
+                b"audited message",
+            )
+            .unwrap();
+        assert!(
+            context
+                .verify(
+                    Algorithm::MlDsa65,
+                    first.public_key(),
+                    b"audited message",
+                    &signature
+                )
+                .unwrap()
+        );
+
+        let mut expected = [0u8; PUBLIC_KEY_FINGERPRINT_SIZE];
+        let mut shake = Shake256::default();
+        shake.update(first.public_key().as_bytes());
+        shake.finalize_xof_into(&mut expected);
+        assert_eq!(fingerprint, expected);
+        assert_eq!(
+            context
+                .public_key_fingerprint(Algorithm::MlDsa65, first.public_key())
+                .unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            context
+                .public_key_fingerprint(Algorithm::MlDsa65, second.public_key())
+                .unwrap(),
+            fingerprint
+        );
+
+        // The key length must match the algorithm the caller names.
+        assert!(matches!(
+            context.public_key_fingerprint(Algorithm::MlDsa44, first.public_key()),
+            Err(Error::InvalidKeySize {
+                expected: 1312,
+                actual: 1952
+            })
+        ));
+        assert!(matches!(
+            context.public_key_fingerprint(Algorithm::Sha3_256, first.public_key()),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+    }
 }
@@ -145,12 +145,14 @@ use alloc::string::ToString;
 
 #[cfg(feature = "wasm")]
 use js_sys::Uint8Array;
+use lib_q_core::SecretExportConsent;
 use lib_q_core::api::Algorithm;
 use lib_q_core::error::{
     Error,
     Result,
 };
 use lib_q_core::traits::{
+    Primitive,
     SigKeypair,
     SigPublicKey,
     SigSecretKey,
@@ -284,6 +286,14 @@ impl Default for SlhDsa {
     }
 }
 
+/// Reports the SHAKE256-128f parameter set used by the [`Signature`] impl; the
+/// `*_for_algorithm` methods accept every SLH-DSA algorithm
+impl Primitive for SlhDsa {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::SlhDsaShake256128fRobust
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl Signature for SlhDsa {
     fn generate_keypair(&self) -> Result<SigKeypair> {
@@ -310,6 +320,25 @@ impl Signature for SlhDsa {
             signature,
         )
     }
+
+    // Sizes of the default SHAKE256-128f parameter set, from the shared algorithm table
+    fn public_key_size(&self) -> usize {
+        self.algorithm()
+            .public_key_len()
+            .expect("SLH-DSA algorithms have a fixed public key length")
+    }
+
+    fn secret_key_size(&self) -> usize {
+        self.algorithm()
+            .secret_key_len()
+            .expect("SLH-DSA algorithms have a fixed secret key length")
+    }
+
+    fn signature_size(&self) -> usize {
+        self.algorithm()
+            .signature_len()
+            .expect("SLH-DSA algorithms have a fixed signature length")
+    }
 }
 
 impl SlhDsa {
@@ -495,7 +524,10 @@ impl SlhDsa {
             let keypair = slh_dsa.generate_keypair_with_randomness(rand)?;
             Ok((
                 keypair.public_key.as_bytes().to_vec(),
-                keypair.secret_key.as_bytes().to_vec(),
+                keypair
+                    .secret_key
+                    .expose_secret(SecretExportConsent)
+                    .to_vec(),
             ))
         } else {
             // Use system RNG (requires std feature)
@@ -515,7 +547,10 @@ impl SlhDsa {
                 let keypair = slh_dsa.generate_keypair_with_randomness(&key_randomness)?;
                 Ok((
                     keypair.public_key.as_bytes().to_vec(),
-                    keypair.secret_key.as_bytes().to_vec(),
+                    keypair
+                        .secret_key
+                        .expose_secret(SecretExportConsent)
+                        .to_vec(),
                 ))
             }
             #[cfg(not(feature = "slh-dsa-std"))]
@@ -769,10 +804,13 @@ impl SlhDsa {
             Err(e) => return Err(JsValue::from_str(&e.to_string())),
         };
 
-        Ok(WasmSlhDsaKeyPair::new(
-            Uint8Array::from(keypair.public_key().as_bytes()),
-            Uint8Array::from(keypair.secret_key().as_bytes()),
-        ))
+        Ok(WasmSlhDsaKeyPair {
+            public_key: Uint8Array::from(keypair.public_key().as_bytes()),
+            secret_key: keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .to_vec(),
+        })
     }
 
     /// Sign a message in WASM (JavaScript) environment
@@ -860,11 +898,14 @@ impl SlhDsa {
 }
 
 /// WASM-compatible SLH-DSA key pair
+///
+/// The secret key is kept in Rust memory and zeroized on drop; JavaScript only ever sees
+/// copies made by the `secret_key` getter.
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub struct WasmSlhDsaKeyPair {
     public_key: Uint8Array,
-    secret_key: Uint8Array,
+    secret_key: Vec<u8>,
 }
 
 #[cfg(feature = "wasm")]
@@ -874,7 +915,7 @@ impl WasmSlhDsaKeyPair {
     pub fn new(public_key: Uint8Array, secret_key: Uint8Array) -> WasmSlhDsaKeyPair {
         WasmSlhDsaKeyPair {
             public_key,
-            secret_key,
+            secret_key: lib_q_core::wasm_common::conversions::uint8array_to_vec(&secret_key),
         }
     }
 
@@ -883,8 +924,23 @@ impl WasmSlhDsaKeyPair {
         self.public_key.clone()
     }
 
+    /// Copy of the secret key; empty after [`zeroize`](Self::zeroize)
     #[wasm_bindgen(getter)]
     pub fn secret_key(&self) -> Uint8Array {
-        self.secret_key.clone()
+        lib_q_core::wasm_common::conversions::vec_to_uint8array(&self.secret_key)
+    }
+
+    /// Overwrite the secret key with zeros
+    ///
+    /// Safe to call more than once; `secret_key` returns an empty array afterwards.
+    pub fn zeroize(&mut self) {
+        lib_q_core::wasm_common::zeroize_secret(&mut self.secret_key);
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl Drop for WasmSlhDsaKeyPair {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
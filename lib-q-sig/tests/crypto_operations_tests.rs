@@ -11,6 +11,7 @@ extern crate alloc;
 
 use lib_q_core::{
     Algorithm,
+    SecretExportConsent,
     SigPublicKey,
     Signature,
     SignatureOperations,
@@ -171,8 +172,8 @@ mod ml_dsa_crypto_tests {
                     "Public keys should be unique"
                 );
                 assert_ne!(
-                    keypairs[i].secret_key().as_bytes(),
-                    keypairs[j].secret_key().as_bytes(),
+                    keypairs[i].secret_key().expose_secret(SecretExportConsent),
+                    keypairs[j].secret_key().expose_secret(SecretExportConsent),
                     "Secret keys should be unique"
                 );
             }
@@ -214,6 +215,46 @@ mod ml_dsa_crypto_tests {
             assert!(is_valid, "All signatures should verify correctly");
         }
     }
+
+    #[test]
+    fn test_ml_dsa_keygen_with_injected_rng_is_reproducible() {
+        use lib_q_core::SignatureContext;
+        use lib_q_random::new_deterministic_rng;
+
+        let mut ctx = SignatureContext::with_provider(Box::new(
+            LibQSignatureProvider::new().expect("Provider creation should succeed"),
+        ));
+        let mut keygen = |seed: [u8; 32]| {
+            let mut rng = new_deterministic_rng(seed);
+            ctx.generate_keypair_with_rng(Algorithm::MlDsa65, &mut rng)
+                .expect("Key generation should succeed")
+        };
+
+        let first = keygen([0x3C; 32]);
+        let second = keygen([0x3C; 32]);
+        assert_eq!(
+            first.public_key().as_bytes(),
+            second.public_key().as_bytes()
+        );
+        assert_eq!(
+            first.secret_key().expose_secret(SecretExportConsent),
+            second.secret_key().expose_secret(SecretExportConsent)
+        );
+        assert_ne!(
+            keygen([0xC3; 32]).public_key().as_bytes(),
+            first.public_key().as_bytes()
+        );
+
+        let ml_dsa = MlDsa::ml_dsa_65();
+        let signature = ml_dsa
+            .sign(first.secret_key(), b"reproducible")
+            .expect("Signing should succeed");
+        assert!(
+            ml_dsa
+                .verify(first.public_key(), b"reproducible", &signature)
+                .expect("Verification should succeed")
+        );
+    }
 }
 
 /// Test end-to-end cryptographic operations for SLH-DSA (implicit OS RNG; requires `slh-dsa-std`)
@@ -375,6 +416,36 @@ mod slh_dsa_crypto_tests {
         }
     }
 
+    #[test]
+    fn test_sphincs_shake_128f_alias_signature_length_and_truncation() {
+        // SLH-DSA-SHAKE-128f (FIPS 205 Table 2): n = 16, h = 66, d = 22, k = 33, a = 6 => 17088 bytes
+        const SIGNATURE_LEN: usize = 17088;
+
+        let sphincs = lib_q_sig::create_signature("sphincs-shake-128f").expect("sphincs alias");
+        let message = b"sphincs shake 128f";
+        let keypair = sphincs
+            .generate_keypair()
+            .expect("Key generation should succeed");
+        let signature = sphincs
+            .sign(keypair.secret_key(), message)
+            .expect("Signing should succeed");
+        assert_eq!(signature.len(), SIGNATURE_LEN);
+        assert!(
+            sphincs
+                .verify(keypair.public_key(), message, &signature)
+                .expect("Verification should succeed")
+        );
+
+        let truncated = &signature[..SIGNATURE_LEN - 1];
+        assert!(
+            !matches!(
+                sphincs.verify(keypair.public_key(), message, truncated),
+                Ok(true)
+            ),
+            "truncated signature must not verify"
+        );
+    }
+
     #[test]
     fn test_slh_dsa_shake256_192f_robust_end_to_end() {
         let slh_dsa = SlhDsa::new();
@@ -473,8 +544,8 @@ mod slh_dsa_crypto_tests {
                     "Public keys should be unique"
                 );
                 assert_ne!(
-                    keypairs[i].secret_key().as_bytes(),
-                    keypairs[j].secret_key().as_bytes(),
+                    keypairs[i].secret_key().expose_secret(SecretExportConsent),
+                    keypairs[j].secret_key().expose_secret(SecretExportConsent),
                     "Secret keys should be unique"
                 );
             }
@@ -590,6 +661,37 @@ mod fn_dsa_crypto_tests {
         }
     }
 
+    #[test]
+    fn test_falcon_alias_round_trip_and_flipped_signature() {
+        for name in ["falcon", "falcon-512", "falcon-1024"] {
+            let falcon = lib_q_sig::create_signature(name).expect("falcon alias");
+            let message = b"falcon alias round trip";
+            let keypair = falcon
+                .generate_keypair()
+                .expect("Key generation should succeed");
+            let signature = falcon
+                .sign(keypair.secret_key(), message)
+                .expect("Signing should succeed");
+            assert!(
+                falcon
+                    .verify(keypair.public_key(), message, &signature)
+                    .expect("Verification should succeed"),
+                "{name}: valid signature must verify"
+            );
+
+            let mut flipped = signature.clone();
+            let last = flipped.len() - 1;
+            flipped[last] ^= 0x01;
+            assert!(
+                !matches!(
+                    falcon.verify(keypair.public_key(), message, &flipped),
+                    Ok(true)
+                ),
+                "{name}: signature with a flipped byte must not verify"
+            );
+        }
+    }
+
     #[test]
     fn test_fn_dsa_keypair_uniqueness() {
         let fn_dsa = FnDsa512::new();
@@ -612,8 +714,8 @@ mod fn_dsa_crypto_tests {
                     "Public keys should be unique"
                 );
                 assert_ne!(
-                    keypairs[i].secret_key().as_bytes(),
-                    keypairs[j].secret_key().as_bytes(),
+                    keypairs[i].secret_key().expose_secret(SecretExportConsent),
+                    keypairs[j].secret_key().expose_secret(SecretExportConsent),
                     "Secret keys should be unique"
                 );
             }
@@ -626,6 +728,117 @@ mod fn_dsa_crypto_tests {
 mod provider_integration_tests {
     use super::*;
 
+    #[test]
+    fn test_signature_context_with_context_string() {
+        use lib_q_core::SignatureContext;
+
+        let mut ctx = SignatureContext::with_provider(Box::new(
+            LibQSignatureProvider::new().expect("provider"),
+        ));
+        let keypair = ctx
+            .generate_keypair(Algorithm::MlDsa65, None)
+            .expect("Key generation should succeed");
+        let message = b"context string message";
+
+        let signature = ctx
+            .sign_with_context(
+                Algorithm::MlDsa65,
+                keypair.secret_key(),
+                message,
+                b"app-v1",
+                None,
+            )
+            .expect("Signing should succeed");
+        assert!(
+            ctx.verify_with_context(
+                Algorithm::MlDsa65,
+                keypair.public_key(),
+                message,
+                b"app-v1",
+                &signature,
+            )
+            .expect("Verification should succeed")
+        );
+        assert!(
+            !ctx.verify_with_context(
+                Algorithm::MlDsa65,
+                keypair.public_key(),
+                message,
+                b"app-v2",
+                &signature,
+            )
+            .expect("Verification should succeed")
+        );
+
+        let too_long = vec![0u8; lib_q_core::MAX_SIGNATURE_CONTEXT_SIZE + 1];
+        assert!(matches!(
+            ctx.sign_with_context(
+                Algorithm::MlDsa65,
+                keypair.secret_key(),
+                message,
+                &too_long,
+                None,
+            ),
+            Err(lib_q_core::Error::InvalidContextSize { .. })
+        ));
+    }
+
+    #[test]
+    fn test_signature_context_verify_batch_mixed_results() {
+        use lib_q_core::SignatureContext;
+
+        let mut ctx = SignatureContext::with_provider(Box::new(
+            LibQSignatureProvider::new().expect("provider"),
+        ));
+        let signer = ctx
+            .generate_keypair(Algorithm::MlDsa65, None)
+            .expect("Key generation should succeed");
+        let other = ctx
+            .generate_keypair(Algorithm::MlDsa65, None)
+            .expect("Key generation should succeed");
+
+        let sign = |message: &[u8]| {
+            ctx.sign(Algorithm::MlDsa65, signer.secret_key(), message, None)
+                .expect("Signing should succeed")
+        };
+        let good = sign(b"entry 0");
+        let mut corrupted = sign(b"entry 2");
+        corrupted[10] ^= 0x80;
+
+        let items = vec![
+            (
+                signer.public_key().clone(),
+                b"entry 0".to_vec(),
+                good.clone(),
+            ),
+            (
+                other.public_key().clone(),
+                b"entry 0".to_vec(),
+                good.clone(),
+            ),
+            (signer.public_key().clone(), b"entry 2".to_vec(), corrupted),
+            (
+                signer.public_key().clone(),
+                b"entry 3".to_vec(),
+                sign(b"entry 3"),
+            ),
+            (
+                signer.public_key().clone(),
+                b"entry 4".to_vec(),
+                good[..100].to_vec(),
+            ),
+        ];
+        let results = ctx
+            .verify_batch(Algorithm::MlDsa65, &items)
+            .expect("Batch verification should succeed");
+        assert_eq!(results, vec![true, false, false, true, false]);
+
+        assert!(matches!(
+            ctx.verify_batch(Algorithm::Sha3_256, &items),
+            Err(lib_q_core::Error::InvalidAlgorithm { .. })
+        ));
+    }
+
     #[test]
     fn test_provider_ml_dsa_operations() {
         let provider = LibQSignatureProvider::new().expect("Provider creation should succeed");
@@ -650,7 +863,10 @@ mod provider_integration_tests {
                 "ML-DSA-65 public key should be 1952 bytes"
             );
             assert_eq!(
-                keypair.secret_key().as_bytes().len(),
+                keypair
+                    .secret_key()
+                    .expose_secret(SecretExportConsent)
+                    .len(),
                 4032,
                 "ML-DSA-65 secret key should be 4032 bytes"
             );
@@ -681,7 +897,10 @@ mod provider_integration_tests {
                     .generate_keypair(algo, Some(&key_rng))
                     .unwrap_or_else(|_| panic!("keygen {:?}", algo));
                 assert_eq!(kp.public_key().as_bytes().len(), pk_len);
-                assert_eq!(kp.secret_key().as_bytes().len(), sk_len);
+                assert_eq!(
+                    kp.secret_key().expose_secret(SecretExportConsent).len(),
+                    sk_len
+                );
                 let mut s_rng = [0u8; 32];
                 for (i, b) in s_rng.iter_mut().enumerate() {
                     *b = (i as u8).wrapping_mul(19).wrapping_add(13);
@@ -731,7 +950,10 @@ mod provider_integration_tests {
                 "FN-DSA-512 public key should be 897 bytes"
             );
             assert_eq!(
-                keypair.secret_key().as_bytes().len(),
+                keypair
+                    .secret_key()
+                    .expose_secret(SecretExportConsent)
+                    .len(),
                 1281,
                 "FN-DSA-512 secret key should be 1281 bytes"
             );
@@ -1053,7 +1275,7 @@ mod memory_safety_tests {
 
             // Check that keys are not all zeros or all ones
             let public_key_bytes = keypair.public_key().as_bytes();
-            let secret_key_bytes = keypair.secret_key().as_bytes();
+            let secret_key_bytes = keypair.secret_key().expose_secret(SecretExportConsent);
 
             assert!(
                 !public_key_bytes.iter().all(|&b| b == 0),
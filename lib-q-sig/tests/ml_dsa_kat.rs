@@ -0,0 +1,90 @@
+//! NIST known-answer tests for ML-DSA through [`LibQSignatureProvider`]
+//!
+//! The vectors are the FIPS 204 KAT digests shipped with `lib-q-ml-dsa`
+//! (`lib-q-ml-dsa/tests/kats/nistkats-*.json`): deterministic key generation from a 32-byte
+//! seed and signing with fixed randomness and an empty context. Each file records SHA3-256
+//! digests of the verification key, signing key and signature, so these tests pin the exact
+//! bytes the provider produces rather than only checking that signatures verify.
+
+#![cfg(all(feature = "std", feature = "ml-dsa"))]
+
+use std::path::Path;
+
+use lib_q_core::{
+    Algorithm,
+    SecretExportConsent,
+    SignatureOperations,
+    Utils,
+};
+use lib_q_sha3::sha3_256;
+use lib_q_sig::LibQSignatureProvider;
+use serde_json::Value;
+
+fn hex(value: &Value) -> Vec<u8> {
+    Utils::hex_to_bytes(value.as_str().expect("hex string")).expect("valid hex")
+}
+
+fn run_kats(algorithm: Algorithm, parameter_set: u32) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../lib-q-ml-dsa/tests/kats")
+        .join(format!("nistkats-{parameter_set}.json"));
+    let json = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("reading {}: {e}", path.display()));
+    let kats: Value = serde_json::from_str(&json).expect("KAT JSON");
+    let kats = kats.as_array().expect("array of KATs");
+    assert!(!kats.is_empty());
+
+    let provider = LibQSignatureProvider::new().unwrap();
+    for (i, kat) in kats.iter().enumerate() {
+        let keypair = provider
+            .generate_keypair(algorithm, Some(&hex(&kat["key_generation_seed"])))
+            .unwrap();
+        let public_key = keypair.public_key();
+        let secret_key = keypair.secret_key();
+        assert_eq!(
+            sha3_256(public_key.as_bytes()).as_slice(),
+            hex(&kat["sha3_256_hash_of_verification_key"]),
+            "{algorithm:?} KAT {i}: verification key"
+        );
+        assert_eq!(
+            sha3_256(secret_key.expose_secret(SecretExportConsent)).as_slice(),
+            hex(&kat["sha3_256_hash_of_signing_key"]),
+            "{algorithm:?} KAT {i}: signing key"
+        );
+
+        let message = hex(&kat["message"]);
+        let signature = provider
+            .sign(
+                algorithm,
+                secret_key,
+                &message,
+                Some(&hex(&kat["signing_randomness"])),
+            )
+            .unwrap();
+        assert_eq!(
+            sha3_256(&signature).as_slice(),
+            hex(&kat["sha3_256_hash_of_signature"]),
+            "{algorithm:?} KAT {i}: signature"
+        );
+        assert!(
+            provider
+                .verify(algorithm, public_key, &message, &signature)
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn ml_dsa_44_nist_kats() {
+    run_kats(Algorithm::MlDsa44, 44);
+}
+
+#[test]
+fn ml_dsa_65_nist_kats() {
+    run_kats(Algorithm::MlDsa65, 65);
+}
+
+#[test]
+fn ml_dsa_87_nist_kats() {
+    run_kats(Algorithm::MlDsa87, 87);
+}
@@ -8,6 +8,7 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+use lib_q_core::SecretExportConsent;
 #[cfg(feature = "ml-dsa")]
 use lib_q_core::Signature;
 #[cfg(feature = "slh-dsa")]
@@ -45,7 +46,12 @@ mod slh_dsa_no_std_tests {
 
         // Verify key sizes are reasonable
         assert!(!keypair.public_key().as_bytes().is_empty());
-        assert!(!keypair.secret_key().as_bytes().is_empty());
+        assert!(
+            !keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .is_empty()
+        );
 
         // Test that same randomness produces same keys
         let keypair2 = slh_dsa
@@ -57,8 +63,8 @@ mod slh_dsa_no_std_tests {
             keypair2.public_key().as_bytes()
         );
         assert_eq!(
-            keypair.secret_key().as_bytes(),
-            keypair2.secret_key().as_bytes()
+            keypair.secret_key().expose_secret(SecretExportConsent),
+            keypair2.secret_key().expose_secret(SecretExportConsent)
         );
     }
 
@@ -219,7 +225,12 @@ mod ml_dsa_no_std_tests {
 
         // Verify key sizes are reasonable
         assert!(!keypair.public_key().as_bytes().is_empty());
-        assert!(!keypair.secret_key().as_bytes().is_empty());
+        assert!(
+            !keypair
+                .secret_key()
+                .expose_secret(SecretExportConsent)
+                .is_empty()
+        );
 
         // Test that same randomness produces same keys
         let mut randomness2 = [0u8; KEY_GENERATION_RANDOMNESS_SIZE];
@@ -236,8 +247,8 @@ mod ml_dsa_no_std_tests {
             keypair2.public_key().as_bytes()
         );
         assert_eq!(
-            keypair.secret_key().as_bytes(),
-            keypair2.secret_key().as_bytes()
+            keypair.secret_key().expose_secret(SecretExportConsent),
+            keypair2.secret_key().expose_secret(SecretExportConsent)
         );
     }
 
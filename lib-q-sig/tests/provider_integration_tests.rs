@@ -6,6 +6,7 @@
 
 #[cfg(feature = "slh-dsa")]
 use lib_q_core::{
+    SecretExportConsent,
     api::{
         Algorithm,
         SignatureOperations,
@@ -179,8 +180,8 @@ mod provider_tests {
             "Same randomness should produce same keys"
         );
         assert_eq!(
-            keypair1.secret_key().as_bytes(),
-            keypair2.secret_key().as_bytes(),
+            keypair1.secret_key().expose_secret(SecretExportConsent),
+            keypair2.secret_key().expose_secret(SecretExportConsent),
             "Same randomness should produce same keys"
         );
 
@@ -230,7 +231,10 @@ mod provider_tests {
             .generate_keypair(Algorithm::SlhDsaShake256128fRobust, Some(&randomness))
             .expect("Key generation should succeed");
 
-        let secret_key_bytes = keypair.secret_key().as_bytes().to_vec();
+        let secret_key_bytes = keypair
+            .secret_key()
+            .expose_secret(SecretExportConsent)
+            .to_vec();
         drop(keypair);
 
         // In a real implementation, we would check that the memory was zeroized
@@ -17,6 +17,7 @@ use std::time::{
 
 use lib_q_core::{
     Error,
+    SecretExportConsent,
     SigPublicKey,
     Signature,
 };
@@ -315,7 +316,7 @@ mod cryptographic_strength_tests {
                 public_keys.push(public_key_bytes.to_vec());
 
                 // Check secret key uniqueness
-                let secret_key_bytes = keypair.secret_key().as_bytes();
+                let secret_key_bytes = keypair.secret_key().expose_secret(SecretExportConsent);
                 assert!(
                     !secret_keys.contains(&secret_key_bytes.to_vec()),
                     "Secret keys should be unique"
@@ -424,7 +425,7 @@ mod cryptographic_strength_tests {
                     .expect("Key generation should succeed");
 
                 let public_key_bytes = keypair.public_key().as_bytes();
-                let secret_key_bytes = keypair.secret_key().as_bytes();
+                let secret_key_bytes = keypair.secret_key().expose_secret(SecretExportConsent);
 
                 // Check that keys have good entropy (not all same byte)
                 let public_entropy = {
@@ -489,7 +490,7 @@ mod memory_safety_tests {
 
             // Check that secret key is not exposed in public key
             let public_key_bytes = keypair.public_key().as_bytes();
-            let secret_key_bytes = keypair.secret_key().as_bytes();
+            let secret_key_bytes = keypair.secret_key().expose_secret(SecretExportConsent);
 
             // Public and secret keys should be different
             assert_ne!(
@@ -518,7 +519,7 @@ mod memory_safety_tests {
 
             // Check that key data is properly aligned and sized
             let public_key_bytes = keypair.public_key().as_bytes();
-            let secret_key_bytes = keypair.secret_key().as_bytes();
+            let secret_key_bytes = keypair.secret_key().expose_secret(SecretExportConsent);
 
             // Check expected sizes for ML-DSA-65
             assert_eq!(
@@ -0,0 +1,113 @@
+//! `ml_dsa::verify_no_alloc` must not touch the heap
+//!
+//! A counting global allocator records allocations made on the current thread while the
+//! verification runs; key generation and signing happen before counting starts. The tests
+//! only need the `ml-dsa` feature, so they also run under
+//! `cargo test -p lib-q-sig --no-default-features --features ml-dsa`.
+
+#![cfg(feature = "ml-dsa")]
+
+use std::alloc::{
+    GlobalAlloc,
+    Layout,
+    System,
+};
+use std::cell::Cell;
+
+use lib_q_ml_dsa::{
+    ml_dsa_44,
+    ml_dsa_65,
+    ml_dsa_87,
+};
+use lib_q_sig::ml_dsa::verify_no_alloc;
+
+std::thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts allocations per thread so parallel tests do not interfere
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+/// `(public key, signature)` over `message` for ML-DSA-44, -65 and -87
+///
+/// Signed with `lib-q-ml-dsa` directly, since `MlDsa` only signs with the `alloc` feature.
+fn signed(message: &[u8]) -> [(Vec<u8>, Vec<u8>); 3] {
+    let keypair = ml_dsa_44::generate_key_pair([7u8; 32]);
+    let signature = ml_dsa_44::sign(&keypair.signing_key, message, &[], [9u8; 32]).unwrap();
+    let mldsa44 = (
+        keypair.verification_key.as_slice().to_vec(),
+        signature.as_slice().to_vec(),
+    );
+    let keypair = ml_dsa_65::generate_key_pair([7u8; 32]);
+    let signature = ml_dsa_65::sign(&keypair.signing_key, message, &[], [9u8; 32]).unwrap();
+    let mldsa65 = (
+        keypair.verification_key.as_slice().to_vec(),
+        signature.as_slice().to_vec(),
+    );
+    let keypair = ml_dsa_87::generate_key_pair([7u8; 32]);
+    let signature = ml_dsa_87::sign(&keypair.signing_key, message, &[], [9u8; 32]).unwrap();
+    let mldsa87 = (
+        keypair.verification_key.as_slice().to_vec(),
+        signature.as_slice().to_vec(),
+    );
+    [mldsa44, mldsa65, mldsa87]
+}
+
+#[test]
+fn test_verify_no_alloc_does_not_allocate() {
+    let message = b"firmware image v1.2.3";
+    for (public_key, signature) in signed(message) {
+        let mut corrupted = signature.clone();
+        corrupted[10] ^= 0x01;
+
+        let (valid, allocations) =
+            allocations_during(|| verify_no_alloc(&public_key, message, &signature));
+        assert!(valid.unwrap());
+        assert_eq!(allocations, 0);
+
+        let (invalid, allocations) =
+            allocations_during(|| verify_no_alloc(&public_key, message, &corrupted));
+        assert!(!invalid.unwrap());
+        assert_eq!(allocations, 0);
+    }
+}
+
+#[test]
+fn test_verify_no_alloc_validates_lengths() {
+    let [_, (public_key, _), _] = signed(b"m");
+
+    let (result, allocations) =
+        allocations_during(|| verify_no_alloc(&public_key[1..], b"m", &[0u8; 3309]));
+    assert!(matches!(
+        result,
+        Err(lib_q_core::Error::InvalidFormat { .. })
+    ));
+    assert_eq!(allocations, 0);
+    assert!(matches!(
+        verify_no_alloc(&public_key, b"m", &[0u8; 3308]),
+        Err(lib_q_core::Error::InvalidSignatureSize {
+            expected: 3309,
+            actual: 3308,
+        })
+    ));
+}
@@ -15,6 +15,7 @@ use alloc::{
 use lib_q_core::{
     Error,
     Result,
+    SecretExportConsent,
     SigKeypair,
     SigPublicKey,
     SigSecretKey,
@@ -122,6 +123,18 @@ impl<P: ParameterSet> Signature for SlhDsaSignature<P> {
             Err(_) => Ok(false),
         }
     }
+
+    fn public_key_size(&self) -> usize {
+        2 * P::N::USIZE
+    }
+
+    fn secret_key_size(&self) -> usize {
+        4 * P::N::USIZE
+    }
+
+    fn signature_size(&self) -> usize {
+        P::SigLen::USIZE
+    }
 }
 
 impl<P: ParameterSet> SlhDsaSignature<P> {
@@ -230,9 +243,11 @@ pub fn verifying_key_to_sig_public_key<P: ParameterSet>(
 pub fn sig_secret_key_to_signing_key<P: ParameterSet>(
     secret_key: &SigSecretKey,
 ) -> Result<SigningKey<P>> {
-    SigningKey::<P>::try_from(secret_key.as_bytes()).map_err(|_| Error::InvalidKey {
-        key_type: "SLH-DSA signing key".to_string(),
-        reason: "Failed to deserialize signing key".to_string(),
+    SigningKey::<P>::try_from(secret_key.expose_secret(SecretExportConsent)).map_err(|_| {
+        Error::InvalidKey {
+            key_type: "SLH-DSA signing key".to_string(),
+            reason: "Failed to deserialize signing key".to_string(),
+        }
     })
 }
 
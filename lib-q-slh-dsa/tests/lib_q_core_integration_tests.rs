@@ -13,6 +13,7 @@ use alloc::vec;
 
 use lib_q_core::{
     Error,
+    SecretExportConsent,
     SigPublicKey,
     SigSecretKey,
     Signature,
@@ -70,7 +71,7 @@ fn test_type_conversions() {
     // Test SigningKey -> SigSecretKey conversion
     let sig_secret_key = signing_key_to_sig_secret_key(&signing_key)
         .expect("Should convert SigningKey to SigSecretKey");
-    assert!(!sig_secret_key.as_bytes().is_empty());
+    assert!(!sig_secret_key.expose_secret(SecretExportConsent).is_empty());
 
     // Test VerifyingKey -> SigPublicKey conversion
     let sig_public_key = verifying_key_to_sig_public_key::<Shake128f>(&verifying_key)
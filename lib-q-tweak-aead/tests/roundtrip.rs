@@ -5,6 +5,7 @@ use lib_q_core::{
     DecryptSemanticOutcome,
     Error,
     Nonce,
+    SecretExportConsent,
 };
 use lib_q_tweak_aead::params::{
     KEY_BYTES,
@@ -69,7 +70,7 @@ fn decrypt_failure_clears_output_buffer() {
     ct[0] ^= 1;
 
     let mut key_arr = [0u8; KEY_BYTES];
-    key_arr.copy_from_slice(key.as_bytes());
+    key_arr.copy_from_slice(key.expose_secret(SecretExportConsent));
     let mut nonce_arr = [0u8; NONCE_BYTES];
     nonce_arr.copy_from_slice(nonce.as_bytes());
 
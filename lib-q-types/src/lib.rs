@@ -111,6 +111,115 @@ pub enum Algorithm {
 }
 
 impl Algorithm {
+    /// Every algorithm identifier, in declaration order
+    pub const ALL: &'static [Algorithm] = &[
+        Algorithm::MlKem512,
+        Algorithm::MlKem768,
+        Algorithm::MlKem1024,
+        Algorithm::CbKem348864,
+        Algorithm::CbKem460896,
+        Algorithm::CbKem6688128,
+        Algorithm::CbKem6960119,
+        Algorithm::CbKem8192128,
+        Algorithm::Hqc128,
+        Algorithm::Hqc192,
+        Algorithm::Hqc256,
+        Algorithm::MlDsa44,
+        Algorithm::MlDsa65,
+        Algorithm::MlDsa87,
+        Algorithm::FnDsa,
+        Algorithm::FnDsa512,
+        Algorithm::FnDsa1024,
+        Algorithm::SlhDsaSha256128fRobust,
+        Algorithm::SlhDsaSha256192fRobust,
+        Algorithm::SlhDsaSha256256fRobust,
+        Algorithm::SlhDsaShake256128fRobust,
+        Algorithm::SlhDsaShake256192fRobust,
+        Algorithm::SlhDsaShake256256fRobust,
+        Algorithm::Shake128,
+        Algorithm::Shake256,
+        Algorithm::CShake128,
+        Algorithm::CShake256,
+        Algorithm::Sha3_224,
+        Algorithm::Sha3_256,
+        Algorithm::Sha3_384,
+        Algorithm::Sha3_512,
+        Algorithm::Keccak224,
+        Algorithm::Keccak256,
+        Algorithm::Keccak384,
+        Algorithm::Keccak512,
+        Algorithm::Kt128,
+        Algorithm::Kt256,
+        Algorithm::TurboShake128,
+        Algorithm::TurboShake256,
+        Algorithm::Kmac128,
+        Algorithm::Kmac256,
+        Algorithm::TupleHash128,
+        Algorithm::TupleHash256,
+        Algorithm::ParallelHash128,
+        Algorithm::ParallelHash256,
+        Algorithm::Sha224,
+        Algorithm::Sha256,
+        Algorithm::Sha384,
+        Algorithm::Sha512,
+        Algorithm::Sha512_224,
+        Algorithm::Sha512_256,
+        Algorithm::Saturnin,
+        Algorithm::Shake256Aead,
+        Algorithm::DuplexSpongeAead,
+        Algorithm::TweakAead,
+        Algorithm::RomulusN,
+        Algorithm::RomulusM,
+        Algorithm::RoccaS,
+        Algorithm::LatticeRingSignature,
+        Algorithm::LatticeBlindIssuance,
+        Algorithm::LatticeAnonymousToken,
+        Algorithm::LatticeNullifierRegistry,
+        Algorithm::LatticeWitnessNullifier,
+        Algorithm::LatticeDualRingLb,
+        Algorithm::MixOnionRouting,
+        Algorithm::SessionResumptionBinding,
+        Algorithm::FaestReserved,
+    ];
+
+    /// Recommended default algorithm for a category and security level
+    ///
+    /// Returns the NIST-standardized primary: ML-KEM for KEMs and ML-DSA for signatures,
+    /// with SLH-DSA-SHAKE256-256f as the only level 5 signature. Alternatives such as
+    /// CB-KEM, HQC and FN-DSA are never recommended. Returns `None` for combinations
+    /// without a standardized primary, including every hash, AEAD and privacy protocol.
+    pub fn recommended(category: AlgorithmCategory, level: SecurityLevel) -> Option<Algorithm> {
+        match (category, level) {
+            (AlgorithmCategory::Kem, SecurityLevel::Level1) => Some(Algorithm::MlKem512),
+            (AlgorithmCategory::Kem, SecurityLevel::Level3) => Some(Algorithm::MlKem768),
+            (AlgorithmCategory::Kem, SecurityLevel::Level4) => Some(Algorithm::MlKem1024),
+            (AlgorithmCategory::Signature, SecurityLevel::Level1) => Some(Algorithm::MlDsa44),
+            (AlgorithmCategory::Signature, SecurityLevel::Level3) => Some(Algorithm::MlDsa65),
+            (AlgorithmCategory::Signature, SecurityLevel::Level4) => Some(Algorithm::MlDsa87),
+            (AlgorithmCategory::Signature, SecurityLevel::Level5) => {
+                Some(Algorithm::SlhDsaShake256256fRobust)
+            }
+            _ => None,
+        }
+    }
+
+    /// Iterate over the algorithms of a category
+    pub fn algorithms_by_category(category: AlgorithmCategory) -> impl Iterator<Item = Algorithm> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(move |algorithm| algorithm.category() == category)
+    }
+
+    /// Iterate over the algorithms of a category at a security level
+    pub fn algorithms_by_category_and_level(
+        category: AlgorithmCategory,
+        level: SecurityLevel,
+    ) -> impl Iterator<Item = Algorithm> {
+        Self::algorithms_by_category(category)
+            .filter(move |algorithm| algorithm.security_level() == level.as_u32())
+    }
+
     /// Get the security level for this algorithm
     pub fn security_level(&self) -> u32 {
         match self {
@@ -280,6 +389,268 @@ impl Algorithm {
         }
     }
 
+    /// Encoded public key length in bytes, or `None` for algorithms without key pairs
+    pub fn public_key_len(&self) -> Option<usize> {
+        let len = match self {
+            Algorithm::MlKem512 => 800,
+            Algorithm::MlKem768 => 1184,
+            Algorithm::MlKem1024 => 1568,
+            Algorithm::CbKem348864 => 261120,
+            Algorithm::CbKem460896 => 524160,
+            Algorithm::CbKem6688128 => 1044992,
+            Algorithm::CbKem6960119 => 1047319,
+            Algorithm::CbKem8192128 => 1357824,
+            Algorithm::Hqc128 => hqc::HQC128_PUBLIC_KEY_BYTES,
+            Algorithm::Hqc192 => hqc::HQC192_PUBLIC_KEY_BYTES,
+            Algorithm::Hqc256 => hqc::HQC256_PUBLIC_KEY_BYTES,
+
+            Algorithm::MlDsa44 => 1312,
+            Algorithm::MlDsa65 => 1952,
+            Algorithm::MlDsa87 => 2592,
+            Algorithm::FnDsa | Algorithm::FnDsa512 => 897,
+            Algorithm::FnDsa1024 => 1793,
+            // SLH-DSA: 2 * n
+            Algorithm::SlhDsaSha256128fRobust | Algorithm::SlhDsaShake256128fRobust => 32,
+            Algorithm::SlhDsaSha256192fRobust | Algorithm::SlhDsaShake256192fRobust => 48,
+            Algorithm::SlhDsaSha256256fRobust | Algorithm::SlhDsaShake256256fRobust => 64,
+
+            _ => return None,
+        };
+        Some(len)
+    }
+
+    /// Encoded secret key length in bytes, or `None` for algorithms without key pairs
+    pub fn secret_key_len(&self) -> Option<usize> {
+        let len = match self {
+            Algorithm::MlKem512 => 1632,
+            Algorithm::MlKem768 => 2400,
+            Algorithm::MlKem1024 => 3168,
+            Algorithm::CbKem348864 => 6492,
+            Algorithm::CbKem460896 => 13608,
+            Algorithm::CbKem6688128 => 13932,
+            Algorithm::CbKem6960119 => 13948,
+            Algorithm::CbKem8192128 => 14120,
+            Algorithm::Hqc128 => hqc::HQC128_SECRET_KEY_BYTES,
+            Algorithm::Hqc192 => hqc::HQC192_SECRET_KEY_BYTES,
+            Algorithm::Hqc256 => hqc::HQC256_SECRET_KEY_BYTES,
+
+            Algorithm::MlDsa44 => 2560,
+            Algorithm::MlDsa65 => 4032,
+            Algorithm::MlDsa87 => 4896,
+            Algorithm::FnDsa | Algorithm::FnDsa512 => 1281,
+            Algorithm::FnDsa1024 => 2561,
+            // SLH-DSA: 4 * n
+            Algorithm::SlhDsaSha256128fRobust | Algorithm::SlhDsaShake256128fRobust => 64,
+            Algorithm::SlhDsaSha256192fRobust | Algorithm::SlhDsaShake256192fRobust => 96,
+            Algorithm::SlhDsaSha256256fRobust | Algorithm::SlhDsaShake256256fRobust => 128,
+
+            _ => return None,
+        };
+        Some(len)
+    }
+
+    /// KEM ciphertext (encapsulated key) length in bytes, or `None` for non-KEMs
+    ///
+    /// AEAD ciphertexts depend on the plaintext length and are not covered.
+    pub fn ciphertext_len(&self) -> Option<usize> {
+        let len = match self {
+            Algorithm::MlKem512 => 768,
+            Algorithm::MlKem768 => 1088,
+            Algorithm::MlKem1024 => 1568,
+            Algorithm::CbKem348864 => 96,
+            Algorithm::CbKem460896 => 156,
+            Algorithm::CbKem6688128 => 208,
+            Algorithm::CbKem6960119 => 194,
+            Algorithm::CbKem8192128 => 208,
+            Algorithm::Hqc128 => hqc::HQC128_CIPHERTEXT_BYTES,
+            Algorithm::Hqc192 => hqc::HQC192_CIPHERTEXT_BYTES,
+            Algorithm::Hqc256 => hqc::HQC256_CIPHERTEXT_BYTES,
+            _ => return None,
+        };
+        Some(len)
+    }
+
+    /// Signature length in bytes, or `None` for non-signature algorithms
+    ///
+    /// FN-DSA signatures are produced in the fixed-length padded encoding.
+    pub fn signature_len(&self) -> Option<usize> {
+        let len = match self {
+            Algorithm::MlDsa44 => 2420,
+            Algorithm::MlDsa65 => 3309,
+            Algorithm::MlDsa87 => 4627,
+            Algorithm::FnDsa | Algorithm::FnDsa512 => 666,
+            Algorithm::FnDsa1024 => 1280,
+            Algorithm::SlhDsaSha256128fRobust | Algorithm::SlhDsaShake256128fRobust => 17088,
+            Algorithm::SlhDsaSha256192fRobust | Algorithm::SlhDsaShake256192fRobust => 35664,
+            Algorithm::SlhDsaSha256256fRobust | Algorithm::SlhDsaShake256256fRobust => 49856,
+            _ => return None,
+        };
+        Some(len)
+    }
+
+    /// Fixed digest length in bytes, or `None` for XOFs, variable-output hashes and non-hashes
+    pub fn digest_len(&self) -> Option<usize> {
+        let len = match self {
+            Algorithm::Sha3_224 | Algorithm::Keccak224 | Algorithm::Sha224 => 28,
+            Algorithm::Sha512_224 => 28,
+            Algorithm::Sha3_256 | Algorithm::Keccak256 | Algorithm::Sha256 => 32,
+            Algorithm::Sha512_256 => 32,
+            Algorithm::Sha3_384 | Algorithm::Keccak384 | Algorithm::Sha384 => 48,
+            Algorithm::Sha3_512 | Algorithm::Keccak512 | Algorithm::Sha512 => 64,
+            _ => return None,
+        };
+        Some(len)
+    }
+
+    /// Keccak-f[1600] sponge rate in bytes, or `None` for algorithms that are not a Keccak hash
+    ///
+    /// The rate is the 200-byte state minus the capacity: twice the digest length for SHA-3
+    /// and Keccak, and 32 or 64 bytes for the 128- and 256-bit XOFs and the SP 800-185,
+    /// KangarooTwelve and TurboSHAKE constructions built on them.
+    pub fn sponge_rate_bytes(&self) -> Option<usize> {
+        let rate = match self {
+            Algorithm::Sha3_224 | Algorithm::Keccak224 => 144,
+            Algorithm::Sha3_256 | Algorithm::Keccak256 => 136,
+            Algorithm::Sha3_384 | Algorithm::Keccak384 => 104,
+            Algorithm::Sha3_512 | Algorithm::Keccak512 => 72,
+            Algorithm::Shake128 |
+            Algorithm::CShake128 |
+            Algorithm::Kmac128 |
+            Algorithm::TupleHash128 |
+            Algorithm::ParallelHash128 |
+            Algorithm::Kt128 |
+            Algorithm::TurboShake128 => 168,
+            Algorithm::Shake256 |
+            Algorithm::CShake256 |
+            Algorithm::Kmac256 |
+            Algorithm::TupleHash256 |
+            Algorithm::ParallelHash256 |
+            Algorithm::Kt256 |
+            Algorithm::TurboShake256 => 136,
+            _ => return None,
+        };
+        Some(rate)
+    }
+
+    /// AEAD nonce length in bytes, or `None` for non-AEAD algorithms
+    pub fn nonce_len(&self) -> Option<usize> {
+        match self {
+            Algorithm::Saturnin |
+            Algorithm::Shake256Aead |
+            Algorithm::DuplexSpongeAead |
+            Algorithm::TweakAead |
+            Algorithm::RomulusN |
+            Algorithm::RomulusM |
+            Algorithm::RoccaS => Some(16),
+            _ => None,
+        }
+    }
+
+    /// Stable 16-bit identifier for binary wire formats
+    ///
+    /// Ids are assigned explicitly, never derived from the enum discriminant, so adding or
+    /// reordering variants does not change them. Once published an id is never reused.
+    ///
+    /// | Range      | Family                                             |
+    /// |------------|----------------------------------------------------|
+    /// | `0x00xx`   | ML-KEM                                             |
+    /// | `0x01xx`   | Classic McEliece                                   |
+    /// | `0x02xx`   | HQC                                                |
+    /// | `0x10xx`   | ML-DSA                                             |
+    /// | `0x11xx`   | FN-DSA                                             |
+    /// | `0x12xx`   | SLH-DSA                                            |
+    /// | `0x13xx`   | Reserved signatures                                |
+    /// | `0x20xx`   | SHA-3, Keccak and SHA-3 derived functions          |
+    /// | `0x21xx`   | SHA-2                                              |
+    /// | `0x30xx`   | AEAD                                               |
+    /// | `0x40xx`   | Privacy protocols                                  |
+    ///
+    /// The KEM and signature ids are the ones used by the serialized key pair format, and the
+    /// KEM and AEAD ids the ones used by envelope frames.
+    pub const fn wire_id(&self) -> u16 {
+        match self {
+            Algorithm::MlKem512 => 0x0001,
+            Algorithm::MlKem768 => 0x0002,
+            Algorithm::MlKem1024 => 0x0003,
+            Algorithm::CbKem348864 => 0x0101,
+            Algorithm::CbKem460896 => 0x0102,
+            Algorithm::CbKem6688128 => 0x0103,
+            Algorithm::CbKem6960119 => 0x0104,
+            Algorithm::CbKem8192128 => 0x0105,
+            Algorithm::Hqc128 => 0x0201,
+            Algorithm::Hqc192 => 0x0202,
+            Algorithm::Hqc256 => 0x0203,
+
+            Algorithm::MlDsa44 => 0x1001,
+            Algorithm::MlDsa65 => 0x1002,
+            Algorithm::MlDsa87 => 0x1003,
+            Algorithm::FnDsa => 0x1101,
+            Algorithm::FnDsa512 => 0x1102,
+            Algorithm::FnDsa1024 => 0x1103,
+            Algorithm::SlhDsaSha256128fRobust => 0x1201,
+            Algorithm::SlhDsaSha256192fRobust => 0x1202,
+            Algorithm::SlhDsaSha256256fRobust => 0x1203,
+            Algorithm::SlhDsaShake256128fRobust => 0x1204,
+            Algorithm::SlhDsaShake256192fRobust => 0x1205,
+            Algorithm::SlhDsaShake256256fRobust => 0x1206,
+            Algorithm::FaestReserved => 0x1301,
+
+            Algorithm::Shake128 => 0x2001,
+            Algorithm::Shake256 => 0x2002,
+            Algorithm::CShake128 => 0x2003,
+            Algorithm::CShake256 => 0x2004,
+            Algorithm::Sha3_224 => 0x2011,
+            Algorithm::Sha3_256 => 0x2012,
+            Algorithm::Sha3_384 => 0x2013,
+            Algorithm::Sha3_512 => 0x2014,
+            Algorithm::Keccak224 => 0x2021,
+            Algorithm::Keccak256 => 0x2022,
+            Algorithm::Keccak384 => 0x2023,
+            Algorithm::Keccak512 => 0x2024,
+            Algorithm::Kt128 => 0x2031,
+            Algorithm::Kt256 => 0x2032,
+            Algorithm::TurboShake128 => 0x2041,
+            Algorithm::TurboShake256 => 0x2042,
+            Algorithm::Kmac128 => 0x2051,
+            Algorithm::Kmac256 => 0x2052,
+            Algorithm::TupleHash128 => 0x2061,
+            Algorithm::TupleHash256 => 0x2062,
+            Algorithm::ParallelHash128 => 0x2071,
+            Algorithm::ParallelHash256 => 0x2072,
+            Algorithm::Sha224 => 0x2101,
+            Algorithm::Sha256 => 0x2102,
+            Algorithm::Sha384 => 0x2103,
+            Algorithm::Sha512 => 0x2104,
+            Algorithm::Sha512_224 => 0x2105,
+            Algorithm::Sha512_256 => 0x2106,
+
+            Algorithm::Saturnin => 0x3001,
+            Algorithm::Shake256Aead => 0x3002,
+            Algorithm::DuplexSpongeAead => 0x3003,
+            Algorithm::TweakAead => 0x3004,
+            Algorithm::RomulusN => 0x3005,
+            Algorithm::RomulusM => 0x3006,
+            Algorithm::RoccaS => 0x3007,
+
+            Algorithm::LatticeRingSignature => 0x4001,
+            Algorithm::LatticeBlindIssuance => 0x4002,
+            Algorithm::LatticeAnonymousToken => 0x4003,
+            Algorithm::LatticeNullifierRegistry => 0x4004,
+            Algorithm::LatticeWitnessNullifier => 0x4005,
+            Algorithm::LatticeDualRingLb => 0x4006,
+            Algorithm::MixOnionRouting => 0x4007,
+            Algorithm::SessionResumptionBinding => 0x4008,
+        }
+    }
+
+    /// Inverse of [`wire_id`](Self::wire_id); `None` for an unassigned id
+    pub fn from_wire_id(id: u16) -> Option<Algorithm> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|algorithm| algorithm.wire_id() == id)
+    }
+
     /// Check if an algorithm supports a specific category
     pub fn supports_category(&self, category: AlgorithmCategory) -> bool {
         match self {
@@ -517,4 +888,186 @@ mod tests {
             AlgorithmCategory::PrivacyProtocol
         );
     }
+
+    #[test]
+    fn test_recommended_algorithms() {
+        let expected = [
+            (
+                AlgorithmCategory::Kem,
+                SecurityLevel::Level1,
+                Algorithm::MlKem512,
+            ),
+            (
+                AlgorithmCategory::Kem,
+                SecurityLevel::Level3,
+                Algorithm::MlKem768,
+            ),
+            (
+                AlgorithmCategory::Kem,
+                SecurityLevel::Level4,
+                Algorithm::MlKem1024,
+            ),
+            (
+                AlgorithmCategory::Signature,
+                SecurityLevel::Level1,
+                Algorithm::MlDsa44,
+            ),
+            (
+                AlgorithmCategory::Signature,
+                SecurityLevel::Level3,
+                Algorithm::MlDsa65,
+            ),
+            (
+                AlgorithmCategory::Signature,
+                SecurityLevel::Level4,
+                Algorithm::MlDsa87,
+            ),
+            (
+                AlgorithmCategory::Signature,
+                SecurityLevel::Level5,
+                Algorithm::SlhDsaShake256256fRobust,
+            ),
+        ];
+        for (category, level, algorithm) in expected {
+            assert_eq!(Algorithm::recommended(category, level), Some(algorithm));
+            assert_eq!(algorithm.category(), category);
+            assert_eq!(algorithm.security_level(), level.as_u32());
+        }
+
+        assert_eq!(
+            Algorithm::recommended(AlgorithmCategory::Kem, SecurityLevel::Level5),
+            None
+        );
+        for category in [
+            AlgorithmCategory::Hash,
+            AlgorithmCategory::Aead,
+            AlgorithmCategory::PrivacyProtocol,
+        ] {
+            for level in [
+                SecurityLevel::Level1,
+                SecurityLevel::Level3,
+                SecurityLevel::Level4,
+                SecurityLevel::Level5,
+            ] {
+                assert_eq!(Algorithm::recommended(category, level), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_size_metadata_covers_every_kem_and_signature() {
+        for algorithm in Algorithm::algorithms_by_category(AlgorithmCategory::Kem) {
+            assert!(algorithm.public_key_len().is_some(), "{algorithm:?}");
+            assert!(algorithm.secret_key_len().is_some(), "{algorithm:?}");
+            assert!(algorithm.ciphertext_len().is_some(), "{algorithm:?}");
+            assert_eq!(algorithm.signature_len(), None);
+        }
+        for algorithm in Algorithm::algorithms_by_category(AlgorithmCategory::Signature)
+            .filter(|algorithm| *algorithm != Algorithm::FaestReserved)
+        {
+            assert!(algorithm.public_key_len().is_some(), "{algorithm:?}");
+            assert!(algorithm.secret_key_len().is_some(), "{algorithm:?}");
+            assert!(algorithm.signature_len().is_some(), "{algorithm:?}");
+            assert_eq!(algorithm.ciphertext_len(), None);
+        }
+
+        assert_eq!(Algorithm::Sha3_256.digest_len(), Some(32));
+        assert_eq!(Algorithm::Sha512_224.digest_len(), Some(28));
+        assert_eq!(Algorithm::Keccak512.digest_len(), Some(64));
+        assert_eq!(Algorithm::Shake256.digest_len(), None);
+        assert_eq!(Algorithm::Kmac128.digest_len(), None);
+        assert_eq!(Algorithm::MlKem768.digest_len(), None);
+        assert_eq!(Algorithm::Shake256Aead.public_key_len(), None);
+
+        for algorithm in Algorithm::algorithms_by_category(AlgorithmCategory::Aead) {
+            assert!(algorithm.nonce_len().is_some(), "{algorithm:?}");
+        }
+        assert_eq!(Algorithm::MlKem768.nonce_len(), None);
+    }
+
+    #[test]
+    fn test_sponge_rates_match_sha3_parameters() {
+        const STATE_BYTES: usize = 200;
+        for algorithm in [
+            Algorithm::Sha3_224,
+            Algorithm::Sha3_256,
+            Algorithm::Sha3_384,
+            Algorithm::Sha3_512,
+            Algorithm::Keccak224,
+            Algorithm::Keccak512,
+        ] {
+            let capacity = 2 * algorithm.digest_len().unwrap();
+            assert_eq!(
+                algorithm.sponge_rate_bytes(),
+                Some(STATE_BYTES - capacity),
+                "{algorithm:?}"
+            );
+        }
+        assert_eq!(Algorithm::Shake128.sponge_rate_bytes(), Some(168));
+        assert_eq!(Algorithm::Shake256.sponge_rate_bytes(), Some(136));
+        assert_eq!(Algorithm::Kmac128.sponge_rate_bytes(), Some(168));
+        assert_eq!(Algorithm::TupleHash256.sponge_rate_bytes(), Some(136));
+        assert_eq!(Algorithm::Kt128.sponge_rate_bytes(), Some(168));
+
+        for algorithm in [
+            Algorithm::Sha256,
+            Algorithm::Sha512,
+            Algorithm::MlKem768,
+            Algorithm::MlDsa65,
+            Algorithm::Shake256Aead,
+        ] {
+            assert_eq!(algorithm.sponge_rate_bytes(), None, "{algorithm:?}");
+        }
+        for algorithm in Algorithm::algorithms_by_category(AlgorithmCategory::Hash) {
+            if let Some(rate) = algorithm.sponge_rate_bytes() {
+                assert!(rate < STATE_BYTES && rate % 8 == 0, "{algorithm:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_wire_ids_are_unique_and_round_trip() {
+        for (i, algorithm) in Algorithm::ALL.iter().enumerate() {
+            let id = algorithm.wire_id();
+            assert_eq!(Algorithm::from_wire_id(id), Some(*algorithm));
+            for other in &Algorithm::ALL[i + 1..] {
+                assert_ne!(id, other.wire_id(), "{algorithm:?} and {other:?}");
+            }
+        }
+        assert_eq!(Algorithm::from_wire_id(0x0000), None);
+        assert_eq!(Algorithm::from_wire_id(0xFFFF), None);
+
+        // Published ids must never change
+        assert_eq!(Algorithm::MlKem768.wire_id(), 0x0002);
+        assert_eq!(Algorithm::Hqc256.wire_id(), 0x0203);
+        assert_eq!(Algorithm::MlDsa65.wire_id(), 0x1002);
+        assert_eq!(Algorithm::SlhDsaShake256256fRobust.wire_id(), 0x1206);
+        assert_eq!(Algorithm::Sha3_256.wire_id(), 0x2012);
+        assert_eq!(Algorithm::Saturnin.wire_id(), 0x3001);
+        assert_eq!(Algorithm::SessionResumptionBinding.wire_id(), 0x4008);
+    }
+
+    #[test]
+    fn test_algorithms_by_category() {
+        assert_eq!(
+            Algorithm::algorithms_by_category(AlgorithmCategory::Kem).count(),
+            11
+        );
+        assert!(
+            Algorithm::algorithms_by_category(AlgorithmCategory::Signature)
+                .all(|algorithm| algorithm.category() == AlgorithmCategory::Signature)
+        );
+        let level3_kems: [Algorithm; 3] = [
+            Algorithm::MlKem768,
+            Algorithm::CbKem460896,
+            Algorithm::Hqc192,
+        ];
+        assert!(
+            Algorithm::algorithms_by_category_and_level(
+                AlgorithmCategory::Kem,
+                SecurityLevel::Level3
+            )
+            .eq(level3_kems)
+        );
+    }
 }
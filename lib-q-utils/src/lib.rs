@@ -7,14 +7,40 @@
 // bare-metal `no_std` build (no entropy on `*-none-*` targets), so this crate does not declare
 // `#![no_std]`. A `no_std` consumer should depend on `lib-q-core` / `lib-q-random` directly.
 
+use lib_q_core::Error;
+use lib_q_core::labels::{
+    LABEL_KDF_EXPAND,
+    LABEL_KDF_EXTRACT,
+};
 // Re-export core types for public use
 pub use lib_q_core::{
     Result,
     Utils,
 };
+use lib_q_sha3::{
+    CShake256,
+    CustomizedInit,
+    ExtendableOutput,
+    Update,
+};
+use zeroize::{
+    Zeroize,
+    Zeroizing,
+};
+
+/// Largest output accepted by [`derive_key`] (1 MiB, the same bound as [`random_bytes`])
+pub const MAX_DERIVED_KEY_SIZE: usize = 1024 * 1024;
+
+/// Pseudorandom key size produced by the extract step of [`derive_key`]
+const KDF_PRK_SIZE: usize = 64;
 
 // Security validation module
 pub mod security_validation;
+#[cfg(feature = "testing")]
+pub use lib_q_core::testing;
+
+#[cfg(feature = "testing")]
+pub use testing::SeededRng;
 
 /// Constant-time comparison of two byte slices
 ///
@@ -32,6 +58,34 @@ pub fn constant_time_compare(a: &[u8], b: &[u8]) -> bool {
     Utils::constant_time_compare(a, b)
 }
 
+/// Constant-time equality of two byte slices, including their lengths
+///
+/// Length mismatches are folded into the comparison instead of returning early, so
+/// timing depends only on the longer input. Used by the `PartialEq` impls of the
+/// secret key types in `lib_q_core`.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    Utils::constant_time_eq(a, b)
+}
+
+/// Constant-time selection between two equal-length byte slices
+///
+/// Returns a copy of `a` when `choice` is `false` and of `b` when it is `true`, using a
+/// mask derived from `choice` instead of a branch. Building block for implicit rejection,
+/// hybrid combiners and padding; see [`Utils::ct_select`].
+pub fn ct_select(a: &[u8], b: &[u8], choice: bool) -> Vec<u8> {
+    Utils::ct_select(a, b, choice)
+}
+
+/// Overwrite `data` with zeros in a way the compiler cannot elide
+///
+/// Backed by [`zeroize::Zeroize`], which writes every byte with a volatile store and then
+/// issues a compiler fence. A plain loop or `fill(0)` on a buffer that is never read again
+/// is a dead store the optimizer may remove; these writes are kept. The slice length is
+/// unchanged.
+pub fn secure_zeroize(data: &mut [u8]) {
+    data.zeroize();
+}
+
 /// Generate cryptographically secure random bytes
 ///
 /// # Arguments
@@ -49,6 +103,18 @@ pub fn random_bytes(length: usize) -> Result<Vec<u8>> {
     Utils::random_bytes(length)
 }
 
+/// Generate cryptographically secure random bytes, capped at `max` instead of 1 MiB
+///
+/// [`random_bytes`] keeps its 1 MiB cap as a guard against attacker-controlled lengths;
+/// use this when a larger buffer (a one-time pad, test data) is intended.
+///
+/// # Errors
+///
+/// Returns an error if `length` is zero or above `max`, or if random number generation fails
+pub fn random_bytes_with_limit(length: usize, max: usize) -> Result<Vec<u8>> {
+    Utils::random_bytes_with_limit(length, max)
+}
+
 /// Generate a random nonce
 ///
 /// # Arguments
@@ -87,7 +153,9 @@ pub fn bytes_to_hex(bytes: &[u8]) -> String {
 ///
 /// # Errors
 ///
-/// Returns an error if the hex string is invalid
+/// Returns [`Error::HexDecode`] if the hex string has odd length or contains a non-hex
+/// character; [`HexDecodeError::InvalidDigit`](lib_q_core::HexDecodeError::InvalidDigit)
+/// carries the index of the first offending character.
 pub fn hex_to_bytes(hex: &str) -> Result<Vec<u8>> {
     Utils::hex_to_bytes(hex)
 }
@@ -105,6 +173,48 @@ pub fn random_key(size: usize) -> Result<Vec<u8>> {
     Utils::random_bytes(size)
 }
 
+/// Derive `out_len` bytes of key material from input keying material
+///
+/// Extract-then-expand in the style of HKDF (RFC 5869), with cSHAKE256 in place of HMAC:
+///
+/// 1. **Extract:** `PRK = cSHAKE256(len(salt) || salt || ikm, 512, "", "lib-Q KDF extract")`
+/// 2. **Expand:** `OKM = cSHAKE256(PRK || len(info) || info || out_len, 8 * out_len, "", "lib-Q KDF expand")`
+///
+/// The customization strings are [`LABEL_KDF_EXTRACT`] and [`LABEL_KDF_EXPAND`]. Lengths are
+/// encoded as 64-bit big-endian integers, so distinct `(salt, ikm)` and `info` splits never
+/// collide, and the requested length is bound into the output: a 32-byte key is not a prefix
+/// of a 64-byte key derived from the same inputs. Use a distinct `info` per derived key, e.g.
+/// to split one KEM shared secret into encryption and MAC keys.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidMessageSize`](lib_q_core::Error::InvalidMessageSize) when
+/// `out_len` is zero or exceeds [`MAX_DERIVED_KEY_SIZE`].
+pub fn derive_key(ikm: &[u8], salt: &[u8], info: &[u8], out_len: usize) -> Result<Vec<u8>> {
+    if out_len == 0 || out_len > MAX_DERIVED_KEY_SIZE {
+        return Err(Error::InvalidMessageSize {
+            max: MAX_DERIVED_KEY_SIZE,
+            actual: out_len,
+        });
+    }
+
+    let mut extract = CShake256::new_customized(LABEL_KDF_EXTRACT);
+    extract.update(&(salt.len() as u64).to_be_bytes());
+    extract.update(salt);
+    extract.update(ikm);
+    let mut prk = Zeroizing::new([0u8; KDF_PRK_SIZE]);
+    extract.finalize_xof_into(prk.as_mut_slice());
+
+    let mut expand = CShake256::new_customized(LABEL_KDF_EXPAND);
+    expand.update(prk.as_slice());
+    expand.update(&(info.len() as u64).to_be_bytes());
+    expand.update(info);
+    expand.update(&(out_len as u64).to_be_bytes());
+    let mut okm = vec![0u8; out_len];
+    expand.finalize_xof_into(&mut okm);
+    Ok(okm)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,6 +230,33 @@ mod tests {
         assert!(!constant_time_compare(a, b"hell"));
     }
 
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"key", b"key"));
+        assert!(!constant_time_eq(b"key", b"kez"));
+        assert!(!constant_time_eq(b"key", b"key\0"));
+        assert!(constant_time_eq(b"", b""));
+    }
+
+    #[test]
+    fn test_ct_select() {
+        let secret = b"real shared key!";
+        let fallback = b"implicit reject!";
+        assert_eq!(ct_select(secret, fallback, false), secret);
+        assert_eq!(ct_select(secret, fallback, true), fallback);
+    }
+
+    #[test]
+    fn test_secure_zeroize() {
+        let mut key = random_bytes(64).expect("Should generate random bytes");
+        key[0] = 0xFF;
+        secure_zeroize(&mut key);
+        assert_eq!(key.len(), 64);
+        assert!(key.iter().all(|&b| b == 0));
+
+        secure_zeroize(&mut []);
+    }
+
     #[test]
     fn test_random_bytes() {
         let bytes = random_bytes(32).expect("Should generate random bytes");
@@ -136,6 +273,18 @@ mod tests {
         assert!(random_bytes(1024 * 1024 + 1).is_err());
     }
 
+    #[test]
+    fn test_random_bytes_with_limit() {
+        assert_eq!(random_bytes_with_limit(64, 64).unwrap().len(), 64);
+        assert!(random_bytes_with_limit(65, 64).is_err());
+        assert_eq!(
+            random_bytes_with_limit(2 * 1024 * 1024, usize::MAX)
+                .unwrap()
+                .len(),
+            2 * 1024 * 1024
+        );
+    }
+
     #[test]
     fn test_bytes_to_hex() {
         let bytes = vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
@@ -156,6 +305,34 @@ mod tests {
         assert!(hex_to_bytes("12g3").is_err()); // Invalid character
     }
 
+    #[test]
+    fn test_hex_to_bytes_reports_invalid_digit_position() {
+        use lib_q_core::HexDecodeError;
+
+        let position = |hex: &str| match hex_to_bytes(hex) {
+            Err(Error::HexDecode(HexDecodeError::InvalidDigit { position, .. })) => position,
+            other => panic!("{hex:?}: expected InvalidDigit, got {other:?}"),
+        };
+        assert_eq!(position("x0112233"), 0);
+        assert_eq!(position("0011z233"), 4);
+        assert_eq!(position("001122g3"), 6);
+        assert_eq!(position("0011223G"), 7);
+        // Only the first offending digit is reported.
+        assert_eq!(position("00qq22zz"), 2);
+        // A sign is not a hex digit, although `from_str_radix` would accept it.
+        assert_eq!(position("+f"), 0);
+        // Multi-byte characters are rejected at their first byte instead of panicking.
+        assert_eq!(position("0aé"), 2);
+        assert_eq!(position("aé0"), 1);
+
+        assert_eq!(
+            hex_to_bytes("abc"),
+            Err(Error::HexDecode(HexDecodeError::OddLength {
+                char_count: 3
+            }))
+        );
+    }
+
     #[test]
     fn test_hex_roundtrip() {
         let original = vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
@@ -170,6 +347,32 @@ mod tests {
         assert_eq!(nonce.len(), 16);
     }
 
+    #[test]
+    fn test_derive_key() {
+        let ikm = [0x0Bu8; 32];
+        let salt = b"salt";
+
+        let enc = derive_key(&ikm, salt, b"encryption", 32).unwrap();
+        let mac = derive_key(&ikm, salt, b"mac", 32).unwrap();
+        assert_eq!(enc.len(), 32);
+        assert_ne!(enc, mac);
+        assert_eq!(enc, derive_key(&ikm, salt, b"encryption", 32).unwrap());
+
+        assert_ne!(
+            enc,
+            derive_key(&ikm, b"other salt", b"encryption", 32).unwrap()
+        );
+        let long = derive_key(&ikm, salt, b"encryption", 64).unwrap();
+        assert_ne!(&long[..32], enc.as_slice());
+    }
+
+    #[test]
+    fn test_derive_key_invalid_length() {
+        assert!(derive_key(b"ikm", b"", b"", 0).is_err());
+        assert!(derive_key(b"ikm", b"", b"", MAX_DERIVED_KEY_SIZE + 1).is_err());
+        assert!(derive_key(b"ikm", b"", b"", MAX_DERIVED_KEY_SIZE).is_ok());
+    }
+
     #[test]
     fn test_random_key() {
         let key = random_key(32).expect("Should generate random key");
@@ -0,0 +1,324 @@
+//! SHAKE256 Hash Preimage AIR - Proves knowledge of a SHAKE256 preimage
+//!
+//! This AIR proves that the prover knows a preimage `x` such that the first 32 bytes of
+//! `SHAKE256(x)` equal a public digest.
+//!
+//! # Design
+//!
+//! The preimage is absorbed as a single SHAKE256 block (rate 136 bytes), so `x` is limited to
+//! [`MAX_PREIMAGE_SIZE`] bytes and the 32-byte output is read from the state after one
+//! Keccak-f\[1600\] permutation. The trace is the [`KeccakAir`] layout (one row per round,
+//! 24 rounds padded to 32 rows); on top of the permutation constraints this AIR adds:
+//!
+//! - **Padding / capacity binding (first row):** every state byte at or beyond the preimage
+//!   length must equal the SHAKE256 pad (`0x1F ... 0x80`) or zero capacity. Whole 16-bit limbs
+//!   are constrained directly; the limb straddling the end of an odd-length preimage is
+//!   constrained bit-by-bit on its high byte.
+//! - **Output binding (final round):** the first 16 output limbs (32 bytes, little-endian 16-bit
+//!   limbs) must equal the public values.
+//!
+//! The preimage length is a public parameter of the AIR (carried in the proof metadata) since it
+//! fixes the padding position.
+//!
+//! # Security
+//!
+//! SHAKE256 is NIST-approved (FIPS 202). Full Keccak-f constraints are enforced by
+//! `lib-q-plonky-keccak-air`; the preimage is witness and only the digest and length are public.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use digest::{
+    ExtendableOutput,
+    Update,
+};
+use lib_q_plonky_keccak_air::{
+    KeccakAir,
+    KeccakColsRef,
+    NUM_KECCAK_COLS,
+    NUM_ROUNDS_MIN_1,
+    U64_LIMBS,
+    generate_trace_rows,
+};
+use lib_q_sha3::Shake256;
+use lib_q_stark_air::{
+    Air,
+    AirBuilder,
+    BaseAir,
+    WindowAccess,
+};
+use lib_q_stark_field::extension::Complex;
+use lib_q_stark_field::{
+    Field,
+    PrimeCharacteristicRing,
+};
+use lib_q_stark_matrix::dense::RowMajorMatrix;
+use lib_q_stark_mersenne31::Mersenne31;
+
+use super::{
+    AirError,
+    TraceGenerator,
+    validate_trace_dimensions,
+};
+
+/// SHAKE256 rate in bytes (1088 bits).
+pub const SHAKE256_RATE_BYTES: usize = 136;
+
+/// Maximum preimage size in bytes: one rate block minus the mandatory pad byte.
+pub const MAX_PREIMAGE_SIZE: usize = SHAKE256_RATE_BYTES - 1;
+
+/// SHAKE256 output size bound by the proof, in bytes.
+pub const SHAKE_OUTPUT_BYTES: usize = 32;
+
+/// Number of 16-bit public values encoding the digest.
+const OUTPUT_LIMBS: usize = SHAKE_OUTPUT_BYTES / 2;
+
+/// Bytes per Keccak lane.
+const LANE_BYTES: usize = 8;
+
+/// Number of lanes in the Keccak-f\[1600\] state.
+const NUM_LANES: usize = 25;
+
+/// AIR for proving knowledge of a single-block SHAKE256 preimage of a fixed length.
+#[derive(Debug, Clone)]
+pub struct HashPreimageShakeAir {
+    preimage_len: usize,
+}
+
+impl HashPreimageShakeAir {
+    /// Create a new HashPreimageShakeAir for preimages of `preimage_len` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AirError::InvalidInput`] if `preimage_len` is zero or exceeds
+    /// [`MAX_PREIMAGE_SIZE`].
+    pub fn new(preimage_len: usize) -> Result<Self, AirError> {
+        if preimage_len == 0 {
+            return Err(AirError::InvalidInput {
+                reason: "Preimage cannot be empty".into(),
+            });
+        }
+        if preimage_len > MAX_PREIMAGE_SIZE {
+            return Err(AirError::InvalidInput {
+                reason: alloc::format!(
+                    "Preimage size {} exceeds maximum {}",
+                    preimage_len,
+                    MAX_PREIMAGE_SIZE
+                ),
+            });
+        }
+        Ok(Self { preimage_len })
+    }
+
+    /// Preimage length (in bytes) this AIR is specialised for.
+    pub fn preimage_len(&self) -> usize {
+        self.preimage_len
+    }
+
+    /// Public byte value of state byte `index` (padding or capacity), or `None` for a
+    /// preimage (witness) byte.
+    fn known_state_byte(&self, index: usize) -> Option<u8> {
+        if index < self.preimage_len {
+            return None;
+        }
+        let mut byte = 0u8;
+        if index == self.preimage_len {
+            byte ^= 0x1F;
+        }
+        if index == SHAKE256_RATE_BYTES - 1 {
+            byte ^= 0x80;
+        }
+        Some(byte)
+    }
+}
+
+impl<F> BaseAir<F> for HashPreimageShakeAir {
+    fn width(&self) -> usize {
+        NUM_KECCAK_COLS
+    }
+}
+
+impl<AB: AirBuilder> Air<AB> for HashPreimageShakeAir
+where
+    AB::F: Field,
+{
+    fn eval(&self, builder: &mut AB) {
+        // Full Keccak-f[1600] round constraints.
+        KeccakAir {}.eval(builder);
+
+        let main = builder.main();
+        let local = KeccakColsRef::from_row_slice(main.current_slice());
+
+        // SOUNDNESS: bind the absorbed block to `pad(x)` with zero capacity. Without this the
+        // prover could pick an arbitrary 1600-bit input state.
+        for lane in 0..NUM_LANES {
+            let (y, x) = (lane / 5, lane % 5);
+            for limb in 0..U64_LIMBS {
+                let lo_index = lane * LANE_BYTES + limb * 2;
+                let lo = self.known_state_byte(lo_index);
+                let hi = self.known_state_byte(lo_index + 1);
+                match (lo, hi) {
+                    (Some(lo), Some(hi)) => {
+                        let value = u16::from_le_bytes([lo, hi]);
+                        builder
+                            .when_first_row()
+                            .assert_eq(local.preimage(y, x, limb), AB::Expr::from_u16(value));
+                    }
+                    (None, Some(hi)) => {
+                        // Low byte is the last preimage byte, high byte is the pad: constrain
+                        // the high byte through the bit decomposition of A on the first row.
+                        for bit in 0..8 {
+                            let z = limb * 16 + 8 + bit;
+                            let state_bit = local
+                                .a_prime(y, x, z)
+                                .into()
+                                .xor3(&local.c(x, z).into(), &local.c_prime(x, z).into());
+                            builder
+                                .when_first_row()
+                                .assert_eq(state_bit, AB::Expr::from_bool((hi >> bit) & 1 == 1));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // SOUNDNESS: bind the squeezed output (first 32 bytes of the state after the final
+        // round) to the public digest.
+        let pubs: Vec<AB::Expr> = builder.public_values().iter().map(|&p| p.into()).collect();
+        if pubs.len() != OUTPUT_LIMBS {
+            // Malformed statement: no proof may verify against it.
+            builder.assert_zero(AB::Expr::ONE);
+            return;
+        }
+        let final_step = local.step_flags(NUM_ROUNDS_MIN_1);
+        for (i, expected) in pubs.into_iter().enumerate() {
+            let (lane, limb) = (i / U64_LIMBS, i % U64_LIMBS);
+            builder
+                .when(final_step)
+                .assert_eq(local.a_prime_prime_prime(0, lane, limb), expected);
+        }
+    }
+}
+
+/// Input type for HashPreimageShakeAir trace generation.
+pub type HashPreimageShakeInput = Vec<u8>;
+
+/// Compute the 32-byte SHAKE256 digest bound by [`HashPreimageShakeAir`].
+pub fn shake256_digest(preimage: &[u8]) -> [u8; SHAKE_OUTPUT_BYTES] {
+    let mut hasher = Shake256::default();
+    hasher.update(preimage);
+    let mut out = [0u8; SHAKE_OUTPUT_BYTES];
+    hasher.finalize_xof_into(&mut out);
+    out
+}
+
+/// Encode a 32-byte digest as 16 public values (little-endian 16-bit limbs).
+///
+/// Returns `None` if `digest` is not exactly [`SHAKE_OUTPUT_BYTES`] long.
+pub fn digest_to_public_values(digest: &[u8]) -> Option<Vec<Complex<Mersenne31>>> {
+    if digest.len() != SHAKE_OUTPUT_BYTES {
+        return None;
+    }
+    Some(
+        digest
+            .chunks_exact(2)
+            .map(|pair| {
+                let limb = u16::from_le_bytes([pair[0], pair[1]]);
+                Complex::from(Mersenne31::new(u32::from(limb)))
+            })
+            .collect(),
+    )
+}
+
+impl TraceGenerator<Complex<Mersenne31>, HashPreimageShakeInput> for HashPreimageShakeAir {
+    fn generate_trace(
+        &self,
+        inputs: &HashPreimageShakeInput,
+    ) -> Result<RowMajorMatrix<Complex<Mersenne31>>, AirError> {
+        if inputs.len() != self.preimage_len {
+            return Err(AirError::InvalidInput {
+                reason: alloc::format!(
+                    "Preimage size {} does not match AIR preimage length {}",
+                    inputs.len(),
+                    self.preimage_len
+                ),
+            });
+        }
+
+        let mut block = [0u8; NUM_LANES * LANE_BYTES];
+        block[..inputs.len()].copy_from_slice(inputs);
+        for (index, byte) in block.iter_mut().enumerate().skip(inputs.len()) {
+            *byte = self.known_state_byte(index).unwrap_or(0);
+        }
+        let mut state = [0u64; NUM_LANES];
+        for (lane, chunk) in state.iter_mut().zip(block.chunks_exact(LANE_BYTES)) {
+            let mut bytes = [0u8; LANE_BYTES];
+            bytes.copy_from_slice(chunk);
+            *lane = u64::from_le_bytes(bytes);
+        }
+
+        let base: RowMajorMatrix<Mersenne31> = generate_trace_rows(alloc::vec![state], 0);
+        validate_trace_dimensions(NUM_KECCAK_COLS, base.values.len() / NUM_KECCAK_COLS)?;
+        let values = base.values.into_iter().map(Complex::from).collect();
+        Ok(RowMajorMatrix::new(values, NUM_KECCAK_COLS))
+    }
+
+    fn public_values(&self, inputs: &HashPreimageShakeInput) -> Vec<Complex<Mersenne31>> {
+        digest_to_public_values(&shake256_digest(inputs)).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lib_q_stark_matrix::Matrix;
+
+    use super::*;
+    use crate::check_constraints;
+
+    type TestField = Complex<Mersenne31>;
+
+    #[test]
+    fn test_hash_preimage_shake_air_rejects_bad_lengths() {
+        assert!(matches!(
+            HashPreimageShakeAir::new(0),
+            Err(AirError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            HashPreimageShakeAir::new(MAX_PREIMAGE_SIZE + 1),
+            Err(AirError::InvalidInput { .. })
+        ));
+        assert!(HashPreimageShakeAir::new(MAX_PREIMAGE_SIZE).is_ok());
+    }
+
+    #[test]
+    fn test_hash_preimage_shake_trace_satisfies_constraints() {
+        // Even length, odd length (straddling limb) and a full block (pad byte 0x9F).
+        for len in [32usize, 7, MAX_PREIMAGE_SIZE] {
+            let preimage: Vec<u8> = (0..len as u8).collect();
+            let air = HashPreimageShakeAir::new(len).unwrap();
+            let trace: RowMajorMatrix<TestField> = air.generate_trace(&preimage).unwrap();
+            assert_eq!(trace.width(), NUM_KECCAK_COLS);
+            let public_values = air.public_values(&preimage);
+            assert_eq!(public_values.len(), OUTPUT_LIMBS);
+            check_constraints(&air, &trace, &public_values);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "constraints had nonzero value")]
+    fn test_hash_preimage_shake_wrong_digest_fails_constraints() {
+        let preimage = b"correct horse battery staple".to_vec();
+        let air = HashPreimageShakeAir::new(preimage.len()).unwrap();
+        let trace: RowMajorMatrix<TestField> = air.generate_trace(&preimage).unwrap();
+        let wrong = digest_to_public_values(&shake256_digest(b"another preimage")).unwrap();
+        check_constraints(&air, &trace, &wrong);
+    }
+
+    #[test]
+    fn test_digest_to_public_values_rejects_wrong_length() {
+        assert!(digest_to_public_values(&[0u8; 31]).is_none());
+        assert!(digest_to_public_values(&[0u8; 33]).is_none());
+    }
+}
@@ -9,6 +9,7 @@
 //! - [`crate::air::arithmetic::ArithmeticAir`] - Basic arithmetic operations (multiplication constraints)
 //! - [`crate::air::range_proof::RangeProofAir`] - Proves a value is within a specified range
 //! - [`crate::air::hash_preimage::HashPreimageAir`] - Proves knowledge of a Poseidon-128 preimage (industry-standard for STARK constraint encoding)
+//! - [`crate::air::hash_preimage_shake::HashPreimageShakeAir`] - Proves knowledge of a single-block SHAKE256 preimage (full Keccak-f constraints)
 //! - [`crate::air::merkle_inclusion::MerkleInclusionAir`] - Proves membership in a Merkle tree
 //!
 //! # Security
@@ -65,6 +66,7 @@ pub mod credential;
 pub mod fri_verifier;
 pub mod hash_preimage;
 pub mod hash_preimage_nist;
+pub mod hash_preimage_shake;
 pub mod identity_proof;
 pub mod merkle_inclusion;
 pub mod opening_verifier;
@@ -125,6 +127,12 @@ pub use hash_preimage_nist::{
     HashPreimageNistInput,
     expected_hash_to_public_values,
 };
+pub use hash_preimage_shake::{
+    HashPreimageShakeAir,
+    HashPreimageShakeInput,
+    SHAKE_OUTPUT_BYTES,
+    shake256_digest,
+};
 pub use identity_proof::{
     IdentityProofAir,
     IdentityProofInput,
@@ -379,8 +379,7 @@ pub fn verify_membership_with_config(
 /// ```
 pub fn prove_preimage(secret: &[u8]) -> Result<ZkpProof> {
     let mut prover = ZkpProver::new();
-    let public_statement = b""; // Empty for preimage proof
-    prover.prove_secret_value(secret, public_statement)
+    prover.prove_secret_value_poseidon(secret)
 }
 
 /// Verify a preimage proof
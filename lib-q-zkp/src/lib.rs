@@ -68,8 +68,6 @@ extern crate alloc;
 
 // Re-export core types for public use
 #[cfg(feature = "alloc")]
-use alloc::boxed::Box;
-#[cfg(feature = "alloc")]
 use alloc::string::ToString;
 #[cfg(feature = "alloc")]
 use alloc::vec;
@@ -255,6 +253,13 @@ pub enum ProofMetadata {
         /// transparent proof (`StarkProof<DefaultConfig>`) are distinct serialized types.
         zk: bool,
     },
+    /// SHAKE256 hash preimage proof metadata (single-block Keccak-f AIR)
+    HashPreimageShake {
+        /// Preimage length in bytes (fixes the SHAKE256 padding position in the AIR)
+        preimage_len: u16,
+        /// Output size in bytes (32 for the bound SHAKE256 digest)
+        output_size: u16,
+    },
 }
 
 /// A zero-knowledge proof
@@ -262,6 +267,7 @@ pub enum ProofMetadata {
 #[cfg_attr(feature = "zkp", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZkpProof {
     /// The proof data (serialized STARK proof)
+    #[cfg_attr(feature = "zkp", serde(with = "lib_q_core::encoding::serde_payload"))]
     pub data: Vec<u8>,
     /// The proof type
     pub proof_type: ProofType,
@@ -307,6 +313,75 @@ impl ZkpProof {
         })
     }
 
+    /// Encode the proof in its canonical wire format
+    ///
+    /// ```text
+    /// magic "LQZP" (4) || version (1) || proof type (1) || security level (u32 LE) ||
+    /// metadata length (u32 LE) || metadata (postcard) || data length (u32 LE) || data
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::InvalidState`] if the metadata cannot be serialized or a
+    /// section is longer than `u32::MAX` bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let metadata = postcard::to_allocvec(&self.metadata)
+            .map_err(|_| proof_format_error("metadata serialization failed"))?;
+        let section_len = |len: usize| {
+            u32::try_from(len)
+                .map(u32::to_le_bytes)
+                .map_err(|_| proof_format_error("section exceeds u32::MAX bytes"))
+        };
+
+        let mut out = Vec::with_capacity(PROOF_HEADER_SIZE + 8 + metadata.len() + self.data.len());
+        out.extend_from_slice(&PROOF_WIRE_MAGIC);
+        out.push(PROOF_WIRE_VERSION);
+        out.push(self.proof_type.wire_id());
+        out.extend_from_slice(&self.security_level.to_le_bytes());
+        out.extend_from_slice(&section_len(metadata.len())?);
+        out.extend_from_slice(&metadata);
+        out.extend_from_slice(&section_len(self.data.len())?);
+        out.extend_from_slice(&self.data);
+        Ok(out)
+    }
+
+    /// Decode a proof written by [`to_bytes`](Self::to_bytes)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`lib_q_core::Error::InvalidState`] for a wrong magic, an unsupported version,
+    /// an unknown proof type, malformed metadata, or section lengths that disagree with the
+    /// buffer (including trailing bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (header, rest) = bytes
+            .split_first_chunk::<PROOF_HEADER_SIZE>()
+            .ok_or_else(|| proof_format_error("truncated header"))?;
+        if header[..4] != PROOF_WIRE_MAGIC {
+            return Err(proof_format_error("bad magic"));
+        }
+        if header[4] != PROOF_WIRE_VERSION {
+            return Err(proof_format_error("unsupported version"));
+        }
+        let proof_type = ProofType::from_wire_id(header[5])
+            .ok_or_else(|| proof_format_error("unknown proof type"))?;
+        let security_level = u32::from_le_bytes([header[6], header[7], header[8], header[9]]);
+
+        let (metadata, rest) = split_section(rest)?;
+        let (data, rest) = split_section(rest)?;
+        if !rest.is_empty() {
+            return Err(proof_format_error("trailing bytes after proof data"));
+        }
+        let metadata =
+            postcard::from_bytes(metadata).map_err(|_| proof_format_error("malformed metadata"))?;
+
+        Ok(Self {
+            data: data.to_vec(),
+            proof_type,
+            security_level,
+            metadata,
+        })
+    }
+
     /// Get the tree depth from Merkle inclusion proof metadata
     ///
     /// Returns `Some(depth)` if this is a Merkle inclusion proof with metadata,
@@ -320,6 +395,39 @@ impl ZkpProof {
     }
 }
 
+/// Magic bytes opening every encoded [`ZkpProof`]
+#[cfg(feature = "zkp")]
+pub const PROOF_WIRE_MAGIC: [u8; 4] = *b"LQZP";
+
+/// Current [`ZkpProof`] wire format version
+#[cfg(feature = "zkp")]
+pub const PROOF_WIRE_VERSION: u8 = 1;
+
+/// Magic, version, proof type and security level
+#[cfg(feature = "zkp")]
+const PROOF_HEADER_SIZE: usize = 4 + 1 + 1 + 4;
+
+#[cfg(feature = "zkp")]
+fn proof_format_error(reason: &str) -> lib_q_core::Error {
+    lib_q_core::Error::InvalidState {
+        operation: "ZkpProof decoding".to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Split a `u32 LE` length-prefixed section off the front of `bytes`
+#[cfg(feature = "zkp")]
+fn split_section(bytes: &[u8]) -> Result<(&[u8], &[u8])> {
+    let (len, rest) = bytes
+        .split_first_chunk::<4>()
+        .ok_or_else(|| proof_format_error("truncated section length"))?;
+    let len = u32::from_le_bytes(*len) as usize;
+    if len > rest.len() {
+        return Err(proof_format_error("section length exceeds the buffer"));
+    }
+    Ok(rest.split_at(len))
+}
+
 /// Types of zero-knowledge proofs supported by lib-Q
 ///
 /// Only NIST-approved post-quantum proof systems are included.
@@ -331,6 +439,23 @@ pub enum ProofType {
     Stark,
 }
 
+impl ProofType {
+    /// Stable byte identifying the proof type in [`ZkpProof::to_bytes`]
+    pub fn wire_id(&self) -> u8 {
+        match self {
+            ProofType::Stark => 1,
+        }
+    }
+
+    /// Proof type for a [`wire_id`](Self::wire_id), or `None` if unknown
+    pub fn from_wire_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(ProofType::Stark),
+            _ => None,
+        }
+    }
+}
+
 /// Prover for creating zero-knowledge proofs
 #[cfg(feature = "zkp")]
 pub struct ZkpProver {
@@ -358,36 +483,56 @@ impl ZkpProver {
 
     /// Prove knowledge of a secret value without revealing it
     ///
-    /// This generates a STARK proof that the prover knows a preimage `secret_value`
-    /// whose **Poseidon-128** hash equals the public commitment. The proof uses
-    /// Poseidon for constraint encoding (industry-standard for STARKs; e.g. StarkWare,
-    /// RISC Zero, Succinct). For a NIST-only hash, use [`prove_secret_value_nist`](ZkpProver::prove_secret_value_nist).
+    /// Generates a STARK proof that the prover knows `secret_value` such that the first 32
+    /// bytes of `SHAKE256(secret_value)` equal `public_statement`; see
+    /// [`prove_secret_value_shake256`](ZkpProver::prove_secret_value_shake256). Verify it with
+    /// [`ZkpVerifier::verify`] against the same statement.
     ///
     /// # Arguments
     ///
-    /// * `secret_value` - The secret preimage to prove knowledge of
-    /// * `public_statement` - Additional public data (currently unused; reserved for future use)
+    /// * `secret_value` - The secret preimage to prove knowledge of (1 to 135 bytes)
+    /// * `public_statement` - The 32-byte SHAKE256 digest of `secret_value`
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A zero-knowledge proof that can be verified without revealing the secret
+    /// Returns [`Error::InvalidParameter`](lib_q_core::Error::InvalidParameter) if
+    /// `public_statement` is not the digest of `secret_value`.
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// use lib_q_zkp::{ZkpProver, ZkpVerifier};
+    /// use lib_q_zkp::{ZkpProver, ZkpVerifier, air::shake256_digest};
     ///
-    /// let mut prover = ZkpProver::new();
     /// let secret = b"my secret password";
-    /// let public = b"challenge";
+    /// let statement = shake256_digest(secret);
     ///
-    /// let proof = prover.prove_secret_value(secret, public)?;
+    /// let proof = ZkpProver::new().prove_secret_value(secret, &statement)?;
+    /// assert!(ZkpVerifier::new().verify(proof, &statement)?);
     /// ```
     pub fn prove_secret_value(
         &mut self,
         secret_value: &[u8],
-        _public_statement: &[u8],
+        public_statement: &[u8],
     ) -> Result<ZkpProof> {
+        self.prove_secret_value_shake256(secret_value, public_statement)
+    }
+
+    /// Prove knowledge of a secret value under Poseidon-128
+    ///
+    /// This generates a STARK proof that the prover knows a preimage `secret_value`
+    /// whose **Poseidon-128** hash equals the public commitment. The proof uses
+    /// Poseidon for constraint encoding (industry-standard for STARKs; e.g. StarkWare,
+    /// RISC Zero, Succinct). Verify it with
+    /// [`verify_secret_value`](ZkpVerifier::verify_secret_value), which takes the preimage.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_value` - The secret preimage to prove knowledge of
+    ///
+    /// # Returns
+    ///
+    /// A zero-knowledge proof that can be verified without revealing the secret
+    pub fn prove_secret_value_poseidon(&mut self, secret_value: &[u8]) -> Result<ZkpProof> {
         use crate::air::{
             HashPreimageAir,
             TraceGenerator,
@@ -400,14 +545,14 @@ impl ZkpProver {
         // Validate input size
         if secret_value.is_empty() {
             return Err(lib_q_core::Error::InvalidState {
-                operation: "prove_secret_value".to_string(),
+                operation: "prove_secret_value_poseidon".to_string(),
                 reason: "Secret value cannot be empty".to_string(),
             });
         }
 
         if secret_value.len() > air::hash_preimage::MAX_PREIMAGE_SIZE {
             return Err(lib_q_core::Error::InvalidState {
-                operation: "prove_secret_value".to_string(),
+                operation: "prove_secret_value_poseidon".to_string(),
                 reason: "Secret value exceeds maximum size".to_string(),
             });
         }
@@ -420,7 +565,7 @@ impl ZkpProver {
         let trace: RowMajorMatrix<ZkpField> =
             air.generate_trace(&input)
                 .map_err(|e| lib_q_core::Error::InternalError {
-                    operation: "prove_secret_value".to_string(),
+                    operation: "prove_secret_value_poseidon".to_string(),
                     details: e.to_string(),
                 })?;
 
@@ -448,8 +593,8 @@ impl ZkpProver {
 
     /// Prove knowledge of a secret value using NIST cSHAKE256 (100% NIST compliance)
     ///
-    /// Same semantics as [`prove_secret_value`](ZkpProver::prove_secret_value) but uses
-    /// cSHAKE256 with domain `b"HashPreimageNistAir"` for the commitment. Use this when
+    /// Same semantics as [`prove_secret_value_poseidon`](ZkpProver::prove_secret_value_poseidon)
+    /// but uses cSHAKE256 with domain `b"HashPreimageNistAir"` for the commitment. Use this when
     /// NIST-only hashes are required; prover cost is higher than Poseidon-based proofs.
     ///
     /// # Arguments
@@ -472,7 +617,7 @@ impl ZkpProver {
         // `crate::air::hash_preimage_nist`). A proof from the current AIR would not bind the
         // secret to the public hash, so we refuse rather than emit a proof that proves nothing.
         // Once real Keccak-f constraints exist, replace this with the trace-generation and
-        // STARK-proving flow used by `prove_secret_value`.
+        // STARK-proving flow used by `prove_secret_value_poseidon`.
         let _ = (secret_value, public_statement);
         Err(lib_q_core::Error::NotImplemented {
             feature: "NIST (cSHAKE256) preimage proofs: Keccak-f AIR constraints not implemented"
@@ -480,6 +625,93 @@ impl ZkpProver {
         })
     }
 
+    /// Prove knowledge of a secret value under SHAKE256
+    ///
+    /// Generates a STARK proof (FRI-based polynomial commitment over `Complex<Mersenne31>`) that
+    /// the prover knows `secret_value` such that the first 32 bytes of `SHAKE256(secret_value)`
+    /// equal the public statement. Every Keccak-f\[1600\] round is constrained, together with
+    /// the SHAKE256 padding and zero capacity of the absorbed block.
+    ///
+    /// The proof binds the digest of `secret_value` itself: verify it with
+    /// [`verify_secret_value_shake256`](ZkpVerifier::verify_secret_value_shake256) (or
+    /// [`ZkpVerifier::verify`]) against the expected 32-byte digest, e.g. from
+    /// [`air::shake256_digest`]. The preimage length is public (recorded in the metadata).
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_value` - The secret preimage (1 to 135 bytes, a single SHAKE256 block)
+    /// * `public_statement` - The 32-byte SHAKE256 digest of `secret_value`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`](lib_q_core::Error::InvalidParameter) if
+    /// `public_statement` is not the digest of `secret_value`, so a proof is never produced
+    /// for a statement the witness does not satisfy.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use lib_q_zkp::{ZkpProver, ZkpVerifier, air::shake256_digest};
+    ///
+    /// let secret = b"my secret password";
+    /// let statement = shake256_digest(secret);
+    ///
+    /// let proof = ZkpProver::new().prove_secret_value_shake256(secret, &statement)?;
+    /// assert!(ZkpVerifier::new().verify(proof, &statement)?);
+    /// ```
+    pub fn prove_secret_value_shake256(
+        &mut self,
+        secret_value: &[u8],
+        public_statement: &[u8],
+    ) -> Result<ZkpProof> {
+        use crate::air::HashPreimageShakeAir;
+        use crate::stark::{
+            StarkProver,
+            default_config,
+        };
+
+        let air = HashPreimageShakeAir::new(secret_value.len()).map_err(|e| {
+            lib_q_core::Error::InvalidState {
+                operation: "prove_secret_value_shake256".to_string(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        if !lib_q_core::Utils::constant_time_eq(
+            &air::shake256_digest(secret_value),
+            public_statement,
+        ) {
+            return Err(lib_q_core::Error::InvalidParameter {
+                parameter: "public_statement",
+                reason: "public statement is not the SHAKE256 digest of the secret value",
+            });
+        }
+
+        let input = secret_value.to_vec();
+        let trace: RowMajorMatrix<ZkpField> =
+            air.generate_trace(&input)
+                .map_err(|e| lib_q_core::Error::InternalError {
+                    operation: "prove_secret_value_shake256".to_string(),
+                    details: e.to_string(),
+                })?;
+        let public_values: Vec<ZkpField> = air.public_values(&input);
+
+        let prover = StarkProver::new(default_config());
+        let proof = prover.prove(&air, trace, &public_values).map_err(|e| {
+            lib_q_core::Error::InternalError {
+                operation: "STARK proof generation".to_string(),
+                details: e.to_string(),
+            }
+        })?;
+
+        // `HashPreimageShakeAir::new` bounds the length to a single 136-byte block.
+        let metadata = ProofMetadata::HashPreimageShake {
+            preimage_len: secret_value.len() as u16,
+            output_size: air::SHAKE_OUTPUT_BYTES as u16,
+        };
+        ZkpProof::from_stark_proof(&proof, metadata)
+    }
+
     /// Prove a computation using a circuit
     ///
     /// This generates a STARK proof that the prover knows witness values that
@@ -580,6 +812,13 @@ impl ZkpProver {
         })
     }
 
+    /// Prove knowledge of a secret value (Poseidon variant)
+    pub fn prove_secret_value_poseidon(&mut self, _secret_value: &[u8]) -> Result<ZkpProof> {
+        Err(lib_q_core::Error::NotImplemented {
+            feature: "ZKP feature not enabled".to_string(),
+        })
+    }
+
     /// Prove knowledge of a secret value (NIST variant)
     pub fn prove_secret_value_nist(
         &mut self,
@@ -590,6 +829,17 @@ impl ZkpProver {
             feature: "ZKP feature not enabled".to_string(),
         })
     }
+
+    /// Prove knowledge of a secret value (SHAKE256 variant)
+    pub fn prove_secret_value_shake256(
+        &mut self,
+        _secret_value: &[u8],
+        _public_statement: &[u8],
+    ) -> Result<ZkpProof> {
+        Err(lib_q_core::Error::NotImplemented {
+            feature: "ZKP feature not enabled".to_string(),
+        })
+    }
 }
 
 /// Crate-private helper for NIST secret value verification. Used by both
@@ -627,17 +877,17 @@ impl ZkpVerifier {
 
     /// Verify a zero-knowledge proof of secret value (preimage) knowledge
     ///
-    /// This verifies a proof generated by [`ZkpProver::prove_secret_value`]. The verifier
-    /// recomputes the public Poseidon commitment from the **preimage**, so the caller passes
-    /// the same secret preimage that was given to the prover (NOT the hash output). The proof
-    /// then attests that the prover knew a preimage hashing to that commitment.
+    /// This verifies a proof generated by [`ZkpProver::prove_secret_value_poseidon`]. The
+    /// verifier recomputes the public Poseidon commitment from the **preimage**, so the caller
+    /// passes the same secret preimage that was given to the prover (NOT the hash output). The
+    /// proof then attests that the prover knew a preimage hashing to that commitment.
     /// For NIST proofs use [`verify_secret_value_nist`](ZkpVerifier::verify_secret_value_nist).
     ///
     /// # Arguments
     ///
     /// * `proof` - The proof to verify
-    /// * `preimage` - The secret preimage (same bytes passed to `prove_secret_value`); the
-    ///   verifier hashes it with Poseidon-128 to reconstruct the public commitment
+    /// * `preimage` - The secret preimage (same bytes passed to `prove_secret_value_poseidon`);
+    ///   the verifier hashes it with Poseidon-128 to reconstruct the public commitment
     ///
     /// # Returns
     ///
@@ -696,6 +946,59 @@ impl ZkpVerifier {
         verify_secret_value_nist_impl(proof, expected_hash)
     }
 
+    /// Verify a SHAKE256 secret value proof
+    ///
+    /// Verifies a proof from
+    /// [`prove_secret_value_shake256`](ZkpProver::prove_secret_value_shake256).
+    /// `expected_hash` is the 32-byte SHAKE256 digest the prover claims to know a preimage of.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(true)` if the proof is valid for `expected_hash`, `Ok(false)` otherwise
+    pub fn verify_secret_value_shake256(
+        &self,
+        proof: &ZkpProof,
+        expected_hash: &[u8],
+    ) -> Result<bool> {
+        use crate::air::HashPreimageShakeAir;
+        use crate::air::hash_preimage_shake::digest_to_public_values;
+        use crate::stark::{
+            StarkVerifier,
+            default_config,
+        };
+
+        if proof.proof_type != ProofType::Stark {
+            return Ok(false);
+        }
+        if proof.data.is_empty() {
+            return Ok(false);
+        }
+
+        let ProofMetadata::HashPreimageShake {
+            preimage_len,
+            output_size,
+        } = &proof.metadata
+        else {
+            return Ok(false);
+        };
+        if usize::from(*output_size) != air::SHAKE_OUTPUT_BYTES {
+            return Ok(false);
+        }
+        let Ok(air) = HashPreimageShakeAir::new(usize::from(*preimage_len)) else {
+            return Ok(false);
+        };
+        let Some(public_values) = digest_to_public_values(expected_hash) else {
+            return Ok(false);
+        };
+
+        let stark_proof = proof.to_stark_proof()?;
+        let verifier = StarkVerifier::new(default_config());
+        match verifier.verify(&air, &stark_proof, &public_values) {
+            Ok(()) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
     /// Verify a zero-knowledge proof of computation
     ///
     /// This verifies a proof generated by `ZkpProver::prove_computation`.
@@ -755,6 +1058,8 @@ impl ZkpVerifier {
     ///   (same semantics as `verify_secret_value`).
     /// - `ProofMetadata::HashPreimageNist`: `public_statement` is the expected cSHAKE256
     ///   hash output (same semantics as `verify_secret_value_nist`).
+    /// - `ProofMetadata::HashPreimageShake`: `public_statement` is the expected 32-byte
+    ///   SHAKE256 digest (same semantics as `verify_secret_value_shake256`).
     /// - `ProofMetadata::MerkleInclusion`: `public_statement` is the expected Merkle
     ///   root hash (same semantics as `api::verify_membership`).
     ///
@@ -777,6 +1082,9 @@ impl ZkpVerifier {
             ProofMetadata::HashPreimageNist { .. } => {
                 verify_secret_value_nist_impl(&proof, public_statement)
             }
+            ProofMetadata::HashPreimageShake { .. } => {
+                self.verify_secret_value_shake256(&proof, public_statement)
+            }
             ProofMetadata::MerkleInclusion { .. } => verify_membership(&proof, public_statement),
             ProofMetadata::UnlinkableMembership { .. } => {
                 membership::verify_unlinkable_membership_bytes(&proof, public_statement)
@@ -860,7 +1168,98 @@ impl Default for ZkpVerifier {
     }
 }
 
-/// Get available ZKP algorithms (STARK when zkp feature is enabled).
+/// A ZKP backend instance returned by [`create_zkp`].
+///
+/// Each variant pairs a prover and verifier for one compiled-in proof system, so callers can
+/// prove and verify without downcasting.
+pub enum ZkpInstance {
+    /// zk-STARK backend (`Complex<Mersenne31>`, SHAKE256 commitments)
+    #[cfg(feature = "zkp")]
+    Stark {
+        /// Prover for the STARK backend
+        prover: ZkpProver,
+        /// Verifier for the STARK backend
+        verifier: ZkpVerifier,
+    },
+}
+
+impl ZkpInstance {
+    /// Algorithm name of this instance (as listed by [`available_algorithms`])
+    pub fn algorithm(&self) -> &'static str {
+        #[cfg(feature = "zkp")]
+        {
+            match self {
+                ZkpInstance::Stark { .. } => "stark",
+            }
+        }
+        #[cfg(not(feature = "zkp"))]
+        {
+            match *self {}
+        }
+    }
+
+    /// Prove knowledge of `secret_value` (see [`ZkpProver::prove_secret_value`])
+    pub fn prove(&mut self, secret_value: &[u8], public_statement: &[u8]) -> Result<ZkpProof> {
+        #[cfg(feature = "zkp")]
+        {
+            match self {
+                ZkpInstance::Stark { prover, .. } => {
+                    prover.prove_secret_value(secret_value, public_statement)
+                }
+            }
+        }
+        #[cfg(not(feature = "zkp"))]
+        {
+            let _ = (secret_value, public_statement);
+            match *self {}
+        }
+    }
+
+    /// Verify a proof against its public statement (see [`ZkpVerifier::verify`])
+    pub fn verify(&self, proof: ZkpProof, public_statement: &[u8]) -> Result<bool> {
+        #[cfg(feature = "zkp")]
+        {
+            match self {
+                ZkpInstance::Stark { verifier, .. } => verifier.verify(proof, public_statement),
+            }
+        }
+        #[cfg(not(feature = "zkp"))]
+        {
+            let _ = (proof, public_statement);
+            match *self {}
+        }
+    }
+
+    /// Mutable access to the underlying prover
+    pub fn prover_mut(&mut self) -> &mut ZkpProver {
+        #[cfg(feature = "zkp")]
+        {
+            match self {
+                ZkpInstance::Stark { prover, .. } => prover,
+            }
+        }
+        #[cfg(not(feature = "zkp"))]
+        {
+            match *self {}
+        }
+    }
+
+    /// Shared access to the underlying verifier
+    pub fn verifier(&self) -> &ZkpVerifier {
+        #[cfg(feature = "zkp")]
+        {
+            match self {
+                ZkpInstance::Stark { verifier, .. } => verifier,
+            }
+        }
+        #[cfg(not(feature = "zkp"))]
+        {
+            match *self {}
+        }
+    }
+}
+
+/// Get available ZKP algorithms (one entry per compiled-in backend).
 pub fn available_algorithms() -> Vec<&'static str> {
     let algorithms = vec![
         #[cfg(feature = "zkp")]
@@ -871,10 +1270,15 @@ pub fn available_algorithms() -> Vec<&'static str> {
 }
 
 /// Create a ZKP instance by algorithm name
-pub fn create_zkp(algorithm: &str) -> Result<Box<dyn core::any::Any>> {
+///
+/// Accepts any name returned by [`available_algorithms`].
+pub fn create_zkp(algorithm: &str) -> Result<ZkpInstance> {
     match algorithm {
         #[cfg(feature = "zkp")]
-        "stark" => Ok(Box::new(ZkpProver::new())),
+        "stark" => Ok(ZkpInstance::Stark {
+            prover: ZkpProver::new(),
+            verifier: ZkpVerifier::new(),
+        }),
 
         _ => Err(lib_q_core::Error::InvalidAlgorithm {
             algorithm: "Unknown ZKP algorithm",
@@ -886,6 +1290,78 @@ pub fn create_zkp(algorithm: &str) -> Result<Box<dyn core::any::Any>> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_zkp_proof_wire_round_trip() {
+        let proofs = [
+            ZkpProof {
+                data: vec![0xAB; 1000],
+                proof_type: ProofType::Stark,
+                security_level: 3,
+                metadata: ProofMetadata::UnlinkableMembership {
+                    tree_depth: 20,
+                    digest_width: 5,
+                    zk: true,
+                },
+            },
+            ZkpProof {
+                data: Vec::new(),
+                proof_type: ProofType::Stark,
+                security_level: 1,
+                metadata: ProofMetadata::Credential {
+                    attribute_sizes: vec![4, 32, 0],
+                    reveal_mask: vec![true, false, true],
+                },
+            },
+            ZkpProof {
+                data: b"proof".to_vec(),
+                proof_type: ProofType::Stark,
+                security_level: 5,
+                metadata: ProofMetadata::None,
+            },
+        ];
+        for proof in proofs {
+            let bytes = proof.to_bytes().unwrap();
+            assert_eq!(bytes[..4], PROOF_WIRE_MAGIC);
+            let decoded = ZkpProof::from_bytes(&bytes).unwrap();
+            assert_eq!(decoded.data, proof.data);
+            assert_eq!(decoded.proof_type, proof.proof_type);
+            assert_eq!(decoded.security_level, proof.security_level);
+            assert_eq!(decoded.metadata, proof.metadata);
+        }
+    }
+
+    #[test]
+    fn test_zkp_proof_wire_rejects_corrupted_header() {
+        let proof = ZkpProof {
+            data: vec![7; 64],
+            proof_type: ProofType::Stark,
+            security_level: 1,
+            metadata: ProofMetadata::MerkleInclusion { tree_depth: 8 },
+        };
+        let bytes = proof.to_bytes().unwrap();
+        let corrupt = |index: usize, value: u8| {
+            let mut bytes = bytes.clone();
+            bytes[index] = value;
+            ZkpProof::from_bytes(&bytes)
+        };
+
+        assert!(corrupt(0, b'X').is_err(), "bad magic");
+        assert!(
+            corrupt(4, PROOF_WIRE_VERSION + 1).is_err(),
+            "future version"
+        );
+        assert!(corrupt(5, 0).is_err(), "unknown proof type");
+        assert!(corrupt(5, 0xFF).is_err(), "unknown proof type");
+        // Metadata length pointing past the end of the buffer.
+        assert!(corrupt(13, 0xFF).is_err(), "oversized metadata length");
+
+        assert!(ZkpProof::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(ZkpProof::from_bytes(&bytes[..9]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(ZkpProof::from_bytes(&trailing).is_err());
+    }
+
     #[test]
     fn test_zkp_prover_creation() {
         let _prover = ZkpProver::new();
@@ -902,16 +1378,21 @@ mod tests {
     fn test_zkp_proof_creation() {
         let mut prover = ZkpProver::new();
         let secret_value = b"secret_value";
-        let public_statement = b"public_statement";
+        let public_statement = air::shake256_digest(secret_value);
 
-        // Now that prove_secret_value is implemented, it should succeed
-        let result = prover.prove_secret_value(secret_value, public_statement);
+        let result = prover.prove_secret_value(secret_value, &public_statement);
         // The proof generation should succeed (though it may take some time)
         assert!(
             result.is_ok(),
             "Proof generation should succeed: {:?}",
             result.err()
         );
+        let proof = result.unwrap();
+        assert!(matches!(
+            proof.metadata,
+            ProofMetadata::HashPreimageShake { .. }
+        ));
+        assert!(ZkpVerifier::new().verify(proof, &public_statement).unwrap());
     }
 
     #[cfg(feature = "zkp")]
@@ -960,7 +1441,7 @@ mod tests {
         let secret = b"poseidon_only";
         let mut prover = ZkpProver::new();
         let proof = prover
-            .prove_secret_value(secret, b"")
+            .prove_secret_value_poseidon(secret)
             .expect("Poseidon prove");
         let verifier = ZkpVerifier::new();
         assert!(
@@ -971,6 +1452,108 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "zkp")]
+    #[test]
+    fn test_shake256_secret_value_roundtrip() {
+        use crate::air::shake256_digest;
+
+        let secret = b"shake256 secret preimage";
+        let statement = shake256_digest(secret);
+        let mut prover = ZkpProver::new();
+        let proof = prover
+            .prove_secret_value_shake256(secret, &statement)
+            .expect("SHAKE256 prove");
+        assert!(matches!(
+            proof.metadata,
+            ProofMetadata::HashPreimageShake {
+                preimage_len: 24,
+                output_size: 32
+            }
+        ));
+
+        let verifier = ZkpVerifier::new();
+        assert!(
+            verifier
+                .verify_secret_value_shake256(&proof, &statement)
+                .unwrap()
+        );
+        assert!(verifier.verify(proof.clone(), &statement).unwrap());
+
+        let other = shake256_digest(b"some other secret preimage");
+        assert!(
+            !verifier.verify(proof, &other).unwrap(),
+            "proof must not verify against a different digest"
+        );
+    }
+
+    #[cfg(feature = "zkp")]
+    #[test]
+    fn test_shake256_wrong_secret_fails_verification() {
+        use crate::air::shake256_digest;
+
+        let statement = shake256_digest(b"correct secret");
+        let mut prover = ZkpProver::new();
+        // Same length as the correct secret so only the preimage differs.
+        let wrong_secret = b"wrong!! secret";
+        assert!(matches!(
+            prover.prove_secret_value_shake256(wrong_secret, &statement),
+            Err(lib_q_core::Error::InvalidParameter {
+                parameter: "public_statement",
+                ..
+            })
+        ));
+
+        // A proof for the wrong secret's own statement must not verify against `statement`.
+        let proof = prover
+            .prove_secret_value_shake256(wrong_secret, &shake256_digest(wrong_secret))
+            .expect("SHAKE256 prove");
+        let verifier = ZkpVerifier::new();
+        assert!(
+            !verifier
+                .verify_secret_value_shake256(&proof, &statement)
+                .unwrap(),
+            "proof for a wrong secret must not verify"
+        );
+    }
+
+    #[cfg(feature = "zkp")]
+    #[test]
+    fn test_shake256_rejects_bad_inputs_and_foreign_proofs() {
+        let mut prover = ZkpProver::new();
+        assert!(prover.prove_secret_value_shake256(b"", b"").is_err());
+        assert!(
+            prover
+                .prove_secret_value_shake256(&[0u8; 136], b"")
+                .is_err()
+        );
+
+        let poseidon = prover
+            .prove_secret_value_poseidon(b"poseidon_only")
+            .expect("Poseidon prove");
+        let verifier = ZkpVerifier::new();
+        assert!(
+            !verifier
+                .verify_secret_value_shake256(&poseidon, &[0u8; 32])
+                .unwrap(),
+            "Poseidon proof must not be accepted by SHAKE256 verifier"
+        );
+
+        let forged = ZkpProof {
+            data: alloc::vec![1u8; 8],
+            proof_type: ProofType::Stark,
+            security_level: 1,
+            metadata: ProofMetadata::HashPreimageShake {
+                preimage_len: 8,
+                output_size: 32,
+            },
+        };
+        assert!(
+            !verifier
+                .verify_secret_value_shake256(&forged, &[0u8; 31])
+                .unwrap()
+        );
+    }
+
     #[cfg(feature = "zkp")]
     #[test]
     fn test_verify_rejects_unknown_metadata() {
@@ -1071,8 +1654,29 @@ mod tests {
     fn test_create_zkp() {
         let algorithms = available_algorithms();
         assert!(!algorithms.is_empty());
-        let algorithm = algorithms[0];
-        assert!(create_zkp(algorithm).is_ok());
+        for algorithm in algorithms {
+            let instance = create_zkp(algorithm).expect("listed algorithm must be creatable");
+            assert_eq!(instance.algorithm(), algorithm);
+        }
+        assert!(create_zkp("unknown").is_err());
+    }
+
+    #[cfg(feature = "zkp")]
+    #[test]
+    fn test_create_zkp_stark_prove_verify() {
+        let mut zkp = create_zkp("stark").expect("stark backend");
+        let secret = b"create_zkp secret";
+        let statement = air::shake256_digest(secret);
+        let proof = zkp.prove(secret, &statement).expect("prove");
+        assert!(zkp.verify(proof.clone(), &statement).expect("verify"));
+        assert!(!zkp.verify(proof, b"wrong statement").unwrap_or(false));
+        assert!(zkp.prove(secret, b"wrong statement").is_err());
+        let direct = zkp
+            .prover_mut()
+            .prove_secret_value_poseidon(secret)
+            .expect("prove");
+        // Poseidon preimage proofs are verified against the preimage (see `verify_secret_value`).
+        assert!(zkp.verifier().verify(direct, secret).expect("verify"));
     }
 
     #[cfg(feature = "zkp")]
@@ -1226,11 +1830,11 @@ mod tests {
     #[test]
     fn test_prove_secret_value_rejects_empty_and_oversized_input() {
         let mut prover = ZkpProver::new();
-        let empty = prover.prove_secret_value(b"", b"");
+        let empty = prover.prove_secret_value_poseidon(b"");
         assert!(empty.is_err());
 
         let oversized = vec![0u8; air::hash_preimage::MAX_PREIMAGE_SIZE + 1];
-        let too_large = prover.prove_secret_value(&oversized, b"");
+        let too_large = prover.prove_secret_value_poseidon(&oversized);
         assert!(too_large.is_err());
     }
 
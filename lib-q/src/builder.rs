@@ -0,0 +1,206 @@
+//! Explicitly configured KEM, signature and hash contexts
+//!
+//! [`create_kem_context`](crate::create_kem_context) and friends hand out contexts without
+//! saying which algorithms will actually work. [`LibQBuilder`] takes the algorithms an
+//! application intends to use, checks each one against the features compiled into this
+//! build, and only then wires the providers into a [`LibQ`] facade.
+//!
+//! ```rust
+//! use libq::{
+//!     Algorithm,
+//!     LibQBuilder,
+//! };
+//!
+//! let mut libq = LibQBuilder::new()
+//!     .with_signature(Algorithm::MlDsa65)
+//!     .with_hash(Algorithm::Sha3_256)
+//!     .build()?;
+//! let keypair = libq
+//!     .sig_context()
+//!     .generate_keypair(Algorithm::MlDsa65, None)?;
+//! # Ok::<(), libq::Error>(())
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    vec::Vec,
+};
+
+use lib_q_core::{
+    Algorithm,
+    AlgorithmCategory,
+    Error,
+    HashContext,
+    KemContext,
+    Result,
+    SignatureContext,
+};
+
+use crate::implemented::AlgorithmAvailability;
+
+/// Collects the algorithms an application needs before building a [`LibQ`]
+#[derive(Clone, Debug, Default)]
+pub struct LibQBuilder {
+    kem: Vec<Algorithm>,
+    signature: Vec<Algorithm>,
+    hash: Vec<Algorithm>,
+}
+
+impl LibQBuilder {
+    /// Create a builder with no algorithms requested
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require a KEM algorithm on [`LibQ::kem_context`]
+    pub fn with_kem(mut self, algorithm: Algorithm) -> Self {
+        self.kem.push(algorithm);
+        self
+    }
+
+    /// Require a signature algorithm on [`LibQ::sig_context`]
+    pub fn with_signature(mut self, algorithm: Algorithm) -> Self {
+        self.signature.push(algorithm);
+        self
+    }
+
+    /// Require a hash algorithm on [`LibQ::hash_context`]
+    pub fn with_hash(mut self, algorithm: Algorithm) -> Self {
+        self.hash.push(algorithm);
+        self
+    }
+
+    /// Validate every requested algorithm and build the contexts
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if an algorithm was requested through the wrong
+    /// method (e.g. a signature scheme passed to [`with_kem`](Self::with_kem)), and
+    /// [`Error::UnsupportedAlgorithm`] if it is not implemented with the features enabled on
+    /// this crate (see [`AlgorithmAvailability`]). Provider setup errors are passed through.
+    pub fn build(self) -> Result<LibQ> {
+        for &algorithm in &self.kem {
+            check(
+                algorithm,
+                AlgorithmCategory::Kem,
+                "with_kem expects a KEM algorithm",
+            )?;
+            // The umbrella KEM context is backed by `LibQKemProvider`, which does not route
+            // Classic McEliece; use `LibQCbKemProvider` directly for that.
+            if matches!(
+                algorithm,
+                Algorithm::CbKem348864 |
+                    Algorithm::CbKem460896 |
+                    Algorithm::CbKem6688128 |
+                    Algorithm::CbKem6960119 |
+                    Algorithm::CbKem8192128
+            ) {
+                return Err(unsupported(algorithm));
+            }
+        }
+        for &algorithm in &self.signature {
+            check(
+                algorithm,
+                AlgorithmCategory::Signature,
+                "with_signature expects a signature algorithm",
+            )?;
+        }
+        for &algorithm in &self.hash {
+            check(
+                algorithm,
+                AlgorithmCategory::Hash,
+                "with_hash expects a hash algorithm",
+            )?;
+        }
+
+        let kem = if self.kem.is_empty() {
+            KemContext::new()
+        } else {
+            kem_context()?
+        };
+        let sig = if self.signature.is_empty() {
+            SignatureContext::new()
+        } else {
+            SignatureContext::with_provider(Box::new(crate::LibQSignatureProvider::new()?))
+        };
+        let hash = if self.hash.is_empty() {
+            HashContext::new()
+        } else {
+            HashContext::with_provider(Box::new(crate::LibQHashProvider::new()?))
+        };
+
+        let mut algorithms = self.kem;
+        algorithms.extend(self.signature);
+        algorithms.extend(self.hash);
+        Ok(LibQ {
+            kem,
+            sig,
+            hash,
+            algorithms,
+        })
+    }
+}
+
+/// KEM, signature and hash contexts configured by [`LibQBuilder`]
+///
+/// A context is only backed by a provider when at least one algorithm of its category was
+/// requested; the others are empty and fail with [`Error::ProviderNotConfigured`].
+pub struct LibQ {
+    kem: KemContext,
+    sig: SignatureContext,
+    hash: HashContext,
+    algorithms: Vec<Algorithm>,
+}
+
+impl LibQ {
+    /// The KEM context
+    pub fn kem_context(&mut self) -> &mut KemContext {
+        &mut self.kem
+    }
+
+    /// The signature context
+    pub fn sig_context(&mut self) -> &mut SignatureContext {
+        &mut self.sig
+    }
+
+    /// The hash context
+    pub fn hash_context(&mut self) -> &mut HashContext {
+        &mut self.hash
+    }
+
+    /// Algorithms requested on the builder, KEMs first, then signatures, then hashes
+    pub fn algorithms(&self) -> &[Algorithm] {
+        &self.algorithms
+    }
+}
+
+fn check(algorithm: Algorithm, category: AlgorithmCategory, misuse: &'static str) -> Result<()> {
+    if algorithm.category() != category {
+        return Err(Error::InvalidAlgorithm { algorithm: misuse });
+    }
+    if !algorithm.is_implemented() {
+        return Err(unsupported(algorithm));
+    }
+    Ok(())
+}
+
+pub(crate) fn unsupported(algorithm: Algorithm) -> Error {
+    Error::UnsupportedAlgorithm {
+        algorithm: format!("{algorithm:?} is not implemented in this build"),
+    }
+}
+
+#[cfg(any(feature = "ml-kem", feature = "hqc"))]
+fn kem_context() -> Result<KemContext> {
+    Ok(KemContext::with_provider(Box::new(
+        crate::LibQKemProvider::new()?,
+    )))
+}
+
+/// Unreachable in practice: without a KEM feature no KEM passes [`check`].
+#[cfg(not(any(feature = "ml-kem", feature = "hqc")))]
+fn kem_context() -> Result<KemContext> {
+    Ok(KemContext::new())
+}
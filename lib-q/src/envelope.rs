@@ -0,0 +1,261 @@
+//! Public-key encryption in one call: KEM + KDF + AEAD
+//!
+//! [`Envelope::seal`] encapsulates to the recipient's KEM public key, derives a one-time
+//! AEAD key from the shared secret with SHAKE256 and encrypts the plaintext under a random
+//! nonce. The output is a self-describing frame, so [`Envelope::open`] needs only the
+//! recipient's secret key:
+//!
+//! ```text
+//! magic "LQEV" || version (u8) || KEM id (u16 BE) || AEAD id (u16 BE) ||
+//! encapsulated key length (u32 BE) || encapsulated key ||
+//! nonce length (u16 BE) || nonce || AEAD ciphertext
+//! ```
+//!
+//! [`Envelope::open`] rejects a frame with a different [`ENVELOPE_MAGIC`] or a version other
+//! than [`ENVELOPE_VERSION`] with [`Error::InvalidFormat`] before touching the rest, so a
+//! later format change cannot be mis-parsed as this one. Both the KEM and the AEAD id are the
+//! algorithm's [`Algorithm::wire_id`], read back with [`Algorithm::from_wire_id`] and checked
+//! against the expected category. Ids are never reused.
+//!
+//! The header is bound to the ciphertext: the AEAD's associated data is not the caller's
+//! `aad` alone but the [`write_frame`](lib_q_core::codec::write_frame) encoding of
+//!
+//! ```text
+//! magic, version (u8), KEM id (u16 BE), AEAD id (u16 BE), encapsulated key, caller aad
+//! ```
+//!
+//! so relabelling either algorithm or swapping in another encapsulated key makes
+//! [`Envelope::open`] fail authentication, even where the tampered frame still parses and
+//! decapsulates.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use lib_q_aead::AeadWithMetadata;
+use lib_q_core::labels::{
+    LABEL_ENVELOPE_KDF,
+    hash_with_label,
+};
+use lib_q_core::{
+    AeadKey,
+    Algorithm,
+    AlgorithmCategory,
+    Error,
+    KemOperations,
+    KemPublicKey,
+    KemSecretKey,
+    Nonce,
+    Result,
+    Utils,
+    codec,
+};
+use lib_q_hash::Shake256;
+use zeroize::Zeroizing;
+
+use crate::LibQKemProvider;
+
+/// Magic bytes opening every envelope frame
+pub const ENVELOPE_MAGIC: [u8; 4] = *b"LQEV";
+
+/// Envelope frame format version written by [`Envelope::seal`]
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// Length of the fixed part of a frame: magic, version, both ids and the encapsulated key
+/// length
+const FIXED_HEADER_SIZE: usize = 4 + 1 + 2 + 2 + 4;
+
+/// KEM + AEAD public-key encryption with a self-describing output
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Envelope;
+
+impl Envelope {
+    /// Encrypt `plaintext` to `recipient_pk`
+    ///
+    /// `aad` is authenticated but not encrypted and must be supplied again to
+    /// [`open`](Self::open).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAlgorithm`] if `kem_alg` is not a KEM or `aead_alg` not an
+    /// AEAD with an envelope id, and any error from encapsulation or encryption (for
+    /// example a public key of the wrong size).
+    pub fn seal(
+        recipient_pk: &KemPublicKey,
+        kem_alg: Algorithm,
+        aead_alg: Algorithm,
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>> {
+        let kem_id = kem_id(kem_alg).ok_or(Error::InvalidAlgorithm {
+            algorithm: "Envelope KEM must be ML-KEM, HQC or CB-KEM",
+        })?;
+        let aead_id = aead_id(aead_alg).ok_or(Error::InvalidAlgorithm {
+            algorithm: "Envelope AEAD has no envelope id",
+        })?;
+        let aead = lib_q_aead::create_aead(aead_alg)?;
+
+        let (enc, shared_secret) =
+            LibQKemProvider::new()?.encapsulate(kem_alg, recipient_pk, None)?;
+        let shared_secret = Zeroizing::new(shared_secret);
+        let enc_len = u32::try_from(enc.len()).map_err(|_| Error::InvalidCiphertextSize {
+            expected: u32::MAX as usize,
+            actual: enc.len(),
+        })?;
+        let nonce_len = u16::try_from(aead.nonce_size()).map_err(|_| Error::InvalidNonceSize {
+            expected: u16::MAX as usize,
+            actual: aead.nonce_size(),
+        })?;
+
+        let key = derive_key(aead.as_ref(), &shared_secret);
+        let nonce = Nonce::new(Utils::random_bytes(aead.nonce_size())?);
+        let bound_aad = bound_aad(kem_id, aead_id, &enc, aad)?;
+        let ciphertext = aead.encrypt(&key, &nonce, plaintext, Some(&bound_aad))?;
+
+        let mut frame = Vec::with_capacity(
+            FIXED_HEADER_SIZE + enc.len() + 2 + nonce.as_bytes().len() + ciphertext.len(),
+        );
+        frame.extend_from_slice(&ENVELOPE_MAGIC);
+        frame.push(ENVELOPE_VERSION);
+        frame.extend_from_slice(&kem_id.to_be_bytes());
+        frame.extend_from_slice(&aead_id.to_be_bytes());
+        frame.extend_from_slice(&enc_len.to_be_bytes());
+        frame.extend_from_slice(&enc);
+        frame.extend_from_slice(&nonce_len.to_be_bytes());
+        frame.extend_from_slice(nonce.as_bytes());
+        frame.extend_from_slice(&ciphertext);
+        Ok(frame)
+    }
+
+    /// Decrypt a frame produced by [`seal`](Self::seal)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] if the frame does not start with [`ENVELOPE_MAGIC`] or
+    /// has a version other than [`ENVELOPE_VERSION`], [`Error::InvalidAlgorithm`] for an
+    /// unknown KEM or AEAD id, [`Error::InvalidCiphertextSize`] if a length field runs past
+    /// the end of `bytes`, [`Error::InvalidNonceSize`] if the nonce does not fit the AEAD,
+    /// and any error from decapsulation or decryption (including authentication failure).
+    pub fn open(recipient_sk: &KemSecretKey, bytes: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let mut reader = FrameReader {
+            rest: bytes,
+            offset: 0,
+        };
+        if reader.take(ENVELOPE_MAGIC.len())? != ENVELOPE_MAGIC {
+            return Err(Error::InvalidFormat {
+                context: "not an envelope frame (bad magic)",
+            });
+        }
+        if reader.take(1)?[0] != ENVELOPE_VERSION {
+            return Err(Error::InvalidFormat {
+                context: "unsupported envelope format version",
+            });
+        }
+        let kem_id = reader.read_u16()?;
+        let kem_alg = kem_from_id(kem_id).ok_or(Error::InvalidAlgorithm {
+            algorithm: "Unknown envelope KEM id",
+        })?;
+        let aead_id = reader.read_u16()?;
+        let aead_alg = aead_from_id(aead_id).ok_or(Error::InvalidAlgorithm {
+            algorithm: "Unknown envelope AEAD id",
+        })?;
+        let enc_len = reader.read_u32()? as usize;
+        let enc = reader.take(enc_len)?;
+        let nonce_len = usize::from(reader.read_u16()?);
+        let nonce = reader.take(nonce_len)?;
+        let ciphertext = reader.rest;
+
+        let aead = lib_q_aead::create_aead(aead_alg)?;
+        if nonce.len() != aead.nonce_size() {
+            return Err(Error::InvalidNonceSize {
+                expected: aead.nonce_size(),
+                actual: nonce.len(),
+            });
+        }
+
+        let shared_secret =
+            Zeroizing::new(LibQKemProvider::new()?.decapsulate(kem_alg, recipient_sk, enc)?);
+        let key = derive_key(aead.as_ref(), &shared_secret);
+        let bound_aad = bound_aad(kem_id, aead_id, enc, aad)?;
+        aead.decrypt(
+            &key,
+            &Nonce::new(nonce.to_vec()),
+            ciphertext,
+            Some(&bound_aad),
+        )
+    }
+}
+
+/// Bounds-checked cursor over a frame
+struct FrameReader<'a> {
+    rest: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> FrameReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.rest.len() {
+            return Err(Error::InvalidCiphertextSize {
+                expected: self.offset.saturating_add(len),
+                actual: self.offset + self.rest.len(),
+            });
+        }
+        let (head, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        self.offset += len;
+        Ok(head)
+    }
+
+    fn read_u16(&mut self) -> Result<u16> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+/// AEAD associated data binding the frame header and the encapsulated key to `aad`
+fn bound_aad(kem_id: u16, aead_id: u16, enc: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    codec::write_frame(
+        &mut out,
+        &[
+            &ENVELOPE_MAGIC,
+            &[ENVELOPE_VERSION],
+            &kem_id.to_be_bytes(),
+            &aead_id.to_be_bytes(),
+            enc,
+            aad,
+        ],
+    )?;
+    Ok(out)
+}
+
+/// `SHAKE256(LABEL_ENVELOPE_KDF || shared secret)`, truncated to the AEAD key size
+fn derive_key(aead: &dyn AeadWithMetadata, shared_secret: &[u8]) -> AeadKey {
+    AeadKey::new(hash_with_label::<Shake256>(
+        LABEL_ENVELOPE_KDF,
+        &[shared_secret],
+        aead.key_size(),
+    ))
+}
+
+/// Envelope KEM id: the [`Algorithm::wire_id`] of a KEM
+fn kem_id(algorithm: Algorithm) -> Option<u16> {
+    (algorithm.category() == AlgorithmCategory::Kem).then(|| algorithm.wire_id())
+}
+
+fn kem_from_id(id: u16) -> Option<Algorithm> {
+    Algorithm::from_wire_id(id).filter(|algorithm| algorithm.category() == AlgorithmCategory::Kem)
+}
+
+/// Envelope AEAD id: the [`Algorithm::wire_id`] of an AEAD (`0x30xx`)
+fn aead_id(algorithm: Algorithm) -> Option<u16> {
+    (algorithm.category() == AlgorithmCategory::Aead).then(|| algorithm.wire_id())
+}
+
+fn aead_from_id(id: u16) -> Option<Algorithm> {
+    Algorithm::from_wire_id(id).filter(|algorithm| algorithm.category() == AlgorithmCategory::Aead)
+}
@@ -0,0 +1,69 @@
+//! Which registered algorithms this build can actually run
+//!
+//! [`supported_algorithms`](crate::supported_algorithms) lists everything the core registry
+//! knows about, including algorithms whose implementation crate is not compiled in and that
+//! therefore fail at runtime. [`implemented_algorithms`] narrows that list to the
+//! algorithms backed by the features enabled on this crate.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use lib_q_core::{
+    Algorithm,
+    AlgorithmCategory,
+};
+
+/// Whether an [`Algorithm`] has a working implementation in this build
+pub trait AlgorithmAvailability {
+    /// Returns `true` if the umbrella crate can run this algorithm with the enabled features
+    fn is_implemented(&self) -> bool;
+}
+
+impl AlgorithmAvailability for Algorithm {
+    fn is_implemented(&self) -> bool {
+        match self.category() {
+            AlgorithmCategory::Kem => match self {
+                Algorithm::MlKem512 | Algorithm::MlKem768 | Algorithm::MlKem1024 => {
+                    cfg!(feature = "ml-kem")
+                }
+                Algorithm::Hqc128 | Algorithm::Hqc192 | Algorithm::Hqc256 => cfg!(feature = "hqc"),
+                // The `cb-kem` feature only builds the mceliece348864 parameter set.
+                Algorithm::CbKem348864 => cfg!(feature = "cb-kem"),
+                _ => false,
+            },
+            AlgorithmCategory::Signature => {
+                matches!(
+                    self,
+                    Algorithm::MlDsa44 |
+                        Algorithm::MlDsa65 |
+                        Algorithm::MlDsa87 |
+                        Algorithm::SlhDsaSha256128fRobust |
+                        Algorithm::SlhDsaSha256192fRobust |
+                        Algorithm::SlhDsaSha256256fRobust |
+                        Algorithm::SlhDsaShake256128fRobust |
+                        Algorithm::SlhDsaShake256192fRobust |
+                        Algorithm::SlhDsaShake256256fRobust
+                ) || (cfg!(feature = "fn-dsa") &&
+                    matches!(
+                        self,
+                        Algorithm::FnDsa | Algorithm::FnDsa512 | Algorithm::FnDsa1024
+                    ))
+            }
+            AlgorithmCategory::Hash => lib_q_hash::algorithm_to_hash_algorithm(*self).is_ok(),
+            AlgorithmCategory::Aead => lib_q_aead::is_algorithm_available(*self),
+            _ => false,
+        }
+    }
+}
+
+/// Registered algorithms that this build can run, in declaration order
+///
+/// Always a subset of [`supported_algorithms`](crate::supported_algorithms).
+pub fn implemented_algorithms() -> Vec<Algorithm> {
+    let supported = crate::supported_algorithms();
+    Algorithm::ALL
+        .iter()
+        .copied()
+        .filter(|algorithm| supported.contains(algorithm) && algorithm.is_implemented())
+        .collect()
+}
@@ -0,0 +1,152 @@
+//! Keypair generation for any KEM or signature algorithm in one call
+//!
+//! [`generate_keypair`] picks the KEM or signature provider from
+//! [`Algorithm::category`], so callers that only need key material do not have to set up a
+//! [`KemContext`] or [`SignatureContext`] first.
+//!
+//! ```rust
+//! use libq::{
+//!     Algorithm,
+//!     generate_keypair,
+//! };
+//!
+//! let keypair = generate_keypair(Algorithm::MlDsa65)?;
+//! assert_eq!(keypair.public_key_bytes().len(), 1952);
+//! # Ok::<(), libq::Error>(())
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use core::fmt;
+
+use lib_q_core::{
+    Algorithm,
+    AlgorithmCategory,
+    Error,
+    KemContext,
+    KemKeypair,
+    Result,
+    SecretExportConsent,
+    SigKeypair,
+    SignatureContext,
+};
+
+use crate::builder::unsupported;
+use crate::implemented::AlgorithmAvailability;
+
+/// Keypair produced by [`generate_keypair`]
+///
+/// `Debug` output shows the algorithm and key sizes, never the secret key.
+pub struct GeneratedKeyPair {
+    algorithm: Algorithm,
+    keys: Keys,
+}
+
+enum Keys {
+    Kem(KemKeypair),
+    Signature(SigKeypair),
+}
+
+impl GeneratedKeyPair {
+    /// The algorithm the keys belong to
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Encoded public key
+    pub fn public_key_bytes(&self) -> &[u8] {
+        match &self.keys {
+            Keys::Kem(keypair) => keypair.public_key().as_bytes(),
+            Keys::Signature(keypair) => keypair.public_key().as_bytes(),
+        }
+    }
+
+    /// Encoded secret key; see [`SecretExportConsent`]
+    pub fn secret_key_bytes(&self, consent: SecretExportConsent) -> &[u8] {
+        match &self.keys {
+            Keys::Kem(keypair) => keypair.secret_key().expose_secret(consent),
+            Keys::Signature(keypair) => keypair.secret_key().expose_secret(consent),
+        }
+    }
+
+    /// The KEM keypair, if [`algorithm`](Self::algorithm) is a KEM
+    pub fn as_kem(&self) -> Option<&KemKeypair> {
+        match &self.keys {
+            Keys::Kem(keypair) => Some(keypair),
+            Keys::Signature(_) => None,
+        }
+    }
+
+    /// The signature keypair, if [`algorithm`](Self::algorithm) is a signature scheme
+    pub fn as_signature(&self) -> Option<&SigKeypair> {
+        match &self.keys {
+            Keys::Signature(keypair) => Some(keypair),
+            Keys::Kem(_) => None,
+        }
+    }
+}
+
+impl fmt::Debug for GeneratedKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeneratedKeyPair")
+            .field("algorithm", &self.algorithm)
+            .field("public_key_len", &self.public_key_bytes().len())
+            .field(
+                "secret_key_len",
+                &self.secret_key_bytes(SecretExportConsent).len(),
+            )
+            .finish()
+    }
+}
+
+/// Generate a fresh keypair for a KEM or signature algorithm
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAlgorithm`] for algorithms that have no keypair (hashes, AEADs,
+/// ...), [`Error::UnsupportedAlgorithm`] if the algorithm is not implemented with the
+/// features enabled on this crate, and any key generation error from the provider.
+pub fn generate_keypair(algorithm: Algorithm) -> Result<GeneratedKeyPair> {
+    let category = algorithm.category();
+    if !matches!(
+        category,
+        AlgorithmCategory::Kem | AlgorithmCategory::Signature
+    ) {
+        return Err(Error::InvalidAlgorithm {
+            algorithm: "generate_keypair expects a KEM or signature algorithm",
+        });
+    }
+    if !algorithm.is_implemented() {
+        return Err(unsupported(algorithm));
+    }
+
+    let keys = if category == AlgorithmCategory::Kem {
+        Keys::Kem(kem_context(algorithm)?.generate_keypair(algorithm, None)?)
+    } else {
+        let provider = crate::LibQSignatureProvider::new()?;
+        Keys::Signature(
+            SignatureContext::with_provider(Box::new(provider))
+                .generate_keypair(algorithm, None)?,
+        )
+    };
+    Ok(GeneratedKeyPair { algorithm, keys })
+}
+
+/// KEM context whose provider handles `algorithm`; only called for implemented KEMs
+fn kem_context(algorithm: Algorithm) -> Result<KemContext> {
+    #[cfg(feature = "cb-kem")]
+    if algorithm == Algorithm::CbKem348864 {
+        return Ok(KemContext::with_provider(Box::new(
+            crate::LibQCbKemProvider::new()?,
+        )));
+    }
+    #[cfg(any(feature = "ml-kem", feature = "hqc"))]
+    {
+        let _ = algorithm;
+        Ok(KemContext::with_provider(Box::new(
+            crate::LibQKemProvider::new()?,
+        )))
+    }
+    #[cfg(not(any(feature = "ml-kem", feature = "hqc")))]
+    Err(unsupported(algorithm))
+}
@@ -69,6 +69,8 @@
 //! - `saturnin`: Enable Saturnin authenticated encryption
 //! - `romulus`: Enable Romulus-N and Romulus-M AEAD (LWC / SKINNY-128-384+)
 //! - `hqc`: Enable HQC key encapsulation mechanism (HQC-128 / HQC-192 / HQC-256)
+//! - `ml-kem` / `hqc` also enable [`envelope::Envelope`], one-call KEM + AEAD encryption
+//! - `hpke`: Enable HPKE (`lib-q-hpke`) and the sign-then-encrypt helpers in [`signcrypt`]
 //! - `random`: Enable lib-q-random for secure random number generation
 //! - `random-custom-entropy`: Enable custom entropy source support
 //! - `all-algorithms`: Enable all available algorithms
@@ -112,10 +114,37 @@ use alloc::boxed::Box;
 
 #[cfg(feature = "alloc")]
 pub mod aead;
+#[cfg(feature = "alloc")]
+pub mod builder;
+#[cfg(all(feature = "alloc", any(feature = "ml-kem", feature = "hqc")))]
+pub mod envelope;
+#[cfg(feature = "alloc")]
+pub mod implemented;
+#[cfg(feature = "alloc")]
+pub mod keypair;
+#[cfg(all(feature = "alloc", feature = "hpke"))]
+pub mod signcrypt;
 
 // Re-export everything from lib-q-core
 // Re-export the core provider as the main provider
 // Re-export specific types and functions for convenience
+#[cfg(feature = "alloc")]
+pub use builder::{
+    LibQ,
+    LibQBuilder,
+};
+#[cfg(all(feature = "alloc", any(feature = "ml-kem", feature = "hqc")))]
+pub use envelope::Envelope;
+#[cfg(feature = "alloc")]
+pub use implemented::{
+    AlgorithmAvailability,
+    implemented_algorithms,
+};
+#[cfg(feature = "alloc")]
+pub use keypair::{
+    GeneratedKeyPair,
+    generate_keypair,
+};
 #[cfg(feature = "cb-kem")]
 pub use lib_q_cb_kem::LibQCbKemProvider;
 pub use lib_q_core::{
@@ -296,12 +325,24 @@ pub mod wasm {
         String,
         ToString,
     };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+    #[cfg(not(feature = "std"))]
+    use alloc::{
+        format,
+        vec,
+    };
     #[cfg(feature = "std")]
     use std::string::{
         String,
         ToString,
     };
 
+    /// One-shot AEAD encryption and decryption for every AEAD compiled into the build
+    pub use lib_q_aead::wasm::{
+        aead_decrypt,
+        aead_encrypt,
+    };
     pub use lib_q_core::wasm::*;
     use wasm_bindgen::prelude::*;
 
@@ -414,6 +455,93 @@ pub mod wasm {
     pub fn hex_to_bytes_wasm(hex: &str) -> Result<js_sys::Uint8Array, JsValue> {
         hex_to_bytes(hex).map_err(|e| lib_q_core::wasm_common::wasm_js_error("LIB_Q_HEX", e))
     }
+
+    /// Squeeze `out_len` bytes from an extendable-output hash
+    ///
+    /// Accepts SHAKE, cSHAKE (empty customization) and TurboSHAKE; `hash` on the hash context
+    /// always returns the algorithm's default length. A shorter output is a prefix of a longer
+    /// one for the same input. Output is capped at 1 MiB.
+    ///
+    /// ```js
+    /// const digest = hash_xof("shake256", data, 64);
+    /// ```
+    #[wasm_bindgen]
+    pub fn hash_xof(algorithm: &str, data: &[u8], out_len: usize) -> Result<Vec<u8>, JsValue> {
+        const MAX_XOF_OUTPUT: usize = 1024 * 1024;
+
+        use lib_q_core::wasm_common::wasm_js_error;
+
+        let parsed = parse_algorithm_wasm(algorithm)?;
+        if !lib_q_hash::HashEngine::is_xof(parsed) {
+            return Err(wasm_js_error(
+                "LIB_Q_HASH",
+                format_args!("{algorithm} is not an extendable-output hash"),
+            ));
+        }
+        if out_len == 0 || out_len > MAX_XOF_OUTPUT {
+            return Err(wasm_js_error(
+                "LIB_Q_HASH",
+                format_args!("Output length {out_len} must be between 1 and {MAX_XOF_OUTPUT}"),
+            ));
+        }
+
+        let mut out = vec![0u8; out_len];
+        lib_q_hash::HashEngine::new()
+            .hash_into(parsed, data, &mut out)
+            .map_err(|e| wasm_js_error("LIB_Q_HASH", e))?;
+        Ok(out)
+    }
+
+    /// Encrypt `plaintext` to a KEM public key in one call (see [`crate::Envelope::seal`])
+    ///
+    /// Encapsulation, key derivation and encryption all run inside WASM, so the shared
+    /// secret and the AEAD key never cross into JavaScript. Algorithm names are the usual
+    /// lower-case forms, e.g. `"ml-kem-768"` and `"shake256-aead"`.
+    ///
+    /// ```js
+    /// const sealed = envelope_seal(recipient.public_key, "ml-kem-768", "shake256-aead", message, aad);
+    /// const opened = envelope_open(recipient.secret_key, sealed, aad);
+    /// ```
+    #[cfg(any(feature = "ml-kem", feature = "hqc"))]
+    #[wasm_bindgen]
+    pub fn envelope_seal(
+        recipient_public_key: &[u8],
+        kem_algorithm: &str,
+        aead_algorithm: &str,
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, JsValue> {
+        let kem_algorithm = parse_algorithm_wasm(kem_algorithm)?;
+        let aead_algorithm = parse_algorithm_wasm(aead_algorithm)?;
+        crate::Envelope::seal(
+            &lib_q_core::KemPublicKey::new(recipient_public_key.to_vec()),
+            kem_algorithm,
+            aead_algorithm,
+            plaintext,
+            aad,
+        )
+        .map_err(|e| lib_q_core::wasm_common::wasm_js_error("LIB_Q_ENVELOPE", e))
+    }
+
+    /// Decrypt a frame produced by [`envelope_seal`]
+    ///
+    /// The secret key is moved into a zeroize-on-drop buffer; JavaScript callers should
+    /// still clear their own copy. Wrong keys, wrong `aad` and tampered frames are all
+    /// reported with code `LIB_Q_ENVELOPE`.
+    #[cfg(any(feature = "ml-kem", feature = "hqc"))]
+    #[wasm_bindgen]
+    pub fn envelope_open(
+        recipient_secret_key: Vec<u8>,
+        sealed: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, JsValue> {
+        crate::Envelope::open(
+            &lib_q_core::KemSecretKey::new(recipient_secret_key),
+            sealed,
+            aad,
+        )
+        .map_err(|e| lib_q_core::wasm_common::wasm_js_error("LIB_Q_ENVELOPE", e))
+    }
 }
 
 #[cfg(test)]
@@ -422,6 +550,7 @@ mod tests {
     use lib_q_core::{
         CryptoProvider,
         KemOperations, // Required for trait methods to be in scope
+        SecretExportConsent,
     };
     #[cfg(feature = "hqc")]
     use lib_q_hqc::HqcParams;
@@ -450,6 +579,184 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_implemented_algorithms_subset_of_supported() {
+        let supported = supported_algorithms();
+        let implemented = implemented_algorithms();
+        assert!(implemented.iter().all(|alg| supported.contains(alg)));
+        assert!(implemented.iter().all(|alg| alg.is_implemented()));
+
+        for alg in [Algorithm::MlDsa44, Algorithm::MlDsa65, Algorithm::MlDsa87] {
+            assert!(implemented.contains(&alg), "{alg:?}");
+        }
+        for alg in [
+            Algorithm::MlKem512,
+            Algorithm::MlKem768,
+            Algorithm::MlKem1024,
+        ] {
+            assert_eq!(
+                implemented.contains(&alg),
+                cfg!(feature = "ml-kem"),
+                "{alg:?}"
+            );
+        }
+        assert_eq!(
+            implemented.contains(&Algorithm::FnDsa512),
+            cfg!(feature = "fn-dsa")
+        );
+        assert!(!Algorithm::FaestReserved.is_implemented());
+        assert!(!implemented.contains(&Algorithm::FaestReserved));
+        assert!(
+            implemented
+                .iter()
+                .all(|alg| alg.category() != AlgorithmCategory::PrivacyProtocol)
+        );
+    }
+
+    #[cfg(all(feature = "alloc", feature = "ml-kem"))]
+    #[test]
+    fn test_builder_wires_requested_contexts() {
+        let mut libq = LibQBuilder::new()
+            .with_kem(Algorithm::MlKem768)
+            .with_signature(Algorithm::MlDsa65)
+            .with_hash(Algorithm::Sha3_256)
+            .build()
+            .expect("all requested algorithms are implemented");
+        assert_eq!(
+            libq.algorithms(),
+            [Algorithm::MlKem768, Algorithm::MlDsa65, Algorithm::Sha3_256]
+        );
+
+        let keypair = libq
+            .kem_context()
+            .generate_keypair(Algorithm::MlKem768, None)
+            .expect("ML-KEM-768 keygen");
+        let (ciphertext, shared) = libq
+            .kem_context()
+            .encapsulate(Algorithm::MlKem768, keypair.public_key(), None)
+            .expect("encapsulate");
+        assert_eq!(
+            libq.kem_context()
+                .decapsulate(Algorithm::MlKem768, keypair.secret_key(), &ciphertext)
+                .expect("decapsulate"),
+            shared
+        );
+        let signer = libq
+            .sig_context()
+            .generate_keypair(Algorithm::MlDsa65, None)
+            .expect("ML-DSA-65 keygen");
+        let signature = libq
+            .sig_context()
+            .sign(Algorithm::MlDsa65, signer.secret_key(), b"built", None)
+            .expect("sign");
+        assert!(
+            libq.sig_context()
+                .verify(
+                    Algorithm::MlDsa65,
+                    signer.public_key(),
+                    b"built",
+                    &signature
+                )
+                .expect("verify")
+        );
+        assert_eq!(
+            libq.hash_context()
+                .hash(Algorithm::Sha3_256, b"")
+                .expect("hash")
+                .len(),
+            32
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_builder_rejects_unimplemented_and_misplaced_algorithms() {
+        assert!(matches!(
+            LibQBuilder::new()
+                .with_signature(Algorithm::MlDsa44)
+                .with_signature(Algorithm::FaestReserved)
+                .build(),
+            Err(Error::UnsupportedAlgorithm { .. }) | Err(Error::InvalidAlgorithm { .. })
+        ));
+        assert!(
+            matches!(
+                LibQBuilder::new().with_kem(Algorithm::Hqc128).build(),
+                Err(Error::UnsupportedAlgorithm { .. })
+            ) != cfg!(feature = "hqc")
+        );
+        assert!(matches!(
+            LibQBuilder::new().with_kem(Algorithm::MlDsa65).build(),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+
+        // Contexts of categories that were not requested have no provider.
+        let mut hash_only = LibQBuilder::new()
+            .with_hash(Algorithm::Shake256)
+            .build()
+            .expect("hash-only build");
+        assert!(matches!(
+            hash_only
+                .sig_context()
+                .generate_keypair(Algorithm::MlDsa65, None),
+            Err(Error::ProviderNotConfigured { .. })
+        ));
+    }
+
+    #[cfg(all(feature = "alloc", feature = "ml-kem"))]
+    #[test]
+    fn test_generate_keypair_routes_by_category() {
+        let kem = generate_keypair(Algorithm::MlKem768).expect("ML-KEM-768 keygen");
+        assert_eq!(kem.algorithm(), Algorithm::MlKem768);
+        assert_eq!(
+            Some(kem.public_key_bytes().len()),
+            Algorithm::MlKem768.public_key_len()
+        );
+        assert_eq!(
+            Some(kem.secret_key_bytes(SecretExportConsent).len()),
+            Algorithm::MlKem768.secret_key_len()
+        );
+        let kem_keypair = kem.as_kem().expect("KEM keys");
+        let kem_context = KemContext::with_provider(Box::new(LibQKemProvider::new().unwrap()));
+        let (ciphertext, shared) = kem_context
+            .encapsulate(Algorithm::MlKem768, kem_keypair.public_key(), None)
+            .unwrap();
+        assert_eq!(
+            kem_context
+                .decapsulate(Algorithm::MlKem768, kem_keypair.secret_key(), &ciphertext)
+                .unwrap(),
+            shared
+        );
+
+        let sig = generate_keypair(Algorithm::MlDsa65).expect("ML-DSA-65 keygen");
+        assert_eq!(sig.algorithm(), Algorithm::MlDsa65);
+        assert!(sig.as_kem().is_none());
+        assert_eq!(
+            Some(sig.public_key_bytes().len()),
+            Algorithm::MlDsa65.public_key_len()
+        );
+        let signer = sig.as_signature().expect("signature keys");
+        let sig_context = create_signature_context();
+        let signature = sig_context
+            .sign(Algorithm::MlDsa65, signer.secret_key(), b"facade", None)
+            .unwrap();
+        assert!(
+            sig_context
+                .verify(
+                    Algorithm::MlDsa65,
+                    signer.public_key(),
+                    b"facade",
+                    &signature
+                )
+                .unwrap()
+        );
+
+        assert!(matches!(
+            generate_keypair(Algorithm::Sha3_256),
+            Err(Error::InvalidAlgorithm { .. })
+        ));
+    }
+
     /// `libq::create_signature_context` must ship with `LibQSignatureProvider` wired so ML-DSA works
     /// without callers manually calling `set_provider`.
     #[cfg(feature = "alloc")]
@@ -540,7 +847,12 @@ mod tests {
             );
             let keypair = kem_result.unwrap();
             assert!(!keypair.public_key().as_bytes().is_empty());
-            assert!(!keypair.secret_key().as_bytes().is_empty());
+            assert!(
+                !keypair
+                    .secret_key()
+                    .expose_secret(SecretExportConsent)
+                    .is_empty()
+            );
         }
 
         #[cfg(feature = "hqc")]
@@ -554,7 +866,10 @@ mod tests {
                 lib_q_hqc::Hqc1Params::PUBLIC_KEY_BYTES
             );
             assert_eq!(
-                keypair.secret_key().as_bytes().len(),
+                keypair
+                    .secret_key()
+                    .expose_secret(SecretExportConsent)
+                    .len(),
                 lib_q_hqc::Hqc1Params::SECRET_KEY_BYTES
             );
             let (ciphertext, shared1) = kem_provider
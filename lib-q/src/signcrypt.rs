@@ -0,0 +1,251 @@
+//! Sign-then-encrypt to an HPKE recipient
+//!
+//! [`signcrypt`] signs the plaintext with the sender's signature key and seals
+//! `len(plaintext) ‖ plaintext ‖ signature` to the recipient with HPKE; [`unsigncrypt`]
+//! opens the envelope and returns the plaintext only if the signature verifies. The
+//! signature also covers the HPKE encapsulated key, so a recipient cannot re-seal a signed
+//! message to a third party under the sender's name.
+//!
+//! The HPKE suite is ML-KEM (chosen from the recipient key size) with HKDF-SHAKE256 and
+//! Saturnin-256, the [`HpkeContext`] default. The output is `enc ‖ ciphertext`.
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    vec::Vec,
+};
+
+use lib_q_core::labels::LABEL_SIGNCRYPT;
+use lib_q_core::{
+    Algorithm,
+    Error,
+    KemPublicKey,
+    KemSecretKey,
+    Result,
+    SigPublicKey,
+    SigSecretKey,
+    SignatureOperations,
+};
+use lib_q_hpke::{
+    HpkeAead,
+    HpkeCipherSuite,
+    HpkeContext,
+    HpkeKdf,
+    HpkeKem,
+    HpkeSenderContext,
+};
+use zeroize::Zeroizing;
+
+use crate::LibQSignatureProvider;
+
+const KEMS: [HpkeKem; 3] = [HpkeKem::MlKem512, HpkeKem::MlKem768, HpkeKem::MlKem1024];
+
+fn hpke_context(kem: HpkeKem) -> HpkeContext {
+    let mut hpke = HpkeContext::new();
+    hpke.set_cipher_suite(HpkeCipherSuite::new(
+        kem,
+        HpkeKdf::HkdfShake256,
+        HpkeAead::Saturnin256,
+    ));
+    hpke
+}
+
+fn unsupported_kem_key(key_type: &str, len: usize) -> Error {
+    Error::InvalidKey {
+        key_type: key_type.into(),
+        reason: format!("{len}-byte key does not match ML-KEM-512, ML-KEM-768 or ML-KEM-1024"),
+    }
+}
+
+/// Message actually signed: label, encapsulated key and plaintext
+fn signed_transcript(enc: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let mut transcript =
+        Vec::with_capacity(LABEL_SIGNCRYPT.len() + 4 + enc.len() + plaintext.len());
+    transcript.extend_from_slice(LABEL_SIGNCRYPT);
+    transcript.extend_from_slice(&(enc.len() as u32).to_be_bytes());
+    transcript.extend_from_slice(enc);
+    transcript.extend_from_slice(plaintext);
+    transcript
+}
+
+/// Sign `plaintext` with `sig_sk` and seal it, with the signature, to `recipient_pk`
+///
+/// `info` and `aad` are passed to HPKE unchanged and must be supplied again to
+/// [`unsigncrypt`].
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidKey`] if `recipient_pk` is not an ML-KEM public key,
+/// [`Error::InvalidMessageSize`] if `plaintext` exceeds `u32::MAX` bytes, and any error from
+/// signing or HPKE sealing.
+pub fn signcrypt(
+    sig_sk: &SigSecretKey,
+    sig_alg: Algorithm,
+    recipient_pk: &KemPublicKey,
+    info: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>> {
+    let plaintext_len = u32::try_from(plaintext.len()).map_err(|_| Error::InvalidMessageSize {
+        max: u32::MAX as usize,
+        actual: plaintext.len(),
+    })?;
+    let kem = KEMS
+        .into_iter()
+        .find(|kem| kem.public_key_len() == recipient_pk.data.len())
+        .ok_or_else(|| unsupported_kem_key("KemPublicKey", recipient_pk.data.len()))?;
+
+    let sender = hpke_context(kem).setup_sender(recipient_pk, info)?;
+    let signature = LibQSignatureProvider::new()?.sign(
+        sig_alg,
+        sig_sk,
+        &signed_transcript(&sender.encapsulated_key, plaintext),
+        None,
+    )?;
+
+    seal_payload(sender, aad, plaintext_len, plaintext, &signature)
+}
+
+/// Seal `len ‖ plaintext ‖ signature` and prefix the encapsulated key
+fn seal_payload(
+    mut sender: HpkeSenderContext,
+    aad: &[u8],
+    plaintext_len: u32,
+    plaintext: &[u8],
+    signature: &[u8],
+) -> Result<Vec<u8>> {
+    let mut payload = Zeroizing::new(Vec::with_capacity(4 + plaintext.len() + signature.len()));
+    payload.extend_from_slice(&plaintext_len.to_be_bytes());
+    payload.extend_from_slice(plaintext);
+    payload.extend_from_slice(signature);
+    let ciphertext = sender.seal(aad, &payload)?;
+
+    let mut sealed = core::mem::take(&mut sender.encapsulated_key);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// Open a [`signcrypt`] output and return the plaintext if `sender_pk` signed it
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidKey`] if `recipient_sk` is not an ML-KEM secret key,
+/// [`Error::InvalidCiphertextSize`] if `sealed` is shorter than the encapsulated key, any
+/// error from HPKE opening, and [`Error::VerificationFailed`] if the decrypted payload is
+/// malformed or its signature does not verify. No plaintext is returned in any error case.
+pub fn unsigncrypt(
+    sender_pk: &SigPublicKey,
+    sig_alg: Algorithm,
+    recipient_sk: &KemSecretKey,
+    info: &[u8],
+    aad: &[u8],
+    sealed: &[u8],
+) -> Result<Vec<u8>> {
+    let kem = KEMS
+        .into_iter()
+        .find(|kem| kem.secret_key_len() == recipient_sk.data.len())
+        .ok_or_else(|| unsupported_kem_key("KemSecretKey", recipient_sk.data.len()))?;
+    if sealed.len() < kem.enc_len() {
+        return Err(Error::InvalidCiphertextSize {
+            expected: kem.enc_len(),
+            actual: sealed.len(),
+        });
+    }
+    let (enc, ciphertext) = sealed.split_at(kem.enc_len());
+
+    let mut receiver = hpke_context(kem).setup_receiver(enc, recipient_sk, info)?;
+    let payload = Zeroizing::new(receiver.open(aad, ciphertext)?);
+    verified_plaintext(sender_pk, sig_alg, enc, &payload)
+}
+
+fn verified_plaintext(
+    sender_pk: &SigPublicKey,
+    sig_alg: Algorithm,
+    enc: &[u8],
+    payload: &[u8],
+) -> Result<Vec<u8>> {
+    let malformed = || Error::VerificationFailed {
+        operation: "signcrypt payload".into(),
+    };
+    let (len, rest) = payload.split_first_chunk::<4>().ok_or_else(malformed)?;
+    let len = u32::from_be_bytes(*len) as usize;
+    if len > rest.len() {
+        return Err(malformed());
+    }
+    let (plaintext, signature) = rest.split_at(len);
+
+    let transcript = signed_transcript(enc, plaintext);
+    if LibQSignatureProvider::new()?.verify(sig_alg, sender_pk, &transcript, signature)? {
+        Ok(plaintext.to_vec())
+    } else {
+        Err(Error::VerificationFailed {
+            operation: "signcrypt signature".into(),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "ml-kem"))]
+mod tests {
+    use lib_q_core::KemContext;
+
+    use super::*;
+    use crate::{
+        LibQKemProvider,
+        create_signature_context,
+    };
+
+    #[test]
+    fn tampered_signature_is_rejected_after_successful_decryption() {
+        let sender = create_signature_context()
+            .generate_keypair(Algorithm::MlDsa44, None)
+            .unwrap();
+        let recipient = KemContext::with_provider(Box::new(LibQKemProvider::new().unwrap()))
+            .generate_keypair(Algorithm::MlKem512, None)
+            .unwrap();
+        let plaintext = b"pay 10 to alice";
+
+        // Build a genuine HPKE envelope around a signature with one bit flipped, so the
+        // recipient decrypts it successfully and only the signature check can reject it.
+        let seal_with = |flip: bool| {
+            let hpke_sender = hpke_context(HpkeKem::MlKem512)
+                .setup_sender(recipient.public_key(), b"info")
+                .unwrap();
+            let mut signature = LibQSignatureProvider::new()
+                .unwrap()
+                .sign(
+                    Algorithm::MlDsa44,
+                    sender.secret_key(),
+                    &signed_transcript(&hpke_sender.encapsulated_key, plaintext),
+                    None,
+                )
+                .unwrap();
+            if flip {
+                signature[7] ^= 0x01;
+            }
+            seal_payload(
+                hpke_sender,
+                b"aad",
+                plaintext.len() as u32,
+                plaintext,
+                &signature,
+            )
+            .unwrap()
+        };
+        let open = |sealed: &[u8]| {
+            unsigncrypt(
+                sender.public_key(),
+                Algorithm::MlDsa44,
+                recipient.secret_key(),
+                b"info",
+                b"aad",
+                sealed,
+            )
+        };
+
+        assert_eq!(open(&seal_with(false)).unwrap(), plaintext);
+        assert!(matches!(
+            open(&seal_with(true)),
+            Err(Error::VerificationFailed { .. })
+        ));
+    }
+}
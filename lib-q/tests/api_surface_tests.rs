@@ -155,3 +155,38 @@ fn umbrella_aead_context_smoke() {
     let ctx = libq::aead::context();
     assert!(ctx.provider().is_some());
 }
+
+#[test]
+fn umbrella_primitives_report_algorithm_and_category() {
+    use lib_q_core::{
+        AlgorithmCategory,
+        Primitive,
+    };
+
+    let primitives: Vec<Box<dyn Primitive>> = vec![
+        Box::new(lib_q_kem::ml_kem::MlKem768Impl::default()),
+        Box::new(lib_q_kem::hqc::Hqc128Impl::new()),
+        Box::new(lib_q_sig::ml_dsa::MlDsa::ml_dsa_65()),
+        Box::new(lib_q_sig::slh_dsa::SlhDsa::new()),
+        Box::new(lib_q_fn_dsa::FnDsa512::new()),
+        Box::new(lib_q_hash::Sha3_256Hash::new()),
+        Box::new(lib_q_hash::Sha256Hash::new()),
+    ];
+    let expected = [
+        (Algorithm::MlKem768, AlgorithmCategory::Kem),
+        (Algorithm::Hqc128, AlgorithmCategory::Kem),
+        (Algorithm::MlDsa65, AlgorithmCategory::Signature),
+        (
+            Algorithm::SlhDsaShake256128fRobust,
+            AlgorithmCategory::Signature,
+        ),
+        (Algorithm::FnDsa512, AlgorithmCategory::Signature),
+        (Algorithm::Sha3_256, AlgorithmCategory::Hash),
+        (Algorithm::Sha256, AlgorithmCategory::Hash),
+    ];
+    assert_eq!(primitives.len(), expected.len());
+    for (primitive, (algorithm, category)) in primitives.iter().zip(expected) {
+        assert_eq!(primitive.algorithm(), algorithm);
+        assert_eq!(primitive.category(), category);
+    }
+}
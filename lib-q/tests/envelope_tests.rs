@@ -0,0 +1,222 @@
+//! KEM + AEAD round trips and frame tampering through `libq::Envelope`.
+
+use lib_q_core::{
+    KemContext,
+    KemKeypair,
+};
+use libq::envelope::{
+    ENVELOPE_MAGIC,
+    ENVELOPE_VERSION,
+};
+use libq::{
+    Algorithm,
+    Envelope,
+    Error,
+    LibQKemProvider,
+};
+
+const AAD: &[u8] = b"envelope header";
+
+/// Magic and version precede the algorithm ids
+const IDS: usize = 5;
+
+fn recipient(kem: Algorithm) -> KemKeypair {
+    KemContext::with_provider(Box::new(LibQKemProvider::new().expect("LibQKemProvider")))
+        .generate_keypair(kem, None)
+        .expect("ML-KEM keygen")
+}
+
+#[test]
+fn envelope_round_trips_for_each_ml_kem_level() {
+    for kem in [
+        Algorithm::MlKem512,
+        Algorithm::MlKem768,
+        Algorithm::MlKem1024,
+    ] {
+        let keys = recipient(kem);
+        for plaintext in [&b""[..], b"attack at dawn", &[0xA5; 4096]] {
+            let sealed = Envelope::seal(
+                keys.public_key(),
+                kem,
+                Algorithm::Shake256Aead,
+                plaintext,
+                AAD,
+            )
+            .unwrap();
+            let opened = Envelope::open(keys.secret_key(), &sealed, AAD).unwrap();
+            assert_eq!(opened, plaintext, "{kem:?}");
+        }
+    }
+}
+
+#[test]
+fn envelope_rejects_wrong_key_and_wrong_aad() {
+    let keys = recipient(Algorithm::MlKem768);
+    let other = recipient(Algorithm::MlKem768);
+    let sealed = Envelope::seal(
+        keys.public_key(),
+        Algorithm::MlKem768,
+        Algorithm::Shake256Aead,
+        b"secret",
+        AAD,
+    )
+    .unwrap();
+
+    assert!(Envelope::open(other.secret_key(), &sealed, AAD).is_err());
+    assert!(Envelope::open(keys.secret_key(), &sealed, b"other header").is_err());
+}
+
+#[test]
+fn envelope_rejects_cross_algorithm_tampering() {
+    let keys = recipient(Algorithm::MlKem768);
+    let sealed = Envelope::seal(
+        keys.public_key(),
+        Algorithm::MlKem768,
+        Algorithm::Shake256Aead,
+        b"secret",
+        AAD,
+    )
+    .unwrap();
+
+    // Relabel the frame as ML-KEM-1024 (id 0x0003) and as Saturnin (id 0x3001): the body is
+    // untouched, but it must not be processed under the other algorithm.
+    let mut relabelled_kem = sealed.clone();
+    relabelled_kem[IDS + 1] = 0x03;
+    assert!(Envelope::open(keys.secret_key(), &relabelled_kem, AAD).is_err());
+
+    let mut relabelled_aead = sealed.clone();
+    relabelled_aead[IDS + 3] = 0x01;
+    assert!(Envelope::open(keys.secret_key(), &relabelled_aead, AAD).is_err());
+
+    // Unknown ids are rejected before any decapsulation.
+    let mut unknown_kem = sealed.clone();
+    unknown_kem[IDS] = 0x7F;
+    assert!(matches!(
+        Envelope::open(keys.secret_key(), &unknown_kem, AAD),
+        Err(Error::InvalidAlgorithm { .. })
+    ));
+    let mut unknown_aead = sealed.clone();
+    unknown_aead[IDS + 2] = 0x7F;
+    assert!(matches!(
+        Envelope::open(keys.secret_key(), &unknown_aead, AAD),
+        Err(Error::InvalidAlgorithm { .. })
+    ));
+}
+
+#[test]
+fn envelope_binds_encapsulated_key_to_the_ciphertext() {
+    let keys = recipient(Algorithm::MlKem768);
+    let seal = || {
+        Envelope::seal(
+            keys.public_key(),
+            Algorithm::MlKem768,
+            Algorithm::Shake256Aead,
+            b"secret",
+            AAD,
+        )
+        .unwrap()
+    };
+    let (first, second) = (seal(), seal());
+
+    // Same lengths throughout, so the spliced frame parses and decapsulates.
+    let enc = IDS + 8..IDS + 8 + 1088;
+    let mut spliced = first.clone();
+    spliced[enc.clone()].copy_from_slice(&second[enc]);
+    assert!(matches!(
+        Envelope::open(keys.secret_key(), &spliced, AAD),
+        Err(Error::AuthenticationFailed { .. })
+    ));
+}
+
+/// SHAKE256-AEAD and the duplex-sponge AEAD share key and nonce sizes, so relabelling one as
+/// the other leaves a frame that parses; the bound header must still fail authentication.
+#[cfg(feature = "duplex-sponge-aead")]
+#[test]
+fn envelope_aead_id_relabel_fails_authentication() {
+    let keys = recipient(Algorithm::MlKem768);
+    let sealed = Envelope::seal(
+        keys.public_key(),
+        Algorithm::MlKem768,
+        Algorithm::Shake256Aead,
+        b"secret",
+        AAD,
+    )
+    .unwrap();
+
+    let mut relabelled = sealed.clone();
+    relabelled[IDS + 3] = 0x03;
+    assert!(matches!(
+        Envelope::open(keys.secret_key(), &relabelled, AAD),
+        Err(Error::AuthenticationFailed { .. } | Error::VerificationFailed { .. })
+    ));
+    assert_eq!(
+        Envelope::open(keys.secret_key(), &sealed, AAD).unwrap(),
+        b"secret"
+    );
+}
+
+#[test]
+fn envelope_rejects_truncated_length_fields() {
+    let keys = recipient(Algorithm::MlKem512);
+    let sealed = Envelope::seal(
+        keys.public_key(),
+        Algorithm::MlKem512,
+        Algorithm::Shake256Aead,
+        b"secret",
+        AAD,
+    )
+    .unwrap();
+
+    // Cut inside the magic, inside the ids, inside the encapsulated key length and inside
+    // the key itself.
+    for len in [0, 3, IDS + 3, IDS + 6, 100] {
+        assert!(
+            matches!(
+                Envelope::open(keys.secret_key(), &sealed[..len], AAD),
+                Err(Error::InvalidCiphertextSize { .. })
+            ),
+            "truncated to {len} bytes"
+        );
+    }
+
+    // An encapsulated key length pointing past the end of the frame.
+    let mut oversized = sealed.clone();
+    oversized[IDS + 4..IDS + 8].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert!(matches!(
+        Envelope::open(keys.secret_key(), &oversized, AAD),
+        Err(Error::InvalidCiphertextSize { .. })
+    ));
+}
+
+#[test]
+fn envelope_frames_start_with_magic_and_version() {
+    let keys = recipient(Algorithm::MlKem768);
+    let sealed = Envelope::seal(
+        keys.public_key(),
+        Algorithm::MlKem768,
+        Algorithm::Shake256Aead,
+        b"secret",
+        AAD,
+    )
+    .unwrap();
+    assert_eq!(sealed[..4], ENVELOPE_MAGIC);
+    assert_eq!(sealed[4], ENVELOPE_VERSION);
+    assert_eq!(
+        Envelope::open(keys.secret_key(), &sealed, AAD).unwrap(),
+        b"secret"
+    );
+
+    let mut wrong_magic = sealed.clone();
+    wrong_magic[0] ^= 0x20;
+    assert!(matches!(
+        Envelope::open(keys.secret_key(), &wrong_magic, AAD),
+        Err(Error::InvalidFormat { .. })
+    ));
+
+    let mut future_version = sealed.clone();
+    future_version[4] = ENVELOPE_VERSION + 1;
+    assert!(matches!(
+        Envelope::open(keys.secret_key(), &future_version, AAD),
+        Err(Error::InvalidFormat { .. })
+    ));
+}
@@ -0,0 +1,86 @@
+//! Sign-then-HPKE-seal round trips through `libq::signcrypt`.
+
+use lib_q_core::{
+    KemContext,
+    KemKeypair,
+    SigKeypair,
+};
+use libq::signcrypt::{
+    signcrypt,
+    unsigncrypt,
+};
+use libq::{
+    Algorithm,
+    Error,
+    LibQKemProvider,
+    create_signature_context,
+};
+
+const INFO: &[u8] = b"signcrypt test";
+const AAD: &[u8] = b"header";
+
+fn keys(kem: Algorithm) -> (SigKeypair, KemKeypair) {
+    let sender = create_signature_context()
+        .generate_keypair(Algorithm::MlDsa65, None)
+        .expect("ML-DSA-65 keygen");
+    let recipient =
+        KemContext::with_provider(Box::new(LibQKemProvider::new().expect("LibQKemProvider")))
+            .generate_keypair(kem, None)
+            .expect("ML-KEM keygen");
+    (sender, recipient)
+}
+
+#[test]
+fn signcrypt_roundtrip_for_each_ml_kem_level() {
+    for kem in [
+        Algorithm::MlKem512,
+        Algorithm::MlKem768,
+        Algorithm::MlKem1024,
+    ] {
+        let (sender, recipient) = keys(kem);
+        let sealed = signcrypt(
+            sender.secret_key(),
+            Algorithm::MlDsa65,
+            recipient.public_key(),
+            INFO,
+            AAD,
+            b"attack at dawn",
+        )
+        .expect("signcrypt");
+        let opened = unsigncrypt(
+            sender.public_key(),
+            Algorithm::MlDsa65,
+            recipient.secret_key(),
+            INFO,
+            AAD,
+            &sealed,
+        )
+        .expect("unsigncrypt");
+        assert_eq!(opened, b"attack at dawn", "{kem:?}");
+    }
+}
+
+#[test]
+fn unsigncrypt_rejects_wrong_sender_even_though_decryption_succeeds() {
+    let (sender, recipient) = keys(Algorithm::MlKem768);
+    let (impostor, _) = keys(Algorithm::MlKem768);
+    let sealed = signcrypt(
+        sender.secret_key(),
+        Algorithm::MlDsa65,
+        recipient.public_key(),
+        INFO,
+        AAD,
+        b"transfer 10 coins",
+    )
+    .unwrap();
+
+    let result = unsigncrypt(
+        impostor.public_key(),
+        Algorithm::MlDsa65,
+        recipient.secret_key(),
+        INFO,
+        AAD,
+        &sealed,
+    );
+    assert!(matches!(result, Err(Error::VerificationFailed { .. })));
+}
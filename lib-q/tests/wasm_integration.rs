@@ -35,6 +35,112 @@ fn umbrella_ml_kem_roundtrip_after_init_wasm() {
     assert_eq!(ss1, ss2);
 }
 
+#[cfg(all(target_arch = "wasm32", feature = "wasm", feature = "ml-kem"))]
+#[wasm_bindgen_test]
+fn umbrella_envelope_roundtrip_in_one_call() {
+    use libq::wasm::{
+        envelope_open,
+        envelope_seal,
+    };
+
+    init_wasm().expect("init_wasm");
+    let provider = LibQKemProvider::new().expect("kem provider");
+    let mut kem = KemContext::with_provider(Box::new(provider) as Box<dyn CryptoProvider>);
+    let kp = kem
+        .generate_keypair(Algorithm::MlKem768, None)
+        .expect("keypair");
+
+    let sealed = envelope_seal(
+        kp.public_key().as_bytes(),
+        "ml-kem-768",
+        "shake256-aead",
+        b"sealed in wasm",
+        b"aad",
+    )
+    .expect("envelope_seal");
+    let secret_key = kp
+        .secret_key()
+        .expose_secret(lib_q_core::SecretExportConsent)
+        .to_vec();
+    let opened = envelope_open(secret_key.clone(), &sealed, b"aad").expect("envelope_open");
+    assert_eq!(opened, b"sealed in wasm");
+
+    assert!(envelope_open(secret_key, &sealed, b"other").is_err());
+    assert!(
+        envelope_seal(
+            kp.public_key().as_bytes(),
+            "sha3-256",
+            "shake256-aead",
+            b"m",
+            b""
+        )
+        .is_err()
+    );
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn umbrella_aead_roundtrip_and_auth_failure() {
+    use libq::wasm::{
+        aead_decrypt,
+        aead_encrypt,
+    };
+
+    let key = vec![0x11; 32];
+    let nonce = vec![0x22; 16];
+    let ciphertext = aead_encrypt(
+        "saturnin",
+        key.clone(),
+        nonce.clone(),
+        b"wasm aead".to_vec(),
+        b"header".to_vec(),
+    )
+    .expect("encrypt");
+    let plaintext = aead_decrypt(
+        "saturnin",
+        key.clone(),
+        nonce.clone(),
+        ciphertext.clone(),
+        b"header".to_vec(),
+    )
+    .expect("decrypt");
+    assert_eq!(plaintext, b"wasm aead");
+
+    let error_code = |error: wasm_bindgen::JsValue| {
+        js_sys::Reflect::get(&error, &"code".into())
+            .ok()
+            .and_then(|code| code.as_string())
+    };
+    let forged = aead_decrypt(
+        "saturnin",
+        key.clone(),
+        nonce.clone(),
+        ciphertext,
+        b"other".to_vec(),
+    )
+    .expect_err("wrong AAD must fail");
+    assert_eq!(error_code(forged).as_deref(), Some("LIB_Q_AEAD_AUTH"));
+
+    let short_key = aead_encrypt("saturnin", vec![0x11; 16], nonce, b"m".to_vec(), Vec::new())
+        .expect_err("short key must fail");
+    assert_eq!(error_code(short_key).as_deref(), Some("LIB_Q_AEAD"));
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn umbrella_hash_xof_squeezes_requested_length() {
+    use libq::wasm::hash_xof;
+
+    let short = hash_xof("shake256", b"wasm xof", 16).expect("16-byte squeeze");
+    let long = hash_xof("shake256", b"wasm xof", 64).expect("64-byte squeeze");
+    assert_eq!(short.len(), 16);
+    assert_eq!(long.len(), 64);
+    assert_eq!(short, long[..16]);
+
+    let fixed = hash_xof("sha3-256", b"wasm xof", 32).expect_err("SHA3-256 is not a XOF");
+    assert!(fixed.as_string().is_some());
+}
+
 #[cfg(not(all(target_arch = "wasm32", feature = "wasm", feature = "ml-kem")))]
 #[test]
 fn wasm_integration_skipped_on_native_host() {}